@@ -1,3 +1,4 @@
+use super::offer::parse_outpoint;
 use super::types::{ContractArgs, ContractSubCommand};
 use anyhow::{anyhow, Result};
 use ckb_sdk::{Address, AddressPayload, HttpRpcClient, HumanCapacity, SECP256K1};
@@ -10,6 +11,7 @@ use tockb_sdk::indexer::IndexerRpcClient;
 use tockb_sdk::tx_helper::sign;
 use tockb_sdk::util::{ensure_indexer_sync, parse_privkey_path, send_tx_sync};
 use tockb_sdk::{generator::Generator, settings::Settings};
+use tockb_types::{SignerGroupView, SignerMemberView};
 
 pub fn parse_cell(cell: &str) -> Result<Script> {
     let cell_bytes =
@@ -19,6 +21,33 @@ pub fn parse_cell(cell: &str) -> Result<Script> {
     Ok(cell_typescript)
 }
 
+pub fn parse_signer_group(threshold: Option<u8>, members: &[String]) -> Result<SignerGroupView> {
+    if members.is_empty() {
+        return Ok(Default::default());
+    }
+    let threshold = threshold.ok_or_else(|| anyhow!("threshold is required by signer group"))?;
+    let members = members
+        .iter()
+        .map(|member| {
+            let parts: Vec<&str> = member.split(':').collect();
+            if parts.len() != 3 {
+                return Err(anyhow!("signer group member format err: {}", member));
+            }
+            let lockscript = Script::from(
+                Address::from_str(parts[0])
+                    .map_err(|e| anyhow!(e))?
+                    .payload(),
+            );
+            Ok(SignerMemberView {
+                lockscript: lockscript.as_bytes(),
+                share: parts[1].parse()?,
+                x_pubkey: hex::decode(parts[2])?.into(),
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+    Ok(SignerGroupView { threshold, members })
+}
+
 pub fn contract_tx_generator(
     config_path: String,
     rpc_url: String,
@@ -44,14 +73,29 @@ pub fn contract_tx_generator(
                     .unwrap()
                     .payload(),
             );
+            let signer_offer = match args.offer {
+                Some(offer) => Some(
+                    generator
+                        .get_signer_offer(parse_outpoint(&offer)?)
+                        .map_err(|e| anyhow!(e))?,
+                ),
+                None => None,
+            };
+            let signer_fee_rate = args.signer_fee_rate.unwrap_or_else(|| {
+                signer_offer
+                    .as_ref()
+                    .map_or(200, |offer| offer.signer_fee_rate)
+            });
             generator
                 .deposit_request(
                     from_lockscript,
                     tx_fee,
                     user_lockscript,
-                    args.pledge,
                     args.kind,
-                    args.lot_size,
+                    args.amount,
+                    signer_fee_rate,
+                    args.token_address,
+                    signer_offer,
                 )
                 .unwrap()
         }
@@ -62,6 +106,7 @@ pub fn contract_tx_generator(
                     .payload(),
             );
             let cell_typescript = parse_cell(&args.cell)?;
+            let signer_group = parse_signer_group(args.threshold, &args.member)?;
             generator
                 .bonding(
                     from_lockscript,
@@ -69,6 +114,7 @@ pub fn contract_tx_generator(
                     cell_typescript,
                     signer_lockscript,
                     args.lock_address,
+                    signer_group,
                 )
                 .unwrap()
         }
@@ -93,9 +139,43 @@ pub fn contract_tx_generator(
                     cell_typescript,
                     args.unlock_address,
                     redeemer_lockscript,
+                    args.redeem_amount,
                 )
                 .unwrap()
         }
+        ContractSubCommand::TopupCollateral(args) => {
+            let cell_typescript = parse_cell(&args.cell)?;
+            let capacity: u64 = HumanCapacity::from_str(&args.capacity)
+                .map_err(|e| anyhow!(e))?
+                .into();
+            generator
+                .topup_collateral(from_lockscript, tx_fee, cell_typescript, capacity)
+                .unwrap()
+        }
+        ContractSubCommand::SignerHandoverRequest(args) => {
+            let cell_typescript = parse_cell(&args.cell)?;
+            let new_signer_lockscript = Script::from(
+                Address::from_str(&args.new_signer_lockscript_addr)
+                    .map_err(|e| anyhow!(e))?
+                    .payload(),
+            );
+            generator
+                .signer_handover_request(
+                    from_lockscript,
+                    tx_fee,
+                    cell_typescript,
+                    new_signer_lockscript,
+                    args.lock_address,
+                )
+                .map_err(|e| anyhow!(e))?
+        }
+        ContractSubCommand::SignerHandover(args) => {
+            let cell_typescript = parse_cell(&args.cell)?;
+            let spv_proof = hex::decode(&args.spv_proof)?;
+            generator
+                .signer_handover(from_lockscript, tx_fee, cell_typescript, spv_proof)
+                .map_err(|e| anyhow!(e))?
+        }
         ContractSubCommand::WithdrawCollateral(args) => {
             let cell_typescript = parse_cell(&args.cell)?;
             let spv_proof = hex::decode(&args.spv_proof)?;
@@ -103,6 +183,19 @@ pub fn contract_tx_generator(
                 .withdraw_collateral(from_lockscript, tx_fee, cell_typescript, spv_proof)
                 .unwrap()
         }
+        ContractSubCommand::WithdrawPartialCollateral(args) => {
+            let cell_typescript = parse_cell(&args.cell)?;
+            let spv_proof = hex::decode(&args.spv_proof)?;
+            generator
+                .withdraw_partial_collateral(
+                    from_lockscript,
+                    tx_fee,
+                    cell_typescript,
+                    spv_proof,
+                    args.change_vout_index,
+                )
+                .map_err(|e| anyhow!(e))?
+        }
     };
     Ok(unsigned_tx)
 }
@@ -1,19 +1,28 @@
 pub mod contract;
+pub mod offer;
+pub mod relay;
 pub mod server;
 pub mod sudt;
 pub mod types;
 
 use anyhow::{anyhow, Result};
 use ckb_hash::blake2b_256;
-use ckb_sdk::HttpRpcClient;
+use ckb_sdk::{AddressPayload, HttpRpcClient, SECP256K1};
+use ckb_types::packed::Script;
 use contract::contract_handler;
 use molecule::prelude::{Builder, Entity};
+use offer::offer_handler;
+use relay::relay_handler;
 use server::server_handler;
 use sudt::sudt_handler;
+use tockb_sdk::generator::Generator;
 use tockb_sdk::indexer::IndexerRpcClient;
-use tockb_sdk::settings::{BtcDifficulty, OutpointConf, PriceOracle, ScriptConf, Settings};
-use tockb_sdk::tx_helper::deploy;
-use tockb_sdk::util::{parse_privkey_path, send_tx_sync};
+use tockb_sdk::settings::{
+    BtcDifficulty, BtcHeaderRelay, OutpointConf, ScriptConf, Settings, SignerRegistry,
+};
+use tockb_sdk::tx_helper::{deploy, sign};
+use tockb_sdk::util::{build_signed_price, ensure_indexer_sync, parse_privkey_path, send_tx_sync};
+use tockb_types::config::{CKB_UNITS, PRICE_ORACLE_THRESHOLD};
 use tockb_types::generated::btc_difficulty::BTCDifficulty;
 use types::{DevInitArgs, InitArgs, Opts, SubCommand};
 
@@ -24,6 +33,8 @@ pub fn handler(opt: Opts) -> Result<()> {
         SubCommand::Contract(args) => contract_handler(args),
         SubCommand::Sudt(args) => sudt_handler(args),
         SubCommand::Server(args) => server_handler(args),
+        SubCommand::Relay(args) => relay_handler(args),
+        SubCommand::Offer(args) => offer_handler(args),
         _ => todo!(),
     }
 }
@@ -55,6 +66,10 @@ pub fn dev_init_handler(args: DevInitArgs) -> Result<()> {
         price,
         btc_difficulty,
         sudt_path,
+        btc_network,
+        ltc_network,
+        relay_path,
+        registry_path,
     } = args;
     if std::path::Path::new(&config_path).exists() && !force {
         return Err(anyhow!(
@@ -72,12 +87,18 @@ pub fn dev_init_handler(args: DevInitArgs) -> Result<()> {
     let typescript_bin = std::fs::read(typescript_path)?;
     let lockscript_bin = std::fs::read(lockscript_path)?;
     let sudt_bin = std::fs::read(sudt_path)?;
+    let relay_bin = std::fs::read(relay_path)?;
+    let registry_bin = std::fs::read(registry_path)?;
     let typescript_code_hash = blake2b_256(&typescript_bin);
     let typescript_code_hash_hex = hex::encode(&typescript_code_hash);
     let lockscript_code_hash = blake2b_256(&lockscript_bin);
     let lockscript_code_hash_hex = hex::encode(&lockscript_code_hash);
     let sudt_code_hash = blake2b_256(&sudt_bin);
     let sudt_code_hash_hex = hex::encode(&sudt_code_hash);
+    let relay_code_hash = blake2b_256(&relay_bin);
+    let relay_code_hash_hex = hex::encode(&relay_code_hash);
+    let registry_code_hash = blake2b_256(&registry_bin);
+    let registry_code_hash_hex = hex::encode(&registry_code_hash);
     let btc_difficulty_bytes = BTCDifficulty::new_builder()
         .previous(btc_difficulty.to_le_bytes().to_vec().into())
         .current(btc_difficulty.to_le_bytes().to_vec().into())
@@ -88,14 +109,15 @@ pub fn dev_init_handler(args: DevInitArgs) -> Result<()> {
         typescript_bin,
         lockscript_bin,
         sudt_bin,
-        price.to_le_bytes().to_vec(),
         btc_difficulty_bytes,
+        relay_bin,
+        registry_bin,
     ];
 
     let tx = deploy(&mut rpc_client, &mut indexer_client, &private_key, data).unwrap();
     let tx_hash = send_tx_sync(&mut rpc_client, &tx, 60).unwrap();
     let tx_hash_hex = hex::encode(tx_hash.as_bytes());
-    let settings = Settings {
+    let mut settings = Settings {
         typescript: ScriptConf {
             code_hash: typescript_code_hash_hex,
             outpoint: OutpointConf {
@@ -117,19 +139,130 @@ pub fn dev_init_handler(args: DevInitArgs) -> Result<()> {
                 index: 2,
             },
         },
-        price_oracle: PriceOracle {
+        price_oracle: Default::default(),
+        btc_difficulty_cell: BtcDifficulty {
             outpoint: OutpointConf {
                 tx_hash: tx_hash_hex.clone(),
                 index: 3,
             },
         },
-        btc_difficulty_cell: BtcDifficulty {
-            outpoint: OutpointConf {
-                tx_hash: tx_hash_hex.clone(),
-                index: 4,
+        btc_network,
+        btc_header_relay: BtcHeaderRelay {
+            script: ScriptConf {
+                code_hash: relay_code_hash_hex,
+                outpoint: OutpointConf {
+                    tx_hash: tx_hash_hex.clone(),
+                    index: 4,
+                },
+            },
+            type_args: "".to_owned(),
+        },
+        protocol_params: Default::default(),
+        lock_registry: Default::default(),
+        ltc_network,
+        ltc_difficulty_cell: Default::default(),
+        signer_registry: SignerRegistry {
+            script: ScriptConf {
+                code_hash: registry_code_hash_hex,
+                outpoint: OutpointConf {
+                    tx_hash: tx_hash_hex.clone(),
+                    index: 5,
+                },
             },
         },
     };
+
+    // the dev key is the governance of the default protocol params, the typescript must be built
+    // with the type hash of the params cell as PROTOCOL_PARAMS_TYPE_HASH
+    ensure_indexer_sync(&mut rpc_client, &mut indexer_client, 60).unwrap();
+    let public_key = secp256k1::PublicKey::from_secret_key(&SECP256K1, &private_key);
+    let from_lockscript = Script::from(&AddressPayload::from_pubkey(&public_key));
+    let mut generator = Generator::new(rpc_url.clone(), indexer_url.clone(), settings.clone())
+        .map_err(|e| anyhow!(e))?;
+    let unsigned_tx = generator
+        .create_protocol_params(
+            from_lockscript.clone(),
+            CKB_UNITS / 10,
+            from_lockscript.clone(),
+            300 * CKB_UNITS,
+            Default::default(),
+        )
+        .map_err(|e| anyhow!(e))?;
+    let tx = sign(unsigned_tx, &mut rpc_client, &private_key).map_err(|e| anyhow!(e))?;
+    send_tx_sync(&mut rpc_client, &tx, 60).map_err(|e| anyhow!(e))?;
+    let params_typescript = tx
+        .output(0)
+        .unwrap()
+        .type_()
+        .to_opt()
+        .expect("protocol params cell should have typescript");
+    settings.protocol_params.type_args = hex::encode(params_typescript.args().raw_data().as_ref());
+    println!(
+        "protocol params type hash: {}",
+        hex::encode(params_typescript.calc_script_hash().as_slice())
+    );
+
+    // sign the same price with dev publisher keys, they go stale after PRICE_ORACLE_MAX_AGE. the
+    // price cells are owned by the dev key, the typescript must be built with their type hashes
+    // as PRICE_ORACLE_TYPE_HASHES
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs();
+    for i in 1..=PRICE_ORACLE_THRESHOLD as u8 {
+        let publisher_key = secp256k1::SecretKey::from_slice(&[i; 32])?;
+        let signed_price = build_signed_price(&publisher_key, price, now)?;
+        ensure_indexer_sync(&mut rpc_client, &mut indexer_client, 60).unwrap();
+        let mut generator = Generator::new(rpc_url.clone(), indexer_url.clone(), settings.clone())
+            .map_err(|e| anyhow!(e))?;
+        let unsigned_tx = generator
+            .create_price_oracle(
+                from_lockscript.clone(),
+                CKB_UNITS / 10,
+                from_lockscript.clone(),
+                200 * CKB_UNITS,
+                signed_price,
+            )
+            .map_err(|e| anyhow!(e))?;
+        let tx = sign(unsigned_tx, &mut rpc_client, &private_key).map_err(|e| anyhow!(e))?;
+        send_tx_sync(&mut rpc_client, &tx, 60).map_err(|e| anyhow!(e))?;
+        let price_typescript = tx
+            .output(0)
+            .unwrap()
+            .type_()
+            .to_opt()
+            .expect("price cell should have typescript");
+        settings
+            .price_oracle
+            .type_args
+            .push(hex::encode(price_typescript.args().raw_data().as_ref()));
+        println!(
+            "price oracle type hash of publisher {}: {}",
+            i,
+            hex::encode(price_typescript.calc_script_hash().as_slice())
+        );
+    }
+
+    // the lock registry type hash must be built as LOCK_REGISTRY_TYPE_HASH
+    ensure_indexer_sync(&mut rpc_client, &mut indexer_client, 60).unwrap();
+    let mut generator =
+        Generator::new(rpc_url, indexer_url, settings.clone()).map_err(|e| anyhow!(e))?;
+    let unsigned_tx = generator
+        .create_lock_registry(from_lockscript, CKB_UNITS / 10, 200 * CKB_UNITS)
+        .map_err(|e| anyhow!(e))?;
+    let tx = sign(unsigned_tx, &mut rpc_client, &private_key).map_err(|e| anyhow!(e))?;
+    send_tx_sync(&mut rpc_client, &tx, 60).map_err(|e| anyhow!(e))?;
+    let registry_typescript = tx
+        .output(0)
+        .unwrap()
+        .type_()
+        .to_opt()
+        .expect("lock registry cell should have typescript");
+    settings.lock_registry.type_args = hex::encode(registry_typescript.args().raw_data().as_ref());
+    println!(
+        "lock registry type hash: {}",
+        hex::encode(registry_typescript.calc_script_hash().as_slice())
+    );
+
     log::info!("settings: {:?}", &settings);
     settings.write(&config_path).map_err(|e| anyhow!(e))?;
     println!("tockb config written to {}", &config_path);
@@ -0,0 +1,127 @@
+use super::types::{OfferArgs, OfferSubCommand};
+use anyhow::{anyhow, Result};
+use ckb_sdk::{AddressPayload, HttpRpcClient, HumanCapacity, SECP256K1};
+use ckb_types::packed::{Byte32, OutPoint, Script};
+use ckb_types::prelude::{Builder, Entity, Pack, Unpack};
+use int_enum::IntEnum;
+use std::str::FromStr;
+use tockb_sdk::indexer::IndexerRpcClient;
+use tockb_sdk::tx_helper::sign;
+use tockb_sdk::util::{ensure_indexer_sync, parse_privkey_path, send_tx_sync};
+use tockb_sdk::{generator::Generator, settings::Settings};
+use tockb_types::signer_offer::SignerOfferView;
+use tockb_types::XChainKind;
+
+/// Parse an outpoint in the format of `<tx_hash hex>:<index>`
+pub fn parse_outpoint(outpoint: &str) -> Result<OutPoint> {
+    let parts: Vec<&str> = outpoint.split(':').collect();
+    if parts.len() != 2 {
+        return Err(anyhow!("outpoint format err: {}", outpoint));
+    }
+    let tx_hash = hex::decode(parts[0].trim_start_matches("0x"))
+        .map_err(|e| anyhow!("tx hash should be hex format, err: {}", e))?;
+    let index: u32 = parts[1].parse()?;
+    Ok(OutPoint::new_builder()
+        .tx_hash(Byte32::from_slice(&tx_hash).map_err(|e| anyhow!("tx hash err: {}", e))?)
+        .index(index.pack())
+        .build())
+}
+
+pub fn offer_handler(args: OfferArgs) -> Result<()> {
+    let mut rpc_client = HttpRpcClient::new(args.rpc_url.clone());
+    let mut indexer_client = IndexerRpcClient::new(args.indexer_url.clone());
+    ensure_indexer_sync(&mut rpc_client, &mut indexer_client, 60).unwrap();
+    let settings = Settings::new(&args.config_path)?;
+    let mut generator = Generator::new(args.rpc_url.clone(), args.indexer_url.clone(), settings)
+        .map_err(|e| anyhow!(e))?;
+    let tx_fee: u64 = HumanCapacity::from_str(&args.tx_fee)
+        .map_err(|e| anyhow!(e))?
+        .into();
+
+    let unsigned_tx = match args.subcmd {
+        OfferSubCommand::List(list_args) => {
+            let offers = generator
+                .get_signer_offers(list_args.kind, list_args.amount)
+                .map_err(|e| anyhow!(e))?;
+            let offers = offers
+                .into_iter()
+                .map(|(outpoint, offer)| {
+                    let index: u32 = outpoint.index().unpack();
+                    let tx_hash = hex::encode(outpoint.tx_hash().as_slice());
+                    let chain_kinds = offer
+                        .chain_kinds
+                        .iter()
+                        .map(|kind| kind.int_value())
+                        .collect::<Vec<_>>();
+                    serde_json::json!({
+                        "offer": format!("{}:{}", tx_hash, index),
+                        "chain_kinds": chain_kinds,
+                        "min_lot_amount": offer.min_lot_amount.to_string(),
+                        "max_lot_amount": offer.max_lot_amount.to_string(),
+                        "signer_fee_rate": offer.signer_fee_rate,
+                        "x_lock_address": String::from_utf8_lossy(offer.x_lock_address.as_ref()),
+                        "collateral": HumanCapacity(offer.collateral).to_string(),
+                        "signer_lockscript": hex::encode(offer.signer_lockscript.as_ref()),
+                    })
+                })
+                .collect::<Vec<_>>();
+            println!("{}", serde_json::to_string_pretty(&offers)?);
+            return Ok(());
+        }
+        OfferSubCommand::Publish(publish_args) => {
+            let from_lockscript = get_from_lockscript(&args.private_key_path)?;
+            let chain_kinds = publish_args
+                .kind
+                .iter()
+                .map(|kind| {
+                    XChainKind::from_int(*kind)
+                        .map_err(|_| anyhow!("invalid xchain kind: {}", kind))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            let collateral: u64 = HumanCapacity::from_str(&publish_args.collateral)
+                .map_err(|e| anyhow!(e))?
+                .into();
+            let offer = SignerOfferView {
+                chain_kinds,
+                min_lot_amount: publish_args.min_lot_amount,
+                max_lot_amount: publish_args.max_lot_amount,
+                signer_fee_rate: publish_args.signer_fee_rate,
+                x_lock_address: publish_args.x_lock_address.into(),
+                collateral,
+                signer_lockscript: from_lockscript.as_bytes(),
+            };
+            generator
+                .publish_signer_offer(from_lockscript, tx_fee, offer)
+                .map_err(|e| anyhow!(e))?
+        }
+        OfferSubCommand::Withdraw(withdraw_args) => {
+            let from_lockscript = get_from_lockscript(&args.private_key_path)?;
+            let offer_outpoint = parse_outpoint(&withdraw_args.offer)?;
+            generator
+                .withdraw_signer_offer(from_lockscript, tx_fee, offer_outpoint)
+                .map_err(|e| anyhow!(e))?
+        }
+    };
+
+    let private_key_path = args
+        .private_key_path
+        .ok_or_else(|| anyhow!("private key is required to sign the tx"))?;
+    let from_privkey = parse_privkey_path(&private_key_path)?;
+    let tx = sign(unsigned_tx, &mut rpc_client, &from_privkey).map_err(|e| anyhow!(e))?;
+    send_tx_sync(&mut rpc_client, &tx, 60).map_err(|e| anyhow!(e))?;
+    let print_res = serde_json::json!({
+        "tx_hash": hex::encode(tx.hash().as_slice()),
+    });
+    println!("{}", serde_json::to_string_pretty(&print_res)?);
+    Ok(())
+}
+
+fn get_from_lockscript(private_key_path: &Option<String>) -> Result<Script> {
+    let private_key_path = private_key_path
+        .as_ref()
+        .ok_or_else(|| anyhow!("private key is required to sign the tx"))?;
+    let from_privkey = parse_privkey_path(private_key_path)?;
+    let from_public_key = secp256k1::PublicKey::from_secret_key(&SECP256K1, &from_privkey);
+    let address_payload = AddressPayload::from_pubkey(&from_public_key);
+    Ok(Script::from(&address_payload))
+}
@@ -0,0 +1,142 @@
+use super::types::{RelayArgs, RelaySubCommand};
+use anyhow::{anyhow, Result};
+use bitcoin::hashes::Hash;
+use bitcoincore_rpc::{Auth, Client, RpcApi};
+use ckb_sdk::{AddressPayload, HttpRpcClient, HumanCapacity, SECP256K1};
+use ckb_types::packed::Script;
+use ckb_types::prelude::Entity;
+use std::str::FromStr;
+use tockb_sdk::indexer::IndexerRpcClient;
+use tockb_sdk::tx_helper::sign;
+use tockb_sdk::util::{ensure_indexer_sync, parse_privkey_path, send_tx_sync};
+use tockb_sdk::{generator::Generator, settings::Settings};
+use tockb_types::btc_relay::{BtcRelayEntry, BtcRelayView};
+
+const DIFFICULTY_ADJUSTMENT_INTERVAL: u64 = 2016;
+
+pub fn relay_handler(args: RelayArgs) -> Result<()> {
+    let mut rpc_client = HttpRpcClient::new(args.rpc_url.clone());
+    let mut indexer_client = IndexerRpcClient::new(args.indexer_url.clone());
+    ensure_indexer_sync(&mut rpc_client, &mut indexer_client, 60).unwrap();
+    let btc_client = Client::new(
+        args.btc_rpc_url.clone(),
+        Auth::UserPass(args.btc_rpc_user.clone(), args.btc_rpc_password.clone()),
+    )?;
+
+    let from_privkey = parse_privkey_path(&args.private_key_path)?;
+    let from_public_key = secp256k1::PublicKey::from_secret_key(&SECP256K1, &from_privkey);
+    let address_payload = AddressPayload::from_pubkey(&from_public_key);
+    let from_lockscript = Script::from(&address_payload);
+    let tx_fee: u64 = HumanCapacity::from_str(&args.tx_fee)
+        .map_err(|e| anyhow!(e))?
+        .into();
+
+    match args.subcmd {
+        RelaySubCommand::Init(init_args) => {
+            let mut settings = Settings::new(&args.config_path)?;
+            let mut generator = Generator::new(
+                args.rpc_url.clone(),
+                args.indexer_url.clone(),
+                settings.clone(),
+            )
+            .map_err(|e| anyhow!(e))?;
+            let capacity: u64 = HumanCapacity::from_str(&init_args.capacity)
+                .map_err(|e| anyhow!(e))?
+                .into();
+            let checkpoint = get_checkpoint(&btc_client, init_args.height)?;
+            let unsigned_tx = generator
+                .create_btc_header_relay(from_lockscript, tx_fee, capacity, checkpoint)
+                .map_err(|e| anyhow!(e))?;
+            let tx = sign(unsigned_tx, &mut rpc_client, &from_privkey).map_err(|e| anyhow!(e))?;
+            send_tx_sync(&mut rpc_client, &tx, 60).map_err(|e| anyhow!(e))?;
+
+            let relay_typescript = tx
+                .output(0)
+                .unwrap()
+                .type_()
+                .to_opt()
+                .expect("relay cell should have typescript");
+            settings.btc_header_relay.type_args =
+                hex::encode(relay_typescript.args().raw_data().as_ref());
+            settings.write(&args.config_path).map_err(|e| anyhow!(e))?;
+            let print_res = serde_json::json!({
+                "tx_hash": hex::encode(tx.hash().as_slice()),
+                "relay_typescript": hex::encode(relay_typescript.as_slice()),
+            });
+            println!("{}", serde_json::to_string_pretty(&print_res)?);
+            Ok(())
+        }
+        RelaySubCommand::Push(push_args) => loop {
+            let settings = Settings::new(&args.config_path)?;
+            let mut generator =
+                Generator::new(args.rpc_url.clone(), args.indexer_url.clone(), settings)
+                    .map_err(|e| anyhow!(e))?;
+            let (_, relay) = generator.get_btc_header_relay().map_err(|e| anyhow!(e))?;
+            let raw_headers = get_new_headers(&btc_client, &relay, push_args.max_headers)?;
+            if raw_headers.is_empty() {
+                log::info!("relay is up to date at height {}", relay.height);
+            } else {
+                let unsigned_tx = generator
+                    .update_btc_header_relay(from_lockscript.clone(), tx_fee, raw_headers)
+                    .map_err(|e| anyhow!(e))?;
+                let tx =
+                    sign(unsigned_tx, &mut rpc_client, &from_privkey).map_err(|e| anyhow!(e))?;
+                send_tx_sync(&mut rpc_client, &tx, 60).map_err(|e| anyhow!(e))?;
+                println!(
+                    "pushed headers to relay, tx_hash: {}",
+                    hex::encode(tx.hash().as_slice())
+                );
+            }
+            if !push_args.watch {
+                return Ok(());
+            }
+            std::thread::sleep(std::time::Duration::from_secs(push_args.interval));
+        },
+    }
+}
+
+/// The relay trusts the block at `height` and the start of its difficulty epoch
+fn get_checkpoint(btc_client: &Client, height: u64) -> Result<BtcRelayView> {
+    let header = btc_client.get_block_header(&btc_client.get_block_hash(height)?)?;
+    let epoch_start_height = height - height % DIFFICULTY_ADJUSTMENT_INTERVAL;
+    let epoch_start_header =
+        btc_client.get_block_header(&btc_client.get_block_hash(epoch_start_height)?)?;
+    Ok(BtcRelayView {
+        height,
+        epoch_start_time: epoch_start_header.time,
+        epoch_bits: epoch_start_header.bits,
+        headers: vec![BtcRelayEntry {
+            hash: header.block_hash().into_inner(),
+            // only the chain work relative to the checkpoint matters
+            chain_work: Default::default(),
+            time: header.time,
+            bits: header.bits,
+        }],
+    })
+}
+
+/// Return serialized headers of the bitcoind best chain after the last header of the relay
+/// which is still in the best chain.
+fn get_new_headers(btc_client: &Client, relay: &BtcRelayView, max_headers: u64) -> Result<Vec<u8>> {
+    let best_height = btc_client.get_block_count()?;
+    let mut fork_height = None;
+    for (i, entry) in relay.headers.iter().enumerate().rev() {
+        let height = relay.height - (relay.headers.len() - 1 - i) as u64;
+        if height <= best_height && btc_client.get_block_hash(height)?.into_inner() == entry.hash {
+            fork_height = Some(height);
+            break;
+        }
+    }
+    let fork_height =
+        fork_height.ok_or(anyhow!("relay forks from bitcoind before the kept headers"))?;
+    if best_height <= relay.height {
+        return Ok(vec![]);
+    }
+
+    let mut raw_headers = vec![];
+    for height in (fork_height + 1)..=best_height.min(fork_height + max_headers) {
+        let header = btc_client.get_block_header(&btc_client.get_block_hash(height)?)?;
+        raw_headers.extend(bitcoin::consensus::serialize(&header));
+    }
+    Ok(raw_headers)
+}
@@ -27,6 +27,7 @@ pub fn sudt_handler(args: SudtArgs) -> Result<()> {
         .map_err(|e| anyhow::anyhow!(e))?;
 
     let kind = args.kind;
+    let token_address = args.token_address;
 
     match args.subcmd {
         SudtSubCommand::Transfer(args) => {
@@ -47,6 +48,7 @@ pub fn sudt_handler(args: SudtArgs) -> Result<()> {
                 .transfer_sudt(
                     from_lockscript,
                     kind,
+                    token_address,
                     to_lockscript,
                     args.sudt_amount,
                     ckb_amount,
@@ -72,7 +74,9 @@ pub fn sudt_handler(args: SudtArgs) -> Result<()> {
             Ok(())
         }
         SudtSubCommand::GetBalance(args) => {
-            let balance = generator.get_sudt_balance(args.addr, kind).unwrap();
+            let balance = generator
+                .get_sudt_balance(args.addr, kind, token_address)
+                .unwrap();
             println!("{:?}", balance);
             Ok(())
         }
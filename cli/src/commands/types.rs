@@ -17,6 +17,8 @@ pub enum SubCommand {
     Contract(ContractArgs),
     Sudt(SudtArgs),
     Server(ServerArgs),
+    Relay(RelayArgs),
+    Offer(OfferArgs),
 }
 
 #[derive(Clap, Clone, Debug)]
@@ -64,6 +66,14 @@ pub struct DevInitArgs {
     pub price: u128,
     #[clap(short = 'd', long)]
     pub btc_difficulty: u64,
+    #[clap(long, default_value = "regtest", possible_values = &["mainnet", "testnet", "signet", "regtest"])]
+    pub btc_network: String,
+    #[clap(long, default_value = "regtest", possible_values = &["mainnet", "testnet", "regtest"])]
+    pub ltc_network: String,
+    #[clap(long, default_value = "../build/release/btc-header-relay")]
+    pub relay_path: String,
+    #[clap(long, default_value = "../build/release/signer-registry")]
+    pub registry_path: String,
 }
 
 #[derive(Clap, Clone, Debug)]
@@ -93,7 +103,11 @@ pub enum ContractSubCommand {
     Bonding(BondingArgs),
     MintXt(MintXTArgs),
     PreTermRedeem(PreTermRedeemArgs),
+    TopupCollateral(TopupCollateralArgs),
+    SignerHandoverRequest(SignerHandoverRequestArgs),
+    SignerHandover(SignerHandoverArgs),
     WithdrawCollateral(WithdrawCollateralArgs),
+    WithdrawPartialCollateral(WithdrawPartialCollateralArgs),
 }
 
 #[derive(Clap, Clone, Debug, Serialize, Deserialize)]
@@ -101,11 +115,22 @@ pub struct DepositRequestArgs {
     #[clap(short, long)]
     pub user_lockscript_addr: String,
     #[clap(short, long)]
-    pub pledge: u64,
-    #[clap(short, long)]
     pub kind: u8,
+    /// XT amount in base units, i.e. satoshi for BTC, wei for ETH, litoshi for LTC and the
+    /// smallest unit of the token for ERC-20
     #[clap(short, long)]
-    pub lot_size: u8,
+    pub amount: u128,
+    /// hex address of the token contract, required by ERC-20 deposits
+    #[clap(long)]
+    pub token_address: Option<String>,
+    /// signer fee rate proposed to the signer, in units of 1/100000 of the amount, defaults to
+    /// the fee rate of the offer, or 200 without one
+    #[clap(long)]
+    pub signer_fee_rate: Option<u32>,
+    /// outpoint of the signer offer as `<tx_hash hex>:<index>`, only the signer of the offer can
+    /// bond the deposit within the bonding window
+    #[clap(long)]
+    pub offer: Option<String>,
 }
 
 #[derive(Clap, Clone, Debug, Serialize, Deserialize)]
@@ -117,6 +142,13 @@ pub struct BondingArgs {
     pub signer_lockscript_addr: String,
     #[clap(short, long)]
     pub lock_address: String,
+    /// M of the signer group multisig, required with members
+    #[clap(long)]
+    pub threshold: Option<u8>,
+    /// signer group member as `<lockscript address>:<share>:<compressed pubkey hex>`,
+    /// the first member is the signer
+    #[clap(long)]
+    pub member: Vec<String>,
 }
 
 #[derive(Clap, Clone, Debug, Serialize, Deserialize)]
@@ -135,6 +167,37 @@ pub struct PreTermRedeemArgs {
     pub unlock_address: String,
     #[clap(short, long)]
     pub redeemer_lockscript_addr: String,
+    /// XT amount to redeem in base units, the whole lot if not given
+    #[clap(long)]
+    pub redeem_amount: Option<u128>,
+}
+
+#[derive(Clap, Clone, Debug, Serialize, Deserialize)]
+pub struct TopupCollateralArgs {
+    #[clap(short, long)]
+    pub cell: String,
+    /// CKB added to the collateral, e.g. 10000.5
+    #[clap(long)]
+    pub capacity: String,
+}
+
+#[derive(Clap, Clone, Debug, Serialize, Deserialize)]
+pub struct SignerHandoverRequestArgs {
+    #[clap(short, long)]
+    pub cell: String,
+    #[clap(short, long)]
+    pub new_signer_lockscript_addr: String,
+    /// XChain address of the new signer which the locked XAsset is moved to
+    #[clap(short, long)]
+    pub lock_address: String,
+}
+
+#[derive(Clap, Clone, Debug, Serialize, Deserialize)]
+pub struct SignerHandoverArgs {
+    #[clap(short, long)]
+    pub cell: String,
+    #[clap(short, long)]
+    pub spv_proof: String,
 }
 
 #[derive(Clap, Clone, Debug, Serialize, Deserialize)]
@@ -145,6 +208,17 @@ pub struct WithdrawCollateralArgs {
     pub spv_proof: String,
 }
 
+#[derive(Clap, Clone, Debug, Serialize, Deserialize)]
+pub struct WithdrawPartialCollateralArgs {
+    #[clap(short, long)]
+    pub cell: String,
+    #[clap(short, long)]
+    pub spv_proof: String,
+    /// output index of the remainder paid back to the lock address
+    #[clap(long)]
+    pub change_vout_index: u32,
+}
+
 #[derive(Clap, Clone, Debug)]
 pub struct SudtArgs {
     #[clap(long, default_value = "/tmp/.tockb-cli/config.toml")]
@@ -155,6 +229,9 @@ pub struct SudtArgs {
     pub indexer_url: String,
     #[clap(long)]
     pub kind: u8,
+    /// hex address of the token contract, the XT of each ERC-20 token is a different sudt
+    #[clap(long)]
+    pub token_address: Option<String>,
     #[clap(subcommand)]
     pub subcmd: SudtSubCommand,
 }
@@ -186,3 +263,114 @@ pub struct SudtGetBalanceArgs {
     #[clap(short, long)]
     pub addr: String,
 }
+
+/// relay bitcoin headers from bitcoind to the header relay cell
+#[derive(Clap, Clone, Debug)]
+pub struct RelayArgs {
+    #[clap(long, default_value = "/tmp/.tockb-cli/config.toml")]
+    pub config_path: String,
+    #[clap(long, default_value = "http://127.0.0.1:8114")]
+    pub rpc_url: String,
+    #[clap(long, default_value = "http://127.0.0.1:8116")]
+    pub indexer_url: String,
+    #[clap(long, default_value = "0.1")]
+    pub tx_fee: String,
+    #[clap(short = 'k', long)]
+    pub private_key_path: String,
+    #[clap(long, default_value = "http://127.0.0.1:18443")]
+    pub btc_rpc_url: String,
+    #[clap(long, default_value = "")]
+    pub btc_rpc_user: String,
+    #[clap(long, default_value = "")]
+    pub btc_rpc_password: String,
+    #[clap(subcommand)]
+    pub subcmd: RelaySubCommand,
+}
+
+#[derive(Clap, Clone, Debug)]
+pub enum RelaySubCommand {
+    Init(RelayInitArgs),
+    Push(RelayPushArgs),
+}
+
+/// create the header relay cell with the block at `height` as checkpoint
+#[derive(Clap, Clone, Debug)]
+pub struct RelayInitArgs {
+    #[clap(long)]
+    pub height: u64,
+    #[clap(long, default_value = "80000")]
+    pub capacity: String,
+}
+
+/// push new headers of the bitcoind best chain to the header relay cell
+#[derive(Clap, Clone, Debug)]
+pub struct RelayPushArgs {
+    #[clap(long, default_value = "100")]
+    pub max_headers: u64,
+    /// keep pushing headers every `interval` seconds
+    #[clap(short, long)]
+    pub watch: bool,
+    #[clap(long, default_value = "60")]
+    pub interval: u64,
+}
+
+/// publish and look up the offers of signers in the signer registry
+#[derive(Clap, Clone, Debug)]
+pub struct OfferArgs {
+    #[clap(long, default_value = "/tmp/.tockb-cli/config.toml")]
+    pub config_path: String,
+    #[clap(long, default_value = "http://127.0.0.1:8114")]
+    pub rpc_url: String,
+    #[clap(long, default_value = "http://127.0.0.1:8116")]
+    pub indexer_url: String,
+    #[clap(long, default_value = "0.1")]
+    pub tx_fee: String,
+    #[clap(short = 'k', long)]
+    pub private_key_path: Option<String>,
+    #[clap(subcommand)]
+    pub subcmd: OfferSubCommand,
+}
+
+#[derive(Clap, Clone, Debug)]
+pub enum OfferSubCommand {
+    Publish(OfferPublishArgs),
+    Withdraw(OfferWithdrawArgs),
+    List(OfferListArgs),
+}
+
+/// publish an offer of the signer of the private key, the collateral is locked in the offer cell
+#[derive(Clap, Clone, Debug)]
+pub struct OfferPublishArgs {
+    /// xchain kinds the signer takes, e.g. `--kind 1 --kind 3`
+    #[clap(long, required = true)]
+    pub kind: Vec<u8>,
+    #[clap(long)]
+    pub min_lot_amount: u128,
+    #[clap(long)]
+    pub max_lot_amount: u128,
+    /// in units of 1/100000 of the amount
+    #[clap(long)]
+    pub signer_fee_rate: u32,
+    /// xchain address the signer locks deposits to
+    #[clap(long)]
+    pub x_lock_address: String,
+    #[clap(long)]
+    pub collateral: String,
+}
+
+/// withdraw an offer of the signer of the private key
+#[derive(Clap, Clone, Debug)]
+pub struct OfferWithdrawArgs {
+    /// outpoint of the offer as `<tx_hash hex>:<index>`
+    #[clap(long)]
+    pub offer: String,
+}
+
+/// list the offers which accept a deposit, from the lowest signer fee rate
+#[derive(Clap, Clone, Debug)]
+pub struct OfferListArgs {
+    #[clap(short, long)]
+    pub kind: u8,
+    #[clap(short, long)]
+    pub amount: u128,
+}
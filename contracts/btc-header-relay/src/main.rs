@@ -0,0 +1,82 @@
+#![no_std]
+#![no_main]
+#![feature(lang_items)]
+#![feature(alloc_error_handler)]
+#![feature(panic_info_message)]
+
+extern crate alloc;
+
+use alloc::vec::Vec;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::*,
+    debug, default_alloc, entry,
+    high_level::{load_cell_data, load_input_out_point, load_script, load_witness_args, QueryIter},
+};
+use core::result::Result;
+use int_enum::IntEnum;
+use molecule::prelude::Reader;
+use tockb_types::{
+    btc_header_relay::BTCHeaderRelayArgsReader, btc_relay::BtcRelayView, BtcNetwork, Error,
+};
+
+entry!(entry);
+default_alloc!();
+
+/// Program entry
+fn entry() -> i8 {
+    // Call main function and return error code
+    match main() {
+        Ok(_) => 0,
+        Err(err) => err as i8,
+    }
+}
+
+fn main() -> Result<(), Error> {
+    let args = load_script()?.args().raw_data();
+    if BTCHeaderRelayArgsReader::verify(&args, false).is_err() {
+        return Err(Error::Encoding);
+    }
+    let args_reader = BTCHeaderRelayArgsReader::new_unchecked(&args);
+    let network = BtcNetwork::from_int(args_reader.network().as_slice()[0])?;
+    debug!("btc network: {:?}", network);
+
+    let inputs = QueryIter::new(load_cell_data, Source::GroupInput).collect::<Vec<Vec<u8>>>();
+    let outputs = QueryIter::new(load_cell_data, Source::GroupOutput).collect::<Vec<Vec<u8>>>();
+    match (inputs.len(), outputs.len()) {
+        (0, 1) => verify_creation(args_reader.cell_id().as_slice(), &outputs[0]),
+        (1, 1) => verify_update(network, &inputs[0], &outputs[0]),
+        _ => Err(Error::TxInvalid),
+    }
+}
+
+/// The relay starts from a checkpoint trusted by its creator, consuming `cell_id` makes the
+/// relay cell unique so that toCKB cells can refer to it by type hash.
+fn verify_creation(cell_id: &[u8], output: &[u8]) -> Result<(), Error> {
+    let expect_cell_id = load_input_out_point(0, Source::Input)?;
+    if cell_id != expect_cell_id.as_slice() {
+        return Err(Error::CellIDInvalid);
+    }
+    BtcRelayView::new(output)?;
+    Ok(())
+}
+
+/// New headers are carried by the input_type of the witness, the output must be the relay
+/// after connecting them.
+fn verify_update(network: BtcNetwork, input: &[u8], output: &[u8]) -> Result<(), Error> {
+    let witness_args = load_witness_args(0, Source::GroupInput)?.input_type();
+    let raw_headers = match witness_args.to_opt() {
+        Some(headers) => headers.raw_data(),
+        None => return Err(Error::InvalidWitness),
+    };
+    let relay = BtcRelayView::new(input)?;
+    let expect_relay = relay.apply_headers(network, raw_headers.as_ref())?;
+    debug!(
+        "relay tip height: {} -> {}",
+        relay.height, expect_relay.height
+    );
+    if expect_relay.as_molecule_data().as_ref() != output {
+        return Err(Error::HeaderRelayDataInvalid);
+    }
+    Ok(())
+}
@@ -0,0 +1,54 @@
+#![no_std]
+#![no_main]
+#![feature(lang_items)]
+#![feature(alloc_error_handler)]
+#![feature(panic_info_message)]
+
+extern crate alloc;
+
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::*,
+    debug, default_alloc, entry,
+    high_level::{load_cell_capacity, load_cell_data, load_cell_lock, QueryIter},
+};
+use core::result::Result;
+use tockb_types::{signer_offer::SignerOfferView, Error};
+
+entry!(entry);
+default_alloc!();
+
+/// Program entry
+fn entry() -> i8 {
+    // Call main function and return error code
+    match main() {
+        Ok(_) => 0,
+        Err(err) => err as i8,
+    }
+}
+
+/// Offers are checked when they are published or updated, withdrawing an offer is guarded by the
+/// lock of the offer cell alone.
+fn main() -> Result<(), Error> {
+    for (index, data) in QueryIter::new(load_cell_data, Source::GroupOutput).enumerate() {
+        verify_offer(index, &data)?;
+    }
+    Ok(())
+}
+
+/// The offer is signed by the signer, i.e. a cell locked by `signer_lockscript` is consumed, and
+/// the offer cell is locked by the signer with capacity backing the offered collateral.
+fn verify_offer(index: usize, data: &[u8]) -> Result<(), Error> {
+    let offer = SignerOfferView::new(data)?;
+    debug!("signer offer: {:?}", offer);
+    let signer_lockscript = offer.signer_lockscript.as_ref();
+    let is_signed = QueryIter::new(load_cell_lock, Source::Input)
+        .any(|lock| lock.as_slice() == signer_lockscript);
+    if !is_signed || load_cell_lock(index, Source::GroupOutput)?.as_slice() != signer_lockscript {
+        return Err(Error::SignerOfferInvalid);
+    }
+    if load_cell_capacity(index, Source::GroupOutput)? < offer.collateral {
+        return Err(Error::SignerOfferInvalid);
+    }
+    Ok(())
+}
@@ -16,9 +16,14 @@ use core::result::Result;
 entry!(entry);
 default_alloc!();
 
-// first 54 bytes of toCKB typescript molecule bytes:
-// total_size(4 byte) + offset(4 byte) * 3 + code_hash(32 byte) + hash_type(1 byte) + args_size(4 byte) + xchain_kind(1 byte) = 54 byte
-const TOCKB_LOCKSCRIPT_ARGS_LENGTH: usize = 54;
+// first 87 bytes of toCKB typescript molecule bytes:
+// total_size(4 byte) + offset(4 byte) * 3 + code_hash(32 byte) + hash_type(1 byte) + args_size(4 byte) + xchain_kind(1 byte) + xchain_network(1 byte) + header_relay(32 byte) = 87 byte
+const TOCKB_LOCKSCRIPT_ARGS_LENGTH: usize = 87;
+// cells created before the network and header relay fields are locked by the first 54 bytes of
+// the legacy typescript, which end at xchain_kind(1 byte)
+const LEGACY_TOCKB_LOCKSCRIPT_ARGS_LENGTH: usize = 54;
+// the lock of the lock tx registry is the code_hash(32 byte) + hash_type(1 byte) of toCKB typescript
+const REGISTRY_LOCKSCRIPT_ARGS_LENGTH: usize = 33;
 
 /// Program entry
 fn entry() -> i8 {
@@ -59,11 +64,18 @@ fn main() -> Result<(), Error> {
 
 fn verify() -> Result<(), Error> {
     let args: Bytes = load_script()?.args().unpack();
+    if args.len() == REGISTRY_LOCKSCRIPT_ARGS_LENGTH {
+        return verify_registry(&args);
+    }
+    let args_len = if args.len() == LEGACY_TOCKB_LOCKSCRIPT_ARGS_LENGTH {
+        LEGACY_TOCKB_LOCKSCRIPT_ARGS_LENGTH
+    } else {
+        TOCKB_LOCKSCRIPT_ARGS_LENGTH
+    };
     let count = QueryIter::new(load_cell_type, Source::GroupInput)
         .filter(|type_script_opt| {
             type_script_opt.is_none()
-                || (type_script_opt.as_ref().unwrap().as_slice()[0..TOCKB_LOCKSCRIPT_ARGS_LENGTH]
-                    != args[..])
+                || (type_script_opt.as_ref().unwrap().as_slice()[0..args_len] != args[..])
         })
         .count();
     if 0 != count {
@@ -71,3 +83,18 @@ fn verify() -> Result<(), Error> {
     }
     Ok(())
 }
+
+/// The lock tx registry can be spent along with any toCKB cell, the toCKB typescript only
+/// accepts the registry update in mint.
+fn verify_registry(args: &Bytes) -> Result<(), Error> {
+    let has_toCKB_input = QueryIter::new(load_cell_type, Source::Input).any(|type_script_opt| {
+        type_script_opt.map_or(false, |type_script| {
+            type_script.code_hash().as_slice() == &args[..32]
+                && type_script.hash_type().as_slice() == &args[32..]
+        })
+    });
+    if !has_toCKB_input {
+        return Err(Error::InvalidToCKBCell);
+    }
+    Ok(())
+}
@@ -1,7 +1,6 @@
 use crate::switch::ToCKBCellDataTuple;
-use crate::utils::config::SINCE_AT_TERM_REDEEM;
 use crate::utils::transaction::{get_sum_sudt_amount, is_XT_typescript};
-use crate::utils::types::Error;
+use crate::utils::types::{Error, ToCKBCellDataView};
 use crate::utils::verifier::{verify_capacity, verify_data, verify_since_by_value};
 use ckb_std::ckb_constants::Source;
 use ckb_std::error::SysError;
@@ -19,14 +18,14 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
         .expect("outputs contain toCKB cell");
     verify_capacity()?;
     let lot_size = verify_data(input_toCKB_data, output_toCKB_data)?;
-    verify_burn(lot_size)?;
-    verify_since_by_value(SINCE_AT_TERM_REDEEM)
+    verify_burn(lot_size, output_toCKB_data)?;
+    verify_since_by_value(input_toCKB_data.params_snapshot.since_at_term_redeem)
 }
 
-fn verify_burn(lot_size: u128) -> Result<(), Error> {
-    let lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
-    let mut input_sudt_sum: u128 = get_sum_sudt_amount(0, Source::Input, lock_hash.as_ref())?;
-    let mut output_sudt_num = get_sum_sudt_amount(0, Source::Output, lock_hash.as_ref())?;
+fn verify_burn(lot_size: u128, data: &ToCKBCellDataView) -> Result<(), Error> {
+    let xt_args = data.get_xt_args(load_cell_lock_hash(0, Source::GroupInput)?.as_ref());
+    let mut input_sudt_sum: u128 = get_sum_sudt_amount(0, Source::Input, xt_args.as_ref())?;
+    let mut output_sudt_num = get_sum_sudt_amount(0, Source::Output, xt_args.as_ref())?;
     if input_sudt_sum - output_sudt_num != lot_size {
         return Err(Error::XTBurnInvalid);
     }
@@ -1,17 +1,13 @@
 use crate::switch::ToCKBCellDataTuple;
 use crate::utils::{
-    config::{AUCTION_INIT_PERCENT, AUCTION_MAX_TIME, XT_CELL_CAPACITY},
-    transaction::get_sum_sudt_amount,
+    auction::{get_auction_payout, AuctionPayout},
+    config::XT_CELL_CAPACITY,
+    transaction::{get_sum_sudt_amount, OutputMatcher},
     types::{Error, ToCKBCellDataView},
-    verifier::{verify_auction_inputs, verify_since},
-};
-use ckb_std::{
-    ckb_constants::Source,
-    debug,
-    high_level::{load_cell_capacity, load_cell_lock, load_cell_lock_hash},
+    verifier::{get_bidder_lock_hash, verify_auction_inputs, verify_since},
 };
+use ckb_std::{ckb_constants::Source, debug, high_level::load_cell_lock_hash};
 use core::result::Result;
-use molecule::prelude::Entity;
 
 pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
     debug!("begin verify Auction: SignerTimeout");
@@ -19,20 +15,20 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
         .0
         .as_ref()
         .expect("inputs should contain toCKB cell");
-    let toCKB_lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
+    let xt_args = input_data.get_xt_args(load_cell_lock_hash(0, Source::GroupInput)?.as_ref());
 
-    let lot_amount = input_data.get_lot_xt_amount()?;
+    let lot_amount = input_data.get_lot_xt_amount();
 
     debug!("begin verify since");
     let auction_time = verify_since()?;
     debug!("begin verify input");
-    let inputs_xt_amount = verify_auction_inputs(toCKB_lock_hash.as_ref(), lot_amount, 0)?;
+    let inputs_xt_amount = verify_auction_inputs(xt_args.as_ref(), lot_amount, 0)?;
     debug!("begin verify output");
     verify_outputs(
         input_data,
         inputs_xt_amount,
         auction_time,
-        toCKB_lock_hash.as_ref(),
+        xt_args.as_ref(),
         lot_amount,
     )?;
 
@@ -43,78 +39,61 @@ fn verify_outputs(
     input_data: &ToCKBCellDataView,
     inputs_xt_amount: u128,
     auction_time: u64,
-    toCKB_lock_hash: &[u8],
+    xt_args: &[u8],
     lot_amount: u128,
 ) -> Result<(), Error> {
-    // check bidder cell
     debug!("begin check bidder cell");
-    debug!(
-        "auction_time: {}, AUCTION_MAX_TIME: {}",
-        auction_time, AUCTION_MAX_TIME
-    );
-
-    let mut output_index = 0;
-    // - 1. check bidder lock
-    if load_cell_lock_hash(output_index, Source::Output)? != load_cell_lock_hash(1, Source::Input)?
-    {
-        return Err(Error::InvalidAuctionBidderCell);
-    }
-    debug!("1. check bidder lock success! ");
 
     // expect paying ckb to bidder,trigger and signer
     // cap of toCKB_cell ==  XT_CELL_CAPACITY + to_bidder + to_trigger + to_signer
-    let asset_collateral = load_cell_capacity(0, Source::GroupInput)? - XT_CELL_CAPACITY;
-    let mut to_bidder = asset_collateral;
-    let init_collateral = asset_collateral * AUCTION_INIT_PERCENT as u64 / 100;
-    if auction_time < AUCTION_MAX_TIME {
-        to_bidder =
-            init_collateral + (asset_collateral - init_collateral) / AUCTION_MAX_TIME * auction_time
-    }
-
+    let AuctionPayout {
+        collateral: asset_collateral,
+        to_bidder,
+    } = get_auction_payout(auction_time)?;
     let to_trigger = (asset_collateral - to_bidder) / 2;
 
     // the XT_CELL_CAPACITY is provided by signer when bonding
     let to_signer = asset_collateral - to_bidder - to_trigger + XT_CELL_CAPACITY;
 
-    // - 2. check the repayment to bidder
-    // expect bidder_cell_cap == repayment_to_bidder + (cap_sum of inputs_xt_cell)
-    if load_cell_capacity(output_index, Source::Output)? <= to_bidder {
-        return Err(Error::InvalidAuctionBidderCell);
-    }
-    debug!("2. check bidder cell capacity success! ");
-
-    debug!("collateral: {}, ", asset_collateral);
     debug!(
         "to_bidder: {}, to_trigger: {}, to_signer:{}",
         to_bidder, to_trigger, to_signer
     );
 
+    let mut matcher = OutputMatcher::default();
+
     // check trigger cell
-    if to_trigger > 0 {
-        debug!("begin check trigger cell, output_index={}", output_index);
-        output_index += 1;
-        if to_trigger != load_cell_capacity(output_index, Source::Output)?
-            || input_data.liquidation_trigger_lockscript.as_ref()
-                != load_cell_lock(output_index, Source::Output)?.as_slice()
-        {
-            return Err(Error::InvalidTriggerOrSignerCell);
-        }
+    if to_trigger > 0
+        && matcher
+            .claim_cell(
+                input_data.liquidation_trigger_lockscript.as_ref(),
+                to_trigger,
+            )?
+            .is_none()
+    {
+        return Err(Error::InvalidTriggerOrSignerCell);
     }
 
-    // check signer cell
-    if to_signer > 0 {
-        debug!("begin check signer cell, output_index={}", output_index);
-        output_index += 1;
-        if to_signer != load_cell_capacity(output_index, Source::Output)?
-            || input_data.signer_lockscript.as_ref()
-                != load_cell_lock(output_index, Source::Output)?.as_slice()
-        {
+    // check signer cells, a signer group gets one cell per member split by shares
+    for (signer_lockscript, payout) in input_data.get_signer_payouts(to_signer) {
+        if payout > 0 && matcher.claim_cell(signer_lockscript, payout)?.is_none() {
             return Err(Error::InvalidTriggerOrSignerCell);
         }
     }
 
-    // - check XT change, make sure inputs_sudt_amount == outputs_sudt_amount
-    let outputs_xt_amount = get_sum_sudt_amount(output_index + 1, Source::Output, toCKB_lock_hash)?;
+    // check the repayment to bidder, the owner of the XT paying the lot
+    // expect bidder_cell_cap == repayment_to_bidder + (cap_sum of inputs_xt_cell)
+    let bidder_lock_hash = get_bidder_lock_hash(xt_args)?;
+    if matcher
+        .claim_cell_by_lock_hash(bidder_lock_hash.as_ref(), to_bidder)?
+        .is_none()
+    {
+        return Err(Error::InvalidAuctionBidderCell);
+    }
+    debug!("check bidder cell success! ");
+
+    // check XT change, make sure inputs_sudt_amount == outputs_sudt_amount
+    let outputs_xt_amount = get_sum_sudt_amount(0, Source::Output, xt_args)?;
     if inputs_xt_amount - outputs_xt_amount != lot_amount {
         return Err(Error::XTAmountInvalid);
     }
@@ -1,11 +1,13 @@
 use crate::switch::ToCKBCellDataTuple;
 use crate::utils::config::*;
-use crate::utils::transaction::{get_price, XChainKind};
-use crate::utils::types::{Error, ToCKBCellDataView};
-use crate::utils::verifier::verify_btc_address;
+use crate::utils::transaction::{get_price, get_protocol_params};
+use crate::utils::types::{since::RelativeSince, Error, ToCKBCellDataView};
+use crate::utils::xchain::get_xchain_verifier;
+use alloc::vec::Vec;
 use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::prelude::*;
 use ckb_std::debug;
-use ckb_std::high_level::load_cell_capacity;
+use ckb_std::high_level::{load_cell, load_cell_capacity, load_input_since, QueryIter};
 use core::result::Result;
 
 pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
@@ -22,31 +24,71 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
     let amount = verify_data(input_toCKB_data, output_toCKB_data)?;
     debug!("amount {:?}", amount);
 
-    verify_collateral(amount)
+    let collateral = verify_collateral(amount)?;
+    if output_toCKB_data.is_signer_group() {
+        verify_signer_group_funding(output_toCKB_data, collateral)?;
+    }
+    Ok(())
 }
 
 fn verify_data(
     input_toCKB_data: &ToCKBCellDataView,
     out_toCKB_data: &ToCKBCellDataView,
 ) -> Result<u128, Error> {
-    let amount: u128 = match input_toCKB_data.get_xchain_kind() {
-        XChainKind::Btc => {
-            verify_btc_address(out_toCKB_data.x_lock_address.as_ref())?;
-            let btc_lot_size = out_toCKB_data.get_btc_lot_size()?;
-            btc_lot_size.get_sudt_amount()
-        }
-        XChainKind::Eth => {
-            if out_toCKB_data.x_lock_address.as_ref().len() != 20 {
-                return Err(Error::XChainAddressInvalid);
-            }
-            let eth_lot_size = out_toCKB_data.get_eth_lot_size()?;
-            eth_lot_size.get_sudt_amount()
-        }
-    };
+    get_xchain_verifier(input_toCKB_data.get_xchain_kind())
+        .verify_address(out_toCKB_data.x_lock_address.as_ref())?;
     if is_data_mutated(input_toCKB_data, out_toCKB_data) {
         return Err(Error::InvariantDataMutated);
     }
-    Ok(amount)
+    if out_toCKB_data.params_snapshot != get_protocol_params()?.snapshot() {
+        return Err(Error::InvalidDataChange);
+    }
+    if out_toCKB_data.is_signer_group() {
+        verify_signer_group(out_toCKB_data)?;
+    }
+    verify_chosen_signer(input_toCKB_data, out_toCKB_data)?;
+    Ok(out_toCKB_data.get_lot_xt_amount())
+}
+
+/// Within BONDING_WINDOW after the deposit request, only the signer the user chose from the
+/// signer registry can bond, other signers consume the deposit with a relative since of at least
+/// the window.
+fn verify_chosen_signer(
+    input_toCKB_data: &ToCKBCellDataView,
+    out_toCKB_data: &ToCKBCellDataView,
+) -> Result<(), Error> {
+    match input_toCKB_data.get_chosen_signer() {
+        Some(signer) if signer != out_toCKB_data.signer_lockscript.as_ref() => {
+            let since = load_input_since(0, Source::GroupInput)?;
+            debug!("bond by a signer not chosen, since: {:x}", since);
+            match RelativeSince::new(since).and_then(|since| since.as_seconds()) {
+                Ok(seconds) if seconds >= BONDING_WINDOW => Ok(()),
+                _ => Err(Error::SignerNotChosen),
+            }
+        }
+        _ => Ok(()),
+    }
+}
+
+/// A signer group locks the XAsset in the multisig of its members on the XChain, and the first
+/// member acts as `signer_lockscript` for the txs driven by a single signer.
+fn verify_signer_group(out_toCKB_data: &ToCKBCellDataView) -> Result<(), Error> {
+    let group = &out_toCKB_data.signer_group;
+    if group.members.len() > MAX_SIGNER_GROUP_SIZE
+        || group.members[0].lockscript != out_toCKB_data.signer_lockscript
+    {
+        return Err(Error::SignerGroupInvalid);
+    }
+    for (i, member) in group.members.iter().enumerate() {
+        if group.members[..i]
+            .iter()
+            .any(|other| other.lockscript == member.lockscript)
+        {
+            return Err(Error::SignerGroupInvalid);
+        }
+    }
+    get_xchain_verifier(out_toCKB_data.get_xchain_kind())
+        .verify_signer_group_address(out_toCKB_data)
 }
 
 fn is_data_mutated(
@@ -55,10 +97,44 @@ fn is_data_mutated(
 ) -> bool {
     input_toCKB_data.user_lockscript != out_toCKB_data.user_lockscript
         || input_toCKB_data.get_raw_lot_size() != out_toCKB_data.get_raw_lot_size()
+        || input_toCKB_data.get_lot_xt_amount() != out_toCKB_data.get_lot_xt_amount()
         || input_toCKB_data.x_extra != out_toCKB_data.x_extra
+        || input_toCKB_data.signer_fee_rate != out_toCKB_data.signer_fee_rate
+        || input_toCKB_data.pledge != out_toCKB_data.pledge
+}
+
+/// Everything paid back to a signer group is split by shares, so the members fund the collateral
+/// by shares too: the payouts of the collateral sum to it, and each member spends at least its
+/// payout from inputs of its own lock.
+fn verify_signer_group_funding(
+    out_toCKB_data: &ToCKBCellDataView,
+    collateral: u64,
+) -> Result<(), Error> {
+    for (lockscript, payout) in out_toCKB_data.get_signer_payouts(collateral) {
+        let spent = get_sum_capacity_of_lockscript(lockscript, Source::Input);
+        let change = get_sum_capacity_of_lockscript(lockscript, Source::Output);
+        debug!(
+            "member spent {}, change {}, share {}",
+            spent, change, payout
+        );
+        if spent < change + payout {
+            return Err(Error::CollateralInvalid);
+        }
+    }
+    Ok(())
 }
 
-fn verify_collateral(lot_amount: u128) -> Result<(), Error> {
+fn get_sum_capacity_of_lockscript(lockscript: &[u8], source: Source) -> u64 {
+    QueryIter::new(load_cell, source)
+        .filter(|cell| cell.lock().as_slice() == lockscript)
+        .map(|cell| cell.capacity().unpack())
+        .collect::<Vec<u64>>()
+        .into_iter()
+        .sum::<u64>()
+}
+
+/// Returns the collateral the signer side added to the toCKB cell
+fn verify_collateral(lot_amount: u128) -> Result<u64, Error> {
     let input_capacity = load_cell_capacity(0, Source::GroupInput)?;
     let output_capacity = load_cell_capacity(0, Source::GroupOutput)?;
     debug!(
@@ -70,12 +146,15 @@ fn verify_collateral(lot_amount: u128) -> Result<(), Error> {
     }
 
     let price = get_price()?;
-    let expect_collateral: u128 = lot_amount * (COLLATERAL_PERCENT as u128)
+    let collateral_percent = get_protocol_params()?.collateral_percent;
+    let expect_collateral: u128 = lot_amount * (collateral_percent as u128)
         + (2 * XT_CELL_CAPACITY * 100 / CKB_UNITS) as u128 * price;
     let actual_collateral: u128 =
         ((output_capacity - input_capacity) * 100 / CKB_UNITS) as u128 * price;
-    if actual_collateral != expect_collateral {
+    // an arbitrary lot amount is generally not a multiple of the price, so the signer
+    // provides the least capacity, in units of 0.01 CKB, which covers the expected collateral
+    if actual_collateral < expect_collateral || actual_collateral >= expect_collateral + price {
         return Err(Error::CollateralInvalid);
     }
-    Ok(())
+    Ok(output_capacity - input_capacity)
 }
@@ -1,13 +1,15 @@
 use crate::switch::ToCKBCellDataTuple;
 use crate::utils::{
-    config::PLEDGE,
     transaction::*,
     types::{Error, ToCKBCellDataView},
+    xchain::get_xchain_verifier,
 };
 use ckb_std::{
     ckb_constants::Source,
     debug,
-    high_level::{load_cell_capacity, load_input_out_point},
+    high_level::{
+        load_cell_capacity, load_cell_type, load_input_out_point, load_script, QueryIter,
+    },
 };
 use core::result::Result;
 use molecule::prelude::Entity;
@@ -18,33 +20,65 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
         .1
         .as_ref()
         .expect("outputs contain toCKB cell");
-    verify_capacity()?;
+    let params = get_protocol_params()?;
+    verify_capacity(toCKB_data, params.pledge)?;
     debug!("verify capacity success");
-    verify_lot_size(toCKB_data)?;
-    debug!("verify lot size success");
+    verify_lot_amount(toCKB_data)?;
+    debug!("verify lot amount success");
+    verify_signer_fee_rate(toCKB_data, params.max_signer_fee_rate)?;
+    debug!("verify signer fee rate success");
     verify_cell_id()
 }
 
-fn verify_capacity() -> Result<(), Error> {
+fn verify_capacity(toCKB_data: &ToCKBCellDataView, pledge: u64) -> Result<(), Error> {
     let capacity = load_cell_capacity(0, Source::GroupOutput)?;
-    if capacity != PLEDGE {
+    if capacity != pledge || toCKB_data.pledge != pledge {
         return Err(Error::PledgeInvalid);
     }
     Ok(())
 }
 
-fn verify_lot_size(toCKB_data: &ToCKBCellDataView) -> Result<(), Error> {
-    let xchain_kind = toCKB_data.get_xchain_kind();
-    match xchain_kind {
-        XChainKind::Btc if toCKB_data.get_btc_lot_size().is_ok() => Ok(()),
-        XChainKind::Eth if toCKB_data.get_eth_lot_size().is_ok() => Ok(()),
-        _ => Err(Error::LotSizeInvalid),
+fn verify_signer_fee_rate(toCKB_data: &ToCKBCellDataView, max_rate: u32) -> Result<(), Error> {
+    if toCKB_data.signer_fee_rate > max_rate {
+        return Err(Error::SignerFeeRateInvalid);
     }
+    Ok(())
 }
 
+fn verify_lot_amount(toCKB_data: &ToCKBCellDataView) -> Result<(), Error> {
+    let (min, max) = get_xchain_verifier(toCKB_data.get_xchain_kind()).lot_amount_range();
+    let amount = toCKB_data.get_lot_xt_amount();
+    if amount < min || amount > max {
+        return Err(Error::LotSizeInvalid);
+    }
+    Ok(())
+}
+
+/// The cell id is the outpoint of an input consumed by the deposit request, so it is unique,
+/// and deposits requested in one tx take different inputs. Deposits with other type args are in
+/// other script groups, so the toCKB outputs of the tx are checked for the same cell id.
 fn verify_cell_id() -> Result<(), Error> {
-    let expect_cell_id = load_input_out_point(0, Source::Input)?;
-    if get_cell_id()?.as_slice() != expect_cell_id.as_slice() {
+    let cell_id = get_cell_id()?;
+    let is_input = QueryIter::new(load_input_out_point, Source::Input)
+        .any(|out_point| out_point.as_slice() == cell_id.as_slice());
+    if !is_input {
+        return Err(Error::CellIDInvalid);
+    }
+    let script = load_script()?;
+    let same_cell_id_count = QueryIter::new(load_cell_type, Source::Output)
+        .filter_map(|type_opt| type_opt)
+        .filter(|type_script| {
+            type_script.code_hash().raw_data() == script.code_hash().raw_data()
+                && type_script.hash_type() == script.hash_type()
+        })
+        .filter(
+            |type_script| match ToCKBTypeArgsView::from_slice(&type_script.args().raw_data()) {
+                Ok(args) => args.cell_id.as_slice() == cell_id.as_slice(),
+                Err(_) => false,
+            },
+        )
+        .count();
+    if same_cell_id_count != 1 {
         return Err(Error::CellIDInvalid);
     }
     Ok(())
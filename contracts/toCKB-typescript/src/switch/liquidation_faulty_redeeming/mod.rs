@@ -1,8 +1,8 @@
 use crate::switch::ToCKBCellDataTuple;
 use crate::utils::{
-    transaction::XChainKind,
     types::{mint_xt_witness::MintXTWitnessReader, Error, ToCKBCellDataView},
-    verifier::{verify_btc_faulty_witness, verify_capacity},
+    verifier::verify_capacity,
+    xchain::get_xchain_verifier,
 };
 use ckb_std::{ckb_constants::Source, debug, high_level::load_witness_args};
 use core::result::Result;
@@ -28,9 +28,15 @@ fn verify_data(
     output_data: &ToCKBCellDataView,
 ) -> Result<(), Error> {
     if input_data.get_raw_lot_size() != output_data.get_raw_lot_size()
+        || input_data.get_lot_xt_amount() != output_data.get_lot_xt_amount()
+        || input_data.get_redeem_xt_amount() != output_data.get_redeem_xt_amount()
         || input_data.user_lockscript != output_data.user_lockscript
         || input_data.x_lock_address != output_data.x_lock_address
         || input_data.signer_lockscript != output_data.signer_lockscript
+        || input_data.signer_group != output_data.signer_group
+        || input_data.signer_fee_rate != output_data.signer_fee_rate
+        || input_data.pledge != output_data.pledge
+        || input_data.params_snapshot != output_data.params_snapshot
         || input_data.x_unlock_address != output_data.x_unlock_address
         || input_data.redeemer_lockscript != output_data.redeemer_lockscript
         || input_data.x_extra != output_data.x_extra
@@ -56,8 +62,10 @@ fn verify_witness(data: &ToCKBCellDataView) -> Result<(), Error> {
     debug!("witness: {:?}", witness);
     let proof = witness.spv_proof().raw_data();
     let cell_dep_index_list = witness.cell_dep_index_list().raw_data();
-    match data.get_xchain_kind() {
-        XChainKind::Btc => verify_btc_faulty_witness(data, proof, cell_dep_index_list, true),
-        XChainKind::Eth => todo!(),
-    }
+    get_xchain_verifier(data.get_xchain_kind()).verify_faulty(
+        data,
+        proof,
+        cell_dep_index_list,
+        true,
+    )
 }
@@ -1,8 +1,8 @@
 use crate::switch::ToCKBCellDataTuple;
 use crate::utils::{
-    transaction::XChainKind,
-    types::{mint_xt_witness::MintXTWitnessReader, Error, ToCKBCellDataView},
-    verifier::{verify_btc_faulty_witness, verify_capacity},
+    types::{mint_xt_witness::MintXTWitnessReader, Error, ToCKBCellDataView, ToCKBStatus},
+    verifier::{verify_capacity, verify_handover_capacity},
+    xchain::get_xchain_verifier,
 };
 use ckb_std::{ckb_constants::Source, debug, high_level::load_witness_args};
 use core::result::Result;
@@ -18,9 +18,16 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
         .as_ref()
         .expect("outputs should contain toCKB cell");
 
-    verify_capacity()?;
+    // when handing over, the collateral of the new signer is returned and the move of the locked
+    // XAsset to it is not faulty
+    let is_handover = input_data.status == ToCKBStatus::Handover;
+    if is_handover {
+        verify_handover_capacity(input_data.redeemer_lockscript.as_ref())?;
+    } else {
+        verify_capacity()?;
+    }
     verify_data(input_data, output_data)?;
-    verify_witness(input_data)
+    verify_witness(input_data, is_handover)
 }
 
 fn verify_data(
@@ -28,9 +35,14 @@ fn verify_data(
     output_data: &ToCKBCellDataView,
 ) -> Result<(), Error> {
     if input_data.get_raw_lot_size() != output_data.get_raw_lot_size()
+        || input_data.get_lot_xt_amount() != output_data.get_lot_xt_amount()
         || input_data.user_lockscript != output_data.user_lockscript
         || input_data.x_lock_address != output_data.x_lock_address
         || input_data.signer_lockscript != output_data.signer_lockscript
+        || input_data.signer_group != output_data.signer_group
+        || input_data.signer_fee_rate != output_data.signer_fee_rate
+        || input_data.pledge != output_data.pledge
+        || input_data.params_snapshot != output_data.params_snapshot
         || input_data.x_extra != output_data.x_extra
     {
         return Err(Error::InvariantDataMutated);
@@ -39,7 +51,7 @@ fn verify_data(
     Ok(())
 }
 
-fn verify_witness(data: &ToCKBCellDataView) -> Result<(), Error> {
+fn verify_witness(data: &ToCKBCellDataView, is_handover: bool) -> Result<(), Error> {
     let witness_args = load_witness_args(0, Source::GroupInput)?.input_type();
     debug!("witness_args: {:?}", &witness_args);
     if witness_args.is_none() {
@@ -52,8 +64,10 @@ fn verify_witness(data: &ToCKBCellDataView) -> Result<(), Error> {
     debug!("witness: {:?}", witness);
     let proof = witness.spv_proof().raw_data();
     let cell_dep_index_list = witness.cell_dep_index_list().raw_data();
-    match data.get_xchain_kind() {
-        XChainKind::Btc => verify_btc_faulty_witness(data, proof, cell_dep_index_list, false),
-        XChainKind::Eth => todo!(),
-    }
+    get_xchain_verifier(data.get_xchain_kind()).verify_faulty(
+        data,
+        proof,
+        cell_dep_index_list,
+        is_handover,
+    )
 }
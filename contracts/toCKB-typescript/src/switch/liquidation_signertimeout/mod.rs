@@ -1,9 +1,6 @@
 use crate::switch::ToCKBCellDataTuple;
+use crate::utils::types::{Error, ToCKBCellDataView};
 use crate::utils::verifier::{verify_capacity, verify_since_by_value};
-use crate::utils::{
-    config::SINCE_SIGNER_TIMEOUT,
-    types::{Error, ToCKBCellDataView},
-};
 use core::result::Result;
 
 pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
@@ -16,7 +13,7 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
         .as_ref()
         .expect("outputs should contain toCKB cell");
 
-    verify_since_by_value(SINCE_SIGNER_TIMEOUT)?;
+    verify_since_by_value(input_data.params_snapshot.since_signer_timeout)?;
     verify_capacity()?;
     verify_data(input_data, output_data)?;
     Ok(())
@@ -27,9 +24,15 @@ fn verify_data(
     output_data: &ToCKBCellDataView,
 ) -> Result<(), Error> {
     if input_data.get_raw_lot_size() != output_data.get_raw_lot_size()
+        || input_data.get_lot_xt_amount() != output_data.get_lot_xt_amount()
+        || input_data.get_redeem_xt_amount() != output_data.get_redeem_xt_amount()
         || input_data.user_lockscript != output_data.user_lockscript
         || input_data.x_lock_address != output_data.x_lock_address
         || input_data.signer_lockscript != output_data.signer_lockscript
+        || input_data.signer_group != output_data.signer_group
+        || input_data.signer_fee_rate != output_data.signer_fee_rate
+        || input_data.pledge != output_data.pledge
+        || input_data.params_snapshot != output_data.params_snapshot
         || input_data.x_unlock_address != output_data.x_unlock_address
         || input_data.redeemer_lockscript != output_data.redeemer_lockscript
         || input_data.x_extra != output_data.x_extra
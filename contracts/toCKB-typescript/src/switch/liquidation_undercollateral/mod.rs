@@ -1,7 +1,8 @@
 use crate::switch::ToCKBCellDataTuple;
-use crate::utils::config::{CKB_UNITS, LIQUIDATION_COLLATERAL_PERCENT, XT_CELL_CAPACITY};
+use crate::utils::config::{CKB_UNITS, XT_CELL_CAPACITY};
 use crate::utils::transaction::get_price;
-use crate::utils::types::{Error, ToCKBCellDataView};
+use crate::utils::types::{Error, ToCKBCellDataView, ToCKBStatus};
+use crate::utils::verifier::verify_handover_capacity;
 use ckb_std::{ckb_constants::Source, debug, high_level::load_cell_capacity};
 use core::result::Result;
 
@@ -16,7 +17,7 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
         .as_ref()
         .expect("outputs should contain toCKB cell");
 
-    let asset_collateral = verify_capacity()? - XT_CELL_CAPACITY;
+    let asset_collateral = verify_capacity(input_data)? - XT_CELL_CAPACITY;
     debug!("verify capacity success");
     verify_data(input_data, output_data)?;
     debug!("verify data success");
@@ -24,9 +25,15 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
     Ok(())
 }
 
-fn verify_capacity() -> Result<u64, Error> {
-    let cap_input = load_cell_capacity(0, Source::GroupInput).expect("get input capacity");
+/// Returns the collateral left in the toCKB cell. When handing over, the collateral of the new
+/// signer is returned to it and only the collateral of the current signer is liquidated.
+fn verify_capacity(input_data: &ToCKBCellDataView) -> Result<u64, Error> {
     let cap_output = load_cell_capacity(0, Source::GroupOutput).expect("get output capacity");
+    if input_data.status == ToCKBStatus::Handover {
+        verify_handover_capacity(input_data.redeemer_lockscript.as_ref())?;
+        return Ok(cap_output);
+    }
+    let cap_input = load_cell_capacity(0, Source::GroupInput).expect("get input capacity");
     if cap_input != cap_output {
         return Err(Error::CapacityInvalid);
     }
@@ -38,9 +45,14 @@ fn verify_data(
     output_data: &ToCKBCellDataView,
 ) -> Result<(), Error> {
     if input_data.get_raw_lot_size() != output_data.get_raw_lot_size()
+        || input_data.get_lot_xt_amount() != output_data.get_lot_xt_amount()
         || input_data.user_lockscript != output_data.user_lockscript
         || input_data.x_lock_address != output_data.x_lock_address
         || input_data.signer_lockscript != output_data.signer_lockscript
+        || input_data.signer_group != output_data.signer_group
+        || input_data.signer_fee_rate != output_data.signer_fee_rate
+        || input_data.pledge != output_data.pledge
+        || input_data.params_snapshot != output_data.params_snapshot
         || input_data.x_extra != output_data.x_extra
     {
         return Err(Error::InvariantDataMutated);
@@ -52,11 +64,12 @@ fn verify_undercollateral(
     asset_collateral: u128,
     input_data: &ToCKBCellDataView,
 ) -> Result<(), Error> {
-    let lot_amount: u128 = input_data.get_lot_xt_amount()?;
+    let lot_amount: u128 = input_data.get_lot_xt_amount();
     let price: u128 = get_price()?;
     debug!("get price succ: {:?}", price);
+    let liquidation_collateral_percent = input_data.params_snapshot.liquidation_collateral_percent;
     if asset_collateral * price * 100
-        >= lot_amount * (LIQUIDATION_COLLATERAL_PERCENT as u128) * (CKB_UNITS as u128)
+        >= lot_amount * (liquidation_collateral_percent as u128) * (CKB_UNITS as u128)
     {
         return Err(Error::UndercollateralInvalid);
     }
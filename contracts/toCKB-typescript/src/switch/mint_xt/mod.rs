@@ -1,30 +1,32 @@
 use crate::switch::ToCKBCellDataTuple;
-use crate::utils::verifier::verify_btc_witness;
 use crate::utils::{
-    config::{PLEDGE, SIGNER_FEE_RATE, SUDT_CODE_HASH, XT_CELL_CAPACITY},
-    transaction::{is_XT_typescript, XChainKind},
+    config::XT_CELL_CAPACITY,
+    lock_registry::verify_lock_registry_insert,
+    transaction::{is_XT_typescript, OutputMatcher},
     types::{mint_xt_witness::MintXTWitnessReader, Error, ToCKBCellDataView, XExtraView},
+    xchain::get_xchain_verifier,
 };
 use ckb_std::{
     ckb_constants::Source,
     debug,
     high_level::{
-        load_cell_capacity, load_cell_data, load_cell_lock, load_cell_lock_hash, load_cell_type,
-        load_witness_args, QueryIter,
+        load_cell_capacity, load_cell_lock_hash, load_cell_type, load_witness_args, QueryIter,
     },
 };
 use core::result::Result;
-use molecule::prelude::{Entity, Reader};
+use molecule::prelude::Reader;
 
 pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
     debug!("start mint_xt");
     let input_data = toCKB_data_tuple.0.as_ref().expect("should not happen");
     let output_data = toCKB_data_tuple.1.as_ref().expect("should not happen");
-    verify_capacity()?;
+    verify_capacity(input_data)?;
     let x_extra = verify_witness(input_data)?;
     debug!("verify witness finish");
     verify_data(input_data, output_data, &x_extra)?;
     debug!("verify data finish");
+    verify_lock_registry_insert(&output_data.x_extra)?;
+    debug!("verify lock registry finish");
     verify_xt_issue(input_data)?;
     debug!("verify xt issue finish");
     Ok(())
@@ -36,8 +38,13 @@ fn verify_data(
     x_extra: &XExtraView,
 ) -> Result<(), Error> {
     if input_data.signer_lockscript != output_data.signer_lockscript
+        || input_data.signer_group != output_data.signer_group
+        || input_data.signer_fee_rate != output_data.signer_fee_rate
+        || input_data.pledge != output_data.pledge
+        || input_data.params_snapshot != output_data.params_snapshot
         || input_data.user_lockscript != output_data.user_lockscript
         || input_data.get_raw_lot_size() != output_data.get_raw_lot_size()
+        || input_data.get_lot_xt_amount() != output_data.get_lot_xt_amount()
         || input_data.x_lock_address != output_data.x_lock_address
         || &output_data.x_extra != x_extra
     {
@@ -62,111 +69,65 @@ fn verify_witness(data: &ToCKBCellDataView) -> Result<XExtraView, Error> {
     debug!("witness: {:?}", witness);
     let proof = witness.spv_proof().raw_data();
     let cell_dep_index_list = witness.cell_dep_index_list().raw_data();
-    match data.get_xchain_kind() {
-        XChainKind::Btc => {
-            let btc_extra = verify_btc_witness(
-                data,
-                proof,
-                cell_dep_index_list,
-                data.x_lock_address.as_ref(),
-                data.get_btc_lot_size()?.get_sudt_amount(),
-                false,
-            )?;
-            Ok(XExtraView::Btc(btc_extra))
-        }
-        XChainKind::Eth => todo!(),
-    }
+    get_xchain_verifier(data.get_xchain_kind()).verify_funding(data, proof, cell_dep_index_list)
 }
 
 fn verify_xt_issue(data: &ToCKBCellDataView) -> Result<(), Error> {
-    match data.get_xchain_kind() {
-        XChainKind::Btc => verify_btc_xt_issue(data),
-        XChainKind::Eth => todo!(),
-    }
-}
-
-fn verify_btc_xt_issue(data: &ToCKBCellDataView) -> Result<(), Error> {
-    let lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
-    debug!("lockscript hash: {:?}", hex::encode(lock_hash));
+    let xt_args = data.get_xt_args(load_cell_lock_hash(0, Source::GroupInput)?.as_ref());
+    debug!("XT args: {:?}", hex::encode(&xt_args));
     let input_xt_num = QueryIter::new(load_cell_type, Source::Input)
         .filter(|type_opt| type_opt.is_some())
-        .filter(|script| is_XT_typescript(script, lock_hash.as_ref()))
+        .filter(|script| is_XT_typescript(script, xt_args.as_ref()))
         .count();
     if input_xt_num != 0 {
         return Err(Error::InvalidXTInInputOrOutput);
     }
     let output_xt_num = QueryIter::new(load_cell_type, Source::Output)
         .filter(|type_opt| type_opt.is_some())
-        .filter(|script| is_XT_typescript(script, lock_hash.as_ref()))
+        .filter(|script| is_XT_typescript(script, xt_args.as_ref()))
         .count();
     debug!("output_xt_num: {}", output_xt_num);
     if output_xt_num != 2 {
         return Err(Error::InvalidXTInInputOrOutput);
     }
-    let xt_amount = data.get_btc_lot_size()?.get_sudt_amount();
-    debug!("xt_amount: {}", xt_amount);
-    // fixed order of output cells is required
-    // user-sudt-cell should be outputs[1]
-    // signer-sudt-cell should be outputs[2]
+    let xt_amount = data.get_lot_xt_amount();
+    let signer_fee = data.get_signer_fee(xt_amount);
+    debug!("xt_amount: {}, signer_fee: {}", xt_amount, signer_fee);
+    // the user XT cell takes the pledge as capacity, the signer XT cell is paid by the
+    // collateral, and they can be anywhere in the outputs
+    let pledge = data.pledge;
     let expect = [
         (
-            1,
             data.user_lockscript.as_ref(),
-            xt_amount - xt_amount * SIGNER_FEE_RATE.0 / SIGNER_FEE_RATE.1,
+            xt_amount - signer_fee,
+            pledge,
         ),
         (
-            2,
             data.signer_lockscript.as_ref(),
-            xt_amount * SIGNER_FEE_RATE.0 / SIGNER_FEE_RATE.1,
+            signer_fee,
+            XT_CELL_CAPACITY,
         ),
     ];
     debug!("expect: {:?}", expect);
 
-    for (i, lockscript, amount) in expect.iter() {
-        let script = load_cell_type(*i, Source::Output)?;
-        if script.is_none() {
-            return Err(Error::InvalidMintOutput);
-        }
-        let script = script.unwrap();
-        if !(script.code_hash().raw_data().as_ref() == SUDT_CODE_HASH.as_ref()
-            && script.args().raw_data().as_ref() == lock_hash.as_ref()
-            && script.hash_type() == 0u8.into())
+    let mut matcher = OutputMatcher::default();
+    for (lockscript, amount, capacity) in expect.iter() {
+        if matcher
+            .claim_xt_cell(lockscript, xt_args.as_ref(), *amount, *capacity)?
+            .is_none()
         {
             return Err(Error::InvalidMintOutput);
         }
-        let cell_data = load_cell_data(*i, Source::Output)?;
-        let mut amount_vec = [0u8; 16];
-        amount_vec.copy_from_slice(&cell_data);
-        let token_amount = u128::from_le_bytes(amount_vec);
-        debug!("token_amount: {}, amout: {}", token_amount, amount);
-        if token_amount != *amount {
-            return Err(Error::InvalidMintOutput);
-        }
-        let lock = load_cell_lock(*i, Source::Output)?;
-        debug!(
-            "lock: {:?}, expect lock: {:?}",
-            hex::encode(lock.as_slice()),
-            hex::encode(lockscript.as_ref())
-        );
-        if lock.as_slice() != lockscript.as_ref() {
-            return Err(Error::InvalidMintOutput);
-        }
     }
     Ok(())
 }
 
-fn verify_capacity() -> Result<(), Error> {
+/// The pledge is the one locked at deposit request, params may have changed since.
+fn verify_capacity(data: &ToCKBCellDataView) -> Result<(), Error> {
+    let pledge = data.pledge;
     let toCKB_output_cap = load_cell_capacity(0, Source::GroupOutput)?;
     let toCKB_input_cap = load_cell_capacity(0, Source::GroupInput)?;
-    if toCKB_input_cap - toCKB_output_cap != PLEDGE + XT_CELL_CAPACITY {
-        return Err(Error::CapacityInvalid);
-    }
-    let user_xt_cell_cap = load_cell_capacity(1, Source::Output)?;
-    if user_xt_cell_cap != PLEDGE {
-        return Err(Error::CapacityInvalid);
-    }
-    let signer_xt_cell_cap = load_cell_capacity(2, Source::Output)?;
-    if signer_xt_cell_cap != XT_CELL_CAPACITY {
+    if toCKB_input_cap - toCKB_output_cap != pledge + XT_CELL_CAPACITY {
         return Err(Error::CapacityInvalid);
     }
     Ok(())
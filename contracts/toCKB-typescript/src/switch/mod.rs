@@ -10,24 +10,31 @@ mod liquidation_faulty_warranty;
 mod liquidation_signertimeout;
 mod liquidation_undercollateral;
 mod mint_xt;
+mod partial_redeem;
 mod pre_undercollateral_redeem;
 mod preterm_redeem;
+mod signer_handover;
+mod signer_handover_request;
+mod topup_collateral;
 mod withdraw_collateral;
+mod withdraw_partial_collateral;
 mod withdraw_pledge;
 mod withdraw_pledge_collateral;
 
 use crate::utils::{
     config::SUDT_CODE_HASH,
+    lock_registry::forbid_lock_registry_update,
     transaction::get_xchain_kind,
     types::{Error, ToCKBCellDataView, ToCKBStatus, XChainKind},
 };
 use alloc::vec::Vec;
 use ckb_std::{
     ckb_constants::Source,
+    ckb_types::packed::Script,
     debug,
     high_level::{
-        load_cell_data, load_cell_type, load_cell_type_hash, load_input_since, load_script_hash,
-        QueryIter,
+        load_cell_data, load_cell_type, load_cell_type_hash, load_input_since, load_script,
+        load_script_hash, QueryIter,
     },
 };
 
@@ -40,8 +47,13 @@ enum TxType {
     MintXT,
     PretermRedeem,
     AttermRedeem,
+    PartialRedeem,
     PreUndercollateralRedeem,
+    TopUpCollateral,
+    SignerHandoverRequest,
+    SignerHandover,
     WithdrawCollateral,
+    WithdrawPartialCollateral,
     LiquidationSignerTimeout,
     LiquidationUndercollateral,
     LiquidationFaultyWhenWarranty,
@@ -62,7 +74,11 @@ pub fn verify() -> Result<(), Error> {
     let toCKB_data_tuple = get_toCKB_data_tuple(xchain_kind)?;
     debug!("toCKB_data_tuple: {:?}", toCKB_data_tuple);
     let tx_type = get_tx_type(&toCKB_data_tuple)?;
+    if !is_batchable(&tx_type) {
+        verify_exclusive()?;
+    }
     verify_xt(&tx_type)?;
+    verify_lock_registry(&tx_type)?;
     debug!("tx_type: {:?}", tx_type);
     switch(&tx_type, &toCKB_data_tuple)?;
     Ok(())
@@ -99,7 +115,6 @@ fn get_tx_type(data_tuple: &ToCKBCellDataTuple) -> Result<TxType, Error> {
 }
 
 fn get_generation_tx_type(data: &ToCKBCellDataView) -> Result<TxType, Error> {
-    verify_unique(Source::Output)?;
     if let ToCKBStatus::Initial = data.status {
         Ok(TxType::DepositRequest)
     } else {
@@ -111,31 +126,36 @@ fn get_transformation_tx_type(
     input_data: &ToCKBCellDataView,
     output_data: &ToCKBCellDataView,
 ) -> Result<TxType, Error> {
-    verify_unique(Source::Input)?;
-    verify_unique(Source::Output)?;
     use ToCKBStatus::*;
     use TxType::*;
     let status_transformation = (input_data.status, output_data.status);
     match status_transformation {
         (Initial, Bonded) => Ok(Bonding),
         (Bonded, Warranty) => Ok(MintXT),
+        (Warranty, Warranty) => Ok(TopUpCollateral),
+        (Warranty, Handover) => Ok(SignerHandoverRequest),
+        (Handover, Warranty) => Ok(SignerHandover),
         (Warranty, Redeeming) => {
-            if let 0 = load_input_since(0, Source::GroupInput)? {
+            if output_data.is_partial_redeem() {
+                Ok(PartialRedeem)
+            } else if let 0 = load_input_since(0, Source::GroupInput)? {
                 Ok(PretermRedeem)
             } else {
                 Ok(AttermRedeem)
             }
         }
+        (Redeeming, Warranty) => Ok(WithdrawPartialCollateral),
         (Redeeming, SignerTimeout) => Ok(LiquidationSignerTimeout),
-        (Warranty, Undercollateral) => Ok(LiquidationUndercollateral),
-        (Warranty, FaultyWhenWarranty) => Ok(LiquidationFaultyWhenWarranty),
+        (Warranty, Undercollateral) | (Handover, Undercollateral) => Ok(LiquidationUndercollateral),
+        (Warranty, FaultyWhenWarranty) | (Handover, FaultyWhenWarranty) => {
+            Ok(LiquidationFaultyWhenWarranty)
+        }
         (Redeeming, FaultyWhenRedeeming) => Ok(LiquidationFaultyWhenRedeeming),
         _ => Err(Error::TxInvalid),
     }
 }
 
 fn get_deletion_tx_type(data: &ToCKBCellDataView) -> Result<TxType, Error> {
-    verify_unique(Source::Input)?;
     use ToCKBStatus::*;
     use TxType::*;
     match data.status {
@@ -147,9 +167,56 @@ fn get_deletion_tx_type(data: &ToCKBCellDataView) -> Result<TxType, Error> {
         Undercollateral => Ok(AuctionUnderCollateral),
         FaultyWhenWarranty => Ok(AuctionFaultyWhenWarranty),
         FaultyWhenRedeeming => Ok(AuctionFaultyWhenRedeeming),
+        Handover => Err(Error::TxInvalid),
+    }
+}
+
+/// Every deposit has its own cell id in the type args, so the toCKB cells of a tx are paired
+/// by cell id into script groups and each transition is verified by its own group. The
+/// transitions which only check the toCKB cell itself can be batched in one tx.
+fn is_batchable(tx_type: &TxType) -> bool {
+    use TxType::*;
+    match tx_type {
+        DepositRequest
+        | Bonding
+        | TopUpCollateral
+        | SignerHandoverRequest
+        | LiquidationSignerTimeout
+        | LiquidationUndercollateral
+        | LiquidationFaultyWhenRedeeming => true,
+        _ => false,
     }
 }
 
+/// The transitions which pay out cells or mint and burn XT account for the whole tx, so the
+/// toCKB cell must be the only one in the tx and placed at index 0.
+fn verify_exclusive() -> Result<(), Error> {
+    let script = load_script()?;
+    let is_toCKB_cell = |type_opt: &Option<Script>| match type_opt {
+        Some(type_script) => {
+            type_script.code_hash().raw_data() == script.code_hash().raw_data()
+                && type_script.hash_type() == script.hash_type()
+        }
+        None => false,
+    };
+    for (source, group_source) in &[
+        (Source::Input, Source::GroupInput),
+        (Source::Output, Source::GroupOutput),
+    ] {
+        let count = QueryIter::new(load_cell_type, *source)
+            .filter(is_toCKB_cell)
+            .count();
+        let group_count = QueryIter::new(load_cell_type, *group_source).count();
+        if count != group_count {
+            return Err(Error::TxInvalid);
+        }
+        if group_count != 0 {
+            verify_unique(*source)?;
+        }
+    }
+    Ok(())
+}
+
 fn verify_unique(source: Source) -> Result<(), Error> {
     let type_hash = load_cell_type_hash(0, source)?;
     let self_type_hash = load_script_hash()?;
@@ -165,6 +232,7 @@ fn verify_xt(tx_type: &TxType) -> Result<(), Error> {
         MintXT
         | PretermRedeem
         | AttermRedeem
+        | PartialRedeem
         | PreUndercollateralRedeem
         | AuctionSignerTimeout
         | AuctionUnderCollateral
@@ -174,6 +242,15 @@ fn verify_xt(tx_type: &TxType) -> Result<(), Error> {
     }
 }
 
+/// Only mint, handover and partial withdraw update the lock tx registry, by inserting the lock tx
+/// they move the XAsset to.
+fn verify_lock_registry(tx_type: &TxType) -> Result<(), Error> {
+    match tx_type {
+        TxType::MintXT | TxType::SignerHandover | TxType::WithdrawPartialCollateral => Ok(()),
+        _ => forbid_lock_registry_update(),
+    }
+}
+
 fn forbid_mint_xt() -> Result<(), Error> {
     let sudt_cell_count = QueryIter::new(load_cell_type, Source::Output)
         .filter(|type_opt| type_opt.is_some())
@@ -210,12 +287,27 @@ fn switch(tx_type: &TxType, toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(),
         AttermRedeem => {
             atterm_redeem::verify(toCKB_data_tuple)?;
         }
+        PartialRedeem => {
+            partial_redeem::verify(toCKB_data_tuple)?;
+        }
         PreUndercollateralRedeem => {
             pre_undercollateral_redeem::verify(toCKB_data_tuple)?;
         }
+        TopUpCollateral => {
+            topup_collateral::verify(toCKB_data_tuple)?;
+        }
+        SignerHandoverRequest => {
+            signer_handover_request::verify(toCKB_data_tuple)?;
+        }
+        SignerHandover => {
+            signer_handover::verify(toCKB_data_tuple)?;
+        }
         WithdrawCollateral => {
             withdraw_collateral::verify(toCKB_data_tuple)?;
         }
+        WithdrawPartialCollateral => {
+            withdraw_partial_collateral::verify(toCKB_data_tuple)?;
+        }
         LiquidationSignerTimeout => {
             liquidation_signertimeout::verify(toCKB_data_tuple)?;
         }
@@ -0,0 +1,38 @@
+use crate::switch::preterm_redeem::verify_burn;
+use crate::switch::ToCKBCellDataTuple;
+use crate::utils::types::{Error, ToCKBCellDataView};
+use crate::utils::verifier::{verify_capacity, verify_data};
+use crate::utils::xchain::get_xchain_verifier;
+use ckb_std::debug;
+use core::result::Result;
+
+/// Burn part of the lot, the cell keeps the whole collateral until the signer pays the redeemed
+/// part and moves the remainder back to Warranty.
+pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
+    debug!("start partial redeem");
+    let input_toCKB_data = toCKB_data_tuple
+        .0
+        .as_ref()
+        .expect("inputs contain toCKB cell");
+    let output_toCKB_data = toCKB_data_tuple
+        .1
+        .as_ref()
+        .expect("outputs contain toCKB cell");
+    verify_capacity()?;
+    verify_data(input_toCKB_data, output_toCKB_data)?;
+    let redeem_amount = output_toCKB_data.get_redeem_xt_amount();
+    debug!("redeem amount {}", redeem_amount);
+    verify_redeem_amount(redeem_amount, output_toCKB_data)?;
+    verify_burn(redeem_amount, output_toCKB_data)
+}
+
+/// The signer pays the redeemed part and relocks the remainder on XChain, so neither of them may
+/// be dust below the minimum lot amount.
+fn verify_redeem_amount(redeem_amount: u128, data: &ToCKBCellDataView) -> Result<(), Error> {
+    let (min, _) = get_xchain_verifier(data.get_xchain_kind()).lot_amount_range();
+    let remainder = data.get_lot_xt_amount().saturating_sub(redeem_amount);
+    if redeem_amount < min || remainder < min {
+        return Err(Error::RedeemAmountInvalid);
+    }
+    Ok(())
+}
@@ -1,6 +1,6 @@
 use crate::switch::ToCKBCellDataTuple;
-use crate::utils::config::{CKB_UNITS, PRE_UNDERCOLLATERAL_RATE, XT_CELL_CAPACITY};
-use crate::utils::transaction::{get_price, get_sum_sudt_amount, is_XT_typescript, XChainKind};
+use crate::utils::config::{CKB_UNITS, XT_CELL_CAPACITY};
+use crate::utils::transaction::{get_price, get_sum_sudt_amount, is_XT_typescript};
 use crate::utils::types::{Error, ToCKBCellDataView};
 use ckb_std::ckb_constants::Source;
 use ckb_std::debug;
@@ -16,16 +16,13 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
         .0
         .as_ref()
         .expect("inputs contain toCKB cell");
-    let lot_size = match input_toCKB_data.get_xchain_kind() {
-        XChainKind::Btc => input_toCKB_data.get_btc_lot_size()?.get_sudt_amount(),
-        XChainKind::Eth => input_toCKB_data.get_eth_lot_size()?.get_sudt_amount(),
-    };
-    verify_collateral_rate(lot_size)?;
+    let lot_size = input_toCKB_data.get_lot_xt_amount();
+    verify_collateral_rate(lot_size, input_toCKB_data)?;
     verify_burn(lot_size, input_toCKB_data)
 }
 
 fn verify_burn(lot_size: u128, data: &ToCKBCellDataView) -> Result<(), Error> {
-    let lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
+    let xt_args = data.get_xt_args(load_cell_lock_hash(0, Source::GroupInput)?.as_ref());
 
     let mut is_signer = false;
     let mut input_sudt_sum: u128 = 0;
@@ -40,7 +37,7 @@ fn verify_burn(lot_size: u128, data: &ToCKBCellDataView) -> Result<(), Error> {
                 if lock.as_bytes() == data.signer_lockscript {
                     is_signer = true;
                 }
-                if !is_XT_typescript(&cell_type, lock_hash.as_ref()) {
+                if !is_XT_typescript(&cell_type, xt_args.as_ref()) {
                     input_index += 1;
                     continue;
                 }
@@ -60,7 +57,7 @@ fn verify_burn(lot_size: u128, data: &ToCKBCellDataView) -> Result<(), Error> {
         return Err(Error::InputSignerInvalid);
     }
 
-    let output_sudt_sum = get_sum_sudt_amount(0, Source::Output, lock_hash.as_ref())?;
+    let output_sudt_sum = get_sum_sudt_amount(0, Source::Output, xt_args.as_ref())?;
 
     if input_sudt_sum - output_sudt_sum != lot_size {
         return Err(Error::XTBurnInvalid);
@@ -69,8 +66,9 @@ fn verify_burn(lot_size: u128, data: &ToCKBCellDataView) -> Result<(), Error> {
     Ok(())
 }
 
-fn verify_collateral_rate(lot_size: u128) -> Result<(), Error> {
+fn verify_collateral_rate(lot_size: u128, data: &ToCKBCellDataView) -> Result<(), Error> {
     let price = get_price()?;
+    let pre_undercollateral_rate = data.params_snapshot.pre_undercollateral_rate;
     let input_capacity = load_cell_capacity(0, Source::GroupInput)?;
 
     debug!(
@@ -78,7 +76,7 @@ fn verify_collateral_rate(lot_size: u128) -> Result<(), Error> {
         input_capacity, price, lot_size
     );
     if (100 * (input_capacity - XT_CELL_CAPACITY) as u128 * price) / (CKB_UNITS as u128)
-        >= PRE_UNDERCOLLATERAL_RATE as u128 * lot_size
+        >= pre_undercollateral_rate as u128 * lot_size
     {
         return Err(Error::UndercollateralInvalid);
     }
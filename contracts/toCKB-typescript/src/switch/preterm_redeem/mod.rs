@@ -1,5 +1,4 @@
 use crate::switch::ToCKBCellDataTuple;
-use crate::utils::config::SIGNER_FEE_RATE;
 use crate::utils::transaction::is_XT_typescript;
 use crate::utils::types::{Error, ToCKBCellDataView};
 use crate::utils::verifier::{verify_capacity, verify_data};
@@ -24,14 +23,14 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
     verify_burn(lot_size, output_toCKB_data)
 }
 
-fn verify_burn(lot_size: u128, out_toCKB_data: &ToCKBCellDataView) -> Result<(), Error> {
+pub fn verify_burn(lot_size: u128, out_toCKB_data: &ToCKBCellDataView) -> Result<(), Error> {
     let mut deposit_requestor = false;
     let mut input_sudt_sum: u128 = 0;
     let mut output_sudt_sum: u128 = 0;
     let mut output_sudt_xt_receipt_sum: u128 = 0;
     let mut input_index = 0;
 
-    let lock_hash = load_cell_lock_hash(0, Source::GroupInput)?;
+    let xt_args = out_toCKB_data.get_xt_args(load_cell_lock_hash(0, Source::GroupInput)?.as_ref());
 
     loop {
         let cell_type = load_cell_type(input_index, Source::Input);
@@ -40,7 +39,7 @@ fn verify_burn(lot_size: u128, out_toCKB_data: &ToCKBCellDataView) -> Result<(),
             Err(SysError::IndexOutOfBound) => break,
             Err(_err) => panic!("iter input return an error"),
             Ok(cell_type) => {
-                if !is_XT_typescript(&cell_type, lock_hash.as_ref()) {
+                if !is_XT_typescript(&cell_type, xt_args.as_ref()) {
                     input_index += 1;
                     continue;
                 }
@@ -70,7 +69,7 @@ fn verify_burn(lot_size: u128, out_toCKB_data: &ToCKBCellDataView) -> Result<(),
             Err(SysError::IndexOutOfBound) => break,
             Err(_err) => panic!("iter output return an error"),
             Ok(cell_type) => {
-                if !is_XT_typescript(&cell_type, lock_hash.as_ref()) {
+                if !is_XT_typescript(&cell_type, xt_args.as_ref()) {
                     output_index += 1;
                     continue;
                 }
@@ -96,7 +95,7 @@ fn verify_burn(lot_size: u128, out_toCKB_data: &ToCKBCellDataView) -> Result<(),
         return Err(Error::XTBurnInvalid);
     }
     if !deposit_requestor {
-        let signer_fee: u128 = lot_size * SIGNER_FEE_RATE.0 / SIGNER_FEE_RATE.1;
+        let signer_fee: u128 = out_toCKB_data.get_signer_fee(lot_size);
         debug!("input_sudt_sum {:?}, output_sudt_sum {:?}, output_sudt_xt_receipt_sum {:?}, signer_fee {:?}, lot_size {:?}", input_sudt_sum, output_sudt_sum, output_sudt_xt_receipt_sum, signer_fee, lot_size);
         if (input_sudt_sum - output_sudt_sum != lot_size)
             || (output_sudt_xt_receipt_sum != signer_fee)
@@ -0,0 +1,71 @@
+use crate::switch::ToCKBCellDataTuple;
+use crate::utils::{
+    lock_registry::verify_lock_registry_insert,
+    types::{mint_xt_witness::MintXTWitnessReader, Error, ToCKBCellDataView, XExtraView},
+    verifier::verify_handover_capacity,
+    xchain::get_xchain_verifier,
+};
+use ckb_std::{ckb_constants::Source, debug, high_level::load_witness_args};
+use core::result::Result;
+use molecule::prelude::Reader;
+
+pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
+    debug!("start signer handover");
+    let input_data = toCKB_data_tuple
+        .0
+        .as_ref()
+        .expect("inputs should contain toCKB cell");
+    let output_data = toCKB_data_tuple
+        .1
+        .as_ref()
+        .expect("outputs should contain toCKB cell");
+
+    // the collateral of the previous signer is released
+    verify_handover_capacity(input_data.signer_lockscript.as_ref())?;
+    let x_extra = verify_witness(input_data)?;
+    verify_data(input_data, output_data, &x_extra)?;
+    // the new lock can not be used to mint another deposit
+    verify_lock_registry_insert(&output_data.x_extra)
+}
+
+fn verify_data(
+    input_data: &ToCKBCellDataView,
+    output_data: &ToCKBCellDataView,
+    x_extra: &XExtraView,
+) -> Result<(), Error> {
+    if input_data.get_raw_lot_size() != output_data.get_raw_lot_size()
+        || input_data.get_lot_xt_amount() != output_data.get_lot_xt_amount()
+        || input_data.user_lockscript != output_data.user_lockscript
+        || input_data.redeemer_lockscript != output_data.signer_lockscript
+        || input_data.x_unlock_address != output_data.x_lock_address
+        || input_data.x_unlock_address != output_data.x_unlock_address
+        || input_data.redeemer_lockscript != output_data.redeemer_lockscript
+        || input_data.signer_group != output_data.signer_group
+        || input_data.signer_fee_rate != output_data.signer_fee_rate
+        || input_data.pledge != output_data.pledge
+        || input_data.params_snapshot != output_data.params_snapshot
+        || input_data.liquidation_trigger_lockscript != output_data.liquidation_trigger_lockscript
+        || &output_data.x_extra != x_extra
+    {
+        return Err(Error::InvalidDataChange);
+    }
+    Ok(())
+}
+
+/// ensure the locked XAsset is moved to the new signer by verifying the spv proof
+fn verify_witness(data: &ToCKBCellDataView) -> Result<XExtraView, Error> {
+    let witness_args = load_witness_args(0, Source::GroupInput)?.input_type();
+    debug!("witness_args: {:?}", &witness_args);
+    if witness_args.is_none() {
+        return Err(Error::InvalidWitness);
+    }
+    let witness_args = witness_args.to_opt().unwrap().raw_data();
+    if MintXTWitnessReader::verify(&witness_args, false).is_err() {
+        return Err(Error::InvalidWitness);
+    }
+    let witness = MintXTWitnessReader::new_unchecked(&witness_args);
+    debug!("witness: {:?}", witness);
+    let proof = witness.spv_proof().raw_data();
+    let cell_dep_index_list = witness.cell_dep_index_list().raw_data();
+    get_xchain_verifier(data.get_xchain_kind()).verify_handover(data, proof, cell_dep_index_list)
+}
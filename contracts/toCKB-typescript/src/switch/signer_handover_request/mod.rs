@@ -0,0 +1,86 @@
+use crate::switch::ToCKBCellDataTuple;
+use crate::utils::{
+    types::{Error, ToCKBCellDataView},
+    xchain::get_xchain_verifier,
+};
+use ckb_std::{
+    ckb_constants::Source,
+    debug,
+    high_level::{load_cell_capacity, load_cell_lock, QueryIter},
+};
+use core::result::Result;
+use molecule::prelude::Entity;
+
+pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
+    debug!("start signer handover request");
+    let input_data = toCKB_data_tuple
+        .0
+        .as_ref()
+        .expect("inputs should contain toCKB cell");
+    let output_data = toCKB_data_tuple
+        .1
+        .as_ref()
+        .expect("outputs should contain toCKB cell");
+
+    verify_data(input_data, output_data)?;
+    verify_signer(input_data)?;
+    verify_collateral()
+}
+
+/// The new signer lockscript and its lock address are kept in redeemer_lockscript and
+/// x_unlock_address until the handover ends.
+fn verify_data(
+    input_data: &ToCKBCellDataView,
+    output_data: &ToCKBCellDataView,
+) -> Result<(), Error> {
+    if input_data.get_raw_lot_size() != output_data.get_raw_lot_size()
+        || input_data.get_lot_xt_amount() != output_data.get_lot_xt_amount()
+        || input_data.user_lockscript != output_data.user_lockscript
+        || input_data.x_lock_address != output_data.x_lock_address
+        || input_data.signer_lockscript != output_data.signer_lockscript
+        || input_data.signer_group != output_data.signer_group
+        || input_data.signer_fee_rate != output_data.signer_fee_rate
+        || input_data.pledge != output_data.pledge
+        || input_data.params_snapshot != output_data.params_snapshot
+        || input_data.liquidation_trigger_lockscript != output_data.liquidation_trigger_lockscript
+        || input_data.x_extra != output_data.x_extra
+    {
+        return Err(Error::InvariantDataMutated);
+    }
+
+    get_xchain_verifier(output_data.get_xchain_kind())
+        .verify_address(output_data.x_unlock_address.as_ref())?;
+    // a signer group moves the BTC only with the multisig of its members, which one new signer
+    // can not take over
+    if input_data.is_signer_group()
+        || output_data.redeemer_lockscript == output_data.signer_lockscript
+        || output_data.x_unlock_address == output_data.x_lock_address
+    {
+        return Err(Error::SignerHandoverInvalid);
+    }
+    Ok(())
+}
+
+/// The handover must be agreed by the current signer
+fn verify_signer(data: &ToCKBCellDataView) -> Result<(), Error> {
+    let is_signer = QueryIter::new(load_cell_lock, Source::Input)
+        .any(|lock| lock.as_bytes() == data.signer_lockscript);
+    if !is_signer {
+        return Err(Error::InputSignerInvalid);
+    }
+    Ok(())
+}
+
+/// The new signer posts the same collateral as the current one
+fn verify_collateral() -> Result<(), Error> {
+    let input_capacity = load_cell_capacity(0, Source::GroupInput)?;
+    let output_capacity = load_cell_capacity(0, Source::GroupOutput)?;
+    debug!(
+        "output_capacity {:?}, input_capacity {:?}",
+        output_capacity, input_capacity
+    );
+    if Some(output_capacity) != input_capacity.checked_mul(2) {
+        return Err(Error::CollateralInvalid);
+    }
+    Ok(())
+}
@@ -0,0 +1,36 @@
+use crate::switch::ToCKBCellDataTuple;
+use crate::utils::types::Error;
+use ckb_std::ckb_constants::Source;
+use ckb_std::debug;
+use ckb_std::high_level::{load_cell_capacity, load_cell_data, load_cell_lock_hash};
+use core::result::Result;
+
+pub fn verify(_toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
+    debug!("start topup collateral");
+    verify_data()?;
+    verify_collateral()
+}
+
+/// Topup only adds capacity to the toCKB cell, the data and lock can not be changed
+fn verify_data() -> Result<(), Error> {
+    if load_cell_data(0, Source::GroupInput)? != load_cell_data(0, Source::GroupOutput)?
+        || load_cell_lock_hash(0, Source::GroupInput)?
+            != load_cell_lock_hash(0, Source::GroupOutput)?
+    {
+        return Err(Error::InvariantDataMutated);
+    }
+    Ok(())
+}
+
+fn verify_collateral() -> Result<(), Error> {
+    let input_capacity = load_cell_capacity(0, Source::GroupInput)?;
+    let output_capacity = load_cell_capacity(0, Source::GroupOutput)?;
+    debug!(
+        "output_capacity {:?}, input_capacity {:?}",
+        output_capacity, input_capacity
+    );
+    if output_capacity <= input_capacity {
+        return Err(Error::CollateralInvalid);
+    }
+    Ok(())
+}
@@ -1,8 +1,8 @@
 use crate::switch::ToCKBCellDataTuple;
 use crate::utils::{
-    transaction::XChainKind,
     types::{mint_xt_witness::MintXTWitnessReader, Error, ToCKBCellDataView, XExtraView},
-    verifier::{verify_btc_witness, verify_capacity_with_value},
+    verifier::verify_capacity_with_value,
+    xchain::get_xchain_verifier,
 };
 
 use ckb_std::ckb_types::prelude::*;
@@ -16,6 +16,10 @@ use core::result::Result;
 pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
     debug!("start withdraw collateral");
     let input_data = toCKB_data_tuple.0.as_ref().expect("should not happen");
+    // a partially redeemed cell goes back to Warranty with the remainder instead
+    if input_data.is_partial_redeem() {
+        return Err(Error::TxInvalid);
+    }
     // verify_capacity(input_data)?;
     let ckb_cell_cap = load_cell_capacity(0, Source::GroupInput)?;
     verify_capacity_with_value(input_data, ckb_cell_cap)?;
@@ -43,21 +47,7 @@ fn verify_witness(data: &ToCKBCellDataView) -> Result<XExtraView, Error> {
     debug!("witness: {:?}", witness);
     let proof = witness.spv_proof().raw_data();
     let cell_dep_index_list = witness.cell_dep_index_list().raw_data();
-    match data.get_xchain_kind() {
-        XChainKind::Btc => {
-            let btc_extra = verify_btc_witness(
-                data,
-                proof,
-                cell_dep_index_list,
-                data.x_unlock_address.as_ref(),
-                data.get_btc_lot_size()?.get_sudt_amount(),
-                true,
-            )?;
-            debug!("extra {:?}", btc_extra);
-            Ok(XExtraView::Btc(btc_extra))
-        }
-        XChainKind::Eth => todo!(),
-    }
+    get_xchain_verifier(data.get_xchain_kind()).verify_redeem(data, proof, cell_dep_index_list)
 }
 
 fn verify_extra(data: &ToCKBCellDataView, x_extra: &XExtraView) -> Result<(), Error> {
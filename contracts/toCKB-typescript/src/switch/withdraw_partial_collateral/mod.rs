@@ -0,0 +1,107 @@
+use crate::switch::ToCKBCellDataTuple;
+use crate::utils::{
+    config::XT_CELL_CAPACITY,
+    lock_registry::verify_lock_registry_insert,
+    types::{
+        mint_xt_witness::MintXTWitnessReader, Error, ToCKBCellDataView, XExtraView,
+        ARBITRARY_LOT_SIZE,
+    },
+    verifier::verify_capacity_with_value,
+    xchain::get_xchain_verifier,
+};
+use ckb_std::{
+    ckb_constants::Source,
+    debug,
+    high_level::{load_cell_capacity, load_witness_args},
+};
+use core::result::Result;
+use molecule::prelude::Reader;
+
+pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
+    debug!("start withdraw partial collateral");
+    let input_data = toCKB_data_tuple
+        .0
+        .as_ref()
+        .expect("inputs should contain toCKB cell");
+    let output_data = toCKB_data_tuple
+        .1
+        .as_ref()
+        .expect("outputs should contain toCKB cell");
+    if !input_data.is_partial_redeem() {
+        return Err(Error::TxInvalid);
+    }
+
+    verify_capacity(input_data, output_data)?;
+    debug!("verify capacity finish");
+    let x_extra = verify_witness(input_data)?;
+    debug!("verify witness finish");
+    verify_data(input_data, output_data, &x_extra)?;
+    // the new lock can not be used to mint another deposit
+    verify_lock_registry_insert(&output_data.x_extra)
+}
+
+/// The collateral backing the redeemed part is released to the signer, the capacity reserved for
+/// the XT cell of a later auction stays in the cell.
+fn verify_capacity(
+    input_data: &ToCKBCellDataView,
+    output_data: &ToCKBCellDataView,
+) -> Result<(), Error> {
+    let cap_input = load_cell_capacity(0, Source::GroupInput)?;
+    let cap_output = load_cell_capacity(0, Source::GroupOutput)?;
+    let asset_collateral = (cap_input - XT_CELL_CAPACITY) as u128;
+    let remain_collateral =
+        asset_collateral * output_data.get_lot_xt_amount() / input_data.get_lot_xt_amount();
+    debug!(
+        "asset_collateral: {}, remain_collateral: {}",
+        asset_collateral, remain_collateral
+    );
+    if cap_output != XT_CELL_CAPACITY + remain_collateral as u64 {
+        return Err(Error::CapacityInvalid);
+    }
+    verify_capacity_with_value(input_data, cap_input - cap_output)
+}
+
+fn verify_data(
+    input_data: &ToCKBCellDataView,
+    output_data: &ToCKBCellDataView,
+    x_extra: &XExtraView,
+) -> Result<(), Error> {
+    if output_data.get_raw_lot_size() != ARBITRARY_LOT_SIZE
+        || output_data.get_lot_xt_amount()
+            != input_data.get_lot_xt_amount() - input_data.get_redeem_xt_amount()
+        || input_data.user_lockscript != output_data.user_lockscript
+        || input_data.x_lock_address != output_data.x_lock_address
+        || input_data.signer_lockscript != output_data.signer_lockscript
+        || input_data.signer_group != output_data.signer_group
+        || input_data.signer_fee_rate != output_data.signer_fee_rate
+        || input_data.pledge != output_data.pledge
+        || input_data.params_snapshot != output_data.params_snapshot
+        || input_data.liquidation_trigger_lockscript != output_data.liquidation_trigger_lockscript
+        || &output_data.x_extra != x_extra
+    {
+        return Err(Error::InvalidDataChange);
+    }
+    Ok(())
+}
+
+/// ensure the redeemed part is paid to x_unlock_address and the remainder is locked again
+fn verify_witness(data: &ToCKBCellDataView) -> Result<XExtraView, Error> {
+    let witness_args = load_witness_args(0, Source::GroupInput)?.input_type();
+    debug!("witness_args: {:?}", &witness_args);
+    if witness_args.is_none() {
+        return Err(Error::InvalidWitness);
+    }
+    let witness_args = witness_args.to_opt().unwrap().raw_data();
+    if MintXTWitnessReader::verify(&witness_args, false).is_err() {
+        return Err(Error::InvalidWitness);
+    }
+    let witness = MintXTWitnessReader::new_unchecked(&witness_args);
+    debug!("witness: {:?}", witness);
+    let proof = witness.spv_proof().raw_data();
+    let cell_dep_index_list = witness.cell_dep_index_list().raw_data();
+    get_xchain_verifier(data.get_xchain_kind()).verify_partial_withdraw(
+        data,
+        proof,
+        cell_dep_index_list,
+    )
+}
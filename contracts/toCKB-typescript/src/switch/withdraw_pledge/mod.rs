@@ -1,7 +1,9 @@
 use crate::switch::ToCKBCellDataTuple;
-use crate::utils::config::{PLEDGE, SINCE_WITHDRAW_PLEDGE};
+use crate::utils::transaction::get_protocol_params;
 use crate::utils::types::Error;
-use crate::utils::verifier::{verify_capacity_with_value, verify_since_by_value};
+use crate::utils::verifier::{verify_capacity_to_lockscript, verify_since_by_value};
+use ckb_std::ckb_constants::Source;
+use ckb_std::high_level::load_cell_capacity;
 use core::result::Result;
 
 pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
@@ -9,6 +11,9 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
         .0
         .as_ref()
         .expect("inputs contain toCKB cell");
-    verify_since_by_value(SINCE_WITHDRAW_PLEDGE)?;
-    verify_capacity_with_value(input_toCKB_data, PLEDGE)
+    verify_since_by_value(get_protocol_params()?.since_withdraw_pledge)?;
+    // nobody bonded the request, the pledge goes back to the user, the signer lockscript
+    // only records the chosen signer
+    let pledge = load_cell_capacity(0, Source::GroupInput)?;
+    verify_capacity_to_lockscript(input_toCKB_data.user_lockscript.as_ref(), pledge)
 }
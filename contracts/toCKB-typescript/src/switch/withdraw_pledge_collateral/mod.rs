@@ -1,5 +1,5 @@
 use crate::switch::ToCKBCellDataTuple;
-use crate::utils::config::SINCE_WITHDRAW_PLEDGE_COLLATERAL;
+use crate::utils::transaction::get_protocol_params;
 use crate::utils::types::Error;
 use crate::utils::verifier::{verify_capacity_with_value, verify_since_by_value};
 use ckb_std::ckb_constants::Source;
@@ -11,7 +11,7 @@ pub fn verify(toCKB_data_tuple: &ToCKBCellDataTuple) -> Result<(), Error> {
         .0
         .as_ref()
         .expect("inputs contain toCKB cell");
-    verify_since_by_value(SINCE_WITHDRAW_PLEDGE_COLLATERAL)?;
+    verify_since_by_value(get_protocol_params()?.since_withdraw_pledge_collateral)?;
     let pledge_collateral = load_cell_capacity(0, Source::GroupInput)?;
     verify_capacity_with_value(input_toCKB_data, pledge_collateral)
 }
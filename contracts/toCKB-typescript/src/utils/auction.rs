@@ -0,0 +1,54 @@
+use crate::utils::{
+    config::XT_CELL_CAPACITY,
+    transaction::{get_protocol_params, ProtocolParamsView},
+    types::Error,
+};
+use ckb_std::{ckb_constants::Source, debug, high_level::load_cell_capacity};
+
+/// The collateral of the auctioned toCKB cell and the part of it paid to the bidder, the rest is
+/// split by the trigger and the signer depending on the auction.
+pub struct AuctionPayout {
+    pub collateral: u64,
+    pub to_bidder: u64,
+}
+
+/// Price the auction along the curve of the protocol params, `auction_time` is the relative
+/// since of the toCKB cell in seconds. The XT_CELL_CAPACITY of the cell is not auctioned.
+pub fn get_auction_payout(auction_time: u64) -> Result<AuctionPayout, Error> {
+    let params = get_protocol_params()?;
+    let collateral = load_cell_capacity(0, Source::GroupInput)? - XT_CELL_CAPACITY;
+    price_auction(&params, collateral, auction_time)
+}
+
+/// Like `get_auction_payout`, but the depositor is compensated with `faulty_compensation_percent`
+/// of the collateral first and only the rest is auctioned. Returns the compensation and the
+/// payout of the auctioned collateral.
+pub fn get_faulty_warranty_auction_payout(
+    auction_time: u64,
+) -> Result<(u64, AuctionPayout), Error> {
+    let params = get_protocol_params()?;
+    let collateral = load_cell_capacity(0, Source::GroupInput)? - XT_CELL_CAPACITY;
+    let to_depositor = params.get_faulty_compensation(collateral);
+    debug!("to_depositor: {}", to_depositor);
+    let payout = price_auction(&params, collateral - to_depositor, auction_time)?;
+    Ok((to_depositor, payout))
+}
+
+fn price_auction(
+    params: &ProtocolParamsView,
+    collateral: u64,
+    auction_time: u64,
+) -> Result<AuctionPayout, Error> {
+    debug!(
+        "auction_time: {}, auction_max_time: {}, auction_curve: {:?}",
+        auction_time,
+        params.auction_max_time,
+        params.get_auction_curve()?
+    );
+    let to_bidder = params.get_auction_bidder_payout(collateral, auction_time)?;
+    debug!("collateral: {}, to_bidder: {}", collateral, to_bidder);
+    Ok(AuctionPayout {
+        collateral,
+        to_bidder,
+    })
+}
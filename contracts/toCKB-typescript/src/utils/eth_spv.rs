@@ -0,0 +1,433 @@
+use crate::utils::types::Error;
+use alloc::vec::Vec;
+use tiny_keccak::{Hasher, Keccak};
+
+// index of fields in rlp encoded block header
+const HEADER_TX_ROOT_INDEX: usize = 4;
+const HEADER_RECEIPT_ROOT_INDEX: usize = 5;
+const HEADER_NUMBER_INDEX: usize = 8;
+// EIP-2718 typed envelopes start with a byte in [0, 0x7f]
+const TYPED_ENVELOPE_MAX: u8 = 0x7f;
+const LEGACY_TX_TYPE: u8 = 0;
+const ACCESS_LIST_TX_TYPE: u8 = 1;
+const DYNAMIC_FEE_TX_TYPE: u8 = 2;
+const RECEIPT_STATUS_SUCCESS: u8 = 1;
+// index of logs in rlp encoded receipt
+const RECEIPT_LOGS_INDEX: usize = 3;
+// keccak256("Transfer(address,address,uint256)"), the first topic of ERC-20 `Transfer` events
+const TRANSFER_EVENT_TOPIC: [u8; 32] = [
+    0xdd, 0xf2, 0x52, 0xad, 0x1b, 0xe2, 0xc8, 0x9b, 0x69, 0xc2, 0xb0, 0x68, 0xfc, 0x37, 0x8d, 0xaa,
+    0x95, 0x2b, 0xa7, 0xf1, 0x63, 0xc4, 0xa1, 0x16, 0x28, 0xf5, 0x5a, 0x4d, 0xf5, 0x23, 0xb3, 0xef,
+];
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut output = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut output);
+    output
+}
+
+/// A decoded rlp item, `raw` is the whole encoding and `payload` is the content.
+#[derive(Debug, Clone, Copy)]
+pub struct RlpItem<'a> {
+    pub raw: &'a [u8],
+    pub payload: &'a [u8],
+    pub is_list: bool,
+}
+
+impl<'a> RlpItem<'a> {
+    pub fn decode(data: &'a [u8]) -> Result<RlpItem<'a>, Error> {
+        let first = *data.first().ok_or(Error::SpvProofInvalid)?;
+        let (is_list, offset, len) = match first {
+            0x00..=0x7f => {
+                return Ok(RlpItem {
+                    raw: &data[..1],
+                    payload: &data[..1],
+                    is_list: false,
+                })
+            }
+            0x80..=0xb7 => (false, 1, (first - 0x80) as usize),
+            0xb8..=0xbf => {
+                let len_of_len = (first - 0xb7) as usize;
+                (
+                    false,
+                    1 + len_of_len,
+                    decode_length(&data[1..], len_of_len)?,
+                )
+            }
+            0xc0..=0xf7 => (true, 1, (first - 0xc0) as usize),
+            0xf8..=0xff => {
+                let len_of_len = (first - 0xf7) as usize;
+                (true, 1 + len_of_len, decode_length(&data[1..], len_of_len)?)
+            }
+        };
+        let end = offset.checked_add(len).ok_or(Error::SpvProofInvalid)?;
+        if data.len() < end {
+            return Err(Error::SpvProofInvalid);
+        }
+        Ok(RlpItem {
+            raw: &data[..end],
+            payload: &data[offset..end],
+            is_list,
+        })
+    }
+
+    /// decode data which must be exactly one rlp list
+    pub fn decode_list(data: &'a [u8]) -> Result<Vec<RlpItem<'a>>, Error> {
+        let item = RlpItem::decode(data)?;
+        if !item.is_list || item.raw.len() != data.len() {
+            return Err(Error::SpvProofInvalid);
+        }
+        item.items()
+    }
+
+    pub fn items(&self) -> Result<Vec<RlpItem<'a>>, Error> {
+        if !self.is_list {
+            return Err(Error::SpvProofInvalid);
+        }
+        let mut items = Vec::new();
+        let mut rest = self.payload;
+        while !rest.is_empty() {
+            let item = RlpItem::decode(rest)?;
+            rest = &rest[item.raw.len()..];
+            items.push(item);
+        }
+        Ok(items)
+    }
+
+    pub fn as_bytes(&self) -> Result<&'a [u8], Error> {
+        if self.is_list {
+            return Err(Error::SpvProofInvalid);
+        }
+        Ok(self.payload)
+    }
+}
+
+fn decode_length(data: &[u8], len_of_len: usize) -> Result<usize, Error> {
+    if data.len() < len_of_len || len_of_len > 4 {
+        return Err(Error::SpvProofInvalid);
+    }
+    Ok(data[..len_of_len]
+        .iter()
+        .fold(0usize, |acc, b| (acc << 8) | *b as usize))
+}
+
+fn decode_uint(data: &[u8]) -> Result<u64, Error> {
+    if data.len() > 8 {
+        return Err(Error::SpvProofInvalid);
+    }
+    Ok(data.iter().fold(0u64, |acc, b| (acc << 8) | *b as u64))
+}
+
+/// compare a big endian integer without leading zeros with a u128
+pub fn is_value_enough(value: &[u8], expect: u128) -> bool {
+    if value.len() > 16 {
+        return true;
+    }
+    let mut buf = [0u8; 16];
+    buf[16 - value.len()..].copy_from_slice(value);
+    u128::from_be_bytes(buf) >= expect
+}
+
+fn rlp_encode_list_header(payload_len: usize) -> Vec<u8> {
+    if payload_len < 56 {
+        return [0xc0 + payload_len as u8].to_vec();
+    }
+    let len_bytes = (payload_len as u64).to_be_bytes();
+    let start = len_bytes
+        .iter()
+        .position(|b| *b != 0)
+        .expect("length is not zero");
+    let mut ret = Vec::with_capacity(9);
+    ret.push(0xf7 + (8 - start) as u8);
+    ret.extend_from_slice(&len_bytes[start..]);
+    ret
+}
+
+/// rlp encode an unsigned integer, which is the key of transactions trie and receipts trie
+pub fn rlp_encode_index(index: u64) -> Vec<u8> {
+    if index == 0 {
+        return [0x80].to_vec();
+    }
+    let bytes = index.to_be_bytes();
+    let start = bytes
+        .iter()
+        .position(|b| *b != 0)
+        .expect("index is not zero");
+    let bytes = &bytes[start..];
+    if bytes.len() == 1 && bytes[0] < 0x80 {
+        return bytes.to_vec();
+    }
+    let mut ret = Vec::with_capacity(bytes.len() + 1);
+    ret.push(0x80 + bytes.len() as u8);
+    ret.extend_from_slice(bytes);
+    ret
+}
+
+/// verify merkle patricia proof and return the value of the key
+pub fn verify_mpt_proof(root: &[u8], key: &[u8], proof: &[&[u8]]) -> Result<Vec<u8>, Error> {
+    let nibbles = key
+        .iter()
+        .flat_map(|b| [b >> 4, b & 0x0f].to_vec())
+        .collect::<Vec<u8>>();
+    let mut nibble_pos = 0;
+    let mut proof_iter = proof.iter();
+    let mut node: &[u8] = next_hashed_node(&mut proof_iter, root)?;
+    loop {
+        let items = RlpItem::decode_list(node)?;
+        let next_ref = match items.len() {
+            // branch node
+            17 => {
+                if nibble_pos == nibbles.len() {
+                    let value = items[16].as_bytes()?;
+                    if value.is_empty() {
+                        return Err(Error::BadMerkleProof);
+                    }
+                    return Ok(value.to_vec());
+                }
+                let child = items[nibbles[nibble_pos] as usize];
+                nibble_pos += 1;
+                child
+            }
+            // extension or leaf node
+            2 => {
+                let encoded_path = items[0].as_bytes()?;
+                let flag = *encoded_path.first().ok_or(Error::BadMerkleProof)? >> 4;
+                let mut path = Vec::with_capacity(encoded_path.len() * 2);
+                if flag & 1 == 1 {
+                    path.push(encoded_path[0] & 0x0f);
+                }
+                for b in &encoded_path[1..] {
+                    path.push(b >> 4);
+                    path.push(b & 0x0f);
+                }
+                let rest = &nibbles[nibble_pos..];
+                if rest.len() < path.len() || rest[..path.len()] != path[..] {
+                    return Err(Error::BadMerkleProof);
+                }
+                nibble_pos += path.len();
+                let is_leaf = flag & 2 == 2;
+                if is_leaf {
+                    if nibble_pos != nibbles.len() {
+                        return Err(Error::BadMerkleProof);
+                    }
+                    return Ok(items[1].as_bytes()?.to_vec());
+                }
+                items[1]
+            }
+            _ => return Err(Error::BadMerkleProof),
+        };
+        // nodes shorter than 32 bytes are embedded in their parent
+        node = if next_ref.is_list {
+            next_ref.raw
+        } else {
+            next_hashed_node(&mut proof_iter, next_ref.as_bytes()?)?
+        };
+    }
+}
+
+fn next_hashed_node<'a, I: Iterator<Item = &'a &'a [u8]>>(
+    proof_iter: &mut I,
+    hash: &[u8],
+) -> Result<&'a [u8], Error> {
+    let node = proof_iter.next().ok_or(Error::BadMerkleProof)?;
+    if hash.len() != 32 || keccak256(node) != hash {
+        return Err(Error::BadMerkleProof);
+    }
+    Ok(node)
+}
+
+pub struct EthHeader {
+    pub hash: [u8; 32],
+    pub number: u64,
+    pub tx_root: Vec<u8>,
+    pub receipt_root: Vec<u8>,
+}
+
+pub fn parse_header(header_data: &[u8]) -> Result<EthHeader, Error> {
+    let items = RlpItem::decode_list(header_data)?;
+    if items.len() <= HEADER_NUMBER_INDEX {
+        return Err(Error::SpvProofInvalid);
+    }
+    Ok(EthHeader {
+        hash: keccak256(header_data),
+        number: decode_uint(items[HEADER_NUMBER_INDEX].as_bytes()?)?,
+        tx_root: items[HEADER_TX_ROOT_INDEX].as_bytes()?.to_vec(),
+        receipt_root: items[HEADER_RECEIPT_ROOT_INDEX].as_bytes()?.to_vec(),
+    })
+}
+
+pub struct EthTransfer<'a> {
+    pub to: &'a [u8],
+    pub value: &'a [u8],
+}
+
+/// decode legacy, EIP-2930 and EIP-1559 transactions, return the tx type,
+/// the fields and the index of `to` in the fields
+fn decode_tx(tx_data: &[u8]) -> Result<(u8, Vec<RlpItem>, usize), Error> {
+    let first = *tx_data.first().ok_or(Error::SpvProofInvalid)?;
+    let (tx_type, items, to_index, fields_count) = if first > TYPED_ENVELOPE_MAX {
+        (LEGACY_TX_TYPE, RlpItem::decode_list(tx_data)?, 3, 9)
+    } else {
+        let items = RlpItem::decode_list(&tx_data[1..])?;
+        match first {
+            ACCESS_LIST_TX_TYPE => (first, items, 4, 11),
+            DYNAMIC_FEE_TX_TYPE => (first, items, 5, 12),
+            _ => return Err(Error::UnsupportedFundingType),
+        }
+    };
+    if items.len() != fields_count {
+        return Err(Error::SpvProofInvalid);
+    }
+    Ok((tx_type, items, to_index))
+}
+
+/// parse `to` and `value` of the transaction
+pub fn parse_transfer(tx_data: &[u8]) -> Result<EthTransfer, Error> {
+    let (_, items, to_index) = decode_tx(tx_data)?;
+    Ok(EthTransfer {
+        to: items[to_index].as_bytes()?,
+        value: items[to_index + 1].as_bytes()?,
+    })
+}
+
+/// recover the sender address of the transaction from its signature
+pub fn recover_sender(tx_data: &[u8]) -> Result<[u8; 20], Error> {
+    let (tx_type, items, _) = decode_tx(tx_data)?;
+    // the last three fields are the signature
+    let (unsigned_fields, signature) = items.split_at(items.len() - 3);
+    let v = decode_uint(signature[0].as_bytes()?)?;
+    let mut signing_data = Vec::new();
+    let recovery_id = if tx_type == LEGACY_TX_TYPE {
+        let mut payload = unsigned_fields
+            .iter()
+            .flat_map(|item| item.raw.to_vec())
+            .collect::<Vec<u8>>();
+        let recovery_id = match v {
+            27 | 28 => v - 27,
+            // EIP-155, v = chain_id * 2 + 35 + recovery_id
+            v if v >= 35 => {
+                let chain_id = ((v - 35) / 2).to_be_bytes();
+                let start = chain_id
+                    .iter()
+                    .position(|b| *b != 0)
+                    .unwrap_or(chain_id.len());
+                let chain_id = &chain_id[start..];
+                if chain_id.len() == 1 && chain_id[0] < 0x80 {
+                    payload.push(chain_id[0]);
+                } else {
+                    payload.push(0x80 + chain_id.len() as u8);
+                    payload.extend_from_slice(chain_id);
+                }
+                payload.extend_from_slice(&[0x80, 0x80]);
+                (v - 35) % 2
+            }
+            _ => return Err(Error::SpvProofInvalid),
+        };
+        signing_data.extend(rlp_encode_list_header(payload.len()));
+        signing_data.extend(payload);
+        recovery_id
+    } else {
+        let payload = unsigned_fields
+            .iter()
+            .flat_map(|item| item.raw.to_vec())
+            .collect::<Vec<u8>>();
+        signing_data.push(tx_type);
+        signing_data.extend(rlp_encode_list_header(payload.len()));
+        signing_data.extend(payload);
+        v
+    };
+    if recovery_id > 1 {
+        return Err(Error::SpvProofInvalid);
+    }
+
+    let r = signature[1].as_bytes()?;
+    let s = signature[2].as_bytes()?;
+    if r.len() > 32 || s.len() > 32 {
+        return Err(Error::SpvProofInvalid);
+    }
+    let mut sig = [0u8; 64];
+    sig[32 - r.len()..32].copy_from_slice(r);
+    sig[64 - s.len()..].copy_from_slice(s);
+    let message = secp256k1::Message::parse(&keccak256(&signing_data));
+    let signature = secp256k1::Signature::parse(&sig);
+    let recovery_id =
+        secp256k1::RecoveryId::parse(recovery_id as u8).map_err(|_| Error::SpvProofInvalid)?;
+    let pubkey = secp256k1::recover(&message, &signature, &recovery_id)
+        .map_err(|_| Error::SpvProofInvalid)?;
+    // address is the last 20 bytes of keccak256 of the uncompressed public key without prefix
+    let pubkey_hash = keccak256(&pubkey.serialize()[1..]);
+    let mut address = [0u8; 20];
+    address.copy_from_slice(&pubkey_hash[12..]);
+    Ok(address)
+}
+
+/// decode legacy and typed receipts into their fields
+fn decode_receipt(receipt_data: &[u8]) -> Result<Vec<RlpItem>, Error> {
+    let first = *receipt_data.first().ok_or(Error::SpvProofInvalid)?;
+    let receipt_data = if first > TYPED_ENVELOPE_MAX {
+        receipt_data
+    } else {
+        &receipt_data[1..]
+    };
+    RlpItem::decode_list(receipt_data)
+}
+
+/// check the receipt status, pre-byzantium receipts which carry state root are not supported
+pub fn verify_receipt_status(receipt_data: &[u8]) -> Result<(), Error> {
+    let items = decode_receipt(receipt_data)?;
+    let status = items.first().ok_or(Error::SpvProofInvalid)?.as_bytes()?;
+    if status.len() > 1 {
+        return Err(Error::UnsupportedFundingType);
+    }
+    if status != [RECEIPT_STATUS_SUCCESS] {
+        return Err(Error::EthTxFailed);
+    }
+    Ok(())
+}
+
+/// An ERC-20 `Transfer` event, `value` is big endian without leading zeros
+pub struct TokenTransfer<'a> {
+    pub from: &'a [u8],
+    pub to: &'a [u8],
+    pub value: &'a [u8],
+}
+
+/// parse the `Transfer` events emitted by the token contract `token` from the receipt logs
+pub fn parse_token_transfers<'a>(
+    receipt_data: &'a [u8],
+    token: &[u8],
+) -> Result<Vec<TokenTransfer<'a>>, Error> {
+    let items = decode_receipt(receipt_data)?;
+    let logs = items
+        .get(RECEIPT_LOGS_INDEX)
+        .ok_or(Error::SpvProofInvalid)?
+        .items()?;
+    let mut transfers = Vec::new();
+    for log in logs {
+        // a log is [address, topics, data]
+        let fields = log.items()?;
+        if fields.len() != 3 || fields[0].as_bytes()? != token {
+            continue;
+        }
+        let topics = fields[1].items()?;
+        if topics.len() != 3 || topics[0].as_bytes()? != TRANSFER_EVENT_TOPIC {
+            continue;
+        }
+        let from = topics[1].as_bytes()?;
+        let to = topics[2].as_bytes()?;
+        let value = fields[2].as_bytes()?;
+        if from.len() != 32 || to.len() != 32 || value.len() != 32 {
+            return Err(Error::SpvProofInvalid);
+        }
+        let start = value.iter().position(|b| *b != 0).unwrap_or(value.len());
+        // indexed addresses are left padded to 32 bytes
+        transfers.push(TokenTransfer {
+            from: &from[12..],
+            to: &to[12..],
+            value: &value[start..],
+        });
+    }
+    Ok(transfers)
+}
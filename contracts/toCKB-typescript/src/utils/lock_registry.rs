@@ -0,0 +1,59 @@
+use crate::utils::{config::LOCK_REGISTRY_TYPE_HASH, types::Error};
+use ckb_std::{
+    ckb_constants::Source,
+    debug,
+    high_level::{
+        load_cell_capacity, load_cell_data, load_cell_lock_hash, load_cell_type_hash,
+        load_script_hash, load_witness_args, QueryIter,
+    },
+};
+use tockb_types::{
+    lock_registry::{get_registry_key, LockRegistryView},
+    tockb_cell::XExtraView,
+};
+
+fn find_lock_registry(source: Source) -> Option<usize> {
+    QueryIter::new(load_cell_type_hash, source)
+        .position(|type_hash| type_hash == Some(LOCK_REGISTRY_TYPE_HASH))
+}
+
+/// The registry is locked by the toCKB lockscript, which lets any toCKB cell spend it, so the
+/// toCKB cells in a tx which does not lock the XAsset again must keep it untouched.
+pub fn forbid_lock_registry_update() -> Result<(), Error> {
+    if find_lock_registry(Source::Input).is_some() {
+        return Err(Error::LockRegistryInvalid);
+    }
+    Ok(())
+}
+
+/// Verify the registry in the tx inserts the new lock tx of the deposit, with the deposit's type
+/// hash as the value. Mint, handover and partial withdraw lock the XAsset in a new lock tx, all of
+/// them are registered so none of them can mint another deposit.
+///
+/// The merkle proof that the lock tx is not registered yet is the input_type of the registry
+/// witness.
+pub fn verify_lock_registry_insert(x_extra: &XExtraView) -> Result<(), Error> {
+    let input_index = find_lock_registry(Source::Input).ok_or(Error::LockRegistryNotFound)?;
+    let output_index = find_lock_registry(Source::Output).ok_or(Error::LockRegistryNotFound)?;
+    if load_cell_lock_hash(input_index, Source::Input)?
+        != load_cell_lock_hash(output_index, Source::Output)?
+        || load_cell_capacity(output_index, Source::Output)?
+            < load_cell_capacity(input_index, Source::Input)?
+    {
+        return Err(Error::LockRegistryInvalid);
+    }
+    let proof = load_witness_args(input_index, Source::Input)?
+        .input_type()
+        .to_opt()
+        .ok_or(Error::LockRegistryInvalid)?
+        .raw_data();
+    let input_registry = LockRegistryView::new(&load_cell_data(input_index, Source::Input)?)?;
+    let output_registry = LockRegistryView::new(&load_cell_data(output_index, Source::Output)?)?;
+    let key = get_registry_key(x_extra);
+    debug!("lock registry key: {:?}", key);
+    let expect_registry = input_registry.insert(key, load_script_hash()?, proof.as_ref())?;
+    if output_registry != expect_registry {
+        return Err(Error::LockRegistryInvalid);
+    }
+    Ok(())
+}
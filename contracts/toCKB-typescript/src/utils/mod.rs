@@ -1,5 +1,11 @@
+pub mod auction;
+pub mod eth_spv;
+pub mod lock_registry;
+pub mod price_oracle;
+pub mod protocol_params;
 pub mod transaction;
 pub mod verifier;
+pub mod xchain;
 
 pub use tockb_types as types;
 pub use tockb_types::config;
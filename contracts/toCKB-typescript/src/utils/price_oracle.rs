@@ -0,0 +1,115 @@
+use crate::utils::{
+    config::{
+        PRICE_ORACLE_MAX_AGE, PRICE_ORACLE_PUBLISHERS, PRICE_ORACLE_THRESHOLD,
+        PRICE_ORACLE_TYPE_HASHES,
+    },
+    eth_spv::keccak256,
+    types::Error,
+};
+use alloc::vec::Vec;
+use ckb_std::{
+    ckb_constants::Source,
+    ckb_types::prelude::*,
+    debug,
+    error::SysError,
+    high_level::{load_cell_type_hash, load_header, QueryIter},
+    syscalls,
+};
+use tockb_types::generated::price_oracle::SignedPriceReader;
+
+// price(16 byte) + timestamp(8 byte), the publisher signs keccak256 of them
+const SIGNED_PRICE_MESSAGE_LEN: usize = 24;
+
+/// Return the median of prices signed by whitelisted publishers in cell deps.
+///
+/// Cell deps whose type hash is in PRICE_ORACLE_TYPE_HASHES are price oracles, other cell deps are
+/// skipped. The price of a cell must be a `SignedPrice` of the publisher at the same index, each
+/// publisher can only appear once and its price must be published within PRICE_ORACLE_MAX_AGE
+/// seconds before the latest header dep. The header of the block creating the toCKB input must be
+/// one of them, so the builder can not pick a time earlier than the cell to pass stale prices.
+pub fn get_median_price() -> Result<u128, Error> {
+    let input_header = load_header(0, Source::GroupInput).map_err(|err| {
+        debug!("header of the toCKB input not in header deps: {:?}", err);
+        Error::PriceOracleStale
+    })?;
+    let input_timestamp: u64 = input_header.raw().timestamp().unpack();
+    let now = QueryIter::new(load_header, Source::HeaderDep)
+        .map(|header| header.raw().timestamp().unpack())
+        .fold(input_timestamp, u64::max)
+        / 1000;
+
+    let mut publishers = Vec::new();
+    let mut prices = Vec::new();
+    let mut buf = [0u8; SignedPriceReader::TOTAL_SIZE];
+    for (index, type_hash) in QueryIter::new(load_cell_type_hash, Source::CellDep).enumerate() {
+        let publisher_index = match PRICE_ORACLE_TYPE_HASHES
+            .iter()
+            .position(|hash| Some(*hash) == type_hash)
+        {
+            Some(publisher_index) => publisher_index,
+            None => continue,
+        };
+        match syscalls::load_cell_data(&mut buf, 0, index, Source::CellDep) {
+            Ok(len) if len == SignedPriceReader::TOTAL_SIZE => {}
+            Ok(_) | Err(SysError::LengthNotEnough(_)) => return Err(Error::PriceOracleInvalid),
+            Err(err) => return Err(err.into()),
+        }
+        let (price, timestamp) =
+            verify_signed_price(&buf, &PRICE_ORACLE_PUBLISHERS[publisher_index])?;
+        debug!(
+            "price oracle {}: publisher {}, price {}, timestamp {}",
+            index, publisher_index, price, timestamp
+        );
+        // two rounds of the same publisher, the type id only keeps one of them live
+        if publishers.contains(&publisher_index) {
+            return Err(Error::PriceOracleInvalid);
+        }
+        if timestamp.saturating_add(PRICE_ORACLE_MAX_AGE) < now {
+            return Err(Error::PriceOracleStale);
+        }
+        publishers.push(publisher_index);
+        prices.push(price);
+    }
+
+    if prices.len() < PRICE_ORACLE_THRESHOLD {
+        return Err(Error::PriceOracleNotEnough);
+    }
+    prices.sort_unstable();
+    let mid = prices.len() / 2;
+    let price = if prices.len() % 2 == 0 {
+        prices[mid - 1] / 2 + prices[mid] / 2 + (prices[mid - 1] % 2 + prices[mid] % 2) / 2
+    } else {
+        prices[mid]
+    };
+    debug!("median price: {}", price);
+    Ok(price)
+}
+
+/// Return (price, timestamp) if the price is signed by `expected_publisher`
+fn verify_signed_price(data: &[u8], expected_publisher: &[u8; 20]) -> Result<(u128, u64), Error> {
+    let reader = SignedPriceReader::new_unchecked(data);
+    let mut price = [0u8; 16];
+    price.copy_from_slice(reader.price().as_slice());
+    let mut timestamp = [0u8; 8];
+    timestamp.copy_from_slice(reader.timestamp().as_slice());
+    let mut publisher = [0u8; 20];
+    publisher.copy_from_slice(reader.publisher().as_slice());
+    if &publisher != expected_publisher {
+        return Err(Error::PriceOraclePublisherInvalid);
+    }
+
+    let signature = reader.signature().as_slice();
+    let message = secp256k1::Message::parse(&keccak256(&data[..SIGNED_PRICE_MESSAGE_LEN]));
+    let mut sig = [0u8; 64];
+    sig.copy_from_slice(&signature[..64]);
+    let signature_obj = secp256k1::Signature::parse(&sig);
+    let recovery_id =
+        secp256k1::RecoveryId::parse(signature[64]).map_err(|_| Error::PriceOracleInvalid)?;
+    let pubkey = secp256k1::recover(&message, &signature_obj, &recovery_id)
+        .map_err(|_| Error::PriceOraclePublisherInvalid)?;
+    // address is the last 20 bytes of keccak256 of the uncompressed public key without prefix
+    if keccak256(&pubkey.serialize()[1..])[12..] != publisher[..] {
+        return Err(Error::PriceOraclePublisherInvalid);
+    }
+    Ok((u128::from_le_bytes(price), u64::from_le_bytes(timestamp)))
+}
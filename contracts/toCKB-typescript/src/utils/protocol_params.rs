@@ -0,0 +1,26 @@
+use crate::utils::{
+    config::{PROTOCOL_PARAMS_LOCK_HASH, PROTOCOL_PARAMS_TYPE_HASH},
+    types::Error,
+};
+use ckb_std::{
+    ckb_constants::Source,
+    debug,
+    high_level::{load_cell_data, load_cell_lock_hash, load_cell_type_hash, QueryIter},
+};
+use tockb_types::protocol_params::ProtocolParamsView;
+
+/// Return the protocol params kept in the governance cell.
+///
+/// The cell is identified by its type id and must be owned by the governance lock, so only the
+/// governance can update the params, and the tx must reference the live cell as a cell dep.
+pub fn load_protocol_params() -> Result<ProtocolParamsView, Error> {
+    let index = QueryIter::new(load_cell_type_hash, Source::CellDep)
+        .position(|type_hash| type_hash == Some(PROTOCOL_PARAMS_TYPE_HASH))
+        .ok_or(Error::ProtocolParamsNotFound)?;
+    if load_cell_lock_hash(index, Source::CellDep)? != PROTOCOL_PARAMS_LOCK_HASH {
+        return Err(Error::ProtocolParamsInvalid);
+    }
+    let params = ProtocolParamsView::new(&load_cell_data(index, Source::CellDep)?)?;
+    debug!("protocol params: {:?}", params);
+    Ok(params)
+}
@@ -1,16 +1,24 @@
 use crate::utils::{
     config::{SUDT_CODE_HASH, UDT_LEN},
+    price_oracle, protocol_params,
     types::Error,
 };
 
+use alloc::vec::Vec;
 use ckb_std::{
     ckb_constants::Source,
     ckb_types::packed::Script,
     debug,
-    high_level::{load_cell_data, load_cell_type, load_script},
+    error::SysError,
+    high_level::{
+        load_cell_capacity, load_cell_data, load_cell_lock, load_cell_lock_hash, load_cell_type,
+        load_script,
+    },
 };
 use core::result::Result;
+use molecule::prelude::Entity;
 use tockb_types::generated::basic::OutPoint;
+pub use tockb_types::protocol_params::ProtocolParamsView;
 pub use tockb_types::tockb_cell::{ToCKBTypeArgsView, XChainKind};
 
 pub fn get_toCKB_type_args() -> Result<ToCKBTypeArgsView, Error> {
@@ -25,26 +33,33 @@ pub fn get_xchain_kind() -> Result<XChainKind, Error> {
     Ok(get_toCKB_type_args()?.xchain_kind)
 }
 
+/// Decode an address of the UTXO chain of the cell into the script_pubkey it pays to
+pub fn address_to_script(addr: &[u8]) -> Result<Vec<u8>, Error> {
+    get_toCKB_type_args()?.address_to_script(addr)
+}
+
+pub fn get_header_relay() -> Result<Option<[u8; 32]>, Error> {
+    Ok(get_toCKB_type_args()?.get_header_relay())
+}
+
 pub fn get_cell_id() -> Result<OutPoint, Error> {
     Ok(get_toCKB_type_args()?.cell_id)
 }
 
 pub fn get_price() -> Result<u128, Error> {
-    let price_cell_data = load_cell_data(0, Source::CellDep)?;
-    if price_cell_data.len() != 16 {
-        return Err(Error::Encoding);
-    }
-    let mut buf = [0u8; 16];
-    buf.copy_from_slice(&price_cell_data);
-    let price: u128 = u128::from_le_bytes(buf);
-    Ok(price)
+    price_oracle::get_median_price()
+}
+
+pub fn get_protocol_params() -> Result<ProtocolParamsView, Error> {
+    protocol_params::load_protocol_params()
 }
 
-pub fn is_XT_typescript(script: &Option<Script>, toCKB_lock_hash: &[u8]) -> bool {
+/// Whether the script is the sudt of XT, `xt_args` is given by `ToCKBCellDataView::get_xt_args`
+pub fn is_XT_typescript(script: &Option<Script>, xt_args: &[u8]) -> bool {
     match script {
         Some(script) => {
             if script.code_hash().raw_data().as_ref() == SUDT_CODE_HASH.as_ref()
-                && script.args().raw_data().as_ref() == toCKB_lock_hash
+                && script.args().raw_data().as_ref() == xt_args
                 && script.hash_type() == 0u8.into()
             {
                 return true;
@@ -58,7 +73,7 @@ pub fn is_XT_typescript(script: &Option<Script>, toCKB_lock_hash: &[u8]) -> bool
 pub fn get_sum_sudt_amount(
     start_index: usize,
     source: Source,
-    toCKB_lock_hash: &[u8],
+    xt_args: &[u8],
 ) -> Result<u128, Error> {
     let mut index = start_index;
     let mut sum_amount = 0;
@@ -68,7 +83,7 @@ pub fn get_sum_sudt_amount(
             break;
         }
         let script = res.unwrap();
-        if !is_XT_typescript(&script, toCKB_lock_hash) {
+        if !is_XT_typescript(&script, xt_args) {
             index += 1;
             continue;
         }
@@ -83,3 +98,79 @@ pub fn get_sum_sudt_amount(
 
     Ok(sum_amount)
 }
+
+/// Matches the cells a tx must create against its outputs wherever they are placed, so the
+/// toCKB actions can be combined with other cells in one tx. Every output can be claimed only
+/// once, so one cell never counts as the payment to two parties.
+#[derive(Default)]
+pub struct OutputMatcher {
+    claimed: Vec<usize>,
+}
+
+impl OutputMatcher {
+    /// Claim an output with the lockscript and capacity
+    pub fn claim_cell(&mut self, lockscript: &[u8], capacity: u64) -> Result<Option<usize>, Error> {
+        self.claim(|index| {
+            Ok(load_cell_capacity(index, Source::Output)? == capacity
+                && load_cell_lock(index, Source::Output)?.as_slice() == lockscript)
+        })
+    }
+
+    /// Claim an output with the lock hash and a capacity more than `min_capacity`
+    pub fn claim_cell_by_lock_hash(
+        &mut self,
+        lock_hash: &[u8],
+        min_capacity: u64,
+    ) -> Result<Option<usize>, Error> {
+        self.claim(|index| {
+            Ok(load_cell_capacity(index, Source::Output)? > min_capacity
+                && load_cell_lock_hash(index, Source::Output)?.as_ref() == lock_hash)
+        })
+    }
+
+    /// Claim a XT cell with the lockscript, XT amount and capacity
+    pub fn claim_xt_cell(
+        &mut self,
+        lockscript: &[u8],
+        xt_args: &[u8],
+        amount: u128,
+        capacity: u64,
+    ) -> Result<Option<usize>, Error> {
+        self.claim(|index| {
+            if load_cell_capacity(index, Source::Output)? != capacity
+                || load_cell_lock(index, Source::Output)?.as_slice() != lockscript
+                || !is_XT_typescript(&load_cell_type(index, Source::Output)?, xt_args)
+            {
+                return Ok(false);
+            }
+            let cell_data = load_cell_data(index, Source::Output)?;
+            if cell_data.len() != UDT_LEN {
+                return Ok(false);
+            }
+            let mut data = [0u8; UDT_LEN];
+            data.copy_from_slice(&cell_data);
+            Ok(u128::from_le_bytes(data) == amount)
+        })
+    }
+
+    /// Claim the first unclaimed output accepted by `matches`
+    pub fn claim<F>(&mut self, matches: F) -> Result<Option<usize>, Error>
+    where
+        F: Fn(usize) -> Result<bool, Error>,
+    {
+        let mut index = 0;
+        loop {
+            match load_cell_capacity(index, Source::Output) {
+                Err(SysError::IndexOutOfBound) => return Ok(None),
+                Err(err) => return Err(err.into()),
+                Ok(_) => {}
+            }
+            if !self.claimed.contains(&index) && matches(index)? {
+                debug!("claim output {}", index);
+                self.claimed.push(index);
+                return Ok(Some(index));
+            }
+            index += 1;
+        }
+    }
+}
@@ -0,0 +1,542 @@
+use crate::utils::{
+    config::{BTC_LOT_AMOUNT_RANGE, LTC_LOT_AMOUNT_RANGE, LTC_SPV_CONFIRMATIONS_FACTOR},
+    transaction::{address_to_script, get_header_relay, get_protocol_params, get_xchain_kind},
+    types::{
+        btc_address,
+        btc_difficulty::BTCDifficultyReader,
+        btc_relay::{self, BtcRelayView},
+        ltc_pow,
+        mint_xt_witness::BTCSPVProofReader,
+        BtcExtraView, Error, ToCKBCellDataView, XChainKind, XExtraView,
+    },
+    xchain::XChainVerifier,
+};
+use alloc::vec::Vec;
+use bitcoin_spv::types::{HeaderArray, MerkleArray, Vin, Vout};
+use bitcoin_spv::{btcspv, validatespv};
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::bytes::Bytes;
+use ckb_std::debug;
+use ckb_std::high_level::{load_cell_data, load_cell_type_hash};
+use primitive_types::U256;
+
+/// LTC shares the tx format and the scripts of BTC, only the headers are checked differently in
+/// `verify_btc_spv`, and the lock is kept in the extra of its own kind.
+pub struct UtxoVerifier {
+    lot_amount_range: (u128, u128),
+    as_x_extra: fn(BtcExtraView) -> XExtraView,
+}
+
+pub static BTC_VERIFIER: UtxoVerifier = UtxoVerifier {
+    lot_amount_range: BTC_LOT_AMOUNT_RANGE,
+    as_x_extra: XExtraView::Btc,
+};
+
+pub static LTC_VERIFIER: UtxoVerifier = UtxoVerifier {
+    lot_amount_range: LTC_LOT_AMOUNT_RANGE,
+    as_x_extra: XExtraView::Ltc,
+};
+
+impl XChainVerifier for UtxoVerifier {
+    fn lot_amount_range(&self) -> (u128, u128) {
+        self.lot_amount_range
+    }
+
+    fn verify_address(&self, address: &[u8]) -> Result<(), Error> {
+        verify_utxo_address(address)
+    }
+
+    /// A signer group locks the XAsset in the M-of-N multisig of its members
+    fn verify_signer_group_address(&self, data: &ToCKBCellDataView) -> Result<(), Error> {
+        let group = &data.signer_group;
+        let pubkeys: Vec<&[u8]> = group
+            .members
+            .iter()
+            .map(|member| member.x_pubkey.as_ref())
+            .collect();
+        let expect_script = btc_address::multisig_p2wsh_script(group.threshold, &pubkeys)?;
+        let script = address_to_script(data.x_lock_address.as_ref())?;
+        if script != expect_script {
+            return Err(Error::SignerGroupInvalid);
+        }
+        Ok(())
+    }
+
+    fn verify_funding(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error> {
+        let btc_extra = verify_btc_witness(
+            data,
+            proof,
+            cell_dep_index_list,
+            data.x_lock_address.as_ref(),
+            data.get_lot_xt_amount(),
+            false,
+        )?;
+        Ok((self.as_x_extra)(btc_extra))
+    }
+
+    fn verify_redeem(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error> {
+        let btc_extra = verify_btc_witness(
+            data,
+            proof,
+            cell_dep_index_list,
+            data.x_unlock_address.as_ref(),
+            data.get_lot_xt_amount(),
+            true,
+        )?;
+        debug!("extra {:?}", btc_extra);
+        Ok((self.as_x_extra)(btc_extra))
+    }
+
+    fn verify_partial_withdraw(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error> {
+        let btc_extra = verify_btc_partial_withdraw_witness(data, proof, cell_dep_index_list)?;
+        Ok((self.as_x_extra)(btc_extra))
+    }
+
+    fn verify_handover(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error> {
+        let btc_extra = verify_btc_handover_witness(data, proof, cell_dep_index_list)?;
+        Ok((self.as_x_extra)(btc_extra))
+    }
+
+    fn verify_faulty(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+        allow_pay_to_unlock_address: bool,
+    ) -> Result<(), Error> {
+        verify_btc_faulty_witness(
+            data,
+            proof,
+            cell_dep_index_list,
+            allow_pay_to_unlock_address,
+        )
+    }
+}
+
+fn verify_btc_witness(
+    _data: &ToCKBCellDataView,
+    proof: &[u8],
+    cell_dep_index_list: &[u8],
+    expect_address: &[u8],
+    expect_value: u128,
+    is_return_vin: bool,
+) -> Result<BtcExtraView, Error> {
+    debug!(
+        "proof: {:?}, cell_dep_index_list: {:?}",
+        proof, cell_dep_index_list
+    );
+    if cell_dep_index_list.len() != 1 {
+        return Err(Error::InvalidWitness);
+    }
+    // parse witness
+    if BTCSPVProofReader::verify(proof, false).is_err() {
+        return Err(Error::InvalidWitness);
+    }
+    let proof_reader = BTCSPVProofReader::new_unchecked(proof);
+    debug!("proof_reader: {:?}", proof_reader);
+
+    // verify btc spv
+    let tx_hash = verify_btc_spv(proof_reader, cell_dep_index_list[0].into())?;
+
+    // verify transfer amount, to matches
+    let funding_output_index = proof_reader.funding_output_index().into();
+
+    let vout = Vout::new(proof_reader.vout().raw_data())?;
+    let tx_out = vout.index(funding_output_index as usize)?;
+    let script = get_script(tx_out.script_pubkey().as_ref())?;
+    debug!("funding script: {}", hex::encode(script));
+    if !btc_address::is_supported_script(script) {
+        return Err(Error::UnsupportedFundingType);
+    }
+    let expect_script = address_to_script(expect_address).map_err(|_| Error::WrongFundingAddr)?;
+    if script != expect_script.as_slice() {
+        return Err(Error::WrongFundingAddr);
+    }
+
+    let value = tx_out.value() as u128;
+    debug!("actual value: {}, expect: {}", value, expect_value);
+    if value < expect_value {
+        return Err(Error::FundingNotEnough);
+    }
+    if is_return_vin {
+        let funding_input_index: u32 = proof_reader.funding_input_index().into();
+        let vin = Vin::new(proof_reader.vin().raw_data())?;
+        let tx_in = vin.index(funding_input_index as usize)?;
+        debug!(
+            "vin tx_id {}",
+            hex::encode(tx_in.outpoint().txid_le().as_ref().as_ref())
+        );
+        debug!("vin output index {}", tx_in.outpoint().vout_index());
+        Ok(BtcExtraView {
+            lock_tx_hash: tx_in.outpoint().txid_le().as_ref().as_ref().into(),
+            lock_vout_index: tx_in.outpoint().vout_index(),
+        })
+    } else {
+        Ok(BtcExtraView {
+            lock_tx_hash: tx_hash,
+            lock_vout_index: funding_output_index,
+        })
+    }
+}
+
+/// The tx is faulty if it spends the locked btc.
+/// When `allow_pay_to_unlock_address` is set, which is the case when redeeming or handing over,
+/// paying the redeemed amount to x_unlock_address is the sanctioned move and not faulty, as long as
+/// the remainder of a partial redemption is paid back to x_lock_address.
+fn verify_btc_faulty_witness(
+    data: &ToCKBCellDataView,
+    proof: &[u8],
+    cell_dep_index_list: &[u8],
+    allow_pay_to_unlock_address: bool,
+) -> Result<(), Error> {
+    debug!(
+        "proof: {:?}, cell_dep_index_list: {:?}",
+        proof, cell_dep_index_list
+    );
+    if cell_dep_index_list.len() != 1 {
+        return Err(Error::InvalidWitness);
+    }
+    // parse witness
+    if BTCSPVProofReader::verify(proof, false).is_err() {
+        return Err(Error::InvalidWitness);
+    }
+    let proof_reader = BTCSPVProofReader::new_unchecked(proof);
+    debug!("proof_reader: {:?}", proof_reader);
+
+    // verify btc spv
+    verify_btc_spv(proof_reader, cell_dep_index_list[0].into())?;
+
+    // get tx in
+    let funding_input_index: u32 = proof_reader.funding_input_index().into();
+
+    let vin = Vin::new(proof_reader.vin().raw_data())?;
+    let tx_in = vin.index(funding_input_index as usize)?;
+
+    // get mint_xt's funding_output info from cell_data
+    let btc_extra = data
+        .x_extra
+        .get_utxo_extra()
+        .ok_or(Error::FaultyBtcWitnessInvalid)?;
+
+    // check if the locked btc is transferred by signer
+    let btc_extra_txid: Vec<u8> = btc_extra.lock_tx_hash.clone().into();
+    debug!(
+        "btc_extra_txid: {},  tx_in.outpoint().txid_le(): {}",
+        hex::encode(btc_extra_txid.as_slice()),
+        hex::encode(tx_in.outpoint().txid_le().as_ref().as_ref())
+    );
+
+    debug!(
+        "btc_extra.lock_vout_index: {},   tx_in.outpoint().vout_index(): {}",
+        btc_extra.lock_vout_index,
+        tx_in.outpoint().vout_index()
+    );
+
+    if tx_in.outpoint().txid_le().as_ref().as_ref() != btc_extra_txid.as_slice()
+        || tx_in.outpoint().vout_index() != btc_extra.lock_vout_index
+    {
+        return Err(Error::FaultyBtcWitnessInvalid);
+    }
+
+    // check if signer transferred insufficient btc_amount to x_unlock_address
+    if allow_pay_to_unlock_address {
+        debug!("verify_btc_faulty_witness allow_pay_to_unlock_address");
+        // verify transfer amount, to matches
+        let vout = Vout::new(proof_reader.vout().raw_data())?;
+        let mut index: usize = 0;
+        let mut sum_amount: u128 = 0;
+        // an undecodable x_unlock_address can not be paid, so nothing is counted
+        let expect_script = address_to_script(data.x_unlock_address.as_ref()).ok();
+        let change_script = address_to_script(data.x_lock_address.as_ref()).ok();
+        let mut change_amount: u128 = 0;
+        let redeem_amount = data.get_redeem_xt_amount();
+        let remain_amount = data.get_lot_xt_amount() - redeem_amount;
+
+        // calc sum_amount which signer transferred to user
+        debug!("begin calc sum_amount which signer transferred to user");
+        loop {
+            let tx_out = match vout.index(index.into()) {
+                Ok(out) => out,
+                Err(_) => {
+                    break;
+                }
+            };
+            index += 1;
+
+            // outputs with non-standard long scripts can not pay to x_unlock_address
+            let script = match get_script(tx_out.script_pubkey().as_ref()) {
+                Ok(script) => script,
+                Err(_) => continue,
+            };
+            debug!("output script: {}", hex::encode(script));
+            if change_script.as_deref() == Some(script) {
+                change_amount += tx_out.value() as u128;
+            }
+            if expect_script.as_deref() != Some(script) {
+                continue;
+            }
+
+            sum_amount += tx_out.value() as u128;
+        }
+
+        debug!(
+            "calc sum_amount: {}, redeem_amount: {}, change_amount: {}, remain_amount: {}",
+            sum_amount, redeem_amount, change_amount, remain_amount
+        );
+        if sum_amount >= redeem_amount && change_amount >= remain_amount {
+            // it means signer transferred enough amount to x_unlock_address, which is not faulty
+            return Err(Error::FaultyBtcWitnessInvalid);
+        }
+    }
+    Ok(())
+}
+
+/// Verify the locked btc is moved to x_unlock_address, which is the lock address of the new signer
+/// when handing over, return the new lock outpoint.
+fn verify_btc_handover_witness(
+    data: &ToCKBCellDataView,
+    proof: &[u8],
+    cell_dep_index_list: &[u8],
+) -> Result<BtcExtraView, Error> {
+    let new_btc_extra = verify_btc_witness(
+        data,
+        proof,
+        cell_dep_index_list,
+        data.x_unlock_address.as_ref(),
+        data.get_lot_xt_amount(),
+        false,
+    )?;
+
+    let btc_extra = data.x_extra.get_utxo_extra().ok_or(Error::XChainMismatch)?;
+    let proof_reader = BTCSPVProofReader::new_unchecked(proof);
+    let funding_input_index: u32 = proof_reader.funding_input_index().into();
+    let vin = Vin::new(proof_reader.vin().raw_data())?;
+    let tx_in = vin.index(funding_input_index as usize)?;
+    let btc_extra_txid: Vec<u8> = btc_extra.lock_tx_hash.clone().into();
+    if tx_in.outpoint().txid_le().as_ref().as_ref() != btc_extra_txid.as_slice()
+        || tx_in.outpoint().vout_index() != btc_extra.lock_vout_index
+    {
+        return Err(Error::SignerHandoverInvalid);
+    }
+    Ok(new_btc_extra)
+}
+
+/// Verify the locked btc is spent paying the redeemed amount to x_unlock_address and the remainder
+/// back to x_lock_address, return the outpoint of the remainder which becomes the new lock outpoint.
+fn verify_btc_partial_withdraw_witness(
+    data: &ToCKBCellDataView,
+    proof: &[u8],
+    cell_dep_index_list: &[u8],
+) -> Result<BtcExtraView, Error> {
+    let spent_btc_extra = verify_btc_witness(
+        data,
+        proof,
+        cell_dep_index_list,
+        data.x_unlock_address.as_ref(),
+        data.get_redeem_xt_amount(),
+        true,
+    )?;
+    if data.x_extra.get_utxo_extra() != Some(&spent_btc_extra) {
+        return Err(Error::PartialWithdrawInvalid);
+    }
+
+    let remain_amount = data.get_lot_xt_amount() - data.get_redeem_xt_amount();
+    let change_script =
+        address_to_script(data.x_lock_address.as_ref()).map_err(|_| Error::WrongFundingAddr)?;
+    let proof_reader = BTCSPVProofReader::new_unchecked(proof);
+    let vout = Vout::new(proof_reader.vout().raw_data())?;
+    let mut index: usize = 0;
+    loop {
+        let tx_out = vout
+            .index(index)
+            .map_err(|_| Error::PartialWithdrawInvalid)?;
+        let script = get_script(tx_out.script_pubkey().as_ref()).ok();
+        if script == Some(change_script.as_slice()) && tx_out.value() as u128 >= remain_amount {
+            debug!("remainder paid back at vout {}", index);
+            return Ok(BtcExtraView {
+                lock_tx_hash: proof_reader.tx_id().raw_data().to_vec().into(),
+                lock_vout_index: index as u32,
+            });
+        }
+        index += 1;
+    }
+}
+
+/// Verify the tx is included in the first proof header, which is accepted by either the
+/// difficulty oracle or the header relay cell at `cell_dep_index`.
+fn verify_btc_spv(proof: BTCSPVProofReader, cell_dep_index: usize) -> Result<Bytes, Error> {
+    debug!("start verify_btc_spv");
+    if !btcspv::validate_vin(proof.vin().raw_data()) {
+        return Err(Error::SpvProofInvalid);
+    }
+    debug!("finish validate_vin");
+    if !btcspv::validate_vout(proof.vout().raw_data()) {
+        return Err(Error::SpvProofInvalid);
+    }
+    debug!("finish validate_vout");
+    // an inner node of the merkle tree is the hash256 of 64 bytes, so a 64 bytes tx could pose as
+    // an inner node and make the merkle proof ambiguous
+    if 4 + proof.vin().raw_data().len() + proof.vout().raw_data().len() + 4 == 64 {
+        return Err(Error::BtcTxLengthInvalid);
+    }
+    let mut ver = [0u8; 4];
+    ver.copy_from_slice(proof.version().raw_data());
+    let mut lock = [0u8; 4];
+    lock.copy_from_slice(proof.locktime().raw_data());
+    debug!("ver: {:?}, lock: {:?}", ver, lock);
+    // btcspv::hash256(&[version, vin.as_ref(), vout.as_ref(), locktime])
+    let vin = Vin::new(proof.vin().raw_data())?;
+    let vout = Vout::new(proof.vout().raw_data())?;
+    debug!("{:?}", &[&ver, vin.as_ref(), vout.as_ref(), &lock]);
+    let tx_id = validatespv::calculate_txid(&ver, &vin, &vout, &lock);
+    debug!("tx_id: {:?}", tx_id);
+    if tx_id.as_ref() != proof.tx_id().raw_data() {
+        return Err(Error::WrongTxId);
+    }
+
+    let headers = HeaderArray::new(proof.headers().raw_data())?;
+    let kind = get_xchain_kind()?;
+    match get_header_relay()? {
+        // the header relay checks the proof of work of BTC
+        Some(_) if kind == XChainKind::Ltc => return Err(Error::HeaderRelayMismatch),
+        Some(relay_type_hash) => {
+            verify_btc_headers_by_relay(&headers, cell_dep_index, relay_type_hash)?
+        }
+        None => verify_btc_headers_by_difficulty(&headers, cell_dep_index, kind)?,
+    }
+    debug!("finish diff verify");
+
+    // verify tx
+    let header = headers.index(0);
+    let mut idx = [0u8; 8];
+    idx.copy_from_slice(proof.index().raw_data());
+    debug!("tx_id: {}", hex::encode(tx_id.as_ref()));
+    debug!("merkle_root: {}", hex::encode(header.tx_root().as_ref()));
+    debug!(
+        "proof: {}",
+        hex::encode(proof.intermediate_nodes().raw_data())
+    );
+    debug!("index: {}", u64::from_le_bytes(idx));
+    if !validatespv::prove(
+        tx_id,
+        header.tx_root(),
+        &MerkleArray::new(proof.intermediate_nodes().raw_data())?,
+        u64::from_le_bytes(idx),
+    ) {
+        return Err(Error::BadMerkleProof);
+    }
+    debug!("finish merkle proof verify");
+
+    Ok(Bytes::from(&tx_id.as_ref()[..]))
+}
+
+/// LTC headers are checked the same way, except that their proof of work is the scrypt hash and
+/// more confirmations are required as LTC blocks come faster.
+fn verify_btc_headers_by_difficulty(
+    headers: &HeaderArray,
+    cell_dep_index: usize,
+    kind: XChainKind,
+) -> Result<(), Error> {
+    let dep_data = load_cell_data(cell_dep_index, Source::CellDep)?;
+    debug!("dep data is {:?}", &dep_data);
+    if BTCDifficultyReader::verify(&dep_data, false).is_err() {
+        return Err(Error::DifficultyDataInvalid);
+    }
+    let difficulty = BTCDifficultyReader::new_unchecked(&dep_data);
+    debug!("difficulty_reader: {:?}", difficulty);
+
+    let observed_diff = match kind {
+        XChainKind::Ltc => ltc_pow::validate_header_chain(headers.as_ref())?,
+        _ => validatespv::validate_header_chain(headers, false)?,
+    };
+    let previous_diff = U256::from_little_endian(difficulty.previous().raw_data());
+    let current_diff = U256::from_little_endian(difficulty.current().raw_data());
+    let header_diffs = (0..headers.len())
+        .map(|index| headers.index(index).difficulty())
+        .collect::<Vec<_>>();
+    debug!(
+        "previous: {:?}, current: {:?}, header_diffs: {:?}",
+        previous_diff, current_diff, header_diffs
+    );
+
+    // the proof may straddle the retarget from the previous epoch to the current one
+    let req_diff =
+        btc_relay::get_spv_required_difficulty(&header_diffs, previous_diff, current_diff)?;
+
+    // the block containing the tx must be confirmed by enough blocks on top of it
+    let mut confirmations = get_protocol_params()?.btc_spv_confirmations as u64;
+    if kind == XChainKind::Ltc {
+        confirmations *= LTC_SPV_CONFIRMATIONS_FACTOR as u64;
+    }
+    debug!(
+        "headers: {}, confirmations: {}",
+        headers.len(),
+        confirmations
+    );
+    if headers.len() < confirmations as usize {
+        return Err(Error::BtcHeaderNotConfirmed);
+    }
+    if observed_diff < req_diff * confirmations {
+        return Err(Error::InsufficientDifficulty);
+    }
+    Ok(())
+}
+
+/// The header containing the tx must be kept by the header relay cell with `btc_spv_confirmations`
+/// of the protocol params, the relay has already checked its proof of work.
+fn verify_btc_headers_by_relay(
+    headers: &HeaderArray,
+    cell_dep_index: usize,
+    relay_type_hash: [u8; 32],
+) -> Result<(), Error> {
+    match load_cell_type_hash(cell_dep_index, Source::CellDep)? {
+        Some(type_hash) if type_hash == relay_type_hash => {}
+        _ => return Err(Error::HeaderRelayMismatch),
+    }
+    let relay = BtcRelayView::new(&load_cell_data(cell_dep_index, Source::CellDep)?)?;
+    let header = headers.index(0);
+    let header_hash = btc_relay::hash256(header.as_ref());
+    let confirmations = relay
+        .get_confirmations(&header_hash)
+        .ok_or(Error::BtcHeaderNotInRelay)?;
+    debug!("confirmations: {}", confirmations);
+    if confirmations < get_protocol_params()?.btc_spv_confirmations as u64 {
+        return Err(Error::BtcHeaderNotConfirmed);
+    }
+    Ok(())
+}
+
+/// Verify the address decodes on the UTXO chain and network of the cell, i.e. BTC or LTC
+fn verify_utxo_address(addr: &[u8]) -> Result<(), Error> {
+    address_to_script(addr)?;
+    Ok(())
+}
+
+/// Strip the length prefix of a serialized script_pubkey, standard scripts are shorter than 0xfd
+/// bytes so the prefix is always a single byte.
+fn get_script(script_pubkey: &[u8]) -> Result<&[u8], Error> {
+    match script_pubkey.split_first() {
+        Some((len, script)) if *len < 0xfd && *len as usize == script.len() => Ok(script),
+        _ => Err(Error::UnsupportedFundingType),
+    }
+}
@@ -0,0 +1,438 @@
+use crate::utils::{
+    config::{ERC20_LOT_AMOUNT_RANGE, ETH_CONFIRMED_HEADERS_TYPE_HASH, ETH_LOT_AMOUNT_RANGE},
+    eth_spv,
+    types::{
+        eth_confirmed_headers::ETHConfirmedHeadersReader, mint_xt_witness::ETHSPVProofReader,
+        Error, EthExtraView, ToCKBCellDataView, XExtraView,
+    },
+    xchain::XChainVerifier,
+};
+use alloc::vec::Vec;
+use ckb_std::ckb_constants::Source;
+use ckb_std::ckb_types::bytes::Bytes;
+use ckb_std::debug;
+use ckb_std::high_level::{load_cell_data, load_cell_type_hash};
+
+/// ERC-20 deposits are ETH deposits whose lock is moved by the `Transfer` events of the token
+/// instead of the tx value, see `verify_eth_witness` and `verify_eth_faulty_witness`.
+pub struct EthVerifier {
+    lot_amount_range: (u128, u128),
+}
+
+pub static ETH_VERIFIER: EthVerifier = EthVerifier {
+    lot_amount_range: ETH_LOT_AMOUNT_RANGE,
+};
+
+pub static ERC20_VERIFIER: EthVerifier = EthVerifier {
+    lot_amount_range: ERC20_LOT_AMOUNT_RANGE,
+};
+
+impl XChainVerifier for EthVerifier {
+    fn lot_amount_range(&self) -> (u128, u128) {
+        self.lot_amount_range
+    }
+
+    fn verify_address(&self, address: &[u8]) -> Result<(), Error> {
+        if address.len() != 20 {
+            return Err(Error::XChainAddressInvalid);
+        }
+        Ok(())
+    }
+
+    fn verify_funding(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error> {
+        let eth_extra = verify_eth_witness(
+            data,
+            proof,
+            cell_dep_index_list,
+            data.x_lock_address.as_ref(),
+            data.get_lot_xt_amount(),
+        )?;
+        data.x_extra.relock_eth(eth_extra)
+    }
+
+    fn verify_redeem(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error> {
+        verify_eth_redeem_witness(data, proof, cell_dep_index_list)?;
+        Ok(data.x_extra.clone())
+    }
+
+    fn verify_partial_withdraw(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error> {
+        data.x_extra.relock_eth(verify_eth_partial_withdraw_witness(
+            data,
+            proof,
+            cell_dep_index_list,
+        )?)
+    }
+
+    fn verify_handover(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error> {
+        data.x_extra.relock_eth(verify_eth_handover_witness(
+            data,
+            proof,
+            cell_dep_index_list,
+        )?)
+    }
+
+    fn verify_faulty(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+        allow_pay_to_unlock_address: bool,
+    ) -> Result<(), Error> {
+        verify_eth_faulty_witness(
+            data,
+            proof,
+            cell_dep_index_list,
+            allow_pay_to_unlock_address,
+        )
+    }
+}
+
+/// Verify the ETH tx pays `expect_value` to `expect_address`, in the token of the deposit if it
+/// is an ERC-20 one, return the tx info.
+fn verify_eth_witness(
+    data: &ToCKBCellDataView,
+    proof: &[u8],
+    cell_dep_index_list: &[u8],
+    expect_address: &[u8],
+    expect_value: u128,
+) -> Result<EthExtraView, Error> {
+    debug!(
+        "proof: {:?}, cell_dep_index_list: {:?}",
+        proof, cell_dep_index_list
+    );
+    let dep_data = load_eth_confirmed_headers(cell_dep_index_list)?;
+    let headers_reader = ETHConfirmedHeadersReader::new_unchecked(&dep_data);
+    // parse witness
+    if ETHSPVProofReader::verify(proof, false).is_err() {
+        return Err(Error::InvalidWitness);
+    }
+    let proof_reader = ETHSPVProofReader::new_unchecked(proof);
+    debug!("proof_reader: {:?}", proof_reader);
+
+    // verify eth spv
+    let eth_extra = verify_eth_spv(proof_reader, headers_reader)?;
+
+    if let Some(token) = data.x_extra.get_token_address() {
+        verify_token_transfer(
+            proof_reader.receipt_data().raw_data(),
+            token,
+            expect_address,
+            expect_value,
+        )?;
+        return Ok(eth_extra);
+    }
+
+    // verify transfer amount, to matches
+    let transfer = eth_spv::parse_transfer(proof_reader.tx_data().raw_data())?;
+    debug!(
+        "to: {}, expect_address: {}",
+        hex::encode(transfer.to),
+        hex::encode(expect_address)
+    );
+    if transfer.to != expect_address {
+        return Err(Error::WrongFundingAddr);
+    }
+    debug!(
+        "actual value: {}, expect: {}",
+        hex::encode(transfer.value),
+        expect_value
+    );
+    if !eth_spv::is_value_enough(transfer.value, expect_value) {
+        return Err(Error::FundingNotEnough);
+    }
+    Ok(eth_extra)
+}
+
+/// Verify the receipt has a `Transfer` event of the token paying `expect_value` to
+/// `expect_address`. The token contract emits the event, so the tx may call the token directly or
+/// through other contracts.
+fn verify_token_transfer(
+    receipt_data: &[u8],
+    token: &[u8],
+    expect_address: &[u8],
+    expect_value: u128,
+) -> Result<(), Error> {
+    let transfers = eth_spv::parse_token_transfers(receipt_data, token)?;
+    let values = transfers
+        .iter()
+        .filter(|transfer| transfer.to == expect_address)
+        .map(|transfer| transfer.value)
+        .collect::<Vec<_>>();
+    debug!("token transfers to expect_address: {}", values.len());
+    if values.is_empty() {
+        return Err(Error::WrongFundingAddr);
+    }
+    if !values
+        .iter()
+        .any(|value| eth_spv::is_value_enough(value, expect_value))
+    {
+        return Err(Error::FundingNotEnough);
+    }
+    Ok(())
+}
+
+/// The x_lock_address of an eth deposit is dedicated to the deposit, so any transaction
+/// sent from it after the locking transaction moves the locked ETH or token.
+/// When redeeming or handing over, the transaction is faulty unless it pays the redeemed amount to
+/// x_unlock_address, and for a token deposit moves the token nowhere else.
+fn verify_eth_faulty_witness(
+    data: &ToCKBCellDataView,
+    proof: &[u8],
+    cell_dep_index_list: &[u8],
+    allow_pay_to_unlock_address: bool,
+) -> Result<(), Error> {
+    debug!(
+        "proof: {:?}, cell_dep_index_list: {:?}",
+        proof, cell_dep_index_list
+    );
+    let dep_data = load_eth_confirmed_headers(cell_dep_index_list)?;
+    let headers_reader = ETHConfirmedHeadersReader::new_unchecked(&dep_data);
+    // parse witness
+    if ETHSPVProofReader::verify(proof, false).is_err() {
+        return Err(Error::InvalidWitness);
+    }
+    let proof_reader = ETHSPVProofReader::new_unchecked(proof);
+    debug!("proof_reader: {:?}", proof_reader);
+
+    // verify eth spv
+    let tx_location = verify_eth_spv(proof_reader, headers_reader)?;
+
+    // get mint_xt's locking tx info from cell_data
+    let eth_extra = data
+        .x_extra
+        .get_eth_extra()
+        .ok_or(Error::FaultyEthWitnessInvalid)?;
+
+    // the tx must happen after the locking tx
+    debug!(
+        "tx at ({}, {}), locking tx at ({}, {})",
+        tx_location.lock_block_number,
+        tx_location.lock_tx_index,
+        eth_extra.lock_block_number,
+        eth_extra.lock_tx_index
+    );
+    if (tx_location.lock_block_number, tx_location.lock_tx_index)
+        <= (eth_extra.lock_block_number, eth_extra.lock_tx_index)
+    {
+        return Err(Error::FaultyEthWitnessInvalid);
+    }
+
+    // check if the locked eth is transferred by signer
+    let tx_data = proof_reader.tx_data().raw_data();
+    let sender = eth_spv::recover_sender(tx_data)?;
+    debug!(
+        "sender: {}, x_lock_address: {}",
+        hex::encode(sender),
+        hex::encode(data.x_lock_address.as_ref())
+    );
+    if &sender[..] != data.x_lock_address.as_ref() {
+        return Err(Error::FaultyEthWitnessInvalid);
+    }
+
+    // check if signer transferred insufficient eth_amount to x_unlock_address
+    if allow_pay_to_unlock_address {
+        debug!("verify_eth_faulty_witness allow_pay_to_unlock_address");
+        let unlock_address = data.x_unlock_address.as_ref();
+        let redeem_amount = data.get_redeem_xt_amount();
+        let is_paid = match data.x_extra.get_token_address() {
+            Some(token) => {
+                let transfers =
+                    eth_spv::parse_token_transfers(proof_reader.receipt_data().raw_data(), token)?;
+                transfers.iter().any(|transfer| {
+                    transfer.to == unlock_address
+                        && eth_spv::is_value_enough(transfer.value, redeem_amount)
+                }) && transfers.iter().all(|transfer| {
+                    transfer.from != data.x_lock_address.as_ref() || transfer.to == unlock_address
+                })
+            }
+            None => {
+                let transfer = eth_spv::parse_transfer(tx_data)?;
+                transfer.to == unlock_address
+                    && eth_spv::is_value_enough(transfer.value, redeem_amount)
+            }
+        };
+        if is_paid {
+            // it means signer transferred enough amount to x_unlock_address, which is not faulty
+            return Err(Error::FaultyEthWitnessInvalid);
+        }
+    }
+    Ok(())
+}
+
+/// Verify the locked eth is sent from x_lock_address to x_unlock_address, which is the lock address
+/// of the new signer when handing over, return the new locking tx info.
+fn verify_eth_handover_witness(
+    data: &ToCKBCellDataView,
+    proof: &[u8],
+    cell_dep_index_list: &[u8],
+) -> Result<EthExtraView, Error> {
+    let new_eth_extra = verify_eth_witness(
+        data,
+        proof,
+        cell_dep_index_list,
+        data.x_unlock_address.as_ref(),
+        data.get_lot_xt_amount(),
+    )?;
+
+    let eth_extra = data.x_extra.get_eth_extra().ok_or(Error::XChainMismatch)?;
+    let proof_reader = ETHSPVProofReader::new_unchecked(proof);
+    let sender = eth_spv::recover_sender(proof_reader.tx_data().raw_data())?;
+    if &sender[..] != data.x_lock_address.as_ref()
+        || (new_eth_extra.lock_block_number, new_eth_extra.lock_tx_index)
+            <= (eth_extra.lock_block_number, eth_extra.lock_tx_index)
+    {
+        return Err(Error::SignerHandoverInvalid);
+    }
+    Ok(new_eth_extra)
+}
+
+/// Verify the locked eth is sent from x_lock_address to x_unlock_address after the locking tx.
+/// x_lock_address is dedicated to the deposit, so the tx spends the lock of the deposit.
+fn verify_eth_redeem_witness(
+    data: &ToCKBCellDataView,
+    proof: &[u8],
+    cell_dep_index_list: &[u8],
+) -> Result<(), Error> {
+    let redeem_extra = verify_eth_witness(
+        data,
+        proof,
+        cell_dep_index_list,
+        data.x_unlock_address.as_ref(),
+        data.get_lot_xt_amount(),
+    )?;
+
+    let eth_extra = data.x_extra.get_eth_extra().ok_or(Error::XChainMismatch)?;
+    let proof_reader = ETHSPVProofReader::new_unchecked(proof);
+    let sender = eth_spv::recover_sender(proof_reader.tx_data().raw_data())?;
+    debug!(
+        "sender: {}, x_lock_address: {}",
+        hex::encode(sender),
+        hex::encode(data.x_lock_address.as_ref())
+    );
+    if &sender[..] != data.x_lock_address.as_ref()
+        || (redeem_extra.lock_block_number, redeem_extra.lock_tx_index)
+            <= (eth_extra.lock_block_number, eth_extra.lock_tx_index)
+    {
+        return Err(Error::FaultyEthWitnessInvalid);
+    }
+    Ok(())
+}
+
+/// Verify the redeemed part of the locked eth is sent from x_lock_address to x_unlock_address, the
+/// remainder stays at x_lock_address, return the withdrawing tx info as the new locking tx info.
+fn verify_eth_partial_withdraw_witness(
+    data: &ToCKBCellDataView,
+    proof: &[u8],
+    cell_dep_index_list: &[u8],
+) -> Result<EthExtraView, Error> {
+    let new_eth_extra = verify_eth_witness(
+        data,
+        proof,
+        cell_dep_index_list,
+        data.x_unlock_address.as_ref(),
+        data.get_redeem_xt_amount(),
+    )?;
+
+    let eth_extra = data.x_extra.get_eth_extra().ok_or(Error::XChainMismatch)?;
+    let proof_reader = ETHSPVProofReader::new_unchecked(proof);
+    let sender = eth_spv::recover_sender(proof_reader.tx_data().raw_data())?;
+    if &sender[..] != data.x_lock_address.as_ref()
+        || (new_eth_extra.lock_block_number, new_eth_extra.lock_tx_index)
+            <= (eth_extra.lock_block_number, eth_extra.lock_tx_index)
+    {
+        return Err(Error::PartialWithdrawInvalid);
+    }
+    Ok(new_eth_extra)
+}
+
+fn load_eth_confirmed_headers(cell_dep_index_list: &[u8]) -> Result<Vec<u8>, Error> {
+    if cell_dep_index_list.len() != 1 {
+        return Err(Error::InvalidWitness);
+    }
+    let cell_dep_index = cell_dep_index_list[0].into();
+    // ETH headers are not checked by proof of work, only the trusted relay cell can list them
+    if load_cell_type_hash(cell_dep_index, Source::CellDep)?
+        != Some(ETH_CONFIRMED_HEADERS_TYPE_HASH)
+    {
+        return Err(Error::ConfirmedHeadersDataInvalid);
+    }
+    let dep_data = load_cell_data(cell_dep_index, Source::CellDep)?;
+    debug!("dep data is {:?}", &dep_data);
+    if ETHConfirmedHeadersReader::verify(&dep_data, false).is_err() {
+        return Err(Error::ConfirmedHeadersDataInvalid);
+    }
+    Ok(dep_data)
+}
+
+/// verify the tx is included in a confirmed block and return where it is
+fn verify_eth_spv(
+    proof: ETHSPVProofReader,
+    confirmed_headers: ETHConfirmedHeadersReader,
+) -> Result<EthExtraView, Error> {
+    debug!("start verify_eth_spv");
+    let header = eth_spv::parse_header(proof.header_data().raw_data())?;
+    debug!("block hash: {}", hex::encode(header.hash));
+
+    // verify the block is confirmed
+    let block_hashes = confirmed_headers.block_hashes().raw_data();
+    if block_hashes.len() % 32 != 0 {
+        return Err(Error::ConfirmedHeadersDataInvalid);
+    }
+    if !block_hashes.chunks(32).any(|hash| hash == header.hash) {
+        return Err(Error::EthHeaderNotConfirmed);
+    }
+    debug!("finish header verify");
+
+    // verify tx and receipt, both tries are keyed by rlp encoded tx index
+    let key = eth_spv::rlp_encode_index(proof.tx_index().into());
+    let tx_proof = proof
+        .tx_proof()
+        .iter()
+        .map(|node| node.raw_data())
+        .collect::<Vec<_>>();
+    let tx_data = eth_spv::verify_mpt_proof(&header.tx_root, &key, &tx_proof)?;
+    if tx_data.as_slice() != proof.tx_data().raw_data() {
+        return Err(Error::WrongTxId);
+    }
+    let receipt_proof = proof
+        .receipt_proof()
+        .iter()
+        .map(|node| node.raw_data())
+        .collect::<Vec<_>>();
+    let receipt_data = eth_spv::verify_mpt_proof(&header.receipt_root, &key, &receipt_proof)?;
+    if receipt_data.as_slice() != proof.receipt_data().raw_data() {
+        return Err(Error::BadMerkleProof);
+    }
+    eth_spv::verify_receipt_status(&receipt_data)?;
+    debug!("finish merkle patricia proof verify");
+
+    let tx_index: u64 = proof.tx_index().into();
+    if tx_index > u32::MAX as u64 {
+        return Err(Error::SpvProofInvalid);
+    }
+    Ok(EthExtraView {
+        lock_tx_hash: Bytes::from(&eth_spv::keccak256(&tx_data)[..]),
+        lock_tx_index: tx_index as u32,
+        lock_block_number: header.number,
+    })
+}
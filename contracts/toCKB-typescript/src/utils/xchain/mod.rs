@@ -0,0 +1,78 @@
+//! The XChain specific verification of the toCKB typescript.
+//!
+//! Every transition only depends on the XChain through `XChainVerifier`, so supporting another
+//! chain means a module implementing the trait and an arm in `get_xchain_verifier`.
+
+mod btc;
+mod eth;
+
+use crate::utils::types::{Error, ToCKBCellDataView, XChainKind, XExtraView};
+
+pub trait XChainVerifier {
+    /// Range of the lot amount of a deposit in base units of the XAsset
+    fn lot_amount_range(&self) -> (u128, u128);
+
+    /// Verify the address is a valid lock or unlock address on the XChain
+    fn verify_address(&self, address: &[u8]) -> Result<(), Error>;
+
+    /// Verify x_lock_address is the multisig address of the signer group, only chains with native
+    /// multisig support signer groups.
+    fn verify_signer_group_address(&self, _data: &ToCKBCellDataView) -> Result<(), Error> {
+        Err(Error::SignerGroupInvalid)
+    }
+
+    /// Verify the proof pays the lot amount to x_lock_address, return the lock of the deposit
+    fn verify_funding(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error>;
+
+    /// Verify the proof pays the lot amount to x_unlock_address out of the lock, return the spent
+    /// lock
+    fn verify_redeem(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error>;
+
+    /// Verify the proof pays the redeemed amount to x_unlock_address and locks the remainder
+    /// again, return the new lock
+    fn verify_partial_withdraw(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error>;
+
+    /// Verify the proof moves the locked XAsset to x_unlock_address of the new signer, return the
+    /// new lock
+    fn verify_handover(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+    ) -> Result<XExtraView, Error>;
+
+    /// Verify the proof moves the locked XAsset against the protocol. When
+    /// `allow_pay_to_unlock_address` is set, paying the redeemed amount to x_unlock_address is
+    /// not faulty.
+    fn verify_faulty(
+        &self,
+        data: &ToCKBCellDataView,
+        proof: &[u8],
+        cell_dep_index_list: &[u8],
+        allow_pay_to_unlock_address: bool,
+    ) -> Result<(), Error>;
+}
+
+pub fn get_xchain_verifier(kind: XChainKind) -> &'static dyn XChainVerifier {
+    match kind {
+        XChainKind::Btc => &btc::BTC_VERIFIER,
+        XChainKind::Ltc => &btc::LTC_VERIFIER,
+        XChainKind::Eth => &eth::ETH_VERIFIER,
+        XChainKind::Erc20 => &eth::ERC20_VERIFIER,
+    }
+}
@@ -6,10 +6,11 @@ use ckb_types::H256;
 use ckb_types::{core::TransactionView, packed::Script};
 use molecule::prelude::{Builder, Entity};
 use std::str::FromStr;
-use tockb_sdk::settings::{BtcDifficulty, OutpointConf, PriceOracle, ScriptConf};
+use tockb_sdk::settings::{BtcDifficulty, OutpointConf, ScriptConf};
 use tockb_sdk::tx_helper::{deploy, sign};
-use tockb_sdk::util::{ensure_indexer_sync, send_tx_sync};
+use tockb_sdk::util::{build_signed_price, ensure_indexer_sync, send_tx_sync};
 use tockb_sdk::{generator::Generator, indexer::IndexerRpcClient, settings::Settings};
+use tockb_types::config::{CKB_UNITS, PRICE_ORACLE_THRESHOLD, SIGNER_FEE_RATE};
 use tockb_types::generated::btc_difficulty::BTCDifficulty;
 
 const TIMEOUT: u64 = 60;
@@ -47,18 +48,20 @@ fn main() -> Result<()> {
         .build()
         .as_bytes()
         .to_vec();
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs();
     let data = vec![
         typescript_bin,
         lockscript_bin,
         sudt_bin,
-        price.to_le_bytes().to_vec(),
         btc_difficulty_bytes,
     ];
 
     let tx = deploy(&mut rpc_client, &mut indexer_client, &private_key, data).unwrap();
     let tx_hash = send_tx_sync(&mut rpc_client, &tx, TIMEOUT).unwrap();
     let tx_hash_hex = hex::encode(tx_hash.as_bytes());
-    let settings = Settings {
+    let mut settings = Settings {
         typescript: ScriptConf {
             code_hash: typescript_code_hash_hex,
             outpoint: OutpointConf {
@@ -80,38 +83,101 @@ fn main() -> Result<()> {
                 index: 2,
             },
         },
-        price_oracle: PriceOracle {
-            outpoint: OutpointConf {
-                tx_hash: tx_hash_hex.clone(),
-                index: 3,
-            },
-        },
+        price_oracle: Default::default(),
         btc_difficulty_cell: BtcDifficulty {
             outpoint: OutpointConf {
                 tx_hash: tx_hash_hex.clone(),
-                index: 4,
+                index: 3,
             },
         },
+        btc_network: "regtest".to_owned(),
+        btc_header_relay: Default::default(),
+        protocol_params: Default::default(),
+        lock_registry: Default::default(),
+        ltc_network: "regtest".to_owned(),
+        ltc_difficulty_cell: Default::default(),
+        signer_registry: Default::default(),
     };
     // dbg!(&settings);
 
     let user_address = "ckt1qyqvsv5240xeh85wvnau2eky8pwrhh4jr8ts8vyj37";
     let user_lockscript = Script::from(Address::from_str(user_address.clone()).unwrap().payload());
 
-    // deposit request
-    log::info!("deposit_request start");
+    // protocol params governed by the dev key
+    log::info!("create protocol params start");
     ensure_indexer_sync(&mut rpc_client, &mut indexer_client, 60).unwrap();
     let timeout = 60;
     let tx_fee = 1000_0000;
+    let mut generator =
+        Generator::new(rpc_url.clone(), indexer_url.clone(), settings.clone()).unwrap();
+    let unsigned_tx = generator
+        .create_protocol_params(
+            from_lockscript.clone(),
+            tx_fee,
+            from_lockscript.clone(),
+            300 * CKB_UNITS,
+            Default::default(),
+        )
+        .unwrap();
+    let tx = sign(unsigned_tx, &mut rpc_client, &private_key).unwrap();
+    send_tx_sync(&mut rpc_client, &tx, timeout).unwrap();
+    let params_typescript = tx.output(0).unwrap().type_().to_opt().unwrap();
+    settings.protocol_params.type_args = hex::encode(params_typescript.args().raw_data().as_ref());
+
+    // lock registry
+    log::info!("create lock registry start");
+    ensure_indexer_sync(&mut rpc_client, &mut indexer_client, 60).unwrap();
+    let mut generator =
+        Generator::new(rpc_url.clone(), indexer_url.clone(), settings.clone()).unwrap();
+    let unsigned_tx = generator
+        .create_lock_registry(from_lockscript.clone(), tx_fee, 200 * CKB_UNITS)
+        .unwrap();
+    let tx = sign(unsigned_tx, &mut rpc_client, &private_key).unwrap();
+    send_tx_sync(&mut rpc_client, &tx, timeout).unwrap();
+    let registry_typescript = tx.output(0).unwrap().type_().to_opt().unwrap();
+    settings.lock_registry.type_args = hex::encode(registry_typescript.args().raw_data().as_ref());
+
+    // price cells owned by the dev key, the typescript must be built with their type hashes as
+    // PRICE_ORACLE_TYPE_HASHES
+    log::info!("create price oracles start");
+    for i in 1..=PRICE_ORACLE_THRESHOLD as u8 {
+        let publisher_key = secp256k1::SecretKey::from_slice(&[i; 32])?;
+        let signed_price = build_signed_price(&publisher_key, price, now)?;
+        ensure_indexer_sync(&mut rpc_client, &mut indexer_client, 60).unwrap();
+        let mut generator =
+            Generator::new(rpc_url.clone(), indexer_url.clone(), settings.clone()).unwrap();
+        let unsigned_tx = generator
+            .create_price_oracle(
+                from_lockscript.clone(),
+                tx_fee,
+                from_lockscript.clone(),
+                200 * CKB_UNITS,
+                signed_price,
+            )
+            .unwrap();
+        let tx = sign(unsigned_tx, &mut rpc_client, &private_key).unwrap();
+        send_tx_sync(&mut rpc_client, &tx, timeout).unwrap();
+        let price_typescript = tx.output(0).unwrap().type_().to_opt().unwrap();
+        settings
+            .price_oracle
+            .type_args
+            .push(hex::encode(price_typescript.args().raw_data().as_ref()));
+    }
+
+    // deposit request
+    log::info!("deposit_request start");
+    ensure_indexer_sync(&mut rpc_client, &mut indexer_client, 60).unwrap();
     let mut generator = Generator::new(rpc_url, indexer_url, settings).unwrap();
     let unsigned_tx = generator
         .deposit_request(
             from_lockscript.clone(),
             tx_fee,
             user_lockscript.clone(),
-            10000,
-            1,
             1,
+            25_000_000,
+            SIGNER_FEE_RATE,
+            None,
+            None,
         )
         .unwrap();
     let tx = sign(unsigned_tx, &mut rpc_client, &private_key).unwrap();
@@ -132,6 +198,7 @@ fn main() -> Result<()> {
             cell_typescript.clone(),
             signer_lockscript,
             lock_address,
+            Default::default(),
         )
         .unwrap();
     let tx = sign(unsigned_tx, &mut rpc_client, &private_key).unwrap();
@@ -179,6 +246,7 @@ fn main() -> Result<()> {
             cell_typescript.clone(),
             unlock_address,
             redeemer_lockscript,
+            None,
         )
         .unwrap();
     let tx = sign(unsigned_tx, &mut rpc_client, &private_key).unwrap();
@@ -1,8 +1,13 @@
-use crate::indexer::{Cell, IndexerRpcClient, Order, Pagination, ScriptType, SearchKey};
+use crate::indexer::{
+    Cell, IOType, IndexerRpcClient, Order, Pagination, ScriptType, SearchKey, Tx,
+};
 use crate::util::is_mature;
 use anyhow::Result;
 use ckb_jsonrpc_types::Uint32;
-use ckb_types::packed::{self, Script};
+use ckb_types::{
+    packed::{self, Script},
+    H256,
+};
 use tockb_types::config::UDT_LEN;
 
 /// get live cell by typescript
@@ -28,6 +33,48 @@ pub fn get_live_cell_by_typescript(
     }
 }
 
+pub fn get_live_cells_by_typescript(
+    indexer_client: &mut IndexerRpcClient,
+    typescript: Script,
+) -> Result<Vec<Cell>, String> {
+    let search_key = SearchKey {
+        script: typescript.into(),
+        script_type: ScriptType::Type,
+        args_len: None,
+    };
+    get_live_cells(indexer_client, search_key, |_, _| (false, true))
+}
+
+/// get hashes of the txs which create cells with the typescript, in the order of blocks
+pub fn get_tx_hashes_by_typescript(
+    indexer_client: &mut IndexerRpcClient,
+    typescript: Script,
+) -> Result<Vec<H256>, String> {
+    let search_key = SearchKey {
+        script: typescript.into(),
+        script_type: ScriptType::Type,
+        args_len: None,
+    };
+    let limit = Uint32::from(100u32);
+    let mut tx_hashes = Vec::new();
+    let mut cursor = None;
+    loop {
+        let txs: Pagination<Tx> =
+            indexer_client.get_transactions(search_key.clone(), Order::Asc, limit, cursor)?;
+        if txs.objects.len() == 0 {
+            break;
+        }
+        cursor = Some(txs.last_cursor);
+        tx_hashes.extend(
+            txs.objects
+                .into_iter()
+                .filter(|tx| tx.io_type == IOType::Output)
+                .map(|tx| tx.tx_hash),
+        );
+    }
+    Ok(tx_hashes)
+}
+
 /// get cells to supply capacity
 /// if max_mature_number is None, skip mature check
 pub fn get_live_cells_by_lock_and_capacity(
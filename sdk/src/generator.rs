@@ -1,28 +1,42 @@
-use crate::cell_collector::{collect_sudt_amount, get_live_cell_by_typescript};
+use crate::cell_collector::{
+    collect_sudt_amount, get_live_cell_by_typescript, get_live_cells_by_typescript,
+    get_tx_hashes_by_typescript,
+};
 use crate::indexer::IndexerRpcClient;
 use crate::settings::{OutpointConf, Settings};
 use crate::tx_helper::TxHelper;
 use crate::util::{check_capacity, get_live_cell};
+use ckb_hash::new_blake2b;
 use ckb_sdk::{Address, GenesisInfo, HttpRpcClient};
 use ckb_types::prelude::{Pack, Unpack};
 use ckb_types::{
     bytes::Bytes,
-    core::{BlockView, Capacity, DepType, TransactionView},
+    core::{BlockView, Capacity, DepType, ScriptHashType, TransactionView},
     packed::{self, Byte32, CellDep, CellOutput, OutPoint, Script, WitnessArgs},
     prelude::{Builder, Entity},
+    H256,
 };
 use int_enum::IntEnum;
 use molecule::prelude::Byte;
 use std::str::FromStr;
 
+use tockb_types::btc_header_relay::BTCHeaderRelayArgs;
+use tockb_types::btc_relay::BtcRelayView;
 use tockb_types::config::{
-    CKB_UNITS, COLLATERAL_PERCENT, PLEDGE, SIGNER_FEE_RATE, UDT_LEN, XT_CELL_CAPACITY,
+    BONDING_WINDOW, CKB_UNITS, LOCK_TYPE_FLAG, SINCE_TYPE_TIMESTAMP, TYPE_ID_CODE_HASH, UDT_LEN,
+    XT_CELL_CAPACITY,
 };
 use tockb_types::generated::mint_xt_witness::{BTCSPVProof, MintXTWitness};
-use tockb_types::generated::tockb_cell_data::ToCKBCellData;
+use tockb_types::generated::price_oracle::SignedPrice;
+use tockb_types::generated::tockb_cell_data::{Erc20Extra, LtcExtra, ToCKBCellData, XExtra};
+use tockb_types::lock_registry::{build_lock_registry, get_registry_key, LockRegistryView};
+use tockb_types::protocol_params::ProtocolParamsView;
+use tockb_types::signer_offer::SignerOfferView;
+use tockb_types::since::RelativeSince;
 use tockb_types::tockb_cell_data::ToCKBTypeArgs;
 use tockb_types::{
-    basic, BtcExtraView, ToCKBCellDataView, ToCKBStatus, ToCKBTypeArgsView, XExtraView,
+    basic, get_lot_amount_range, BtcExtraView, SignerGroupView, ToCKBCellDataView, ToCKBStatus,
+    ToCKBTypeArgsView, XChainKind, XExtraView, ARBITRARY_LOT_SIZE,
 };
 
 pub struct Generator {
@@ -49,27 +63,74 @@ impl Generator {
         })
     }
 
-    fn get_price_oracle(&mut self) -> Result<(CellDep, u128), String> {
-        let outpoint = OutPoint::new_builder()
-            .tx_hash(
-                Byte32::from_slice(
-                    &hex::decode(&self.settings.price_oracle.outpoint.tx_hash)
-                        .map_err(|e| format!("invalid price oracle config. err: {}", e))?,
-                )
-                .map_err(|e| format!("invalid price oracle config. err: {}", e))?,
-            )
-            .index(self.settings.price_oracle.outpoint.index.pack())
-            .build();
-        let cell_dep = CellDep::new_builder()
-            .out_point(outpoint.clone())
-            .dep_type(DepType::Code.into())
-            .build();
-        let cell = get_live_cell(&mut self.rpc_client, outpoint, true)?;
+    /// Return the median price of the live price cells in settings and their outpoints, the
+    /// typescript verifies their signatures and freshness
+    fn get_price_oracle(&mut self) -> Result<(u128, Vec<OutpointConf>), String> {
+        let mut prices = vec![];
+        let mut outpoints = vec![];
+        for type_args in self.settings.price_oracle.type_args.clone() {
+            let type_args = hex::decode(&type_args)
+                .map_err(|e| format!("invalid price oracle config. err: {}", e))?;
+            if type_args.len() != 32 {
+                return Err("invalid price oracle config".to_owned());
+            }
+            let typescript = build_type_id_script(type_args.into());
+            let cell = get_live_cell_by_typescript(&mut self.indexer_client, typescript)?
+                .ok_or("price oracle cell not found".to_owned())?;
+            let outpoint: OutPoint = cell.out_point.into();
+            outpoints.push(OutpointConf {
+                tx_hash: hex::encode(outpoint.tx_hash().as_slice()),
+                index: outpoint.index().unpack(),
+            });
+            let data = packed::Bytes::from(cell.output_data).raw_data();
+            let signed_price = SignedPrice::from_slice(data.as_ref())
+                .map_err(|e| format!("invalid signed price cell. err: {}", e))?;
+            let mut buf = [0u8; UDT_LEN];
+            buf.copy_from_slice(signed_price.price().as_slice());
+            prices.push(u128::from_le_bytes(buf));
+        }
+        if prices.is_empty() {
+            return Err("no price oracle in settings".to_owned());
+        }
+        prices.sort_unstable();
+        let mid = prices.len() / 2;
+        let price = if prices.len() % 2 == 0 {
+            prices[mid - 1] / 2 + prices[mid] / 2 + (prices[mid - 1] % 2 + prices[mid] % 2) / 2
+        } else {
+            prices[mid]
+        };
+        Ok((price, outpoints))
+    }
 
-        let mut buf = [0u8; UDT_LEN];
-        buf.copy_from_slice(cell.1.as_ref());
-        let price = u128::from_le_bytes(buf);
-        Ok((cell_dep, price))
+    /// Add the tip header and the header of the block creating the toCKB input as header deps,
+    /// the typescript checks price freshness against them
+    fn add_price_header_deps(&mut self, helper: &mut TxHelper) -> Result<(), String> {
+        let tip_header = self.rpc_client.get_tip_header()?;
+        let tx_hash: H256 = helper
+            .transaction
+            .inputs()
+            .get(0)
+            .expect("toCKB cell should be added to inputs")
+            .previous_output()
+            .tx_hash()
+            .unpack();
+        let input_block_hash = self
+            .rpc_client
+            .get_transaction(tx_hash.clone())?
+            .ok_or(format!("tx {} not found", tx_hash))?
+            .tx_status
+            .block_hash
+            .ok_or(format!("tx {} not committed", tx_hash))?;
+        let mut builder = helper
+            .transaction
+            .as_advanced_builder()
+            .header_dep(tip_header.hash.pack());
+        // duplicated header deps are rejected
+        if input_block_hash != tip_header.hash {
+            builder = builder.header_dep(input_block_hash.pack());
+        }
+        helper.transaction = builder.build();
+        Ok(())
     }
 
     fn add_cell_deps(
@@ -105,11 +166,12 @@ impl Generator {
         from_lockscript: Script,
         tx_fee: u64,
         user_lockscript: Script,
-        pledge: u64,
         kind: u8,
-        lot_size: u8,
+        amount: u128,
+        signer_fee_rate: u32,
+        token_address: Option<String>,
+        signer_offer: Option<SignerOfferView>,
     ) -> Result<TransactionView, String> {
-        let to_capacity = pledge * CKB_UNITS;
         let mut helper = TxHelper::default();
 
         let outpoints = vec![
@@ -117,11 +179,54 @@ impl Generator {
             self.settings.typescript.outpoint.clone(),
         ];
         self.add_cell_deps(&mut helper, outpoints)?;
+        let params = self.add_protocol_params_dep(&mut helper)?;
+        if signer_fee_rate > params.max_signer_fee_rate {
+            return Err(format!(
+                "signer fee rate should be no more than {}, got {}",
+                params.max_signer_fee_rate, signer_fee_rate
+            ));
+        }
+        let to_capacity = params.pledge;
+        let xchain_kind =
+            XChainKind::from_int(kind).map_err(|_| format!("invalid xchain kind: {}", kind))?;
+        // the chosen signer has the priority to bond the request
+        let signer_lockscript = match signer_offer {
+            Some(offer) => {
+                if !offer.accepts(xchain_kind, amount, signer_fee_rate) {
+                    return Err(format!(
+                        "signer offer does not accept the deposit, kind: {}, amount: {}, signer fee rate: {}",
+                        kind, amount, signer_fee_rate
+                    ));
+                }
+                basic::Script::from_slice(&offer.signer_lockscript).unwrap()
+            }
+            None => basic::Script::default(),
+        };
 
+        // the extra is filled at mint, but its union arm must match the kind from the start
+        let x_extra = if kind == XChainKind::Ltc.int_value() {
+            XExtra::new_builder().set(LtcExtra::default()).build()
+        } else if kind == XChainKind::Erc20.int_value() {
+            // the token is fixed at request, only the lock is filled at mint
+            let token_address = token_address
+                .ok_or_else(|| "token address is required by ERC-20 deposit".to_owned())
+                .and_then(|address| decode_token_address(&address))?;
+            let erc20_extra = Erc20Extra::new_builder()
+                .token_address(basic::Bytes::from(token_address))
+                .build();
+            XExtra::new_builder().set(erc20_extra).build()
+        } else {
+            XExtra::default()
+        };
         let tockb_data = ToCKBCellData::new_builder()
             .status(Byte::new(ToCKBStatus::Initial.int_value()))
-            .lot_size(Byte::new(lot_size))
+            .lot_size(Byte::new(ARBITRARY_LOT_SIZE))
             .user_lockscript(basic::Script::from_slice(user_lockscript.as_slice()).unwrap())
+            .x_extra(x_extra)
+            .amount(amount.into())
+            .signer_fee_rate(signer_fee_rate.into())
+            .pledge(params.pledge.into())
+            .signer_lockscript(signer_lockscript)
             .build()
             .as_bytes();
         check_capacity(to_capacity, tockb_data.len())?;
@@ -129,7 +234,7 @@ impl Generator {
             .expect("wrong lockscript code hash config");
         let typescript_code_hash = hex::decode(&self.settings.typescript.code_hash)
             .expect("wrong typescript code hash config");
-        let typescript_args = [0u8; 37];
+        let typescript_args = [0u8; 70];
 
         let typescript = Script::new_builder()
             .code_hash(Byte32::from_slice(&typescript_code_hash).unwrap())
@@ -139,8 +244,8 @@ impl Generator {
         let lockscript = Script::new_builder()
             .code_hash(Byte32::from_slice(&lockscript_code_hash).unwrap())
             .hash_type(DepType::Code.into())
-            // TODO: should change args to `code_hash + hash_type + kind`
-            .args(typescript.as_slice()[0..54].pack())
+            // TODO: should change args to `code_hash + hash_type + kind + network + header_relay`
+            .args(typescript.as_slice()[0..87].pack())
             .build();
         let to_output = CellOutput::new_builder()
             .capacity(Capacity::shannons(to_capacity).pack())
@@ -165,12 +270,14 @@ impl Generator {
             .as_bytes();
         let new_typescript_args = ToCKBTypeArgs::new_builder()
             .xchain_kind(Byte::new(kind))
+            .xchain_network(Byte::new(self.get_xchain_network(kind)?))
+            .header_relay(self.get_header_relay(kind)?)
             .cell_id(basic::OutPoint::from_slice(first_outpoint.as_ref()).unwrap())
             .build()
             .as_bytes();
         assert!(
-            new_typescript_args.len() == 37,
-            "typescript_args len should be 37"
+            new_typescript_args.len() == 70,
+            "typescript_args len should be 70"
         );
         let new_typescript = typescript
             .as_builder()
@@ -179,7 +286,7 @@ impl Generator {
 
         let new_lockscript = lockscript
             .as_builder()
-            .args(new_typescript.as_slice()[0..54].pack())
+            .args(new_typescript.as_slice()[0..87].pack())
             .build();
         let new_output = to_output
             .as_builder()
@@ -192,6 +299,224 @@ impl Generator {
         Ok(tx)
     }
 
+    fn get_xchain_network(&self, kind: u8) -> Result<u8, String> {
+        if kind == XChainKind::Btc.int_value() {
+            Ok(self.settings.get_btc_network()?.int_value())
+        } else if kind == XChainKind::Ltc.int_value() {
+            Ok(self.settings.get_ltc_network()?.int_value())
+        } else {
+            Ok(0)
+        }
+    }
+
+    fn get_header_relay(&self, kind: u8) -> Result<basic::Byte32, String> {
+        match self.get_header_relay_typescript()? {
+            Some(typescript) if kind == XChainKind::Btc.int_value() => {
+                Ok(basic::Byte32::from_slice(typescript.calc_script_hash().as_slice()).unwrap())
+            }
+            _ => Ok(Default::default()),
+        }
+    }
+
+    fn get_header_relay_typescript(&self) -> Result<Option<Script>, String> {
+        let conf = &self.settings.btc_header_relay;
+        if conf.type_args.is_empty() {
+            return Ok(None);
+        }
+        let code_hash = hex::decode(&conf.script.code_hash)
+            .map_err(|e| format!("invalid btc header relay config. err: {}", e))?;
+        let type_args = hex::decode(&conf.type_args)
+            .map_err(|e| format!("invalid btc header relay config. err: {}", e))?;
+        let typescript = Script::new_builder()
+            .code_hash(
+                Byte32::from_slice(&code_hash)
+                    .map_err(|e| format!("invalid btc header relay config. err: {}", e))?,
+            )
+            .hash_type(DepType::Code.into())
+            .args(Bytes::from(type_args).pack())
+            .build();
+        Ok(Some(typescript))
+    }
+
+    /// Return the live header relay cell and its data
+    pub fn get_btc_header_relay(&mut self) -> Result<(OutPoint, BtcRelayView), String> {
+        let typescript = self
+            .get_header_relay_typescript()?
+            .ok_or("btc header relay is not configured".to_owned())?;
+        let cell = get_live_cell_by_typescript(&mut self.indexer_client, typescript)?
+            .ok_or("btc header relay cell not found".to_owned())?;
+        let data = packed::Bytes::from(cell.output_data).raw_data();
+        let relay = BtcRelayView::new(data.as_ref())
+            .map_err(|err| format!("Parse to BtcRelayView error: {}", err as i8))?;
+        Ok((cell.out_point.into(), relay))
+    }
+
+    /// Cell dep which the spv proof of the toCKB cell is checked against
+    fn get_btc_proof_dep(&mut self, typescript: &Script) -> Result<OutpointConf, String> {
+        let typescript_args = ToCKBTypeArgsView::from_slice(typescript.args().raw_data().as_ref())
+            .map_err(|err| format!("Parse to ToCKBTypeArgsView error: {}", err as i8))?;
+        match typescript_args.get_header_relay() {
+            Some(relay_type_hash) => {
+                let relay_typescript = self
+                    .get_header_relay_typescript()?
+                    .ok_or("btc header relay is not configured".to_owned())?;
+                if relay_typescript.calc_script_hash().as_slice() != &relay_type_hash[..] {
+                    return Err("header relay of the toCKB cell mismatches config".to_owned());
+                }
+                let (outpoint, _) = self.get_btc_header_relay()?;
+                Ok(OutpointConf {
+                    tx_hash: hex::encode(outpoint.tx_hash().as_slice()),
+                    index: outpoint.index().unpack(),
+                })
+            }
+            None if typescript_args.xchain_kind == XChainKind::Ltc => {
+                let outpoint = &self.settings.ltc_difficulty_cell.outpoint;
+                if outpoint.tx_hash.is_empty() {
+                    return Err("ltc difficulty cell is not configured".to_owned());
+                }
+                Ok(outpoint.clone())
+            }
+            None => Ok(self.settings.btc_difficulty_cell.outpoint.clone()),
+        }
+    }
+
+    fn get_protocol_params_typescript(&self) -> Result<Script, String> {
+        let type_args = hex::decode(&self.settings.protocol_params.type_args)
+            .map_err(|e| format!("invalid protocol params config. err: {}", e))?;
+        if type_args.len() != 32 {
+            return Err("protocol params is not configured".to_owned());
+        }
+        Ok(build_type_id_script(type_args.into()))
+    }
+
+    fn get_lock_registry_typescript(&self) -> Result<Script, String> {
+        let type_args = hex::decode(&self.settings.lock_registry.type_args)
+            .map_err(|e| format!("invalid lock registry config. err: {}", e))?;
+        if type_args.len() != 32 {
+            return Err("lock registry is not configured".to_owned());
+        }
+        Ok(build_type_id_script(type_args.into()))
+    }
+
+    /// Return the lock txs in the registry with the type hashes of their deposits, collected from
+    /// the txs which updated the registry
+    fn get_lock_registry_leaves(
+        &mut self,
+        registry_typescript: Script,
+    ) -> Result<Vec<([u8; 32], [u8; 32])>, String> {
+        let tockb_code_hash = hex::decode(&self.settings.typescript.code_hash)
+            .expect("wrong typescript code hash config");
+        let mut leaves = vec![];
+        for tx_hash in get_tx_hashes_by_typescript(&mut self.indexer_client, registry_typescript)? {
+            let tx: packed::Transaction = self
+                .rpc_client
+                .get_transaction(tx_hash.clone())?
+                .ok_or(format!("tx {} not found", tx_hash))?
+                .transaction
+                .inner
+                .into();
+            for (output, data) in tx.into_view().outputs_with_data_iter() {
+                let tockb_typescript = match output.type_().to_opt() {
+                    Some(script) if script.code_hash().as_slice() == &tockb_code_hash[..] => script,
+                    _ => continue,
+                };
+                let type_args =
+                    ToCKBTypeArgsView::from_slice(tockb_typescript.args().raw_data().as_ref())
+                        .map_err(|err| {
+                            format!("Parse to ToCKBTypeArgsView error: {}", err as i8)
+                        })?;
+                let data_view = ToCKBCellDataView::new(data.as_ref(), type_args.xchain_kind)
+                    .map_err(|err| format!("Parse to ToCKBCellDataView error: {}", err as i8))?;
+                if let ToCKBStatus::Warranty = data_view.status {
+                    let type_hash: H256 = tockb_typescript.calc_script_hash().unpack();
+                    leaves.push((get_registry_key(&data_view.x_extra), type_hash.0));
+                }
+            }
+        }
+        Ok(leaves)
+    }
+
+    /// Add the registry to the tx, inserting the lock tx with the type hash of the deposit, return
+    /// the witness of the registry input
+    fn add_lock_registry_insert(
+        &mut self,
+        helper: &mut TxHelper,
+        registry_key: [u8; 32],
+        tockb_type_hash: [u8; 32],
+    ) -> Result<WitnessArgs, String> {
+        let registry_typescript = self.get_lock_registry_typescript()?;
+        let registered = self.get_lock_registry_leaves(registry_typescript.clone())?;
+        let (registry, proof) = build_lock_registry(&registered, registry_key)
+            .map_err(|err| format!("build lock registry error: {}", err as i8))?;
+        let (registry_cell, registry_data) =
+            self.get_ckb_cell(helper, registry_typescript, true)?;
+        if registry_data != registry.as_molecule_data() {
+            return Err("lock registry mismatches the registry txs".to_owned());
+        }
+        let new_registry = registry
+            .insert(registry_key, tockb_type_hash, &proof)
+            .map_err(|_| "lock tx is registered already".to_owned())?;
+        helper.add_output(registry_cell, new_registry.as_molecule_data());
+        Ok(WitnessArgs::new_builder()
+            .input_type(Some(Bytes::from(proof)).pack())
+            .build())
+    }
+
+    /// Return the live protocol params cell and its params
+    pub fn get_protocol_params(&mut self) -> Result<(OutPoint, ProtocolParamsView), String> {
+        let typescript = self.get_protocol_params_typescript()?;
+        let cell = get_live_cell_by_typescript(&mut self.indexer_client, typescript)?
+            .ok_or("protocol params cell not found".to_owned())?;
+        let data = packed::Bytes::from(cell.output_data).raw_data();
+        let params = ProtocolParamsView::new(data.as_ref())
+            .map_err(|err| format!("Parse to ProtocolParamsView error: {}", err as i8))?;
+        Ok((cell.out_point.into(), params))
+    }
+
+    /// Quote the collateral an auction of the toCKB cell pays to the bidder, if the toCKB cell is
+    /// consumed with the relative `since`, along the auction curve of the protocol params. The
+    /// compensation to the depositor of a faulty warranty deposit is not auctioned.
+    pub fn quote_auction_bidder_payout(
+        &mut self,
+        cell_typescript: Script,
+        since: u64,
+    ) -> Result<u64, String> {
+        let auction_time = RelativeSince::new(since)
+            .and_then(|since| since.as_seconds())
+            .map_err(|_| "auction since should be a relative since".to_owned())?;
+        let type_args =
+            ToCKBTypeArgsView::from_slice(cell_typescript.args().raw_data().as_ref())
+                .map_err(|err| format!("Parse to ToCKBTypeArgsView error: {}", err as i8))?;
+        let cell = get_live_cell_by_typescript(&mut self.indexer_client, cell_typescript)?
+            .ok_or("toCKB cell not found".to_owned())?;
+        let data = packed::Bytes::from(cell.output_data).raw_data();
+        let data_view = ToCKBCellDataView::new(data.as_ref(), type_args.xchain_kind)
+            .map_err(|err| format!("Parse to ToCKBCellDataView error: {}", err as i8))?;
+        let capacity: u64 = CellOutput::from(cell.output).capacity().unpack();
+        let (_, params) = self.get_protocol_params()?;
+        let mut collateral = capacity - XT_CELL_CAPACITY;
+        if data_view.status == ToCKBStatus::FaultyWhenWarranty {
+            collateral -= params.get_faulty_compensation(collateral);
+        }
+        params
+            .get_auction_bidder_payout(collateral, auction_time)
+            .map_err(|err| format!("invalid auction curve: {}", err as i8))
+    }
+
+    /// Add the protocol params cell as cell dep, the typescript reads params from it
+    fn add_protocol_params_dep(
+        &mut self,
+        helper: &mut TxHelper,
+    ) -> Result<ProtocolParamsView, String> {
+        let (outpoint, params) = self.get_protocol_params()?;
+        let outpoints = vec![OutpointConf {
+            tx_hash: hex::encode(outpoint.tx_hash().as_slice()),
+            index: outpoint.index().unpack(),
+        }];
+        self.add_cell_deps(helper, outpoints)?;
+        Ok(params)
+    }
+
     fn get_ckb_cell(
         &mut self,
         helper: &mut TxHelper,
@@ -226,7 +551,13 @@ impl Generator {
         cell_typescript: Script,
         signer_lockscript: Script,
         lock_address: String,
+        signer_group: SignerGroupView,
     ) -> Result<TransactionView, String> {
+        if !signer_group.members.is_empty()
+            && signer_group.members[0].lockscript != signer_lockscript.as_bytes()
+        {
+            return Err("signer_lockscript should be the first member of signer group".to_owned());
+        }
         let mut helper = TxHelper::default();
         let (ckb_cell, ckb_cell_data) = self.get_ckb_cell(&mut helper, cell_typescript, true)?;
         let input_capacity: u64 = ckb_cell.capacity().unpack();
@@ -242,25 +573,48 @@ impl Generator {
         let data_view: ToCKBCellDataView =
             ToCKBCellDataView::new(ckb_cell_data.as_ref(), typescript_args.xchain_kind)
                 .map_err(|err| format!("Parse to ToCKBCellDataView error: {}", err as i8))?;
-        let sudt_amount: u128 = data_view
-            .get_lot_xt_amount()
-            .map_err(|err| format!("get_lot_xt_amount error: {}", err as i8))?;
-        let (_price_oracle_dep, price) = self.get_price_oracle()?;
+        // other signers wait for the bonding window of the chosen signer to pass
+        let is_chosen = data_view
+            .get_chosen_signer()
+            .map_or(true, |chosen| chosen == signer_lockscript.as_slice());
+        if !is_chosen {
+            // the toCKB cell is the only input now
+            let tockb_input = helper
+                .transaction
+                .inputs()
+                .get(0)
+                .expect("toCKB cell should be added to inputs")
+                .as_builder()
+                .since((LOCK_TYPE_FLAG | SINCE_TYPE_TIMESTAMP | BONDING_WINDOW).pack())
+                .build();
+            helper.transaction = helper
+                .transaction
+                .as_advanced_builder()
+                .set_inputs(vec![tockb_input])
+                .build();
+        }
+        let sudt_amount: u128 = data_view.get_lot_xt_amount();
+        let (price, price_outpoints) = self.get_price_oracle()?;
+        let params = self.add_protocol_params_dep(&mut helper)?;
+        let collateral_percent = params.collateral_percent;
+        // collateral in units of 0.01 CKB, rounded up to cover the lot amount
+        let collateral = (sudt_amount * (collateral_percent as u128) + price - 1) / price;
         let to_capacity = (input_capacity as u128
             + 2 * XT_CELL_CAPACITY as u128
-            + sudt_amount * (COLLATERAL_PERCENT as u128) / (100 * price) * CKB_UNITS as u128)
-            as u64;
-        let outpoints = vec![
-            self.settings.price_oracle.outpoint.clone(),
-            self.settings.typescript.outpoint.clone(),
-            self.settings.lockscript.outpoint.clone(),
-        ];
+            + collateral * (CKB_UNITS / 100) as u128) as u64;
+        let mut outpoints = price_outpoints;
+        outpoints.push(self.settings.typescript.outpoint.clone());
+        outpoints.push(self.settings.lockscript.outpoint.clone());
         self.add_cell_deps(&mut helper, outpoints)?;
+        self.add_price_header_deps(&mut helper)?;
 
+        verify_x_address(&typescript_args, &lock_address)?;
         let mut to_data_view = data_view.clone();
         to_data_view.status = ToCKBStatus::Bonded;
         to_data_view.x_lock_address = Bytes::from(lock_address);
         to_data_view.signer_lockscript = signer_lockscript.as_bytes();
+        to_data_view.signer_group = signer_group;
+        to_data_view.params_snapshot = params.snapshot();
         let tockb_data = to_data_view
             .as_molecule_data()
             .map_err(|e| format!("serde tockb_data err: {}", e))?;
@@ -272,6 +626,26 @@ impl Generator {
             .lock(ckb_cell.lock())
             .build();
         helper.add_output(to_output, tockb_data);
+        // the members of a signer group fund the collateral by shares, `from_lockscript` funds
+        // its own share together with the fee
+        if to_data_view.is_signer_group() {
+            for (member_lockscript, share) in
+                to_data_view.get_signer_payouts(to_capacity - input_capacity)
+            {
+                if member_lockscript == from_lockscript.as_slice() {
+                    continue;
+                }
+                let member_lockscript = Script::from_slice(member_lockscript)
+                    .map_err(|e| format!("member lockscript decode error: {}", e))?;
+                helper.supply_capacity_of_lockscript(
+                    &mut self.rpc_client,
+                    &mut self.indexer_client,
+                    member_lockscript,
+                    &self.genesis_info,
+                    share,
+                )?;
+            }
+        }
         let tx = helper.supply_capacity(
             &mut self.rpc_client,
             &mut self.indexer_client,
@@ -294,19 +668,23 @@ impl Generator {
         let from_ckb_cell_data = ToCKBCellData::from_slice(ckb_cell_data.as_ref()).unwrap();
 
         // add cellDeps
+        let btc_proof_dep =
+            self.get_btc_proof_dep(&from_cell.type_().to_opt().unwrap_or_default())?;
         let outpoints = vec![
-            self.settings.btc_difficulty_cell.outpoint.clone(),
+            btc_proof_dep,
             self.settings.lockscript.outpoint.clone(),
             self.settings.typescript.outpoint.clone(),
             self.settings.sudt.outpoint.clone(),
         ];
         self.add_cell_deps(&mut helper, outpoints)?;
+        self.add_protocol_params_dep(&mut helper)?;
 
         let (tockb_typescript, _) = match from_cell.type_().to_opt() {
             Some(script) => (script.clone(), script.args().raw_data().as_ref()[0]),
             None => return Err("typescript of tockb cell is none".to_owned()),
         };
         let tockb_lockscript = from_cell.lock();
+        let tockb_type_hash: H256 = tockb_typescript.calc_script_hash().unpack();
 
         let typescript_args =
             ToCKBTypeArgsView::from_slice(tockb_typescript.args().raw_data().as_ref())
@@ -314,13 +692,11 @@ impl Generator {
 
         let data_view = ToCKBCellDataView::new(ckb_cell_data.as_ref(), typescript_args.xchain_kind)
             .map_err(|err| format!("Parse to ToCKBCellDataView error: {}", err as i8))?;
-        let lot_amount = data_view
-            .get_lot_xt_amount()
-            .map_err(|_| "get lot_amount from tockb cell data error".to_owned())?;
+        let lot_amount = data_view.get_lot_xt_amount();
         let from_capacity: u64 = from_cell.capacity().unpack();
         // gen output of tockb cell
-        {
-            let to_capacity = from_capacity - PLEDGE - XT_CELL_CAPACITY;
+        let registry_key = {
+            let to_capacity = from_capacity - data_view.pledge - XT_CELL_CAPACITY;
 
             // get tx_id and funding_output_index from spv_proof
             let btc_spv_proof = BTCSPVProof::from_slice(spv_proof.as_slice())
@@ -330,10 +706,13 @@ impl Generator {
 
             let mut output_data_view = data_view.clone();
             output_data_view.status = ToCKBStatus::Warranty;
-            output_data_view.x_extra = XExtraView::Btc(BtcExtraView {
-                lock_tx_hash: tx_id.into(),
-                lock_vout_index: funding_output_index,
-            });
+            output_data_view.x_extra = get_utxo_x_extra(
+                &data_view,
+                BtcExtraView {
+                    lock_tx_hash: tx_id.into(),
+                    lock_vout_index: funding_output_index,
+                },
+            );
             let tockb_data = output_data_view
                 .as_molecule_data()
                 .expect("output_data_view.as_molecule_data error");
@@ -345,7 +724,8 @@ impl Generator {
                 .lock(tockb_lockscript.clone())
                 .build();
             helper.add_output(to_output, tockb_data);
-        }
+            get_registry_key(&output_data_view.x_extra)
+        };
         // 2 xt cells
         {
             // mint xt cell to user, amount = lot_size * (1 - signer fee rate)
@@ -356,20 +736,21 @@ impl Generator {
 
             let sudt_typescript_code_hash = hex::decode(&self.settings.sudt.code_hash)
                 .expect("wrong sudt_script code hash config");
+            let xt_args = data_view.get_xt_args(tockb_lockscript.calc_script_hash().as_slice());
             let sudt_typescript = Script::new_builder()
                 .code_hash(Byte32::from_slice(&sudt_typescript_code_hash).unwrap())
                 .hash_type(DepType::Code.into())
-                .args(tockb_lockscript.calc_script_hash().as_bytes().pack())
+                .args(Bytes::from(xt_args).pack())
                 .build();
 
             let sudt_user_output = CellOutput::new_builder()
-                .capacity(Capacity::shannons(PLEDGE).pack())
+                .capacity(Capacity::shannons(data_view.pledge).pack())
                 .type_(Some(sudt_typescript.clone()).pack())
                 .lock(user_lockscript)
                 .build();
 
             let (to_user, to_signer) = {
-                let signer_fee = lot_amount * SIGNER_FEE_RATE.0 / SIGNER_FEE_RATE.1;
+                let signer_fee = data_view.get_signer_fee(lot_amount);
                 (lot_amount - signer_fee, signer_fee)
             };
             let to_user_amount_data: Bytes = to_user.to_le_bytes().to_vec().into();
@@ -390,6 +771,9 @@ impl Generator {
             let to_signer_amount_data = to_signer.to_le_bytes().to_vec().into();
             helper.add_output(sudt_signer_output, to_signer_amount_data);
         }
+        // insert the lock tx into the lock registry, so it can not mint again
+        let registry_witness =
+            self.add_lock_registry_insert(&mut helper, registry_key, tockb_type_hash.0)?;
 
         // add witness
         {
@@ -404,7 +788,10 @@ impl Generator {
             helper.transaction = helper
                 .transaction
                 .as_advanced_builder()
-                .set_witnesses(vec![witness.as_bytes().pack()])
+                .set_witnesses(vec![
+                    witness.as_bytes().pack(),
+                    registry_witness.as_bytes().pack(),
+                ])
                 .build();
         }
 
@@ -426,6 +813,7 @@ impl Generator {
         cell_typescript: Script,
         x_unlock_address: String,
         redeemer_lockscript: Script,
+        redeem_amount: Option<u128>,
     ) -> Result<TransactionView, String> {
         let mut helper = TxHelper::default();
         let (from_cell, ckb_cell_data) = self.get_ckb_cell(&mut helper, cell_typescript, true)?;
@@ -451,19 +839,37 @@ impl Generator {
             ToCKBTypeArgsView::from_slice(tockb_typescript.args().raw_data().as_ref())
                 .map_err(|err| format!("Parse to ToCKBTypeArgsView error: {}", err as i8))?;
 
+        verify_x_address(&typescript_args, &x_unlock_address)?;
+
         let data_view = ToCKBCellDataView::new(ckb_cell_data.as_ref(), typescript_args.xchain_kind)
             .map_err(|err| format!("Parse to ToCKBCellDataView error: {}", err as i8))?;
-        let lot_amount = data_view
-            .get_lot_xt_amount()
-            .map_err(|_| "get lot_amount from tockb cell data error".to_owned())?;
+        // redeem the whole lot unless a part of it is given
+        let lot_amount = data_view.get_lot_xt_amount();
+        let redeem_amount = redeem_amount.unwrap_or(lot_amount);
+        if redeem_amount == 0 || redeem_amount > lot_amount {
+            return Err(format!(
+                "redeem amount should be in (0, {}], got {}",
+                lot_amount, redeem_amount
+            ));
+        }
+        let (min_amount, _) = get_lot_amount_range(typescript_args.xchain_kind);
+        if redeem_amount != lot_amount
+            && (redeem_amount < min_amount || lot_amount - redeem_amount < min_amount)
+        {
+            return Err(format!(
+                "redeem amount and remainder of a partial redeem should be at least {}, got {}",
+                min_amount, redeem_amount
+            ));
+        }
         let from_capacity: u64 = from_cell.capacity().unpack();
 
         let sudt_typescript_code_hash =
             hex::decode(&self.settings.sudt.code_hash).expect("wrong sudt_script code hash config");
+        let xt_args = data_view.get_xt_args(tockb_lockscript.calc_script_hash().as_slice());
         let sudt_typescript = Script::new_builder()
             .code_hash(Byte32::from_slice(&sudt_typescript_code_hash).unwrap())
             .hash_type(DepType::Code.into())
-            .args(tockb_lockscript.calc_script_hash().as_bytes().pack())
+            .args(Bytes::from(xt_args).pack())
             .build();
 
         let (redeemer_is_depositor, user_lockscript) = {
@@ -480,6 +886,7 @@ impl Generator {
             output_data_view.status = ToCKBStatus::Redeeming;
             output_data_view.x_unlock_address = x_unlock_address.as_bytes().to_vec().into();
             output_data_view.redeemer_lockscript = redeemer_lockscript.as_bytes();
+            output_data_view.set_redeem_xt_amount(redeem_amount);
 
             let tockb_data = output_data_view
                 .as_molecule_data()
@@ -494,10 +901,10 @@ impl Generator {
             helper.add_output(to_output, tockb_data);
         }
 
-        // collect xt cell inputs to burn lot_amount xt
+        // collect xt cell inputs to burn redeem_amount xt
         {
-            let signer_fee = lot_amount * SIGNER_FEE_RATE.0 / SIGNER_FEE_RATE.1;
-            let mut need_sudt_amount = lot_amount;
+            let signer_fee = data_view.get_signer_fee(redeem_amount);
+            let mut need_sudt_amount = redeem_amount;
             if !redeemer_is_depositor {
                 need_sudt_amount += signer_fee;
             }
@@ -537,6 +944,205 @@ impl Generator {
         Ok(tx)
     }
 
+    /// Add capacity to a toCKB cell in Warranty, so the signer can keep it away from
+    /// liquidation when the price drops
+    pub fn topup_collateral(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        cell_typescript: Script,
+        capacity: u64,
+    ) -> Result<TransactionView, String> {
+        let mut helper = TxHelper::default();
+        let (ckb_cell, ckb_cell_data) = self.get_ckb_cell(&mut helper, cell_typescript, true)?;
+        let (_, data_view) = parse_tockb_cell(&ckb_cell, &ckb_cell_data)?;
+        if data_view.status != ToCKBStatus::Warranty {
+            return Err("only toCKB cell in Warranty can be topped up".to_owned());
+        }
+        if capacity == 0 {
+            return Err("topup capacity should be greater than 0".to_owned());
+        }
+        let input_capacity: u64 = ckb_cell.capacity().unpack();
+        let to_capacity = input_capacity
+            .checked_add(capacity)
+            .ok_or("topup capacity overflow".to_owned())?;
+
+        let outpoints = vec![
+            self.settings.typescript.outpoint.clone(),
+            self.settings.lockscript.outpoint.clone(),
+        ];
+        self.add_cell_deps(&mut helper, outpoints)?;
+
+        let to_output = ckb_cell
+            .as_builder()
+            .capacity(Capacity::shannons(to_capacity).pack())
+            .build();
+        helper.add_output(to_output, ckb_cell_data);
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(tx)
+    }
+
+    /// Start handing over a toCKB cell in Warranty to a new signer, who posts the same collateral
+    /// as the current one. The current signer agrees by paying from `from_lockscript`.
+    pub fn signer_handover_request(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        cell_typescript: Script,
+        new_signer_lockscript: Script,
+        new_lock_address: String,
+    ) -> Result<TransactionView, String> {
+        let mut helper = TxHelper::default();
+        let (ckb_cell, ckb_cell_data) = self.get_ckb_cell(&mut helper, cell_typescript, true)?;
+        let (typescript_args, data_view) = parse_tockb_cell(&ckb_cell, &ckb_cell_data)?;
+        if data_view.status != ToCKBStatus::Warranty {
+            return Err("only toCKB cell in Warranty can be handed over".to_owned());
+        }
+        if data_view.signer_lockscript != from_lockscript.as_bytes() {
+            return Err("handover should be requested by the current signer".to_owned());
+        }
+        verify_x_address(&typescript_args, &new_lock_address)?;
+
+        let outpoints = vec![
+            self.settings.typescript.outpoint.clone(),
+            self.settings.lockscript.outpoint.clone(),
+        ];
+        self.add_cell_deps(&mut helper, outpoints)?;
+
+        let from_capacity: u64 = ckb_cell.capacity().unpack();
+        let mut to_data_view = data_view.clone();
+        to_data_view.status = ToCKBStatus::Handover;
+        to_data_view.x_unlock_address = new_lock_address.as_bytes().to_vec().into();
+        to_data_view.redeemer_lockscript = new_signer_lockscript.as_bytes();
+        let tockb_data = to_data_view
+            .as_molecule_data()
+            .map_err(|e| format!("serde tockb_data err: {}", e))?;
+        let to_output = ckb_cell
+            .as_builder()
+            .capacity(Capacity::shannons(2 * from_capacity).pack())
+            .build();
+        helper.add_output(to_output, tockb_data);
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(tx)
+    }
+
+    /// Finish the handover with the spv proof that the locked XAsset is moved to the lock address
+    /// of the new signer, the collateral of the previous signer is released.
+    pub fn signer_handover(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        cell_typescript: Script,
+        spv_proof: Vec<u8>,
+    ) -> Result<TransactionView, String> {
+        let mut helper = TxHelper::default();
+        let (ckb_cell, ckb_cell_data) = self.get_ckb_cell(&mut helper, cell_typescript, true)?;
+        let (_, data_view) = parse_tockb_cell(&ckb_cell, &ckb_cell_data)?;
+        if data_view.status != ToCKBStatus::Handover {
+            return Err("toCKB cell is not in Handover".to_owned());
+        }
+
+        let btc_proof_dep =
+            self.get_btc_proof_dep(&ckb_cell.type_().to_opt().unwrap_or_default())?;
+        let outpoints = vec![
+            btc_proof_dep,
+            self.settings.lockscript.outpoint.clone(),
+            self.settings.typescript.outpoint.clone(),
+        ];
+        self.add_cell_deps(&mut helper, outpoints)?;
+        // the spv proof is checked with the confirmations in the params
+        self.add_protocol_params_dep(&mut helper)?;
+
+        // get tx_id and funding_output_index from spv_proof
+        let btc_spv_proof = BTCSPVProof::from_slice(spv_proof.as_slice())
+            .map_err(|err| format!("btc_spv_proof invalid: {}", err))?;
+        let x_extra = get_utxo_x_extra(
+            &data_view,
+            BtcExtraView {
+                lock_tx_hash: btc_spv_proof.tx_id().raw_data().into(),
+                lock_vout_index: btc_spv_proof.funding_output_index().into(),
+            },
+        );
+        {
+            let witness_data = MintXTWitness::new_builder()
+                .spv_proof(spv_proof.into())
+                .cell_dep_index_list(vec![0].into())
+                .build();
+            let witness = WitnessArgs::new_builder()
+                .input_type(Some(witness_data.as_bytes()).pack())
+                .build();
+            helper.transaction = helper
+                .transaction
+                .as_advanced_builder()
+                .set_witnesses(vec![witness.as_bytes().pack()])
+                .build();
+        }
+
+        let from_capacity: u64 = ckb_cell.capacity().unpack();
+        let mut to_data_view = data_view.clone();
+        to_data_view.status = ToCKBStatus::Warranty;
+        to_data_view.signer_lockscript = data_view.redeemer_lockscript.clone();
+        to_data_view.x_lock_address = data_view.x_unlock_address.clone();
+        to_data_view.x_extra = x_extra;
+        let tockb_data = to_data_view
+            .as_molecule_data()
+            .map_err(|e| format!("serde tockb_data err: {}", e))?;
+        let to_output = ckb_cell
+            .clone()
+            .as_builder()
+            .capacity(Capacity::shannons(from_capacity / 2).pack())
+            .build();
+        helper.add_output(to_output, tockb_data);
+
+        let previous_signer_lockscript =
+            Script::from_slice(data_view.signer_lockscript.as_ref())
+                .map_err(|e| format!("signer_lockscript decode error: {}", e))?;
+        let to_signer_output = CellOutput::new_builder()
+            .capacity(Capacity::shannons(from_capacity / 2).pack())
+            .lock(previous_signer_lockscript)
+            .build();
+        helper.add_output(to_signer_output, Bytes::new());
+
+        // insert the new lock tx into the lock registry, so it can not mint another deposit
+        let tockb_type_hash: H256 = ckb_cell
+            .type_()
+            .to_opt()
+            .expect("toCKB cell should have typescript")
+            .calc_script_hash()
+            .unpack();
+        let registry_witness = self.add_lock_registry_insert(
+            &mut helper,
+            get_registry_key(&to_data_view.x_extra),
+            tockb_type_hash.0,
+        )?;
+        // the registry is input 1, after the toCKB cell
+        helper.transaction = helper
+            .transaction
+            .as_advanced_builder()
+            .witness(registry_witness.as_bytes().pack())
+            .build();
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(tx)
+    }
+
     pub fn withdraw_collateral(
         &mut self,
         from_lockscript: Script,
@@ -546,15 +1152,20 @@ impl Generator {
     ) -> Result<TransactionView, String> {
         let mut helper = TxHelper::default();
 
-        let (ckb_cell, _) = self.get_ckb_cell(&mut helper, cell_typescript, true)?;
+        let (ckb_cell, ckb_cell_data) = self.get_ckb_cell(&mut helper, cell_typescript, true)?;
+        let (_, data_view) = parse_tockb_cell(&ckb_cell, &ckb_cell_data)?;
         let to_capacity: u64 = ckb_cell.capacity().unpack();
 
+        let btc_proof_dep =
+            self.get_btc_proof_dep(&ckb_cell.type_().to_opt().unwrap_or_default())?;
         let outpoints = vec![
-            self.settings.btc_difficulty_cell.outpoint.clone(),
+            btc_proof_dep,
             self.settings.lockscript.outpoint.clone(),
             self.settings.typescript.outpoint.clone(),
         ];
         self.add_cell_deps(&mut helper, outpoints)?;
+        // the spv proof is checked with the confirmations in the params
+        self.add_protocol_params_dep(&mut helper)?;
 
         {
             let witness_data = MintXTWitness::new_builder()
@@ -572,12 +1183,110 @@ impl Generator {
                 .build();
         }
 
-        let to_output = CellOutput::new_builder()
+        add_signer_outputs(&mut helper, &data_view, to_capacity)?;
+
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(tx)
+    }
+
+    /// Settle a partial redemption with the spv proof that the redeemed part is paid to
+    /// x_unlock_address and the remainder is paid back to x_lock_address at `change_vout_index`,
+    /// the collateral backing the redeemed part is released to the signer.
+    pub fn withdraw_partial_collateral(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        cell_typescript: Script,
+        spv_proof: Vec<u8>,
+        change_vout_index: u32,
+    ) -> Result<TransactionView, String> {
+        let mut helper = TxHelper::default();
+        let (ckb_cell, ckb_cell_data) = self.get_ckb_cell(&mut helper, cell_typescript, true)?;
+        let (_, data_view) = parse_tockb_cell(&ckb_cell, &ckb_cell_data)?;
+        if data_view.status != ToCKBStatus::Redeeming || !data_view.is_partial_redeem() {
+            return Err("toCKB cell is not partially redeeming".to_owned());
+        }
+
+        let btc_proof_dep =
+            self.get_btc_proof_dep(&ckb_cell.type_().to_opt().unwrap_or_default())?;
+        let outpoints = vec![
+            btc_proof_dep,
+            self.settings.lockscript.outpoint.clone(),
+            self.settings.typescript.outpoint.clone(),
+        ];
+        self.add_cell_deps(&mut helper, outpoints)?;
+        // the spv proof is checked with the confirmations in the params
+        self.add_protocol_params_dep(&mut helper)?;
+
+        let btc_spv_proof = BTCSPVProof::from_slice(spv_proof.as_slice())
+            .map_err(|err| format!("btc_spv_proof invalid: {}", err))?;
+        let x_extra = get_utxo_x_extra(
+            &data_view,
+            BtcExtraView {
+                lock_tx_hash: btc_spv_proof.tx_id().raw_data().into(),
+                lock_vout_index: change_vout_index,
+            },
+        );
+        {
+            let witness_data = MintXTWitness::new_builder()
+                .spv_proof(spv_proof.into())
+                .cell_dep_index_list(vec![0].into())
+                .build();
+            let witness = WitnessArgs::new_builder()
+                .input_type(Some(witness_data.as_bytes()).pack())
+                .build();
+            helper.transaction = helper
+                .transaction
+                .as_advanced_builder()
+                .set_witnesses(vec![witness.as_bytes().pack()])
+                .build();
+        }
+
+        let from_capacity: u64 = ckb_cell.capacity().unpack();
+        let mut to_data_view = data_view.clone();
+        to_data_view.status = ToCKBStatus::Warranty;
+        to_data_view.x_extra = x_extra;
+        to_data_view.settle_partial_redeem();
+        let asset_collateral = (from_capacity - XT_CELL_CAPACITY) as u128;
+        let to_capacity = XT_CELL_CAPACITY
+            + (asset_collateral * to_data_view.get_lot_xt_amount() / data_view.get_lot_xt_amount())
+                as u64;
+        let tockb_data = to_data_view
+            .as_molecule_data()
+            .map_err(|e| format!("serde tockb_data err: {}", e))?;
+        let to_output = ckb_cell
+            .clone()
+            .as_builder()
             .capacity(Capacity::shannons(to_capacity).pack())
-            .lock(from_lockscript.clone())
             .build();
-        helper.add_output(to_output, Bytes::new());
+        helper.add_output(to_output, tockb_data);
+
+        add_signer_outputs(&mut helper, &data_view, from_capacity - to_capacity)?;
 
+        // insert the new lock tx into the lock registry, so it can not mint another deposit
+        let tockb_type_hash: H256 = ckb_cell
+            .type_()
+            .to_opt()
+            .expect("toCKB cell should have typescript")
+            .calc_script_hash()
+            .unpack();
+        let registry_witness = self.add_lock_registry_insert(
+            &mut helper,
+            get_registry_key(&to_data_view.x_extra),
+            tockb_type_hash.0,
+        )?;
+        // the registry is input 1, after the toCKB cell
+        helper.transaction = helper
+            .transaction
+            .as_advanced_builder()
+            .witness(registry_witness.as_bytes().pack())
+            .build();
         let tx = helper.supply_capacity(
             &mut self.rpc_client,
             &mut self.indexer_client,
@@ -592,6 +1301,7 @@ impl Generator {
         &mut self,
         from_lockscript: Script,
         kind: u8,
+        token_address: Option<String>,
         to_lockscript: Script,
         sudt_amount: u128,
         ckb_amount: u64,
@@ -611,6 +1321,8 @@ impl Generator {
 
         let typescript_args = ToCKBTypeArgs::new_builder()
             .xchain_kind(Byte::new(kind))
+            .xchain_network(Byte::new(self.get_xchain_network(kind)?))
+            .header_relay(self.get_header_relay(kind)?)
             .build();
 
         let typescript = Script::new_builder()
@@ -621,16 +1333,17 @@ impl Generator {
         let lockscript = Script::new_builder()
             .code_hash(Byte32::from_slice(&lockscript_code_hash).unwrap())
             .hash_type(DepType::Code.into())
-            .args(typescript.as_slice()[0..54].pack())
+            .args(typescript.as_slice()[0..87].pack())
             .build();
 
         {
             let sudt_typescript_code_hash = hex::decode(self.settings.sudt.code_hash.clone())
                 .expect("wrong sudt_script code hash config");
+            let xt_args = get_xt_args(&lockscript, token_address)?;
             let sudt_typescript = Script::new_builder()
                 .code_hash(Byte32::from_slice(&sudt_typescript_code_hash).unwrap())
                 .hash_type(DepType::Code.into())
-                .args(lockscript.calc_script_hash().as_bytes().pack())
+                .args(Bytes::from(xt_args).pack())
                 .build();
 
             let sudt_output = CellOutput::new_builder()
@@ -662,7 +1375,389 @@ impl Generator {
         Ok(tx)
     }
 
-    pub fn get_sudt_balance(&mut self, address: String, kind: u8) -> Result<u128, String> {
+    /// Create the header relay cell starting from `checkpoint`, the relay typescript is the type
+    /// of output 0.
+    pub fn create_btc_header_relay(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        capacity: u64,
+        checkpoint: BtcRelayView,
+    ) -> Result<TransactionView, String> {
+        let mut helper = TxHelper::default();
+        let outpoints = vec![self.settings.btc_header_relay.script.outpoint.clone()];
+        self.add_cell_deps(&mut helper, outpoints)?;
+
+        let relay_data = checkpoint.as_molecule_data();
+        check_capacity(capacity, relay_data.len())?;
+        let relay_code_hash = hex::decode(&self.settings.btc_header_relay.script.code_hash)
+            .expect("wrong btc header relay code hash config");
+        let network = self.settings.get_btc_network()?;
+        let typescript = Script::new_builder()
+            .code_hash(Byte32::from_slice(&relay_code_hash).unwrap())
+            .hash_type(DepType::Code.into())
+            .args(
+                BTCHeaderRelayArgs::new_builder()
+                    .network(Byte::new(network.int_value()))
+                    .build()
+                    .as_bytes()
+                    .pack(),
+            )
+            .build();
+        let relay_output = CellOutput::new_builder()
+            .capacity(Capacity::shannons(capacity).pack())
+            .type_(Some(typescript.clone()).pack())
+            .lock(from_lockscript.clone())
+            .build();
+        helper.add_output(relay_output.clone(), relay_data.to_vec().into());
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+
+        // fill typescript args with first outpoint
+        let first_outpoint = tx
+            .inputs()
+            .get(0)
+            .expect("should have input")
+            .previous_output()
+            .as_bytes();
+        let relay_args = BTCHeaderRelayArgs::new_builder()
+            .network(Byte::new(network.int_value()))
+            .cell_id(basic::OutPoint::from_slice(first_outpoint.as_ref()).unwrap())
+            .build();
+        let new_output = relay_output
+            .as_builder()
+            .type_(
+                Some(
+                    typescript
+                        .as_builder()
+                        .args(relay_args.as_bytes().pack())
+                        .build(),
+                )
+                .pack(),
+            )
+            .build();
+        let mut new_outputs = tx.outputs().into_iter().collect::<Vec<_>>();
+        new_outputs[0] = new_output;
+        let tx = tx.as_advanced_builder().set_outputs(new_outputs).build();
+        Ok(tx)
+    }
+
+    /// Push serialized 80-byte headers to the header relay cell
+    pub fn update_btc_header_relay(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        raw_headers: Vec<u8>,
+    ) -> Result<TransactionView, String> {
+        let mut helper = TxHelper::default();
+        let outpoints = vec![self.settings.btc_header_relay.script.outpoint.clone()];
+        self.add_cell_deps(&mut helper, outpoints)?;
+
+        let relay_typescript = self
+            .get_header_relay_typescript()?
+            .ok_or("btc header relay is not configured".to_owned())?;
+        let (relay_cell, relay_data) = self.get_ckb_cell(&mut helper, relay_typescript, true)?;
+        let relay = BtcRelayView::new(relay_data.as_ref())
+            .map_err(|err| format!("Parse to BtcRelayView error: {}", err as i8))?;
+        let new_relay = relay
+            .apply_headers(self.settings.get_btc_network()?, &raw_headers)
+            .map_err(|err| format!("apply headers to relay error: {}", err as i8))?;
+        let new_relay_data = new_relay.as_molecule_data();
+        check_capacity(relay_cell.capacity().unpack(), new_relay_data.len())?;
+        helper.add_output(relay_cell, new_relay_data.to_vec().into());
+
+        let witness = WitnessArgs::new_builder()
+            .input_type(Some(Bytes::from(raw_headers)).pack())
+            .build();
+        helper.transaction = helper
+            .transaction
+            .as_advanced_builder()
+            .set_witnesses(vec![witness.as_bytes().pack()])
+            .build();
+
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(tx)
+    }
+
+    fn get_signer_registry_typescript(&self) -> Result<Script, String> {
+        let code_hash = hex::decode(&self.settings.signer_registry.script.code_hash)
+            .map_err(|e| format!("invalid signer registry config. err: {}", e))?;
+        let typescript = Script::new_builder()
+            .code_hash(
+                Byte32::from_slice(&code_hash)
+                    .map_err(|e| format!("invalid signer registry config. err: {}", e))?,
+            )
+            .hash_type(DepType::Code.into())
+            .build();
+        Ok(typescript)
+    }
+
+    /// Publish the offer of `from_lockscript`, the offer cell is locked by the signer and its
+    /// capacity backs the offered collateral.
+    pub fn publish_signer_offer(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        offer: SignerOfferView,
+    ) -> Result<TransactionView, String> {
+        if from_lockscript.as_slice() != offer.signer_lockscript.as_ref() {
+            return Err("signer lockscript of the offer should be the from lockscript".to_owned());
+        }
+        let mut helper = TxHelper::default();
+        let outpoints = vec![self.settings.signer_registry.script.outpoint.clone()];
+        self.add_cell_deps(&mut helper, outpoints)?;
+
+        let offer_data = offer
+            .as_molecule_data()
+            .map_err(|e| format!("encode signer offer error: {}", e))?;
+        check_capacity(offer.collateral, offer_data.len())?;
+        let offer_output = CellOutput::new_builder()
+            .capacity(Capacity::shannons(offer.collateral).pack())
+            .type_(Some(self.get_signer_registry_typescript()?).pack())
+            .lock(from_lockscript.clone())
+            .build();
+        helper.add_output(offer_output, offer_data.to_vec().into());
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(tx)
+    }
+
+    /// Withdraw the offer at `offer_outpoint`, the collateral goes back to `from_lockscript`
+    pub fn withdraw_signer_offer(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        offer_outpoint: OutPoint,
+    ) -> Result<TransactionView, String> {
+        let offer = self.get_signer_offer(offer_outpoint.clone())?;
+        if from_lockscript.as_slice() != offer.signer_lockscript.as_ref() {
+            return Err("only the signer of the offer can withdraw it".to_owned());
+        }
+        let mut helper = TxHelper::default();
+        let outpoints = vec![self.settings.signer_registry.script.outpoint.clone()];
+        self.add_cell_deps(&mut helper, outpoints)?;
+
+        let genesis_info = self.genesis_info.clone();
+        let mut get_live_cell_fn = |out_point: OutPoint, with_data: bool| {
+            get_live_cell(&mut self.rpc_client, out_point, with_data).map(|(output, _)| output)
+        };
+        helper.add_input(
+            offer_outpoint,
+            None,
+            &mut get_live_cell_fn,
+            &genesis_info,
+            true,
+        )?;
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(tx)
+    }
+
+    /// Return the offer in the live cell at `outpoint`
+    pub fn get_signer_offer(&mut self, outpoint: OutPoint) -> Result<SignerOfferView, String> {
+        let (cell, data) = get_live_cell(&mut self.rpc_client, outpoint, true)?;
+        if cell.type_().to_opt() != Some(self.get_signer_registry_typescript()?) {
+            return Err("cell is not a signer offer".to_owned());
+        }
+        SignerOfferView::new(data.as_ref())
+            .map_err(|err| format!("Parse to SignerOfferView error: {}", err as i8))
+    }
+
+    /// Return the live offers which accept a deposit of `kind` and `amount`, from the lowest
+    /// signer fee rate
+    pub fn get_signer_offers(
+        &mut self,
+        kind: u8,
+        amount: u128,
+    ) -> Result<Vec<(OutPoint, SignerOfferView)>, String> {
+        let xchain_kind =
+            XChainKind::from_int(kind).map_err(|_| format!("invalid xchain kind: {}", kind))?;
+        let typescript = self.get_signer_registry_typescript()?;
+        let cells = get_live_cells_by_typescript(&mut self.indexer_client, typescript)?;
+        let mut offers = vec![];
+        for cell in cells {
+            let data = packed::Bytes::from(cell.output_data).raw_data();
+            let offer = SignerOfferView::new(data.as_ref())
+                .map_err(|err| format!("Parse to SignerOfferView error: {}", err as i8))?;
+            if offer.accepts(xchain_kind, amount, offer.signer_fee_rate) {
+                offers.push((cell.out_point.into(), offer));
+            }
+        }
+        offers.sort_by_key(|(_, offer)| offer.signer_fee_rate);
+        Ok(offers)
+    }
+
+    /// Create the protocol params cell owned by `governance_lockscript`, the cell is identified by
+    /// the type id of output 0. The typescript only accepts the params under the governance lock
+    /// whose hash is `PROTOCOL_PARAMS_LOCK_HASH`.
+    pub fn create_protocol_params(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        governance_lockscript: Script,
+        capacity: u64,
+        params: ProtocolParamsView,
+    ) -> Result<TransactionView, String> {
+        let mut helper = TxHelper::default();
+        let params_data = params.as_molecule_data();
+        check_capacity(capacity, params_data.len())?;
+        let params_output = CellOutput::new_builder()
+            .capacity(Capacity::shannons(capacity).pack())
+            .type_(Some(build_type_id_script(vec![0u8; 32].into())).pack())
+            .lock(governance_lockscript)
+            .build();
+        helper.add_output(params_output, params_data.to_vec().into());
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(fill_type_id(tx, 0))
+    }
+
+    /// Create the empty lock tx registry, the cell is identified by the type id of output 0.
+    ///
+    /// It is locked by the toCKB lockscript with the toCKB typescript as args, so any toCKB cell
+    /// can spend it while the toCKB typescript only accepts the update in mint.
+    pub fn create_lock_registry(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        capacity: u64,
+    ) -> Result<TransactionView, String> {
+        let mut helper = TxHelper::default();
+        let registry_data = LockRegistryView::default().as_molecule_data();
+        check_capacity(capacity, registry_data.len())?;
+        let lockscript_code_hash = hex::decode(&self.settings.lockscript.code_hash)
+            .expect("wrong lockscript code hash config");
+        let mut lockscript_args = hex::decode(&self.settings.typescript.code_hash)
+            .expect("wrong typescript code hash config");
+        lockscript_args.push(ScriptHashType::Data.into());
+        let lockscript = Script::new_builder()
+            .code_hash(Byte32::from_slice(&lockscript_code_hash).unwrap())
+            .hash_type(DepType::Code.into())
+            .args(Bytes::from(lockscript_args).pack())
+            .build();
+        let registry_output = CellOutput::new_builder()
+            .capacity(Capacity::shannons(capacity).pack())
+            .type_(Some(build_type_id_script(vec![0u8; 32].into())).pack())
+            .lock(lockscript)
+            .build();
+        helper.add_output(registry_output, registry_data.to_vec().into());
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(fill_type_id(tx, 0))
+    }
+
+    /// Create the price cell of a publisher owned by `publisher_lockscript`, the cell is identified
+    /// by the type id of output 0.
+    pub fn create_price_oracle(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        publisher_lockscript: Script,
+        capacity: u64,
+        signed_price: Bytes,
+    ) -> Result<TransactionView, String> {
+        let mut helper = TxHelper::default();
+        check_capacity(capacity, signed_price.len())?;
+        let price_output = CellOutput::new_builder()
+            .capacity(Capacity::shannons(capacity).pack())
+            .type_(Some(build_type_id_script(vec![0u8; 32].into())).pack())
+            .lock(publisher_lockscript)
+            .build();
+        helper.add_output(price_output, signed_price);
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(fill_type_id(tx, 0))
+    }
+
+    /// Replace the price of the price cell with `type_args` by a new round, the tx must be signed
+    /// by the lock of the price cell
+    pub fn update_price_oracle(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        type_args: Bytes,
+        signed_price: Bytes,
+    ) -> Result<TransactionView, String> {
+        let mut helper = TxHelper::default();
+        let typescript = build_type_id_script(type_args);
+        let (price_cell, _) = self.get_ckb_cell(&mut helper, typescript, true)?;
+        check_capacity(price_cell.capacity().unpack(), signed_price.len())?;
+        helper.add_output(price_cell, signed_price);
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(tx)
+    }
+
+    /// Replace the protocol params, the tx must be signed by the governance lock of the params cell
+    pub fn update_protocol_params(
+        &mut self,
+        from_lockscript: Script,
+        tx_fee: u64,
+        params: ProtocolParamsView,
+    ) -> Result<TransactionView, String> {
+        let mut helper = TxHelper::default();
+        let typescript = self.get_protocol_params_typescript()?;
+        let (params_cell, _) = self.get_ckb_cell(&mut helper, typescript, true)?;
+        let params_data = params.as_molecule_data();
+        check_capacity(params_cell.capacity().unpack(), params_data.len())?;
+        helper.add_output(params_cell, params_data.to_vec().into());
+        let tx = helper.supply_capacity(
+            &mut self.rpc_client,
+            &mut self.indexer_client,
+            from_lockscript,
+            &self.genesis_info,
+            tx_fee,
+        )?;
+        Ok(tx)
+    }
+
+    pub fn get_sudt_balance(
+        &mut self,
+        address: String,
+        kind: u8,
+        token_address: Option<String>,
+    ) -> Result<u128, String> {
         let addr_lockscript: Script = Address::from_str(&address)?.payload().into();
 
         let lockscript_code_hash = hex::decode(&self.settings.lockscript.code_hash)
@@ -672,6 +1767,8 @@ impl Generator {
 
         let typescript_args = ToCKBTypeArgs::new_builder()
             .xchain_kind(Byte::new(kind))
+            .xchain_network(Byte::new(self.get_xchain_network(kind)?))
+            .header_relay(self.get_header_relay(kind)?)
             .build();
 
         let typescript = Script::new_builder()
@@ -682,17 +1779,125 @@ impl Generator {
         let lockscript = Script::new_builder()
             .code_hash(Byte32::from_slice(&lockscript_code_hash).unwrap())
             .hash_type(DepType::Code.into())
-            .args(typescript.as_slice()[0..54].pack())
+            .args(typescript.as_slice()[0..87].pack())
             .build();
 
         let sudt_typescript_code_hash =
             hex::decode(&self.settings.sudt.code_hash).expect("wrong sudt_script code hash config");
+        let xt_args = get_xt_args(&lockscript, token_address)?;
         let sudt_typescript = Script::new_builder()
             .code_hash(Byte32::from_slice(&sudt_typescript_code_hash).unwrap())
             .hash_type(DepType::Code.into())
-            .args(lockscript.calc_script_hash().as_bytes().pack())
+            .args(Bytes::from(xt_args).pack())
             .build();
 
         collect_sudt_amount(&mut self.indexer_client, addr_lockscript, sudt_typescript)
     }
 }
+
+fn verify_x_address(typescript_args: &ToCKBTypeArgsView, address: &str) -> Result<(), String> {
+    match typescript_args.xchain_kind {
+        XChainKind::Btc | XChainKind::Ltc => {
+            typescript_args
+                .address_to_script(address.as_bytes())
+                .map_err(|_| {
+                    format!(
+                        "invalid {:?} address for network {}: {}",
+                        typescript_args.xchain_kind, typescript_args.xchain_network, address
+                    )
+                })?;
+        }
+        XChainKind::Eth | XChainKind::Erc20 => {}
+    }
+    Ok(())
+}
+
+fn decode_token_address(address: &str) -> Result<Vec<u8>, String> {
+    let token_address = hex::decode(address.trim_start_matches("0x"))
+        .map_err(|err| format!("invalid token address {}: {}", address, err))?;
+    if token_address.len() != 20 {
+        return Err(format!(
+            "token address should be 20 bytes, got {}",
+            token_address.len()
+        ));
+    }
+    Ok(token_address)
+}
+
+/// The sudt args of XT, which is followed by the token address for ERC-20
+fn get_xt_args(
+    tockb_lockscript: &Script,
+    token_address: Option<String>,
+) -> Result<Vec<u8>, String> {
+    let mut xt_args = tockb_lockscript.calc_script_hash().as_bytes().to_vec();
+    if let Some(address) = token_address {
+        xt_args.extend_from_slice(&decode_token_address(&address)?);
+    }
+    Ok(xt_args)
+}
+
+/// The extra of a deposit on a UTXO chain, which is the new lock outpoint of the deposit
+fn get_utxo_x_extra(data_view: &ToCKBCellDataView, extra: BtcExtraView) -> XExtraView {
+    match data_view.get_xchain_kind() {
+        XChainKind::Ltc => XExtraView::Ltc(extra),
+        _ => XExtraView::Btc(extra),
+    }
+}
+
+/// Pay `value` to the signer, or to each member of a signer group by its share
+fn add_signer_outputs(
+    helper: &mut TxHelper,
+    data_view: &ToCKBCellDataView,
+    value: u64,
+) -> Result<(), String> {
+    for (lockscript, payout) in data_view.get_signer_payouts(value) {
+        let lockscript = Script::from_slice(lockscript)
+            .map_err(|e| format!("signer lockscript decode error: {}", e))?;
+        let output = CellOutput::new_builder()
+            .capacity(Capacity::shannons(payout).pack())
+            .lock(lockscript)
+            .build();
+        helper.add_output(output, Bytes::new());
+    }
+    Ok(())
+}
+
+fn parse_tockb_cell(
+    cell: &CellOutput,
+    data: &Bytes,
+) -> Result<(ToCKBTypeArgsView, ToCKBCellDataView), String> {
+    let type_script = cell
+        .type_()
+        .to_opt()
+        .ok_or("typescript of tockb cell is none".to_owned())?;
+    let typescript_args = ToCKBTypeArgsView::from_slice(type_script.args().raw_data().as_ref())
+        .map_err(|err| format!("Parse to ToCKBTypeArgsView error: {}", err as i8))?;
+    let data_view = ToCKBCellDataView::new(data.as_ref(), typescript_args.xchain_kind)
+        .map_err(|err| format!("Parse to ToCKBCellDataView error: {}", err as i8))?;
+    Ok((typescript_args, data_view))
+}
+
+fn build_type_id_script(args: Bytes) -> Script {
+    Script::new_builder()
+        .code_hash(TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(args.pack())
+        .build()
+}
+
+/// Fill the type id args of output `index` with the first input and the output index
+fn fill_type_id(tx: TransactionView, index: usize) -> TransactionView {
+    let first_input = tx.inputs().get(0).expect("should have input");
+    let mut type_id = [0u8; 32];
+    let mut blake2b = new_blake2b();
+    blake2b.update(first_input.as_slice());
+    blake2b.update(&(index as u64).to_le_bytes());
+    blake2b.finalize(&mut type_id);
+    let mut new_outputs = tx.outputs().into_iter().collect::<Vec<_>>();
+    new_outputs[index] = new_outputs[index]
+        .clone()
+        .as_builder()
+        .type_(Some(build_type_id_script(type_id.to_vec().into())).pack())
+        .build();
+    tx.as_advanced_builder().set_outputs(new_outputs).build()
+}
@@ -1,5 +1,6 @@
 use config::{Config, ConfigError, Environment, File};
 use serde_derive::{Deserialize, Serialize};
+use tockb_types::{BtcNetwork, LtcNetwork};
 
 #[derive(Deserialize, Serialize, Default, Debug, Clone)]
 pub struct OutpointConf {
@@ -21,7 +22,9 @@ pub struct ScriptsConf {
 
 #[derive(Deserialize, Serialize, Default, Debug, Clone)]
 pub struct PriceOracle {
-    pub outpoint: OutpointConf,
+    // hex of the type id args of the price cells of whitelisted publishers, each publisher replaces
+    // its cell every round and the median of the live cells is used
+    pub type_args: Vec<String>,
 }
 
 #[derive(Deserialize, Serialize, Default, Debug, Clone)]
@@ -29,6 +32,31 @@ pub struct BtcDifficulty {
     pub outpoint: OutpointConf,
 }
 
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct BtcHeaderRelay {
+    pub script: ScriptConf,
+    // hex of the relay cell typescript args, empty if no relay cell is created
+    pub type_args: String,
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct SignerRegistry {
+    // offers are cells of the registry typescript with empty args
+    pub script: ScriptConf,
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct ProtocolParams {
+    // hex of the type id args of the protocol params cell, empty if no params cell is created
+    pub type_args: String,
+}
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct LockRegistry {
+    // hex of the type id args of the lock tx registry cell, empty if no registry cell is created
+    pub type_args: String,
+}
+
 #[derive(Deserialize, Serialize, Default, Debug, Clone)]
 pub struct Settings {
     pub lockscript: ScriptConf,
@@ -36,6 +64,22 @@ pub struct Settings {
     pub sudt: ScriptConf,
     pub price_oracle: PriceOracle,
     pub btc_difficulty_cell: BtcDifficulty,
+    // one of mainnet, testnet, signet and regtest
+    pub btc_network: String,
+    #[serde(default)]
+    pub btc_header_relay: BtcHeaderRelay,
+    #[serde(default)]
+    pub protocol_params: ProtocolParams,
+    #[serde(default)]
+    pub lock_registry: LockRegistry,
+    // one of mainnet, testnet and regtest
+    #[serde(default)]
+    pub ltc_network: String,
+    // difficulty oracle of LTC, LTC spv proofs are checked against it
+    #[serde(default)]
+    pub ltc_difficulty_cell: BtcDifficulty,
+    #[serde(default)]
+    pub signer_registry: SignerRegistry,
 }
 
 impl Settings {
@@ -46,6 +90,25 @@ impl Settings {
         s.try_into()
     }
 
+    pub fn get_btc_network(&self) -> Result<BtcNetwork, String> {
+        match self.btc_network.as_str() {
+            "mainnet" => Ok(BtcNetwork::Mainnet),
+            "testnet" => Ok(BtcNetwork::Testnet),
+            "signet" => Ok(BtcNetwork::Signet),
+            "regtest" => Ok(BtcNetwork::Regtest),
+            _ => Err(format!("invalid btc network config: {}", self.btc_network)),
+        }
+    }
+
+    pub fn get_ltc_network(&self) -> Result<LtcNetwork, String> {
+        match self.ltc_network.as_str() {
+            "mainnet" => Ok(LtcNetwork::Mainnet),
+            "testnet" => Ok(LtcNetwork::Testnet),
+            "regtest" => Ok(LtcNetwork::Regtest),
+            _ => Err(format!("invalid ltc network config: {}", self.ltc_network)),
+        }
+    }
+
     pub fn write(&self, config_path: &str) -> Result<(), String> {
         let s = toml::to_string(self).map_err(|e| format!("toml serde error: {}", e))?;
         std::fs::write(config_path, &s)
@@ -445,6 +445,54 @@ impl TxHelper {
         Ok(self.transaction.clone())
     }
 
+    /// Add inputs of `lockscript` which pay `capacity` to the tx, the rest goes back to it in a
+    /// change output. Used when several parties fund the outputs, e.g. the members of a signer group.
+    pub fn supply_capacity_of_lockscript(
+        &mut self,
+        rpc_client: &mut HttpRpcClient,
+        indexer_client: &mut IndexerRpcClient,
+        lockscript: Script,
+        genesis_info: &GenesisInfo,
+        capacity: u64,
+    ) -> Result<TransactionView, String> {
+        let mut live_cell_cache: HashMap<(OutPoint, bool), (CellOutput, Bytes)> =
+            Default::default();
+        let mut get_live_cell_fn = |out_point: OutPoint, with_data: bool| {
+            get_live_cell_with_cache(&mut live_cell_cache, rpc_client, out_point, with_data)
+                .map(|(output, _)| output)
+        };
+        // always leave a change cell, so nothing more than `capacity` is spent
+        let need_capacity = capacity + MIN_SECP_CELL_CAPACITY;
+        let cells = get_live_cells_by_lock_and_capacity(
+            indexer_client,
+            lockscript.clone(),
+            need_capacity,
+            None,
+        )?;
+        let collected_capacity = cells.iter().map(|c| c.output.capacity.value()).sum::<u64>();
+        if collected_capacity < need_capacity {
+            return Err(format!(
+                "Capacity(mature) not enough: {:?} => {}",
+                lockscript, collected_capacity,
+            ));
+        }
+        for cell in cells {
+            self.add_input(
+                OutPoint::from(cell.out_point),
+                None,
+                &mut get_live_cell_fn,
+                genesis_info,
+                true,
+            )?;
+        }
+        let change_output = CellOutput::new_builder()
+            .capacity(Capacity::shannons(collected_capacity - capacity).pack())
+            .lock(lockscript)
+            .build();
+        self.add_output(change_output, Bytes::default());
+        Ok(self.transaction.clone())
+    }
+
     pub fn supply_capacity(
         &mut self,
         rpc_client: &mut HttpRpcClient,
@@ -19,6 +19,8 @@ use ckb_types::{
 use secp256k1::SecretKey;
 use std::collections::HashMap;
 use std::collections::HashSet;
+use tiny_keccak::{Hasher, Keccak};
+use tockb_types::generated::price_oracle::SignedPrice;
 
 pub fn clear_0x(s: &str) -> &str {
     if &s[..2] == "0x" || &s[..2] == "0X" {
@@ -222,3 +224,28 @@ pub fn ensure_indexer_sync(
     }
     Ok(())
 }
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    hasher.update(data);
+    let mut output = [0u8; 32];
+    hasher.finalize(&mut output);
+    output
+}
+
+/// Build the data of a price oracle cell, the publisher is the eth style address of privkey
+/// and it signs keccak256 of price and timestamp(in seconds).
+pub fn build_signed_price(privkey: &SecretKey, price: u128, timestamp: u64) -> Result<Bytes> {
+    let mut data = price.to_le_bytes().to_vec();
+    data.extend_from_slice(&timestamp.to_le_bytes());
+    let message = secp256k1::Message::from_slice(&keccak256(&data))?;
+    let (recovery_id, signature) = SECP256K1
+        .sign_recoverable(&message, privkey)
+        .serialize_compact();
+    let pubkey = secp256k1::PublicKey::from_secret_key(&SECP256K1, privkey);
+    data.extend_from_slice(&keccak256(&pubkey.serialize_uncompressed()[1..])[12..]);
+    data.extend_from_slice(&signature);
+    data.push(recovery_id.to_i32() as u8);
+    let signed_price = SignedPrice::from_slice(&data).map_err(|e| anyhow!(e.to_string()))?;
+    Ok(signed_price.as_bytes())
+}
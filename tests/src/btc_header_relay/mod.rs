@@ -0,0 +1,303 @@
+use crate::Loader;
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_tool::ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::*,
+    prelude::*,
+};
+use ckb_tool::{ckb_error::assert_error_eq, ckb_script::ScriptError};
+use primitive_types::U256;
+use tockb_types::{
+    basic,
+    btc_header_relay::BTCHeaderRelayArgs,
+    btc_relay::{bits_to_target, hash256, BtcRelayEntry, BtcRelayView},
+    BtcNetwork, Error,
+};
+
+const MAX_CYCLES: u64 = 100_000_000;
+const REGTEST_BITS: u32 = 0x207f_ffff;
+const START_TIME: u32 = 1_600_000_000;
+
+#[test]
+fn test_create_relay() {
+    let relay = build_checkpoint();
+    let (mut context, tx) = build_create_tx(&relay, true);
+    let tx = context.complete_tx(tx);
+
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_create_relay_with_wrong_cell_id() {
+    let relay = build_checkpoint();
+    let (mut context, tx) = build_create_tx(&relay, false);
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::CellIDInvalid as i8)
+    );
+}
+
+#[test]
+fn test_update_relay() {
+    let relay = build_checkpoint();
+    let headers = mine_headers(&relay.tip().hash, START_TIME, 3);
+    let new_relay = relay
+        .apply_headers(BtcNetwork::Regtest, &headers)
+        .expect("apply headers");
+    assert_eq!(new_relay.height, 3);
+
+    let (mut context, tx) = build_update_tx(&relay, &new_relay, headers);
+    let tx = context.complete_tx(tx);
+
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_update_relay_with_longer_fork() {
+    let relay = build_checkpoint();
+    let headers = mine_headers(&relay.tip().hash, START_TIME, 2);
+    let relay = relay
+        .apply_headers(BtcNetwork::Regtest, &headers)
+        .expect("apply headers");
+
+    let fork_headers = mine_headers(&relay.headers[1].hash, START_TIME + 1, 2);
+    let new_relay = relay
+        .apply_headers(BtcNetwork::Regtest, &fork_headers)
+        .expect("apply fork headers");
+    assert_eq!(new_relay.height, 3);
+    assert_eq!(new_relay.get_confirmations(&relay.tip().hash), None);
+
+    let (mut context, tx) = build_update_tx(&relay, &new_relay, fork_headers);
+    let tx = context.complete_tx(tx);
+
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_wrong_relay_output() {
+    let relay = build_checkpoint();
+    let headers = mine_headers(&relay.tip().hash, START_TIME, 3);
+    let mut new_relay = relay
+        .apply_headers(BtcNetwork::Regtest, &headers)
+        .expect("apply headers");
+    new_relay.height += 1;
+
+    let (mut context, tx) = build_update_tx(&relay, &new_relay, headers);
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::HeaderRelayDataInvalid as i8)
+    );
+}
+
+#[test]
+fn test_chain_work_not_enough() {
+    let relay = build_checkpoint();
+    let headers = mine_headers(&relay.tip().hash, START_TIME, 3);
+    let relay = relay
+        .apply_headers(BtcNetwork::Regtest, &headers)
+        .expect("apply headers");
+
+    let fork_headers = mine_headers(&relay.headers[0].hash, START_TIME + 1, 2);
+    let (mut context, tx) = build_update_tx(&relay, &relay, fork_headers);
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::BtcChainWorkNotEnough as i8)
+    );
+}
+
+#[test]
+fn test_wrong_header_bits() {
+    let relay = build_checkpoint();
+    let headers = mine_header(&relay.tip().hash, START_TIME + 600, REGTEST_BITS - 1);
+
+    let (mut context, tx) = build_update_tx(&relay, &relay, headers.to_vec());
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::BtcHeaderInvalid as i8)
+    );
+}
+
+#[test]
+fn test_unknown_fork_point() {
+    let relay = build_checkpoint();
+    let headers = mine_headers(&[1u8; 32], START_TIME, 1);
+
+    let (mut context, tx) = build_update_tx(&relay, &relay, headers);
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::BtcHeaderForkInvalid as i8)
+    );
+}
+
+fn build_checkpoint() -> BtcRelayView {
+    let genesis = mine_header(&[0u8; 32], START_TIME, REGTEST_BITS);
+    BtcRelayView {
+        height: 0,
+        epoch_start_time: START_TIME,
+        epoch_bits: REGTEST_BITS,
+        headers: vec![BtcRelayEntry {
+            hash: hash256(&genesis),
+            chain_work: U256::from(2u64),
+            time: START_TIME,
+            bits: REGTEST_BITS,
+        }],
+    }
+}
+
+fn mine_header(prev_hash: &[u8; 32], time: u32, bits: u32) -> [u8; 80] {
+    let mut header = [0u8; 80];
+    header[0..4].copy_from_slice(&0x2000_0000u32.to_le_bytes());
+    header[4..36].copy_from_slice(prev_hash);
+    header[68..72].copy_from_slice(&time.to_le_bytes());
+    header[72..76].copy_from_slice(&bits.to_le_bytes());
+    let target = bits_to_target(bits).expect("valid bits");
+    for nonce in 0u32.. {
+        header[76..80].copy_from_slice(&nonce.to_le_bytes());
+        if U256::from_little_endian(&hash256(&header)) <= target {
+            break;
+        }
+    }
+    header
+}
+
+fn mine_headers(prev_hash: &[u8; 32], start_time: u32, count: u32) -> Vec<u8> {
+    let mut prev_hash = *prev_hash;
+    let mut headers = vec![];
+    for i in 1..=count {
+        let header = mine_header(&prev_hash, start_time + i * 600, REGTEST_BITS);
+        prev_hash = hash256(&header);
+        headers.extend_from_slice(&header);
+    }
+    headers
+}
+
+fn load_context_and_out_points() -> (Context, OutPoint, OutPoint) {
+    let mut context = Context::default();
+    let relay_bin: Bytes = Loader::default().load_binary("btc-header-relay");
+    let relay_out_point = context.deploy_cell(relay_bin);
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    (context, relay_out_point, always_success_out_point)
+}
+
+fn build_relay_cell(
+    context: &mut Context,
+    relay_out_point: &OutPoint,
+    always_success_out_point: &OutPoint,
+    cell_id: OutPoint,
+) -> CellOutput {
+    let args = BTCHeaderRelayArgs::new_builder()
+        .network(Byte::new(BtcNetwork::Regtest as u8))
+        .cell_id(basic::OutPoint::from(cell_id))
+        .build();
+    let type_script = context
+        .build_script(relay_out_point, args.as_bytes())
+        .expect("script");
+    let lock_script = context
+        .build_script(always_success_out_point, Bytes::new())
+        .expect("script");
+    CellOutput::new_builder()
+        .capacity(100_000u64.pack())
+        .lock(lock_script)
+        .type_(Some(type_script).pack())
+        .build()
+}
+
+fn build_create_tx(relay: &BtcRelayView, correct_cell_id: bool) -> (Context, TransactionView) {
+    let (mut context, relay_out_point, always_success_out_point) = load_context_and_out_points();
+
+    let lock_script = context
+        .build_script(&always_success_out_point, Bytes::new())
+        .expect("script");
+    let input_cell = CellOutput::new_builder()
+        .capacity(100_000u64.pack())
+        .lock(lock_script)
+        .build();
+    let input_out_point = context.create_cell(input_cell, Bytes::new());
+    let cell_id = if correct_cell_id {
+        input_out_point.clone()
+    } else {
+        OutPoint::new(Default::default(), 1)
+    };
+    let output = build_relay_cell(
+        &mut context,
+        &relay_out_point,
+        &always_success_out_point,
+        cell_id,
+    );
+
+    let tx = TransactionBuilder::default()
+        .input(
+            CellInput::new_builder()
+                .previous_output(input_out_point)
+                .build(),
+        )
+        .output(output)
+        .output_data(relay.as_molecule_data().pack())
+        .cell_dep(CellDep::new_builder().out_point(relay_out_point).build())
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(always_success_out_point)
+                .build(),
+        )
+        .build();
+    (context, tx)
+}
+
+fn build_update_tx(
+    relay: &BtcRelayView,
+    new_relay: &BtcRelayView,
+    raw_headers: Vec<u8>,
+) -> (Context, TransactionView) {
+    let (mut context, relay_out_point, always_success_out_point) = load_context_and_out_points();
+
+    let relay_cell = build_relay_cell(
+        &mut context,
+        &relay_out_point,
+        &always_success_out_point,
+        Default::default(),
+    );
+    let input_out_point = context.create_cell(relay_cell.clone(), relay.as_molecule_data());
+    let witness = WitnessArgs::new_builder()
+        .input_type(Some(Bytes::from(raw_headers)).pack())
+        .build();
+
+    let tx = TransactionBuilder::default()
+        .input(
+            CellInput::new_builder()
+                .previous_output(input_out_point)
+                .build(),
+        )
+        .output(relay_cell)
+        .output_data(new_relay.as_molecule_data().pack())
+        .witness(witness.as_bytes().pack())
+        .cell_dep(CellDep::new_builder().out_point(relay_out_point).build())
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(always_success_out_point)
+                .build(),
+        )
+        .build();
+    (context, tx)
+}
@@ -2,6 +2,8 @@
 #![allow(irrefutable_let_patterns)]
 #![allow(dead_code)]
 
+mod btc_header_relay;
+mod signer_registry;
 mod toCKB_lockscript;
 mod toCKB_typescript;
 
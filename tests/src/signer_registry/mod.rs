@@ -0,0 +1,292 @@
+use crate::Loader;
+use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
+use ckb_tool::ckb_types::{
+    bytes::Bytes,
+    core::{TransactionBuilder, TransactionView},
+    packed::*,
+    prelude::*,
+};
+use ckb_tool::{ckb_error::assert_error_eq, ckb_script::ScriptError};
+use tockb_types::{
+    generated::signer_offer::SignerOffer, signer_offer::SignerOfferView, Error, XChainKind,
+};
+
+const MAX_CYCLES: u64 = 100_000_000;
+const COLLATERAL: u64 = 10_000 * 100_000_000;
+const SIGNER_LOCK_ARGS: u8 = 1;
+const OTHER_LOCK_ARGS: u8 = 2;
+
+#[test]
+fn test_publish_offer() {
+    let (mut context, tx) =
+        build_publish_tx(|_| {}, SIGNER_LOCK_ARGS, SIGNER_LOCK_ARGS, COLLATERAL);
+    let tx = context.complete_tx(tx);
+
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_publish_offer_not_signed_by_signer() {
+    let (mut context, tx) = build_publish_tx(|_| {}, OTHER_LOCK_ARGS, SIGNER_LOCK_ARGS, COLLATERAL);
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::SignerOfferInvalid as i8)
+    );
+}
+
+#[test]
+fn test_publish_offer_not_locked_by_signer() {
+    let (mut context, tx) = build_publish_tx(|_| {}, SIGNER_LOCK_ARGS, OTHER_LOCK_ARGS, COLLATERAL);
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::SignerOfferInvalid as i8)
+    );
+}
+
+#[test]
+fn test_publish_offer_collateral_not_backed() {
+    let (mut context, tx) =
+        build_publish_tx(|_| {}, SIGNER_LOCK_ARGS, SIGNER_LOCK_ARGS, COLLATERAL - 1);
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::SignerOfferInvalid as i8)
+    );
+}
+
+#[test]
+fn test_publish_offer_with_wrong_lot_amount_range() {
+    let (mut context, tx) = build_publish_tx(
+        |offer| offer.min_lot_amount = offer.max_lot_amount + 1,
+        SIGNER_LOCK_ARGS,
+        SIGNER_LOCK_ARGS,
+        COLLATERAL,
+    );
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::SignerOfferInvalid as i8)
+    );
+}
+
+#[test]
+fn test_publish_offer_with_duplicated_chain_kind() {
+    let (mut context, tx) = build_publish_tx(
+        |offer| offer.chain_kinds.push(XChainKind::Btc),
+        SIGNER_LOCK_ARGS,
+        SIGNER_LOCK_ARGS,
+        COLLATERAL,
+    );
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::SignerOfferInvalid as i8)
+    );
+}
+
+#[test]
+fn test_publish_offer_with_unknown_chain_kind() {
+    let (mut context, tx) =
+        build_publish_tx(|_| {}, SIGNER_LOCK_ARGS, SIGNER_LOCK_ARGS, COLLATERAL);
+    let offer_data = SignerOffer::from_slice(tx.outputs_data().get(0).unwrap().raw_data().as_ref())
+        .unwrap()
+        .as_builder()
+        .chain_kinds(vec![0xffu8].into())
+        .build()
+        .as_bytes();
+    let tx = tx
+        .as_advanced_builder()
+        .set_outputs_data(vec![offer_data.pack()])
+        .build();
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::SignerOfferInvalid as i8)
+    );
+}
+
+#[test]
+fn test_update_offer() {
+    let (mut context, registry_out_point, always_success_out_point) = load_context_and_out_points();
+    let offer = build_offer(&mut context, &always_success_out_point);
+    let offer_cell = build_offer_cell(
+        &mut context,
+        &registry_out_point,
+        &always_success_out_point,
+        SIGNER_LOCK_ARGS,
+        COLLATERAL,
+    );
+    let input_out_point = context.create_cell(offer_cell.clone(), offer_data(&offer));
+    let mut new_offer = offer.clone();
+    new_offer.signer_fee_rate = 100;
+
+    let tx = TransactionBuilder::default()
+        .input(
+            CellInput::new_builder()
+                .previous_output(input_out_point)
+                .build(),
+        )
+        .output(offer_cell)
+        .output_data(offer_data(&new_offer).pack())
+        .cell_dep(CellDep::new_builder().out_point(registry_out_point).build())
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(always_success_out_point)
+                .build(),
+        )
+        .build();
+    let tx = context.complete_tx(tx);
+
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_withdraw_offer() {
+    let (mut context, registry_out_point, always_success_out_point) = load_context_and_out_points();
+    let offer = build_offer(&mut context, &always_success_out_point);
+    let offer_cell = build_offer_cell(
+        &mut context,
+        &registry_out_point,
+        &always_success_out_point,
+        SIGNER_LOCK_ARGS,
+        COLLATERAL,
+    );
+    let input_out_point = context.create_cell(offer_cell.clone(), offer_data(&offer));
+    let output = offer_cell.as_builder().type_(None::<Script>.pack()).build();
+
+    let tx = TransactionBuilder::default()
+        .input(
+            CellInput::new_builder()
+                .previous_output(input_out_point)
+                .build(),
+        )
+        .output(output)
+        .output_data(Bytes::new().pack())
+        .cell_dep(CellDep::new_builder().out_point(registry_out_point).build())
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(always_success_out_point)
+                .build(),
+        )
+        .build();
+    let tx = context.complete_tx(tx);
+
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+fn load_context_and_out_points() -> (Context, OutPoint, OutPoint) {
+    let mut context = Context::default();
+    let registry_bin: Bytes = Loader::default().load_binary("signer-registry");
+    let registry_out_point = context.deploy_cell(registry_bin);
+    let always_success_out_point = context.deploy_cell(ALWAYS_SUCCESS.clone());
+    (context, registry_out_point, always_success_out_point)
+}
+
+fn build_lock_script(
+    context: &mut Context,
+    always_success_out_point: &OutPoint,
+    lock_args: u8,
+) -> Script {
+    context
+        .build_script(always_success_out_point, vec![lock_args].into())
+        .expect("script")
+}
+
+fn build_offer(context: &mut Context, always_success_out_point: &OutPoint) -> SignerOfferView {
+    let signer_lockscript = build_lock_script(context, always_success_out_point, SIGNER_LOCK_ARGS);
+    SignerOfferView {
+        chain_kinds: vec![XChainKind::Btc, XChainKind::Ltc],
+        min_lot_amount: 1_000_000,
+        max_lot_amount: 100_000_000,
+        signer_fee_rate: 200,
+        x_lock_address: Bytes::from("bcrt1q5gh8f3fumk5ws8qzgkmj8hzq8zv3yf7e9dcm5u"),
+        collateral: COLLATERAL,
+        signer_lockscript: signer_lockscript.as_bytes(),
+    }
+}
+
+fn offer_data(offer: &SignerOfferView) -> Bytes {
+    offer.as_molecule_data().expect("offer data")
+}
+
+fn build_offer_cell(
+    context: &mut Context,
+    registry_out_point: &OutPoint,
+    always_success_out_point: &OutPoint,
+    lock_args: u8,
+    capacity: u64,
+) -> CellOutput {
+    let type_script = context
+        .build_script(registry_out_point, Bytes::new())
+        .expect("script");
+    let lock_script = build_lock_script(context, always_success_out_point, lock_args);
+    CellOutput::new_builder()
+        .capacity(capacity.pack())
+        .lock(lock_script)
+        .type_(Some(type_script).pack())
+        .build()
+}
+
+/// Publish the default offer modified by `modify`, with an input of `input_lock_args` and the
+/// offer cell locked by `output_lock_args`
+fn build_publish_tx<F: FnOnce(&mut SignerOfferView)>(
+    modify: F,
+    input_lock_args: u8,
+    output_lock_args: u8,
+    capacity: u64,
+) -> (Context, TransactionView) {
+    let (mut context, registry_out_point, always_success_out_point) = load_context_and_out_points();
+    let mut offer = build_offer(&mut context, &always_success_out_point);
+    modify(&mut offer);
+
+    let input_lock = build_lock_script(&mut context, &always_success_out_point, input_lock_args);
+    let input_cell = CellOutput::new_builder()
+        .capacity((COLLATERAL * 2).pack())
+        .lock(input_lock)
+        .build();
+    let input_out_point = context.create_cell(input_cell, Bytes::new());
+    let output = build_offer_cell(
+        &mut context,
+        &registry_out_point,
+        &always_success_out_point,
+        output_lock_args,
+        capacity,
+    );
+
+    let tx = TransactionBuilder::default()
+        .input(
+            CellInput::new_builder()
+                .previous_output(input_out_point)
+                .build(),
+        )
+        .output(output)
+        .output_data(offer_data(&offer).pack())
+        .cell_dep(CellDep::new_builder().out_point(registry_out_point).build())
+        .cell_dep(
+            CellDep::new_builder()
+                .out_point(always_success_out_point)
+                .build(),
+        )
+        .build();
+    (context, tx)
+}
@@ -10,13 +10,16 @@ use ckb_tool::ckb_types::{
 };
 use ckb_tool::{ckb_error::assert_error_eq, ckb_script::ScriptError};
 use tockb_types::basic;
-use tockb_types::generated::tockb_cell_data::ToCKBTypeArgs;
+use tockb_types::generated::tockb_cell_data::{LegacyToCKBTypeArgs, ToCKBTypeArgs};
 
 const MAX_CYCLES: u64 = 10_000_000;
 
-// first 54 bytes of toCKB typescript molecule bytes:
-// total_size(4 byte) + offset(4 byte) * 3 + code_hash(32 byte) + hash_type(1 byte) + args_size(4 byte) + xchain_kind(1 byte) = 54 byte
-const TOCKB_LOCKSCRIPT_ARGS_LENGTH: usize = 54;
+// first 87 bytes of toCKB typescript molecule bytes:
+// total_size(4 byte) + offset(4 byte) * 3 + code_hash(32 byte) + hash_type(1 byte) + args_size(4 byte) + xchain_kind(1 byte) + xchain_network(1 byte) + header_relay(32 byte) = 87 byte
+const TOCKB_LOCKSCRIPT_ARGS_LENGTH: usize = 87;
+// cells created with the legacy typescript args are locked by its first 54 bytes, which end at
+// xchain_kind(1 byte)
+const LEGACY_TOCKB_LOCKSCRIPT_ARGS_LENGTH: usize = 54;
 
 #[repr(i8)]
 pub enum Error {
@@ -92,6 +95,77 @@ fn test_same_args() {
         .expect("pass verification");
 }
 
+#[test]
+fn test_registry_with_toCKB_cell() {
+    let toCKB_cell = build_cell(
+        Byte::new(1),
+        Default::default(),
+        Byte::new(1),
+        Default::default(),
+    );
+    let registry_cell = build_registry_cell(&toCKB_cell);
+    let (mut context, tx) = build_test_context(
+        vec![&toCKB_cell, &registry_cell],
+        vec![&toCKB_cell, &registry_cell],
+    );
+    let tx = context.complete_tx(tx);
+
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_registry_without_toCKB_cell() {
+    let toCKB_cell = build_cell(
+        Byte::new(1),
+        Default::default(),
+        Byte::new(1),
+        Default::default(),
+    );
+    let registry_cell = build_registry_cell(&toCKB_cell);
+    let (mut context, tx) = build_test_context(vec![&registry_cell], vec![&registry_cell]);
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::InvalidToCKBCell as i8)
+    );
+}
+
+#[test]
+fn test_legacy_cell() {
+    let type_script_args = LegacyToCKBTypeArgs::new_builder()
+        .xchain_kind(Byte::new(1))
+        .build()
+        .as_bytes();
+    let legacy_cell = build_legacy_cell(Byte::new(1), type_script_args);
+    let (mut context, tx) = build_test_context(vec![&legacy_cell], vec![&legacy_cell]);
+    let tx = context.complete_tx(tx);
+
+    context
+        .verify_tx(&tx, MAX_CYCLES)
+        .expect("pass verification");
+}
+
+#[test]
+fn test_legacy_lock_with_new_typescript() {
+    let type_script_args = ToCKBTypeArgs::new_builder()
+        .xchain_kind(Byte::new(1))
+        .build()
+        .as_bytes();
+    let invalid_cell = build_legacy_cell(Byte::new(1), type_script_args);
+    let (mut context, tx) = build_test_context(vec![&invalid_cell], vec![&invalid_cell]);
+    let tx = context.complete_tx(tx);
+
+    let err = context.verify_tx(&tx, MAX_CYCLES).unwrap_err();
+    assert_error_eq!(
+        err,
+        ScriptError::ValidationFailure(Error::InvalidToCKBCell as i8)
+    );
+}
+
 fn load_context_and_out_points() -> (Context, OutPoint, OutPoint) {
     // deploy contract
     let mut context = Context::default();
@@ -150,6 +224,34 @@ fn build_cell(
         .build()
 }
 
+/// A cell locked by the legacy typescript args of `expect_xchain_kind`, with `type_script_args`
+fn build_legacy_cell(expect_xchain_kind: Byte, type_script_args: Bytes) -> CellOutput {
+    let (mut context, toCKB_lockscript_out_point, always_success_out_point) =
+        load_context_and_out_points();
+
+    let args = LegacyToCKBTypeArgs::new_builder()
+        .xchain_kind(expect_xchain_kind)
+        .build();
+    let mock_toCKB_typescript = context
+        .build_script(&always_success_out_point, args.as_bytes())
+        .expect("script");
+    let lock_script_args = mock_toCKB_typescript.as_bytes()[0..LEGACY_TOCKB_LOCKSCRIPT_ARGS_LENGTH]
+        .to_vec()
+        .into();
+    let lock_script = context
+        .build_script(&toCKB_lockscript_out_point, lock_script_args)
+        .expect("script");
+    let type_script = context
+        .build_script(&always_success_out_point, type_script_args)
+        .expect("script");
+
+    CellOutput::new_builder()
+        .capacity(11000u64.pack())
+        .lock(lock_script)
+        .type_(Some(type_script).pack())
+        .build()
+}
+
 fn build_test_context(
     input_cells: Vec<&CellOutput>,
     output_cells: Vec<&CellOutput>,
@@ -196,3 +298,20 @@ fn build_test_context(
 
     (context, tx)
 }
+
+/// The lock tx registry, locked by the toCKB lockscript with the typescript code hash and hash
+/// type of `toCKB_cell` as args
+fn build_registry_cell(toCKB_cell: &CellOutput) -> CellOutput {
+    let toCKB_typescript = toCKB_cell.type_().to_opt().expect("toCKB typescript");
+    let mut lock_script_args = toCKB_typescript.code_hash().as_slice().to_vec();
+    lock_script_args.extend_from_slice(toCKB_typescript.hash_type().as_slice());
+    let lock_script = toCKB_cell
+        .lock()
+        .as_builder()
+        .args(Bytes::from(lock_script_args).pack())
+        .build();
+    CellOutput::new_builder()
+        .capacity(11000u64.pack())
+        .lock(lock_script)
+        .build()
+}
@@ -1,6 +1,6 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
-    config::{CKB_UNITS, SINCE_AT_TERM_REDEEM},
+    config::{CKB_UNITS, PLEDGE, SIGNER_FEE_RATE, SINCE_AT_TERM_REDEEM},
     Error,
 };
 
@@ -49,12 +49,20 @@ fn test_wrong_input_since() {
 fn get_correct_btc_case() -> TestCase {
     TestCase {
         cell_deps: vec![],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: 11000 * CKB_UNITS,
                 data: ToCKBCellDataView {
                     status: 3,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -65,6 +73,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: SINCE_AT_TERM_REDEEM,
@@ -74,7 +85,13 @@ fn get_correct_btc_case() -> TestCase {
                 capacity: 11000 * CKB_UNITS,
                 data: ToCKBCellDataView {
                     status: 4,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -85,6 +102,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -97,12 +117,14 @@ fn get_correct_btc_case() -> TestCase {
                 amount: BTC_BURN_AMOUNT,
                 lockscript: Default::default(),
                 owner_script: Default::default(),
+                token_address: None,
                 index: 1,
             }],
             outputs: vec![],
         },
         capacity_cells: Default::default(),
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
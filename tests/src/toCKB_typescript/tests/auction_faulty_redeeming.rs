@@ -1,6 +1,9 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
-    config::{AUCTION_MAX_TIME, CKB_UNITS, LOCK_TYPE_FLAG, SINCE_TYPE_TIMESTAMP, XT_CELL_CAPACITY},
+    config::{
+        AUCTION_MAX_TIME, CKB_UNITS, LOCK_TYPE_FLAG, PLEDGE, SIGNER_FEE_RATE, SINCE_TYPE_TIMESTAMP,
+        XT_CELL_CAPACITY,
+    },
     Error,
 };
 
@@ -24,6 +27,7 @@ fn test_correct_btc_tx_with_xt_change_back() {
         amount: CHANGE,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 2,
     });
     case_runner::run_test(case)
@@ -39,6 +43,7 @@ fn test_wrong_btc_tx_with_xt_change_back() {
         amount: CHANGE + 1,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 2,
     });
     case.expect_return_code = Error::XTAmountInvalid as i8;
@@ -85,15 +90,35 @@ fn test_wrong_xt_refund_capacity() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_fee_payer_input_before_xt() {
+    let mut case = get_correct_btc_case();
+    case.capacity_cells.inputs.push(CapacityCell {
+        capacity: CKB_UNITS,
+        lockscript: fee_payer_lockscript(),
+        index: 1,
+    });
+    case.sudt_cells.inputs[0].index = 2;
+    case_runner::run_test(case)
+}
+
 fn get_correct_btc_case() -> TestCase {
     TestCase {
         cell_deps: vec![],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: TOCKB_CELL_CAPACITY + XT_CELL_CAPACITY,
                 data: ToCKBCellDataView {
                     status: 8,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -104,6 +129,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: SINCE,
@@ -117,6 +145,7 @@ fn get_correct_btc_case() -> TestCase {
                 amount: BTC_BURN_AMOUNT,
                 lockscript: Default::default(),
                 owner_script: Default::default(),
+                token_address: None,
                 index: 1,
             }],
             outputs: vec![SudtCell {
@@ -124,6 +153,7 @@ fn get_correct_btc_case() -> TestCase {
                 amount: BTC_BURN_AMOUNT,
                 lockscript: Default::default(),
                 owner_script: Default::default(),
+                token_address: None,
                 index: 1,
             }],
         },
@@ -136,6 +166,15 @@ fn get_correct_btc_case() -> TestCase {
             }],
         },
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
+
+/// Lock of a cell paying the tx fee, it is not the bidder even if placed before the XT of the bidder
+fn fee_payer_lockscript() -> ScriptView {
+    ScriptView {
+        args: vec![1u8; 20].into(),
+        ..Default::default()
+    }
+}
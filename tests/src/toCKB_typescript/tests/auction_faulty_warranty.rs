@@ -1,15 +1,16 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
     config::{
-        AUCTION_MAX_TIME, CKB_UNITS, LOCK_TYPE_FLAG, SIGNER_FEE_RATE, SINCE_TYPE_TIMESTAMP,
-        XT_CELL_CAPACITY,
+        AUCTION_MAX_TIME, CKB_UNITS, FAULTY_COMPENSATION_PERCENT, LOCK_TYPE_FLAG, PLEDGE,
+        SIGNER_FEE_RATE, SIGNER_FEE_RATE_DENOMINATOR, SINCE_TYPE_TIMESTAMP, XT_CELL_CAPACITY,
     },
     Error,
 };
 
 const BTC_BURN: u128 = 25_000_000;
-const SIGNER_FEE: u128 = BTC_BURN * SIGNER_FEE_RATE.0 / SIGNER_FEE_RATE.1;
+const SIGNER_FEE: u128 = BTC_BURN * SIGNER_FEE_RATE as u128 / SIGNER_FEE_RATE_DENOMINATOR;
 const TOCKB_CAPACITY: u64 = 3_750_000 * CKB_UNITS;
+const COMPENSATION: u64 = TOCKB_CAPACITY / 100 * FAULTY_COMPENSATION_PERCENT as u64;
 const SINCE: u64 = LOCK_TYPE_FLAG | SINCE_TYPE_TIMESTAMP | AUCTION_MAX_TIME;
 
 #[test]
@@ -28,6 +29,7 @@ fn test_correct_btc_tx_with_xt_change_back() {
         amount: CHANGE,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 2,
     });
     case_runner::run_test(case)
@@ -43,6 +45,7 @@ fn test_wrong_btc_tx_with_xt_change_back() {
         amount: CHANGE + 1,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 2,
     });
     case.expect_return_code = Error::XTAmountInvalid as i8;
@@ -65,6 +68,65 @@ fn test_wrong_auction_capacity() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_without_compensation() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![CellDepView::ProtocolParams(ProtocolParamsView {
+        faulty_compensation_percent: 0,
+        ..Default::default()
+    })];
+    case.capacity_cells.outputs = vec![CapacityCell {
+        capacity: TOCKB_CAPACITY + 1,
+        lockscript: Default::default(),
+        index: 0,
+    }];
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_compensation_capacity() {
+    let mut case = get_correct_btc_case();
+    case.capacity_cells.outputs[1].capacity = COMPENSATION - 1;
+    case.expect_return_code = Error::InvalidCompensationCell as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_compensation_lockscript() {
+    let mut case = get_correct_btc_case();
+    case.capacity_cells.outputs[1].lockscript = ScriptView {
+        outpoint_key: ALWAYS_SUCCESS_OUTPOINT_KEY,
+        args: Bytes::from("not the depositor"),
+    };
+    case.expect_return_code = Error::InvalidCompensationCell as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_compensation_by_bidder() {
+    let mut case = get_correct_btc_case();
+    // the bidder can not take the compensation as the whole collateral
+    case.capacity_cells.outputs = vec![CapacityCell {
+        capacity: TOCKB_CAPACITY,
+        lockscript: Default::default(),
+        index: 0,
+    }];
+    case.expect_return_code = Error::InvalidCompensationCell as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_protocol_params_compensation() {
+    let mut case = get_correct_btc_case();
+    // the rest of the collateral can not cover the lot at the liquidation rate
+    case.cell_deps = vec![CellDepView::ProtocolParams(ProtocolParamsView {
+        faulty_compensation_percent: 20,
+        ..Default::default()
+    })];
+    case.expect_return_code = Error::ProtocolParamsInvalid as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_xt_burn() {
     let mut case = get_correct_btc_case();
@@ -89,15 +151,35 @@ fn test_wrong_xt_refund_capacity() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_fee_payer_input_before_xt() {
+    let mut case = get_correct_btc_case();
+    case.capacity_cells.inputs.push(CapacityCell {
+        capacity: CKB_UNITS,
+        lockscript: fee_payer_lockscript(),
+        index: 1,
+    });
+    case.sudt_cells.inputs[0].index = 2;
+    case_runner::run_test(case)
+}
+
 fn get_correct_btc_case() -> TestCase {
     TestCase {
         cell_deps: vec![],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: TOCKB_CAPACITY + XT_CELL_CAPACITY,
                 data: ToCKBCellDataView {
                     status: 7,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -108,6 +190,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: SINCE,
@@ -121,6 +206,7 @@ fn get_correct_btc_case() -> TestCase {
                 amount: BTC_BURN + SIGNER_FEE,
                 lockscript: Default::default(),
                 owner_script: Default::default(),
+                token_address: None,
                 index: 1,
             }],
             outputs: vec![SudtCell {
@@ -128,18 +214,35 @@ fn get_correct_btc_case() -> TestCase {
                 amount: SIGNER_FEE,
                 lockscript: Default::default(),
                 owner_script: Default::default(),
+                token_address: None,
                 index: 1,
             }],
         },
         capacity_cells: CapacityCells {
             inputs: vec![],
-            outputs: vec![CapacityCell {
-                capacity: TOCKB_CAPACITY + 1,
-                lockscript: Default::default(),
-                index: 0,
-            }],
+            outputs: vec![
+                CapacityCell {
+                    capacity: TOCKB_CAPACITY - COMPENSATION + 1,
+                    lockscript: Default::default(),
+                    index: 0,
+                },
+                CapacityCell {
+                    capacity: COMPENSATION,
+                    lockscript: Default::default(),
+                    index: 2,
+                },
+            ],
         },
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
+
+/// Lock of a cell paying the tx fee, it is not the bidder even if placed before the XT of the bidder
+fn fee_payer_lockscript() -> ScriptView {
+    ScriptView {
+        args: vec![1u8; 20].into(),
+        ..Default::default()
+    }
+}
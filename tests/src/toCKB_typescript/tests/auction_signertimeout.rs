@@ -1,6 +1,10 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
-    config::{AUCTION_MAX_TIME, CKB_UNITS, LOCK_TYPE_FLAG, SINCE_TYPE_TIMESTAMP, XT_CELL_CAPACITY},
+    config::{
+        AUCTION_MAX_TIME, BLOCK_INTERVAL_SECONDS, CKB_UNITS, EPOCH_DURATION_SECONDS,
+        LOCK_TYPE_FLAG, PLEDGE, SIGNER_FEE_RATE, SINCE_TYPE_BLOCK_NUMBER, SINCE_TYPE_EPOCH_NUMBER,
+        SINCE_TYPE_TIMESTAMP, VALUE_MASK, XT_CELL_CAPACITY,
+    },
     Error,
 };
 
@@ -24,6 +28,7 @@ fn test_correct_btc_tx_with_xt_change_back() {
         amount: CHANGE,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 2,
     });
     case_runner::run_test(case)
@@ -39,12 +44,30 @@ fn test_wrong_btc_tx_with_xt_change_back() {
         amount: CHANGE + 1,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 2,
     });
     case.expect_return_code = Error::XTAmountInvalid as i8;
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_btc_partial_redeem() {
+    let mut case = get_correct_btc_case();
+    const REDEEM_AMOUNT: u128 = 10_000_000;
+    case.toCKB_cells.inputs[0].data.redeem_amount = REDEEM_AMOUNT;
+    case.sudt_cells.outputs[0].amount = REDEEM_AMOUNT;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_btc_partial_redeem_refund() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.redeem_amount = 10_000_000;
+    case.expect_return_code = Error::InvalidAuctionXTCell as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_input_since() {
     let mut case = get_correct_btc_case();
@@ -61,6 +84,67 @@ fn test_wrong_auction_capacity() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_block_number_since() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].since =
+        LOCK_TYPE_FLAG | SINCE_TYPE_BLOCK_NUMBER | AUCTION_MAX_TIME / BLOCK_INTERVAL_SECONDS;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_auction_capacity_by_block_number() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].since = LOCK_TYPE_FLAG | SINCE_TYPE_BLOCK_NUMBER | 100;
+    case.expect_return_code = Error::InvalidTriggerOrSignerCell as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_epoch_since() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].since =
+        LOCK_TYPE_FLAG | SINCE_TYPE_EPOCH_NUMBER | AUCTION_MAX_TIME / EPOCH_DURATION_SECONDS;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_max_block_number_since() {
+    // the largest block number still counts in seconds, the auction is long over
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].since = LOCK_TYPE_FLAG | SINCE_TYPE_BLOCK_NUMBER | VALUE_MASK;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_max_epoch_since() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].since =
+        LOCK_TYPE_FLAG | SINCE_TYPE_EPOCH_NUMBER | (0xffff << 40) | (0xfffe << 24) | 0xff_ffff;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_auction_capacity_by_epoch_fraction() {
+    let mut case = get_correct_btc_case();
+    // half an epoch short of the max auction time
+    let epochs = AUCTION_MAX_TIME / EPOCH_DURATION_SECONDS - 1;
+    case.toCKB_cells.inputs[0].since =
+        LOCK_TYPE_FLAG | SINCE_TYPE_EPOCH_NUMBER | (2 << 40) | (1 << 24) | epochs;
+    case.expect_return_code = Error::InvalidTriggerOrSignerCell as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_epoch_since_index() {
+    let mut case = get_correct_btc_case();
+    let epochs = AUCTION_MAX_TIME / EPOCH_DURATION_SECONDS;
+    case.toCKB_cells.inputs[0].since =
+        LOCK_TYPE_FLAG | SINCE_TYPE_EPOCH_NUMBER | (2 << 40) | (2 << 24) | epochs;
+    case.expect_return_code = Error::InputSinceInvalid as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_xt_burn() {
     let mut case = get_correct_btc_case();
@@ -86,7 +170,15 @@ fn test_wrong_xt_refund_capacity() {
 }
 
 #[test]
-fn test_wrong_trigger_refund() {
+fn test_correct_outputs_in_any_order() {
+    let mut case = get_correct_btc_case();
+    case.sudt_cells.outputs[0].index = 0;
+    case.capacity_cells.outputs[0].index = 1;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_with_other_outputs() {
     let mut case = get_correct_btc_case();
     case.capacity_cells.outputs.push(CapacityCell {
         capacity: 100,
@@ -94,32 +186,60 @@ fn test_wrong_trigger_refund() {
         index: 1,
     });
     case.sudt_cells.outputs[0].index = 2;
-    case.expect_return_code = Error::InvalidAuctionXTCell as i8;
     case_runner::run_test(case)
 }
 
 #[test]
-fn test_wrong_signer_refund() {
+fn test_wrong_bidder_repayment() {
     let mut case = get_correct_btc_case();
-    case.capacity_cells.outputs.push(CapacityCell {
-        capacity: 100,
-        lockscript: Default::default(),
+    case.capacity_cells.outputs[0].capacity = TOCKB_CELL_CAPACITY;
+    case.expect_return_code = Error::InvalidAuctionBidderCell as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_fee_payer_input_before_xt() {
+    let mut case = get_correct_btc_case();
+    case.capacity_cells.inputs.push(CapacityCell {
+        capacity: CKB_UNITS,
+        lockscript: fee_payer_lockscript(),
         index: 1,
     });
-    case.sudt_cells.outputs[0].index = 2;
-    case.expect_return_code = Error::InvalidAuctionXTCell as i8;
+    case.sudt_cells.inputs[0].index = 2;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_bidder_repayment_to_fee_payer() {
+    let mut case = get_correct_btc_case();
+    case.capacity_cells.inputs.push(CapacityCell {
+        capacity: CKB_UNITS,
+        lockscript: fee_payer_lockscript(),
+        index: 1,
+    });
+    case.sudt_cells.inputs[0].index = 2;
+    case.capacity_cells.outputs[0].lockscript = fee_payer_lockscript();
+    case.expect_return_code = Error::InvalidAuctionBidderCell as i8;
     case_runner::run_test(case)
 }
 
 fn get_correct_btc_case() -> TestCase {
     TestCase {
         cell_deps: vec![],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: TOCKB_CELL_CAPACITY + XT_CELL_CAPACITY,
                 data: ToCKBCellDataView {
                     status: 5,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -130,6 +250,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: SINCE,
@@ -143,6 +266,7 @@ fn get_correct_btc_case() -> TestCase {
                 amount: BTC_BURN_AMOUNT,
                 lockscript: Default::default(),
                 owner_script: Default::default(),
+                token_address: None,
                 index: 1,
             }],
             outputs: vec![SudtCell {
@@ -150,6 +274,7 @@ fn get_correct_btc_case() -> TestCase {
                 amount: BTC_BURN_AMOUNT,
                 lockscript: Default::default(),
                 owner_script: Default::default(),
+                token_address: None,
                 index: 1,
             }],
         },
@@ -162,6 +287,15 @@ fn get_correct_btc_case() -> TestCase {
             }],
         },
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
+
+/// Lock of a cell paying the tx fee, it is not the bidder even if placed before the XT of the bidder
+fn fee_payer_lockscript() -> ScriptView {
+    ScriptView {
+        args: vec![1u8; 20].into(),
+        ..Default::default()
+    }
+}
@@ -1,6 +1,9 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
-    config::{AUCTION_MAX_TIME, CKB_UNITS, LOCK_TYPE_FLAG, SINCE_TYPE_TIMESTAMP, XT_CELL_CAPACITY},
+    config::{
+        AUCTION_MAX_TIME, CKB_UNITS, LOCK_TYPE_FLAG, PLEDGE, SIGNER_FEE_RATE, SINCE_TYPE_TIMESTAMP,
+        XT_CELL_CAPACITY,
+    },
     Error,
 };
 
@@ -24,6 +27,7 @@ fn test_correct_btc_tx_with_xt_change_back() {
         amount: CHANGE,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 2,
     });
     case_runner::run_test(case)
@@ -39,6 +43,7 @@ fn test_wrong_btc_tx_with_xt_change_back() {
         amount: CHANGE + 1,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 2,
     });
     case.expect_return_code = Error::XTAmountInvalid as i8;
@@ -61,6 +66,52 @@ fn test_wrong_auction_capacity() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_linear_curve() {
+    // half of the discount to the collateral is left at half of the auction time
+    let case = get_auction_case_at(
+        AUCTION_MAX_TIME / 2,
+        Default::default(),
+        3_131_250 * CKB_UNITS,
+    );
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_exponential_decay_curve() {
+    // the discount is halved in a day, and halfway to halved again in another half day
+    let params = ProtocolParamsView {
+        auction_curve: 1,
+        auction_curve_param: 24 * 3600,
+        ..Default::default()
+    };
+    let case = get_auction_case_at(36 * 3600, params, 3_285_937 * CKB_UNITS + CKB_UNITS / 2);
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_stepwise_curve() {
+    // the price moves once a day, so the price at 36 hours is the price at 24 hours
+    let params = ProtocolParamsView {
+        auction_curve: 2,
+        auction_curve_param: 24 * 3600,
+        ..Default::default()
+    };
+    let case = get_auction_case_at(36 * 3600, params, 2_925_000 * CKB_UNITS);
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_auction_curve() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![CellDepView::ProtocolParams(ProtocolParamsView {
+        auction_curve: 3,
+        ..Default::default()
+    })];
+    case.expect_return_code = Error::ProtocolParamsInvalid as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_xt_burn() {
     let mut case = get_correct_btc_case();
@@ -69,6 +120,14 @@ fn test_wrong_xt_burn() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_outputs_in_any_order() {
+    let mut case = get_correct_btc_case();
+    case.capacity_cells.outputs[0].index = 1;
+    case.capacity_cells.outputs[1].index = 0;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_trigger_refund() {
     let mut case = get_correct_btc_case();
@@ -93,15 +152,110 @@ fn test_wrong_signer_refund() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_signer_group_refund() {
+    let case = get_correct_signer_group_case();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_group_refund() {
+    let mut case = get_correct_signer_group_case();
+    // the whole refund paid to the first member
+    case.capacity_cells.outputs.pop();
+    case.capacity_cells.outputs[1].capacity = XT_CELL_CAPACITY;
+    case.expect_return_code = Error::InvalidTriggerOrSignerCell as i8;
+    case_runner::run_test(case)
+}
+
+/// the auction at `auction_time` pays `to_bidder` to the bidder, the rest of the collateral is
+/// split by the trigger and the signer
+#[test]
+fn test_correct_fee_payer_input_before_xt() {
+    let mut case = get_correct_btc_case();
+    case.capacity_cells.inputs.push(CapacityCell {
+        capacity: CKB_UNITS,
+        lockscript: fee_payer_lockscript(),
+        index: 1,
+    });
+    case.sudt_cells.inputs[0].index = 2;
+    case_runner::run_test(case)
+}
+
+fn get_auction_case_at(auction_time: u64, params: ProtocolParamsView, to_bidder: u64) -> TestCase {
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![CellDepView::ProtocolParams(params)];
+    case.toCKB_cells.inputs[0].since = LOCK_TYPE_FLAG | SINCE_TYPE_TIMESTAMP | auction_time;
+    let to_trigger = (TOCKB_CAPACITY - to_bidder) / 2;
+    let to_signer = TOCKB_CAPACITY - to_bidder - to_trigger + XT_CELL_CAPACITY;
+    case.capacity_cells.outputs = vec![
+        CapacityCell {
+            capacity: to_bidder + 1,
+            lockscript: Default::default(),
+            index: 0,
+        },
+        CapacityCell {
+            capacity: to_trigger,
+            lockscript: Default::default(),
+            index: 1,
+        },
+        CapacityCell {
+            capacity: to_signer,
+            lockscript: Default::default(),
+            index: 2,
+        },
+    ];
+    case
+}
+
+/// the refund to signers is split 1:3 among a signer group
+fn get_correct_signer_group_case() -> TestCase {
+    let mut case = get_correct_btc_case();
+    let second_signer = || ScriptView {
+        args: vec![1].into(),
+        ..Default::default()
+    };
+    case.toCKB_cells.inputs[0].data.signer_group = SignerGroupView {
+        threshold: 1,
+        members: vec![
+            SignerMemberView {
+                lockscript: Default::default(),
+                share: 1,
+                x_pubkey: Default::default(),
+            },
+            SignerMemberView {
+                lockscript: second_signer(),
+                share: 3,
+                x_pubkey: Default::default(),
+            },
+        ],
+    };
+    case.capacity_cells.outputs[1].capacity = XT_CELL_CAPACITY / 4;
+    case.capacity_cells.outputs.push(CapacityCell {
+        capacity: XT_CELL_CAPACITY * 3 / 4,
+        lockscript: second_signer(),
+        index: 2,
+    });
+    case
+}
+
 fn get_correct_btc_case() -> TestCase {
     TestCase {
         cell_deps: vec![],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: TOCKB_CAPACITY + XT_CELL_CAPACITY,
                 data: ToCKBCellDataView {
                     status: 6,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -112,6 +266,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: SINCE,
@@ -125,6 +282,7 @@ fn get_correct_btc_case() -> TestCase {
                 amount: BTC_BURN_AMOUNT,
                 lockscript: Default::default(),
                 owner_script: Default::default(),
+                token_address: None,
                 index: 1,
             }],
             outputs: vec![],
@@ -145,6 +303,15 @@ fn get_correct_btc_case() -> TestCase {
             ],
         },
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
+
+/// Lock of a cell paying the tx fee, it is not the bidder even if placed before the XT of the bidder
+fn fee_payer_lockscript() -> ScriptView {
+    ScriptView {
+        args: vec![1u8; 20].into(),
+        ..Default::default()
+    }
+}
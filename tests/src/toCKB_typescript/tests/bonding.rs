@@ -1,6 +1,9 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
-    config::{CKB_UNITS, COLLATERAL_PERCENT, XT_CELL_CAPACITY},
+    config::{
+        BONDING_WINDOW, CKB_UNITS, COLLATERAL_PERCENT, LOCK_TYPE_FLAG, PLEDGE, SIGNER_FEE_RATE,
+        SINCE_TYPE_TIMESTAMP, XT_CELL_CAPACITY,
+    },
     tockb_cell::BTC_UNIT,
     Error, ETH_UNIT,
 };
@@ -26,6 +29,13 @@ fn test_correct_tx() {
     case_runner::run_test(eth_case)
 }
 
+#[test]
+fn test_correct_batch_tx() {
+    let mut case = get_correct_btc_case();
+    batch_toCKB_cells(&mut case, get_correct_btc_case());
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_address() {
     let mut btc_case = get_correct_btc_case();
@@ -41,6 +51,64 @@ fn test_wrong_address() {
     case_runner::run_test(eth_case)
 }
 
+#[test]
+fn test_correct_address_types() {
+    // regtest P2PKH, P2SH, P2WSH and P2TR
+    for address in &[
+        "mhds2Q22ECZhgx4tgDvvy4zphsY6vDzFTH",
+        "2MuN8idMWaXxBCnvFktF2Bj8hCkSHKjdzdH",
+        "bcrt1qqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0scjhmyt",
+        "bcrt1pqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0sj9hjuh",
+    ] {
+        let mut case = get_correct_btc_case();
+        case.toCKB_cells.outputs[0].data.x_lock_address = address.to_string();
+        case_runner::run_test(case);
+    }
+}
+
+#[test]
+fn test_wrong_address_types() {
+    for address in &[
+        // mainnet P2PKH and P2WPKH
+        "137ujLw3RB8SuqbGxexZ99nVqswPy7nf6z",
+        "bc1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku8mf3ah",
+        // witness v1 program with bech32 instead of bech32m checksum
+        "bcrt1pqqqsyqcyq5rqwzqfpg9scrgwpugpzysnzs23v9ccrydpk8qarc0s8e87e4",
+        // P2PKH with broken checksum
+        "mhds2Q22ECZhgx4tgDvvy4zphsY6vDzFTJ",
+    ] {
+        let mut case = get_correct_btc_case();
+        case.toCKB_cells.outputs[0].data.x_lock_address = address.to_string();
+        case.expect_return_code = Error::XChainAddressInvalid as i8;
+        case_runner::run_test(case);
+    }
+}
+
+#[test]
+fn test_correct_testnet_address() {
+    let mut case = get_correct_btc_case();
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.type_args.xchain_network = 1;
+    }
+    case.toCKB_cells.outputs[0].data.x_lock_address =
+        "tb1qzulv8gfw9zd3qtuwmnqafmxnkkuf8ckudajzxy".to_owned();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_address_network() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.x_lock_address =
+        "tb1qzulv8gfw9zd3qtuwmnqafmxnkkuf8ckudajzxy".to_owned();
+    case.expect_return_code = Error::XChainAddressInvalid as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_xchain_mismatch() {
     let mut case = get_correct_btc_case();
@@ -50,13 +118,156 @@ fn test_wrong_xchain_mismatch() {
 }
 
 #[test]
-fn test_wrong_modified_lot_size() {
+fn test_wrong_modified_amount() {
     let mut case = get_correct_btc_case();
-    case.toCKB_cells.outputs[0].data.lot_size = 3;
+    case.toCKB_cells.outputs[0].data.amount = Some(100_000_000);
     case.expect_return_code = Error::InvariantDataMutated as i8;
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_wrong_modified_signer_fee_rate() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.signer_fee_rate = SIGNER_FEE_RATE + 1;
+    case.expect_return_code = Error::InvariantDataMutated as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_modified_pledge() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.pledge = PLEDGE + 1;
+    case.expect_return_code = Error::InvariantDataMutated as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_params_snapshot() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0]
+        .data
+        .params_snapshot
+        .liquidation_collateral_percent += 1;
+    case.expect_return_code = Error::InvalidDataChange as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_untrusted_protocol_params() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps
+        .push(CellDepView::UntrustedProtocolParams(Default::default()));
+    case.expect_return_code = Error::ProtocolParamsInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_arbitrary_amount() {
+    // 12_345_678 * 150 / BTC_PRICE = 18518.5, rounded up to 18519 units of 0.01 CKB
+    for (collateral, expect_return_code) in &[
+        (18519, 0),
+        (18518, Error::CollateralInvalid as i8),
+        (18520, Error::CollateralInvalid as i8),
+    ] {
+        let mut case = get_correct_btc_case();
+        case.toCKB_cells.inputs[0].data.amount = Some(12_345_678);
+        case.toCKB_cells.outputs[0].data.amount = Some(12_345_678);
+        case.toCKB_cells.outputs[0].capacity =
+            INPUT_TOCKB_CELL_CAPACITY + 2 * XT_CELL_CAPACITY + collateral * CKB_UNITS / 100;
+        case.expect_return_code = *expect_return_code;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_correct_signer_group() {
+    let case = get_correct_signer_group_case();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_group_address() {
+    let mut case = get_correct_signer_group_case();
+    // 1-of-3 multisig of the same members
+    case.toCKB_cells.outputs[0].data.x_lock_address =
+        "bcrt1qldxru76vd6r8sznk8cvkt3a5r6yh4253du9hzy73wvmacaquc3cqjevqkl".to_owned();
+    case.expect_return_code = Error::SignerGroupInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_group_threshold() {
+    let mut case = get_correct_signer_group_case();
+    case.toCKB_cells.outputs[0].data.signer_group.threshold = 4;
+    case.expect_return_code = Error::SignerGroupInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_group_first_member() {
+    let mut case = get_correct_signer_group_case();
+    case.toCKB_cells.outputs[0]
+        .data
+        .signer_group
+        .members
+        .swap(0, 1);
+    case.expect_return_code = Error::SignerGroupInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_group_duplicated_member() {
+    let mut case = get_correct_signer_group_case();
+    case.toCKB_cells.outputs[0].data.signer_group.members[2].lockscript = get_member_lockscript(0);
+    case.expect_return_code = Error::SignerGroupInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_group_zero_share() {
+    let mut case = get_correct_signer_group_case();
+    case.toCKB_cells.outputs[0].data.signer_group.members[1].share = 0;
+    case.expect_return_code = Error::SignerGroupInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_group_member_not_funding() {
+    let mut case = get_correct_signer_group_case();
+    case.capacity_cells.inputs[1].lockscript = get_member_lockscript(0);
+    case.expect_return_code = Error::CollateralInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_group_member_funding_less_than_share() {
+    let mut case = get_correct_signer_group_case();
+    case.capacity_cells.inputs[1].capacity -= 1;
+    case.capacity_cells.inputs[0].capacity += 1;
+    case.expect_return_code = Error::CollateralInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_group_funding_taken_back() {
+    let mut case = get_correct_signer_group_case();
+    case.capacity_cells.outputs.push(CapacityCell {
+        capacity: 1,
+        lockscript: get_member_lockscript(2),
+        index: 1,
+    });
+    case.expect_return_code = Error::CollateralInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_group_eth() {
+    let mut case = get_correct_eth_case();
+    case.toCKB_cells.outputs[0].data.signer_group = get_signer_group();
+    case.expect_return_code = Error::SignerGroupInvalid as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_collateral_bond() {
     let mut case = get_correct_btc_case();
@@ -65,15 +276,56 @@ fn test_wrong_collateral_bond() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_chosen_signer() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.signer_lockscript = get_chosen_signer();
+    case.toCKB_cells.outputs[0].data.signer_lockscript = get_chosen_signer();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_not_chosen() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.signer_lockscript = get_chosen_signer();
+    case.expect_return_code = Error::SignerNotChosen as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_signer_not_chosen_after_bonding_window() {
+    for (since, expect_return_code) in &[
+        (LOCK_TYPE_FLAG | SINCE_TYPE_TIMESTAMP | BONDING_WINDOW, 0),
+        (
+            LOCK_TYPE_FLAG | SINCE_TYPE_TIMESTAMP | (BONDING_WINDOW - 1),
+            Error::SignerNotChosen as i8,
+        ),
+    ] {
+        let mut case = get_correct_btc_case();
+        case.toCKB_cells.inputs[0].data.signer_lockscript = get_chosen_signer();
+        case.toCKB_cells.inputs[0].since = *since;
+        case.expect_return_code = *expect_return_code;
+        case_runner::run_test(case)
+    }
+}
+
 fn get_correct_btc_case() -> TestCase {
     TestCase {
-        cell_deps: vec![CellDepView::PriceOracle(BTC_PRICE)],
+        cell_deps: price_oracles(BTC_PRICE),
+        header_deps: vec![PRICE_ORACLE_TIMESTAMP],
+        toCKB_input_header: Some(PRICE_ORACLE_TIMESTAMP),
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: INPUT_TOCKB_CELL_CAPACITY,
                 data: ToCKBCellDataView {
                     status: 1,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -84,6 +336,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -93,7 +348,13 @@ fn get_correct_btc_case() -> TestCase {
                 capacity: OUTPUT_TOCKB_CELL_CAPACITY_IF_BTC,
                 data: ToCKBCellDataView {
                     status: 2,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_string(),
                     signer_lockscript: Default::default(),
@@ -104,6 +365,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -113,21 +377,82 @@ fn get_correct_btc_case() -> TestCase {
         sudt_cells: Default::default(),
         capacity_cells: Default::default(),
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
 
 fn get_correct_eth_case() -> TestCase {
     let mut case = get_correct_btc_case();
-    if let CellDepView::PriceOracle(price) = &mut case.cell_deps[0] {
-        *price = ETH_PRICE;
-    }
+    case.cell_deps = price_oracles(ETH_PRICE);
     case.toCKB_cells.outputs[0].capacity = OUTPUT_TOCKB_CELL_CAPACITY_IF_ETH;
     case.toCKB_cells.inputs[0].type_args.xchain_kind = 2;
+    case.toCKB_cells.inputs[0].data.amount = Some(ETH_UNIT / 4);
     case.toCKB_cells.inputs[0].data.x_extra = XExtraView::Eth(Default::default());
     case.toCKB_cells.outputs[0].type_args.xchain_kind = 2;
+    case.toCKB_cells.outputs[0].data.amount = Some(ETH_UNIT / 4);
     case.toCKB_cells.outputs[0].data.x_extra = XExtraView::Eth(Default::default());
     // TODO fix eth address codec
     case.toCKB_cells.outputs[0].data.x_lock_address = "5eE3b766D487d7d1A2eF".to_owned();
     case
 }
+
+fn get_correct_signer_group_case() -> TestCase {
+    let mut case = get_correct_btc_case();
+    let output = &mut case.toCKB_cells.outputs[0].data;
+    output.x_lock_address =
+        "bcrt1qa6j8srqcz7myrjgu4rvd3crxx9wdrwtw3m3jv4dcsumrkt0p9xqs8j3vre".to_owned();
+    output.signer_lockscript = get_member_lockscript(0);
+    output.signer_group = get_signer_group();
+    // each member funds a third of the collateral, the last one takes the rounding remainder
+    let collateral = OUTPUT_TOCKB_CELL_CAPACITY_IF_BTC - INPUT_TOCKB_CELL_CAPACITY;
+    let share = collateral / 3;
+    case.capacity_cells.inputs = (0..3)
+        .map(|i| CapacityCell {
+            capacity: if i == 2 {
+                collateral - 2 * share
+            } else {
+                share
+            },
+            lockscript: get_member_lockscript(i),
+            index: i + 1,
+        })
+        .collect();
+    case
+}
+
+/// signer the user chose from the signer registry at deposit request
+fn get_chosen_signer() -> ScriptView {
+    ScriptView {
+        args: vec![1u8; 20].into(),
+        ..Default::default()
+    }
+}
+
+fn get_member_lockscript(i: usize) -> ScriptView {
+    ScriptView {
+        args: vec![i as u8 + 1; 20].into(),
+        ..Default::default()
+    }
+}
+
+/// 2-of-3 signer group of the pubkeys of private keys 1, 2 and 3 with equal shares
+fn get_signer_group() -> SignerGroupView {
+    let members = [
+        "0279be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        "02c6047f9441ed7d6d3045406e95c07cd85c778e4b8cef3ca7abac09b95c709ee5",
+        "02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113e1ce60f9",
+    ]
+    .iter()
+    .enumerate()
+    .map(|(i, pubkey)| SignerMemberView {
+        lockscript: get_member_lockscript(i),
+        share: 1,
+        x_pubkey: hex::decode(pubkey).unwrap().into(),
+    })
+    .collect();
+    SignerGroupView {
+        threshold: 2,
+        members,
+    }
+}
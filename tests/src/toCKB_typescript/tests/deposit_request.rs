@@ -1,5 +1,11 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
-use tockb_types::{config::PLEDGE, Error};
+use tockb_types::{
+    config::{
+        BTC_LOT_AMOUNT_RANGE, CKB_UNITS, ERC20_LOT_AMOUNT_RANGE, MAX_SIGNER_FEE_RATE, PLEDGE,
+        SIGNER_FEE_RATE, SIGNER_FEE_RATE_DENOMINATOR,
+    },
+    Error,
+};
 
 #[test]
 fn test_correct_tx() {
@@ -15,10 +21,99 @@ fn test_wrong_pledge() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_pledge_updated_by_governance() {
+    let mut case = get_correct_btc_case();
+    let pledge = 20000 * CKB_UNITS;
+    case.cell_deps = vec![CellDepView::ProtocolParams(ProtocolParamsView {
+        pledge,
+        ..Default::default()
+    })];
+    case.toCKB_cells.outputs[0].capacity = pledge;
+    case.toCKB_cells.outputs[0].data.pledge = pledge;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_pledge_updated_by_governance() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![CellDepView::ProtocolParams(ProtocolParamsView {
+        pledge: 20000 * CKB_UNITS,
+        ..Default::default()
+    })];
+    case.expect_return_code = Error::PledgeInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_pledge_in_data() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.pledge = PLEDGE - CKB_UNITS;
+    case.expect_return_code = Error::PledgeInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_signer_fee_rate() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.signer_fee_rate = MAX_SIGNER_FEE_RATE;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_fee_rate() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.signer_fee_rate = MAX_SIGNER_FEE_RATE + 1;
+    case.expect_return_code = Error::SignerFeeRateInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_protocol_params() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![CellDepView::ProtocolParams(ProtocolParamsView {
+        max_signer_fee_rate: SIGNER_FEE_RATE_DENOMINATOR as u32,
+        ..Default::default()
+    })];
+    case.expect_return_code = Error::ProtocolParamsInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_batch_tx() {
+    let mut case = get_correct_btc_case();
+    let mut other = get_correct_btc_case();
+    let mut input = other.capacity_cells.inputs.pop().unwrap();
+    let mut output = other.toCKB_cells.outputs.pop().unwrap();
+    input.index = 1;
+    output.index = 1;
+    output.type_args.cell_id = CellIdView::Input(1);
+    case.capacity_cells.inputs.push(input);
+    case.toCKB_cells.outputs.push(output);
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_batch_tx_with_same_cell_id() {
+    let mut case = get_correct_btc_case();
+    let mut other = get_correct_btc_case();
+    let mut input = other.capacity_cells.inputs.pop().unwrap();
+    let mut output = other.toCKB_cells.outputs.pop().unwrap();
+    input.index = 1;
+    output.index = 1;
+    // a BTC testnet deposit is in another script group, but reuses the input of the first one
+    output.type_args.xchain_network = 1;
+    case.capacity_cells.inputs.push(input);
+    case.toCKB_cells.outputs.push(output);
+    case.expect_return_code = Error::CellIDInvalid as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_cell_id() {
     let mut case = get_correct_btc_case();
-    case.toCKB_cells.outputs[0].type_args.cell_id = Some(Bytes::from("wrong_cell_id"));
+    case.toCKB_cells.outputs[0].type_args.cell_id =
+        CellIdView::OutPoint(Bytes::from("wrong_cell_id"));
     case.expect_return_code = Error::Encoding as i8;
     case_runner::run_test(case)
 }
@@ -47,16 +142,82 @@ fn test_wrong_lot_size() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_legacy_lot_size() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.lot_size = 1;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_amount_bounds() {
+    for amount in &[BTC_LOT_AMOUNT_RANGE.0, BTC_LOT_AMOUNT_RANGE.1] {
+        let mut case = get_correct_btc_case();
+        case.toCKB_cells.outputs[0].data.amount = Some(*amount);
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_wrong_amount_out_of_range() {
+    for amount in &[BTC_LOT_AMOUNT_RANGE.0 - 1, BTC_LOT_AMOUNT_RANGE.1 + 1] {
+        let mut case = get_correct_btc_case();
+        case.toCKB_cells.outputs[0].data.amount = Some(*amount);
+        case.expect_return_code = Error::LotSizeInvalid as i8;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_correct_erc20_amount_bounds() {
+    for amount in &[ERC20_LOT_AMOUNT_RANGE.0, ERC20_LOT_AMOUNT_RANGE.1] {
+        let mut case = get_correct_erc20_case();
+        case.toCKB_cells.outputs[0].data.amount = Some(*amount);
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_wrong_erc20_token_address() {
+    let mut case = get_correct_erc20_case();
+    case.toCKB_cells.outputs[0].data.x_extra = XExtraView::Erc20(Erc20ExtraView {
+        // one byte short of an address
+        token_address: "a0b86991c6218b36c1d".to_owned(),
+        lock: Default::default(),
+    });
+    case.expect_return_code = Error::XChainAddressInvalid as i8;
+    case_runner::run_test(case)
+}
+
+fn get_correct_erc20_case() -> TestCase {
+    let mut case = get_correct_btc_case();
+    let output = &mut case.toCKB_cells.outputs[0];
+    output.type_args.xchain_kind = 4;
+    output.data.x_extra = XExtraView::Erc20(Erc20ExtraView {
+        token_address: "a0b86991c6218b36c1d1".to_owned(),
+        lock: Default::default(),
+    });
+    case
+}
+
 fn get_correct_btc_case() -> TestCase {
     TestCase {
         cell_deps: vec![],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![],
             outputs: vec![ToCKBCell {
                 capacity: PLEDGE,
                 data: ToCKBCellDataView {
                     status: 1,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -67,7 +228,10 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
-                    cell_id: None,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
+                    cell_id: CellIdView::Input(0),
                 },
                 since: 0,
                 index: 0,
@@ -83,6 +247,7 @@ fn get_correct_btc_case() -> TestCase {
             outputs: vec![],
         },
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
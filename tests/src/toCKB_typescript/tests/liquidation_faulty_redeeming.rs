@@ -1,7 +1,14 @@
-use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
-use tockb_types::{config::CKB_UNITS, Error};
+use crate::toCKB_typescript::utils::{case_builder::*, case_runner, eth_proof};
+use tockb_types::{
+    config::{CKB_UNITS, PLEDGE, SIGNER_FEE_RATE},
+    Error,
+};
 
 const COLLATERAL: u64 = 100 * CKB_UNITS;
+const ETH_UNLOCK_ADDRESS: &str = "0x2222222222222222222222222222222222222222";
+const ETH_LOT_AMOUNT: u128 = 250_000_000_000_000_000;
+const ERC20_TOKEN_ADDRESS: &str = "a0b86991c6218b36c1d1";
+const ERC20_LOT_AMOUNT: u128 = 1_000_000_000_000_000_000_000;
 
 #[test]
 fn test_correct_tx() {
@@ -9,6 +16,13 @@ fn test_correct_tx() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_batch_tx() {
+    let mut case = get_correct_btc_case();
+    batch_toCKB_cells(&mut case, get_correct_btc_case());
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_mint_xt() {
     let mut case = get_correct_btc_case();
@@ -17,6 +31,7 @@ fn test_wrong_mint_xt() {
         amount: 100,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 1,
     });
     case.expect_return_code = Error::TxInvalid as i8;
@@ -32,9 +47,9 @@ fn test_wrong_xchain_mismatch() {
 }
 
 #[test]
-fn test_wrong_modified_lot_size() {
+fn test_wrong_modified_amount() {
     let mut case = get_correct_btc_case();
-    case.toCKB_cells.outputs[0].data.lot_size = 3;
+    case.toCKB_cells.outputs[0].data.amount = Some(100_000_000);
     case.expect_return_code = Error::InvariantDataMutated as i8;
     case_runner::run_test(case)
 }
@@ -87,18 +102,153 @@ fn test_wrong_liquidation_trigger() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_eth_tx() {
+    let case = get_correct_eth_case(get_signer_redeem_tx(ETH_LOT_AMOUNT - 1));
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_eth_tx_to_others() {
+    let tx_data =
+        eth_proof::build_transfer_tx(&eth_proof::default_signer_key(), &[3u8; 20], ETH_LOT_AMOUNT);
+    let case = get_correct_eth_case(tx_data);
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_enough_amount() {
+    let mut case = get_correct_eth_case(get_signer_redeem_tx(ETH_LOT_AMOUNT));
+    case.expect_return_code = Error::FaultyEthWitnessInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_tx_before_lock() {
+    let mut case = get_correct_eth_case(get_signer_redeem_tx(ETH_LOT_AMOUNT - 1));
+    set_eth_spv_proof(
+        &mut case,
+        get_signer_redeem_tx(ETH_LOT_AMOUNT - 1),
+        vec![],
+        1,
+    );
+    case.expect_return_code = Error::FaultyEthWitnessInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_erc20_tx() {
+    let case = get_correct_erc20_case(vec![(get_unlock_address(), ERC20_LOT_AMOUNT - 1)]);
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_erc20_tx_to_others() {
+    // the redeemed amount is paid, but more of the token is moved elsewhere
+    let case = get_correct_erc20_case(vec![
+        (get_unlock_address(), ERC20_LOT_AMOUNT),
+        (vec![3u8; 20], 1),
+    ]);
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_erc20_enough_amount() {
+    let mut case = get_correct_erc20_case(vec![(get_unlock_address(), ERC20_LOT_AMOUNT)]);
+    case.expect_return_code = Error::FaultyEthWitnessInvalid as i8;
+    case_runner::run_test(case)
+}
+
+fn get_unlock_address() -> Vec<u8> {
+    hex::decode(&ETH_UNLOCK_ADDRESS[2..]).expect("decode address hex")
+}
+
+fn get_signer_redeem_tx(value: u128) -> Vec<u8> {
+    eth_proof::build_transfer_tx(
+        &eth_proof::default_signer_key(),
+        &get_unlock_address(),
+        value,
+    )
+}
+
+fn get_correct_eth_case(tx_data: Vec<u8>) -> TestCase {
+    let mut case = get_correct_btc_case();
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.type_args.xchain_kind = 2;
+        cell.data.amount = Some(ETH_LOT_AMOUNT);
+        cell.data.x_lock_address = eth_proof::get_address(&eth_proof::default_signer_key());
+        cell.data.x_unlock_address = ETH_UNLOCK_ADDRESS.to_owned();
+        cell.data.x_extra = XExtraView::Eth(EthExtraView {
+            lock_tx_hash: hex::encode([1u8; 32]),
+            lock_tx_index: 0,
+            lock_block_number: 1,
+        });
+    }
+    set_eth_spv_proof(&mut case, tx_data, vec![], 2);
+    case
+}
+
+/// The signer moves the token from x_lock_address with the `(to, value)` transfers in one tx
+fn get_correct_erc20_case(transfers: Vec<(Vec<u8>, u128)>) -> TestCase {
+    let signer_key = eth_proof::default_signer_key();
+    let token = ERC20_TOKEN_ADDRESS.as_bytes();
+    let (to, value) = &transfers[0];
+    let tx_data = eth_proof::build_token_transfer_tx(&signer_key, token, to, *value);
+    let from = hex::decode(&eth_proof::get_address(&signer_key)[2..]).expect("decode address hex");
+    let logs = transfers
+        .iter()
+        .map(|(to, value)| eth_proof::build_transfer_log(token, &from, to, *value))
+        .collect();
+    let mut case = get_correct_eth_case(tx_data.clone());
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.type_args.xchain_kind = 4;
+        cell.data.amount = Some(ERC20_LOT_AMOUNT);
+        cell.data.x_extra = XExtraView::Erc20(Erc20ExtraView {
+            token_address: ERC20_TOKEN_ADDRESS.to_owned(),
+            lock: EthExtraView {
+                lock_tx_hash: hex::encode([1u8; 32]),
+                lock_tx_index: 0,
+                lock_block_number: 1,
+            },
+        });
+    }
+    set_eth_spv_proof(&mut case, tx_data, logs, 2);
+    case
+}
+
 fn get_correct_btc_case() -> TestCase {
     TestCase {
-        cell_deps: vec![CellDepView::DifficultyOracle(DifficultyOracle {
-            previous: 17557993035167,
-            current: 17557993035167,
-        })],
+        cell_deps: vec![
+            CellDepView::DifficultyOracle(DifficultyOracle {
+                previous: 17557993035167,
+                current: 17557993035167,
+            }),
+            CellDepView::single_header_protocol_params(),
+        ],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: COLLATERAL,
                 data: ToCKBCellDataView {
                     status: 4,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_string(),
                     signer_lockscript: Default::default(),
@@ -109,6 +259,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -118,7 +271,13 @@ fn get_correct_btc_case() -> TestCase {
                 capacity: COLLATERAL,
                 data: ToCKBCellDataView {
                     status: 8,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_string(),
                     signer_lockscript: Default::default(),
@@ -129,6 +288,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -152,6 +314,7 @@ fn get_correct_btc_case() -> TestCase {
                 funding_input_index: 0,
             },
         })],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
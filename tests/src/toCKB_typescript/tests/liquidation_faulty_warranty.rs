@@ -1,7 +1,11 @@
-use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
-use tockb_types::{config::CKB_UNITS, Error};
+use crate::toCKB_typescript::utils::{case_builder::*, case_runner, eth_proof};
+use tockb_types::{
+    config::{CKB_UNITS, PLEDGE, SIGNER_FEE_RATE},
+    Error,
+};
 
 const COLLATERAL: u64 = 100 * CKB_UNITS;
+const ETH_UNLOCK_ADDRESS: &str = "0x2222222222222222222222222222222222222222";
 
 #[test]
 fn test_correct_tx() {
@@ -17,6 +21,7 @@ fn test_wrong_mint_xt() {
         amount: 100,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 1,
     });
     case.expect_return_code = Error::TxInvalid as i8;
@@ -32,9 +37,9 @@ fn test_wrong_xchain_mismatch() {
 }
 
 #[test]
-fn test_wrong_modified_lot_size() {
+fn test_wrong_modified_amount() {
     let mut case = get_correct_btc_case();
-    case.toCKB_cells.outputs[0].data.lot_size = 3;
+    case.toCKB_cells.outputs[0].data.amount = Some(100_000_000);
     case.expect_return_code = Error::InvariantDataMutated as i8;
     case_runner::run_test(case)
 }
@@ -76,18 +81,125 @@ fn test_wrong_toCKB_capacity() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_handover_tx() {
+    let case = get_correct_handover_case("bcrt1q4r9hqljdpfwxu6gp3x7qqedg77r6408dn4wmnf");
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_handover_sanctioned_move() {
+    let mut case = get_correct_handover_case("bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d");
+    case.expect_return_code = Error::FaultyBtcWitnessInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_handover_collateral_not_returned() {
+    let mut case = get_correct_handover_case("bcrt1q4r9hqljdpfwxu6gp3x7qqedg77r6408dn4wmnf");
+    case.capacity_cells.outputs.clear();
+    case.expect_return_code = Error::CapacityInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_eth_tx() {
+    let case = get_correct_eth_case(get_signer_transfer_tx());
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_tx_before_lock() {
+    let mut case = get_correct_eth_case(get_signer_transfer_tx());
+    set_eth_spv_proof(&mut case, get_signer_transfer_tx(), vec![], 1);
+    case.expect_return_code = Error::FaultyEthWitnessInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_tx_not_from_signer() {
+    let other_key = secp256k1::SecretKey::from_slice(&[2u8; 32]).expect("build secret key");
+    let tx_data = eth_proof::build_transfer_tx(&other_key, &[3u8; 20], 1);
+    let mut case = get_correct_eth_case(tx_data);
+    case.expect_return_code = Error::FaultyEthWitnessInvalid as i8;
+    case_runner::run_test(case)
+}
+
+fn get_signer_transfer_tx() -> Vec<u8> {
+    eth_proof::build_transfer_tx(&eth_proof::default_signer_key(), &[3u8; 20], 1)
+}
+
+/// The cell is handing over to a new signer, whose lock address is `new_lock_address`
+fn get_correct_handover_case(new_lock_address: &str) -> TestCase {
+    let new_signer_lockscript = || ScriptView {
+        outpoint_key: ALWAYS_SUCCESS_OUTPOINT_KEY,
+        args: Bytes::from("new_signer"),
+    };
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.status = 9;
+    case.toCKB_cells.inputs[0].capacity = 2 * COLLATERAL;
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.data.x_unlock_address = new_lock_address.to_owned();
+        cell.data.redeemer_lockscript = new_signer_lockscript();
+    }
+    case.capacity_cells.outputs.push(CapacityCell {
+        capacity: COLLATERAL,
+        lockscript: new_signer_lockscript(),
+        index: 1,
+    });
+    case
+}
+
+fn get_correct_eth_case(tx_data: Vec<u8>) -> TestCase {
+    let mut case = get_correct_btc_case();
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.type_args.xchain_kind = 2;
+        cell.data.amount = Some(250_000_000_000_000_000);
+        cell.data.x_lock_address = eth_proof::get_address(&eth_proof::default_signer_key());
+        cell.data.x_unlock_address = ETH_UNLOCK_ADDRESS.to_owned();
+        cell.data.x_extra = XExtraView::Eth(EthExtraView {
+            lock_tx_hash: hex::encode([1u8; 32]),
+            lock_tx_index: 0,
+            lock_block_number: 1,
+        });
+    }
+    set_eth_spv_proof(&mut case, tx_data, vec![], 2);
+    case
+}
+
 fn get_correct_btc_case() -> TestCase {
     TestCase {
-        cell_deps: vec![CellDepView::DifficultyOracle(DifficultyOracle {
-            previous: 17557993035167,
-            current: 17557993035167,
-        })],
+        cell_deps: vec![
+            CellDepView::DifficultyOracle(DifficultyOracle {
+                previous: 17557993035167,
+                current: 17557993035167,
+            }),
+            CellDepView::single_header_protocol_params(),
+        ],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: COLLATERAL,
                 data: ToCKBCellDataView {
                     status: 3,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_string(),
                     signer_lockscript: Default::default(),
@@ -98,6 +210,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -107,7 +222,13 @@ fn get_correct_btc_case() -> TestCase {
                 capacity: COLLATERAL,
                 data: ToCKBCellDataView {
                     status: 7,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_string(),
                     signer_lockscript: Default::default(),
@@ -118,6 +239,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -141,6 +265,7 @@ fn get_correct_btc_case() -> TestCase {
                 funding_input_index: 0,
             },
         })],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
@@ -1,6 +1,6 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
-    config::{CKB_UNITS, SINCE_SIGNER_TIMEOUT},
+    config::{CKB_UNITS, PLEDGE, SIGNER_FEE_RATE, SINCE_SIGNER_TIMEOUT},
     Error,
 };
 
@@ -12,6 +12,13 @@ fn test_correct_tx() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_batch_tx() {
+    let mut case = get_correct_btc_case();
+    batch_toCKB_cells(&mut case, get_correct_btc_case());
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_input_since() {
     let mut case = get_correct_btc_case();
@@ -20,6 +27,29 @@ fn test_wrong_input_since() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_tx_when_params_updated() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![CellDepView::ProtocolParams(ProtocolParamsView {
+        since_signer_timeout: SINCE_SIGNER_TIMEOUT + 1,
+        ..Default::default()
+    })];
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_input_since_of_snapshot() {
+    let mut case = get_correct_btc_case();
+    for cell in case.toCKB_cells.inputs.iter_mut() {
+        cell.data.params_snapshot.since_signer_timeout = SINCE_SIGNER_TIMEOUT + 1;
+    }
+    for cell in case.toCKB_cells.outputs.iter_mut() {
+        cell.data.params_snapshot.since_signer_timeout = SINCE_SIGNER_TIMEOUT + 1;
+    }
+    case.expect_return_code = Error::InputSinceInvalid as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_mint_xt() {
     let mut case = get_correct_btc_case();
@@ -28,6 +58,7 @@ fn test_wrong_mint_xt() {
         amount: 100,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 1,
     });
     case.expect_return_code = Error::TxInvalid as i8;
@@ -43,9 +74,9 @@ fn test_wrong_xchain_mismatch() {
 }
 
 #[test]
-fn test_wrong_modified_lot_size() {
+fn test_wrong_modified_amount() {
     let mut case = get_correct_btc_case();
-    case.toCKB_cells.outputs[0].data.lot_size = 3;
+    case.toCKB_cells.outputs[0].data.amount = Some(100_000_000);
     case.expect_return_code = Error::InvariantDataMutated as i8;
     case_runner::run_test(case)
 }
@@ -61,12 +92,20 @@ fn test_wrong_modified_x_lock_address() {
 fn get_correct_btc_case() -> TestCase {
     TestCase {
         cell_deps: vec![],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: COLLATERAL,
                 data: ToCKBCellDataView {
                     status: 4,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_string(),
                     signer_lockscript: Default::default(),
@@ -82,6 +121,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: SINCE_SIGNER_TIMEOUT,
@@ -91,7 +133,13 @@ fn get_correct_btc_case() -> TestCase {
                 capacity: COLLATERAL,
                 data: ToCKBCellDataView {
                     status: 5,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_string(),
                     signer_lockscript: Default::default(),
@@ -107,6 +155,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -116,6 +167,7 @@ fn get_correct_btc_case() -> TestCase {
         sudt_cells: Default::default(),
         capacity_cells: Default::default(),
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
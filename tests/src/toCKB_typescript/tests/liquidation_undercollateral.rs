@@ -1,6 +1,6 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
-    config::{CKB_UNITS, XT_CELL_CAPACITY},
+    config::{CKB_UNITS, PLEDGE, SIGNER_FEE_RATE, XT_CELL_CAPACITY},
     Error,
 };
 
@@ -13,12 +13,54 @@ fn test_correct_tx() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_batch_tx() {
+    let mut case = get_correct_btc_case();
+    batch_toCKB_cells(&mut case, get_correct_btc_case());
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_batch_tx_with_same_cell_id() {
+    let mut case = get_correct_btc_case();
+    let mut other = get_correct_btc_case();
+    let mut input = other.toCKB_cells.inputs.pop().unwrap();
+    let mut output = other.toCKB_cells.outputs.pop().unwrap();
+    input.index = 1;
+    output.index = 1;
+    case.toCKB_cells.inputs.push(input);
+    case.toCKB_cells.outputs.push(output);
+    case.expect_return_code = Error::TxInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_tx_when_handover() {
+    let case = get_correct_btc_handover_case();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_new_signer_collateral_not_returned() {
+    let mut case = get_correct_btc_handover_case();
+    case.capacity_cells.outputs[0].capacity = TOCKB_CELL_CAPACITY - 1;
+    case.expect_return_code = Error::CapacityInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_price_condition_when_handover() {
+    // only the collateral of the current signer is liquidated
+    let mut case = get_correct_btc_handover_case();
+    case.cell_deps = price_oracles(10 * PRICE);
+    case.expect_return_code = Error::UndercollateralInvalid as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_price_condition() {
     let mut case = get_correct_btc_case();
-    if let CellDepView::PriceOracle(price) = &mut case.cell_deps[0] {
-        *price = 10 * PRICE;
-    }
+    case.cell_deps = price_oracles(10 * PRICE);
     case.expect_return_code = Error::UndercollateralInvalid as i8;
     case_runner::run_test(case)
 }
@@ -31,6 +73,7 @@ fn test_wrong_mint_xt() {
         amount: 100,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 1,
     });
     case.expect_return_code = Error::TxInvalid as i8;
@@ -46,9 +89,9 @@ fn test_wrong_xchain_mismatch() {
 }
 
 #[test]
-fn test_wrong_modified_lot_size() {
+fn test_wrong_modified_amount() {
     let mut case = get_correct_btc_case();
-    case.toCKB_cells.outputs[0].data.lot_size = 3;
+    case.toCKB_cells.outputs[0].data.amount = Some(100_000_000);
     case.expect_return_code = Error::InvariantDataMutated as i8;
     case_runner::run_test(case)
 }
@@ -61,15 +104,45 @@ fn test_wrong_modified_x_lock_address() {
     case_runner::run_test(case)
 }
 
+fn new_signer_lockscript() -> ScriptView {
+    ScriptView {
+        outpoint_key: ALWAYS_SUCCESS_OUTPOINT_KEY,
+        args: Bytes::from("new_signer"),
+    }
+}
+
+fn get_correct_btc_handover_case() -> TestCase {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0] = btc_handover_cell(
+        2 * TOCKB_CELL_CAPACITY,
+        BTC_LOCK_ADDRESS,
+        new_signer_lockscript(),
+    );
+    case.capacity_cells.outputs.push(CapacityCell {
+        capacity: TOCKB_CELL_CAPACITY,
+        lockscript: new_signer_lockscript(),
+        index: 1,
+    });
+    case
+}
+
 fn get_correct_btc_case() -> TestCase {
     TestCase {
-        cell_deps: vec![CellDepView::PriceOracle(PRICE)],
+        cell_deps: price_oracles(PRICE),
+        header_deps: vec![PRICE_ORACLE_TIMESTAMP],
+        toCKB_input_header: Some(PRICE_ORACLE_TIMESTAMP),
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: TOCKB_CELL_CAPACITY,
                 data: ToCKBCellDataView {
                     status: 3,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_string(),
                     signer_lockscript: Default::default(),
@@ -85,6 +158,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -94,7 +170,13 @@ fn get_correct_btc_case() -> TestCase {
                 capacity: TOCKB_CELL_CAPACITY,
                 data: ToCKBCellDataView {
                     status: 6,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_string(),
                     signer_lockscript: Default::default(),
@@ -110,6 +192,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -119,6 +204,7 @@ fn get_correct_btc_case() -> TestCase {
         sudt_cells: Default::default(),
         capacity_cells: Default::default(),
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
@@ -1,10 +1,21 @@
-use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
+use crate::toCKB_typescript::utils::{case_builder::*, case_runner, eth_proof};
+use primitive_types::U256;
 use tockb_types::{
-    config::{CKB_UNITS, PLEDGE, XT_CELL_CAPACITY},
+    btc_relay::{get_spv_required_difficulty, hash256},
+    config::{BTC_SPV_CONFIRMATIONS, CKB_UNITS, PLEDGE, SIGNER_FEE_RATE, XT_CELL_CAPACITY},
+    ltc_pow,
     Error::*,
 };
 
 const COLLATERAL: u64 = 100_000 * CKB_UNITS;
+const ETH_LOCK_ADDRESS: &str = "5eE3b766D487d7d1A2eF";
+const ETH_LOT_AMOUNT: u128 = 250_000_000_000_000_000;
+const ERC20_TOKEN_ADDRESS: &str = "a0b86991c6218b36c1d1";
+const ERC20_LOT_AMOUNT: u128 = 1_000_000_000_000_000_000_000;
+const LTC_LOCK_ADDRESS: &str = "rltc1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku3e3xxn";
+const LTC_LOT_AMOUNT: u128 = 300_000_000;
+// scrypt headers of LTC regtest, the first one includes the lock tx and the other 3 confirm it
+const LTC_HEADERS: &str = "0x0000002022222222222222222222222222222222222222222222222222222222222222226428d9e7206982a5812d7e1d20e1f41582aff0fd55f1a0053056d9d29658ceb300105e5fffff7f20060000000000002047df54fac23d959931c45353d11d12dd6a7039843b35aaf314b08a8d70a7042f343434343434343434343434343434343434343434343434343434343434343496105e5fffff7f2001000000000000206f589318eb03d6973a39c4e6e228f3ec324a21a1127d5213315b4eeea32f5a2035353535353535353535353535353535353535353535353535353535353535352c115e5fffff7f2000000000000000205ba399d1631c31f2fcbc3c6983187d94766dc3d1166230da258b227588cb8f0a3636363636363636363636363636363636363636363636363636363636363636c2115e5fffff7f2000000000";
 
 #[test]
 fn test_correct_case() {
@@ -12,6 +23,156 @@ fn test_correct_case() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_arbitrary_amount() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.amount = Some(20_000_000);
+    case.toCKB_cells.outputs[0].data.amount = Some(20_000_000);
+    case.sudt_cells.outputs[0].amount = 19_960_000;
+    case.sudt_cells.outputs[1].amount = 40_000;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_outputs_in_any_order() {
+    let mut case = get_correct_btc_case();
+    case.sudt_cells.outputs[0].index = 2;
+    case.sudt_cells.outputs[1].index = 1;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_with_other_outputs() {
+    let mut case = get_correct_btc_case();
+    case.capacity_cells.outputs.push(CapacityCell {
+        capacity: 100 * CKB_UNITS,
+        lockscript: Default::default(),
+        index: 1,
+    });
+    case.sudt_cells.outputs[0].index = 3;
+    case.lock_registry.as_mut().unwrap().output_index = 4;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_with_registered_lock_txs() {
+    let mut case = get_correct_btc_case();
+    case.lock_registry.as_mut().unwrap().registered = vec![XExtraView::Btc(BtcExtraView {
+        lock_tx_hash: "2b21846ae6f15cc29e41b2846c78d756abfedb0d6fea7222263cac0024713bc3".to_owned(),
+        lock_vout_index: 1,
+    })];
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_reused_lock_tx() {
+    let mut case = get_correct_btc_case();
+    case.lock_registry.as_mut().unwrap().registered = vec![XExtraView::Btc(BtcExtraView {
+        lock_tx_hash: "2b21846ae6f15cc29e41b2846c78d756abfedb0d6fea7222263cac0024713bc3".to_owned(),
+        lock_vout_index: 0,
+    })];
+    case.expect_return_code = XLockTxReused as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_lock_tx_relocked_by_handover() {
+    // a handover moved the XAsset of another deposit to this output and registered it
+    let mut case = get_correct_btc_case();
+    case.lock_registry.as_mut().unwrap().registered = vec![
+        XExtraView::Btc(BtcExtraView {
+            lock_tx_hash: "5227c5fbad9d9202ade7f02452cf880dac1ed270255ebfe6716e8b3e8956571d"
+                .to_owned(),
+            lock_vout_index: 1,
+        }),
+        XExtraView::Btc(BtcExtraView {
+            lock_tx_hash: "2b21846ae6f15cc29e41b2846c78d756abfedb0d6fea7222263cac0024713bc3"
+                .to_owned(),
+            lock_vout_index: 0,
+        }),
+    ];
+    case.expect_return_code = XLockTxReused as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_without_lock_registry() {
+    let mut case = get_correct_btc_case();
+    case.lock_registry = None;
+    case.expect_return_code = LockRegistryNotFound as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_signer_fee_rate() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.signer_fee_rate = 1000;
+    case.toCKB_cells.outputs[0].data.signer_fee_rate = 1000;
+    case.sudt_cells.outputs[0].amount = 24_750_000;
+    case.sudt_cells.outputs[1].amount = 250_000;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_fee_rate() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.signer_fee_rate = 1000;
+    case.toCKB_cells.outputs[0].data.signer_fee_rate = 1000;
+    case.expect_return_code = InvalidMintOutput as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_pledge_updated_after_deposit() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps[1] = CellDepView::ProtocolParams(ProtocolParamsView {
+        btc_spv_confirmations: 1,
+        pledge: 20000 * CKB_UNITS,
+        ..Default::default()
+    });
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_pledge_in_data() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.pledge = 20000 * CKB_UNITS;
+    case.toCKB_cells.outputs[0].data.pledge = 20000 * CKB_UNITS;
+    case.expect_return_code = CapacityInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_legacy_case() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.lot_size = 1;
+    case.toCKB_cells.inputs[0].data.amount = None;
+    case.toCKB_cells.outputs[0].data.lot_size = 1;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_legacy_type_args() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.lot_size = 1;
+    case.toCKB_cells.inputs[0].data.amount = None;
+    case.toCKB_cells.inputs[0].type_args.legacy = true;
+    case.toCKB_cells.outputs[0].data.lot_size = 1;
+    case.toCKB_cells.outputs[0].type_args.legacy = true;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_legacy_amount() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.lot_size = 1;
+    case.toCKB_cells.inputs[0].data.amount = None;
+    case.toCKB_cells.outputs[0].data.lot_size = 1;
+    case.toCKB_cells.outputs[0].data.amount = Some(20_000_000);
+    case.expect_return_code = LotSizeInvalid as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_lot_size() {
     let mut case = get_correct_btc_case();
@@ -68,6 +229,120 @@ fn test_wrong_btc_difficulty() {
     }
 }
 
+#[test]
+fn test_correct_btc_difficulty_of_previous_epoch() {
+    let mut case = get_correct_btc_case();
+    if let CellDepView::DifficultyOracle(difficulty) = &mut case.cell_deps[0] {
+        difficulty.current = difficulty.previous * 2;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_correct_btc_headers_across_retarget() {
+    assert_eq!(
+        required_difficulty(&[100, 100, 150, 150], 100, 150),
+        Ok(100)
+    );
+    assert_eq!(required_difficulty(&[200, 150, 150], 200, 150), Ok(150));
+}
+
+#[test]
+fn test_wrong_btc_headers_across_retarget() {
+    // retarget from the current epoch
+    assert_eq!(
+        required_difficulty(&[150, 100], 100, 150),
+        Err(BtcRetargetInvalid as i8)
+    );
+    // retarget twice
+    assert_eq!(
+        required_difficulty(&[100, 150, 100], 100, 150),
+        Err(BtcRetargetInvalid as i8)
+    );
+    // retarget to a difficulty other than the current one
+    assert_eq!(
+        required_difficulty(&[100, 120], 100, 150),
+        Err(BtcRetargetInvalid as i8)
+    );
+    // more than 4x change
+    assert_eq!(
+        required_difficulty(&[100, 500], 100, 500),
+        Err(BtcRetargetInvalid as i8)
+    );
+    // longer than an epoch before the retarget
+    let mut diffs = vec![100; 2017];
+    diffs.push(150);
+    assert_eq!(
+        required_difficulty(&diffs, 100, 150),
+        Err(BtcRetargetInvalid as i8)
+    );
+}
+
+#[test]
+fn test_wrong_btc_spv_confirmations() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps[1] = CellDepView::ProtocolParams(Default::default());
+    case.expect_return_code = BtcHeaderNotConfirmed as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_64_bytes_tx() {
+    let mut case = get_correct_btc_case();
+    if let Witness::Btc(btc_witness) = &mut case.witnesses[0] {
+        // 4 bytes version, 42 bytes vin, 14 bytes vout and 4 bytes locktime
+        btc_witness.spv_proof.vin = format!("0x01{}000000000000000000", "11".repeat(32));
+        btc_witness.spv_proof.vout = "0x0100e1f50500000000040000000a".to_owned();
+        case.expect_return_code = BtcTxLengthInvalid as i8;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_correct_relay_case() {
+    let case = get_correct_relay_case(BTC_SPV_CONFIRMATIONS as usize);
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_relay_header_not_confirmed() {
+    let mut case = get_correct_relay_case(BTC_SPV_CONFIRMATIONS as usize - 1);
+    case.expect_return_code = BtcHeaderNotConfirmed as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_relay_header_not_confirmed_by_params() {
+    let mut case = get_correct_relay_case(BTC_SPV_CONFIRMATIONS as usize);
+    case.cell_deps
+        .push(CellDepView::ProtocolParams(ProtocolParamsView {
+            btc_spv_confirmations: BTC_SPV_CONFIRMATIONS + 1,
+            ..Default::default()
+        }));
+    case.expect_return_code = BtcHeaderNotConfirmed as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_relay_header_not_in_relay() {
+    let mut case = get_correct_relay_case(BTC_SPV_CONFIRMATIONS as usize);
+    if let CellDepView::HeaderRelay(relay) = &mut case.cell_deps[0] {
+        relay.hashes[0] = [1u8; 32];
+        case.expect_return_code = BtcHeaderNotInRelay as i8;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_wrong_header_relay() {
+    let mut case = get_correct_relay_case(BTC_SPV_CONFIRMATIONS as usize);
+    if let CellDepView::HeaderRelay(relay) = &mut case.cell_deps[0] {
+        relay.type_args = Bytes::from("fake relay");
+        case.expect_return_code = HeaderRelayMismatch as i8;
+        case_runner::run_test(case)
+    }
+}
+
 #[test]
 fn test_wrong_toCKB_capacity() {
     let mut case = get_correct_btc_case();
@@ -80,7 +355,7 @@ fn test_wrong_toCKB_capacity() {
 fn test_wrong_pledge_refund() {
     let mut case = get_correct_btc_case();
     case.sudt_cells.outputs[0].capacity = 1;
-    case.expect_return_code = CapacityInvalid as i8;
+    case.expect_return_code = InvalidMintOutput as i8;
     case_runner::run_test(case)
 }
 
@@ -88,22 +363,420 @@ fn test_wrong_pledge_refund() {
 fn test_wrong_signer_xt_cell_capacity() {
     let mut case = get_correct_btc_case();
     case.sudt_cells.outputs[1].capacity = 1;
-    case.expect_return_code = CapacityInvalid as i8;
+    case.expect_return_code = InvalidMintOutput as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_eth_case() {
+    let case = get_correct_eth_case(ETH_LOT_AMOUNT);
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_eth_funding_not_enough() {
+    let mut case = get_correct_eth_case(ETH_LOT_AMOUNT - 1);
+    case.expect_return_code = FundingNotEnough as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_eth_wrong_x_lock_address() {
+    let mut case = get_correct_eth_case(ETH_LOT_AMOUNT);
+    case.toCKB_cells.inputs[0].data.x_lock_address = "11111111111111111111".to_owned();
+    case.toCKB_cells.outputs[0].data.x_lock_address = "11111111111111111111".to_owned();
+    case.expect_return_code = WrongFundingAddr as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_eth_header_not_confirmed() {
+    let mut case = get_correct_eth_case(ETH_LOT_AMOUNT);
+    if let CellDepView::ConfirmedHeadersOracle(block_hashes) = &mut case.cell_deps[0] {
+        *block_hashes = vec![hex::encode([1u8; 32])];
+        case.expect_return_code = EthHeaderNotConfirmed as i8;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_eth_wrong_tx_data() {
+    let mut case = get_correct_eth_case(ETH_LOT_AMOUNT);
+    let (_, other_proof) = eth_proof::build_single_transfer_proof(
+        ETH_LOCK_ADDRESS.as_bytes(),
+        ETH_LOT_AMOUNT * 2,
+        true,
+    );
+    if let Witness::Eth(eth_witness) = &mut case.witnesses[0] {
+        eth_witness.spv_proof.tx_data = other_proof.tx_data;
+        case.expect_return_code = WrongTxId as i8;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_eth_failed_tx() {
+    let mut case = get_correct_eth_case(ETH_LOT_AMOUNT);
+    let (block_hash, proof) =
+        eth_proof::build_single_transfer_proof(ETH_LOCK_ADDRESS.as_bytes(), ETH_LOT_AMOUNT, false);
+    case.cell_deps[0] = CellDepView::ConfirmedHeadersOracle(vec![block_hash]);
+    if let Witness::Eth(eth_witness) = &mut case.witnesses[0] {
+        eth_witness.spv_proof = proof;
+        case.expect_return_code = EthTxFailed as i8;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_eth_untrusted_confirmed_headers() {
+    let mut case = get_correct_eth_case(ETH_LOT_AMOUNT);
+    for cell_dep in case.cell_deps.iter_mut() {
+        if let CellDepView::ConfirmedHeadersOracle(block_hashes) = cell_dep {
+            *cell_dep = CellDepView::UntrustedConfirmedHeaders(block_hashes.clone());
+        }
+    }
+    case.expect_return_code = ConfirmedHeadersDataInvalid as i8;
+    case_runner::run_test(case)
+}
+
+fn get_correct_eth_case(value: u128) -> TestCase {
+    let (block_hash, proof) =
+        eth_proof::build_single_transfer_proof(ETH_LOCK_ADDRESS.as_bytes(), value, true);
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![CellDepView::ConfirmedHeadersOracle(vec![block_hash])];
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.type_args.xchain_kind = 2;
+        cell.data.amount = Some(ETH_LOT_AMOUNT);
+        cell.data.x_lock_address = ETH_LOCK_ADDRESS.to_owned();
+        cell.data.x_extra = XExtraView::Eth(Default::default());
+    }
+    let eth_extra = || {
+        XExtraView::Eth(EthExtraView {
+            lock_tx_hash: eth_proof::get_tx_hash(&proof),
+            lock_tx_index: 0,
+            lock_block_number: 1,
+        })
+    };
+    case.toCKB_cells.outputs[0].data.x_extra = eth_extra();
+    case.lock_registry.as_mut().unwrap().inserted = eth_extra();
+    case.sudt_cells.outputs[0].amount = 249_500_000_000_000_000;
+    case.sudt_cells.outputs[1].amount = 500_000_000_000_000;
+    case.witnesses = vec![Witness::Eth(EthWitness {
+        cell_dep_index_list: vec![0],
+        spv_proof: proof,
+    })];
+    case
+}
+
+#[test]
+fn test_correct_erc20_case() {
+    let (block_hash, proof) = eth_proof::build_single_token_transfer_proof(
+        ERC20_TOKEN_ADDRESS.as_bytes(),
+        ETH_LOCK_ADDRESS.as_bytes(),
+        ERC20_LOT_AMOUNT,
+    );
+    let case = get_correct_erc20_case(block_hash, proof);
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_erc20_funding_not_enough() {
+    let (block_hash, proof) = eth_proof::build_single_token_transfer_proof(
+        ERC20_TOKEN_ADDRESS.as_bytes(),
+        ETH_LOCK_ADDRESS.as_bytes(),
+        ERC20_LOT_AMOUNT - 1,
+    );
+    let mut case = get_correct_erc20_case(block_hash, proof);
+    case.expect_return_code = FundingNotEnough as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_erc20_wrong_x_lock_address() {
+    let (block_hash, proof) = eth_proof::build_single_token_transfer_proof(
+        ERC20_TOKEN_ADDRESS.as_bytes(),
+        "11111111111111111111".as_bytes(),
+        ERC20_LOT_AMOUNT,
+    );
+    let mut case = get_correct_erc20_case(block_hash, proof);
+    case.expect_return_code = WrongFundingAddr as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_erc20_wrong_token() {
+    // the transfer event of another token does not fund the deposit
+    let (block_hash, proof) = eth_proof::build_single_token_transfer_proof(
+        "11111111111111111111".as_bytes(),
+        ETH_LOCK_ADDRESS.as_bytes(),
+        ERC20_LOT_AMOUNT,
+    );
+    let mut case = get_correct_erc20_case(block_hash, proof);
+    case.expect_return_code = WrongFundingAddr as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_erc20_eth_transfer() {
+    // sending ETH to x_lock_address does not fund a token deposit
+    let (block_hash, proof) =
+        eth_proof::build_single_transfer_proof(ETH_LOCK_ADDRESS.as_bytes(), ERC20_LOT_AMOUNT, true);
+    let mut case = get_correct_erc20_case(block_hash, proof);
+    case.expect_return_code = WrongFundingAddr as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_erc20_wrong_xt_args() {
+    let (block_hash, proof) = eth_proof::build_single_token_transfer_proof(
+        ERC20_TOKEN_ADDRESS.as_bytes(),
+        ETH_LOCK_ADDRESS.as_bytes(),
+        ERC20_LOT_AMOUNT,
+    );
+    let mut case = get_correct_erc20_case(block_hash, proof);
+    for cell in case.sudt_cells.outputs.iter_mut() {
+        cell.token_address = None;
+    }
+    case.expect_return_code = InvalidXTInInputOrOutput as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_erc20_invalid_token_address() {
+    let (block_hash, proof) = eth_proof::build_single_token_transfer_proof(
+        ERC20_TOKEN_ADDRESS.as_bytes(),
+        ETH_LOCK_ADDRESS.as_bytes(),
+        ERC20_LOT_AMOUNT,
+    );
+    let mut case = get_correct_erc20_case(block_hash, proof);
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        if let XExtraView::Erc20(erc20_extra) = &mut cell.data.x_extra {
+            erc20_extra.token_address.pop();
+        }
+    }
+    case.expect_return_code = XChainAddressInvalid as i8;
     case_runner::run_test(case)
 }
 
+fn get_correct_erc20_case(block_hash: String, proof: ETHSPVProofJson) -> TestCase {
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![CellDepView::ConfirmedHeadersOracle(vec![block_hash])];
+    let erc20_extra = |lock: EthExtraView| {
+        XExtraView::Erc20(Erc20ExtraView {
+            token_address: ERC20_TOKEN_ADDRESS.to_owned(),
+            lock,
+        })
+    };
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.type_args.xchain_kind = 4;
+        cell.data.amount = Some(ERC20_LOT_AMOUNT);
+        cell.data.x_lock_address = ETH_LOCK_ADDRESS.to_owned();
+        cell.data.x_extra = erc20_extra(Default::default());
+    }
+    let lock = || EthExtraView {
+        lock_tx_hash: eth_proof::get_tx_hash(&proof),
+        lock_tx_index: 0,
+        lock_block_number: 1,
+    };
+    case.toCKB_cells.outputs[0].data.x_extra = erc20_extra(lock());
+    case.lock_registry.as_mut().unwrap().inserted = erc20_extra(lock());
+    case.sudt_cells.outputs[0].amount = 998_000_000_000_000_000_000;
+    case.sudt_cells.outputs[1].amount = 2_000_000_000_000_000_000;
+    for cell in case.sudt_cells.outputs.iter_mut() {
+        cell.token_address = Some(ERC20_TOKEN_ADDRESS.to_owned());
+    }
+    case.witnesses = vec![Witness::Eth(EthWitness {
+        cell_dep_index_list: vec![0],
+        spv_proof: proof,
+    })];
+    case
+}
+
+#[test]
+fn test_correct_ltc_case() {
+    let case = get_correct_ltc_case();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_ltc_header_not_confirmed() {
+    let mut case = get_correct_ltc_case();
+    if let Witness::Btc(btc_witness) = &mut case.witnesses[0] {
+        // one header short of 4 times the confirmations of BTC
+        let headers = &btc_witness.spv_proof.headers;
+        btc_witness.spv_proof.headers = headers[..headers.len() - 160].to_owned();
+        case.expect_return_code = BtcHeaderNotConfirmed as i8;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_wrong_ltc_header_pow() {
+    let mut case = get_correct_ltc_case();
+    if let Witness::Btc(btc_witness) = &mut case.witnesses[0] {
+        let mut headers = hex::decode(clear_0x(&btc_witness.spv_proof.headers)).unwrap();
+        let len = headers.len();
+        headers[len - 8..len - 4].copy_from_slice(&0x1e0f_ffffu32.to_le_bytes());
+        btc_witness.spv_proof.headers = format!("0x{}", hex::encode(headers));
+        case.expect_return_code = LtcHeaderInvalid as i8;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_wrong_ltc_header_relay() {
+    let mut case = get_correct_ltc_case();
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.type_args.header_relay = Some([1u8; 32]);
+    }
+    case.expect_return_code = HeaderRelayMismatch as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_ltc_wrong_x_lock_address() {
+    let mut case = get_correct_ltc_case();
+    // the BTC address of the same witness program
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.data.x_lock_address = "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_owned();
+    }
+    case.expect_return_code = WrongFundingAddr as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_ltc_genesis_header_pow() {
+    let mut genesis = hex::decode("010000000000000000000000000000000000000000000000000000000000000000000000d9ced4ed1130f7b7faad9be25323ffafa33232a17c3edf6cfd97bee6bafbdd97b9aa8e4ef0ff0f1ecd513f7c").unwrap();
+    assert!(ltc_pow::validate_header_chain(&genesis).is_ok());
+    genesis[76] += 1;
+    assert_eq!(
+        ltc_pow::validate_header_chain(&genesis).map_err(|err| err as i8),
+        Err(LtcHeaderInvalid as i8)
+    );
+}
+
+fn get_correct_ltc_case() -> TestCase {
+    let mut case = get_correct_btc_case();
+    case.cell_deps[0] = CellDepView::DifficultyOracle(DifficultyOracle {
+        previous: 0,
+        current: 0,
+    });
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.type_args.xchain_kind = 3;
+        cell.data.amount = Some(LTC_LOT_AMOUNT);
+        cell.data.x_lock_address = LTC_LOCK_ADDRESS.to_owned();
+        cell.data.x_extra = XExtraView::Ltc(Default::default());
+    }
+    let ltc_extra = || {
+        XExtraView::Ltc(BtcExtraView {
+            lock_tx_hash: "2b21846ae6f15cc29e41b2846c78d756abfedb0d6fea7222263cac0024713bc3"
+                .to_owned(),
+            lock_vout_index: 0,
+        })
+    };
+    case.toCKB_cells.outputs[0].data.x_extra = ltc_extra();
+    case.lock_registry.as_mut().unwrap().inserted = ltc_extra();
+    case.sudt_cells.outputs[0].amount = 299_400_000;
+    case.sudt_cells.outputs[1].amount = 600_000;
+    if let Witness::Btc(btc_witness) = &mut case.witnesses[0] {
+        // a block of the lock tx and one more tx
+        btc_witness.spv_proof.index = 0;
+        btc_witness.spv_proof.headers = LTC_HEADERS.to_owned();
+        btc_witness.spv_proof.intermediate_nodes = format!("0x{}", "11".repeat(32));
+    }
+    case
+}
+
+fn required_difficulty(header_diffs: &[u64], previous: u64, current: u64) -> Result<u64, i8> {
+    let header_diffs = header_diffs
+        .iter()
+        .map(|diff| U256::from(*diff))
+        .collect::<Vec<_>>();
+    get_spv_required_difficulty(&header_diffs, previous.into(), current.into())
+        .map(|diff| diff.as_u64())
+        .map_err(|err| err as i8)
+}
+
+fn get_correct_relay_case(confirmations: usize) -> TestCase {
+    let mut case = get_correct_btc_case();
+    let mut hashes = vec![[0u8; 32]; confirmations];
+    if let Witness::Btc(btc_witness) = &case.witnesses[0] {
+        let headers = hex::decode(clear_0x(&btc_witness.spv_proof.headers)).unwrap();
+        hashes[0] = hash256(&headers[headers.len() - 80..]);
+    }
+    for (i, hash) in hashes.iter_mut().enumerate().skip(1) {
+        hash[0] = i as u8;
+    }
+    let relay = HeaderRelay {
+        type_args: Bytes::from("btc header relay"),
+        height: 645340 + confirmations as u64,
+        hashes,
+    };
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.type_args.header_relay = Some(relay.type_hash());
+    }
+    case.cell_deps = vec![CellDepView::HeaderRelay(relay)];
+    case
+}
+
 fn get_correct_btc_case() -> TestCase {
     TestCase {
-        cell_deps: vec![CellDepView::DifficultyOracle(DifficultyOracle {
-            previous: 17557993035167,
-            current: 17557993035167,
-        })],
+        cell_deps: vec![
+            CellDepView::DifficultyOracle(DifficultyOracle {
+                previous: 17557993035167,
+                current: 17557993035167,
+            }),
+            CellDepView::single_header_protocol_params(),
+        ],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: COLLATERAL,
                 data: ToCKBCellDataView {
                     status: 2,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_string(),
                     signer_lockscript: Default::default(),
@@ -114,6 +787,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -123,7 +799,13 @@ fn get_correct_btc_case() -> TestCase {
                 capacity: COLLATERAL - PLEDGE - XT_CELL_CAPACITY,
                 data: ToCKBCellDataView {
                     status: 3,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d".to_string(),
                     signer_lockscript: Default::default(),
@@ -139,6 +821,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -153,6 +838,7 @@ fn get_correct_btc_case() -> TestCase {
                     amount: 24950000,
                     lockscript: Default::default(),
                     owner_script: Default::default(),
+                    token_address: None,
                     index: 1,
                 },
                 SudtCell {
@@ -160,6 +846,7 @@ fn get_correct_btc_case() -> TestCase {
                     amount: 50000,
                     lockscript: Default::default(),
                     owner_script: Default::default(),
+                    token_address: None,
                     index: 2,
                 },
             ],
@@ -180,6 +867,15 @@ fn get_correct_btc_case() -> TestCase {
                 funding_input_index: 0,
             },
         })],
+        lock_registry: Some(LockRegistryCells {
+            registered: vec![],
+            inserted: XExtraView::Btc(BtcExtraView {
+                lock_tx_hash: "2b21846ae6f15cc29e41b2846c78d756abfedb0d6fea7222263cac0024713bc3".to_owned(),
+                lock_vout_index: 0,
+            }),
+            input_index: 1,
+            output_index: 3,
+        }),
         expect_return_code: 0,
     }
 }
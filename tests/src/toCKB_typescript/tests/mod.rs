@@ -10,9 +10,15 @@ mod liquidation_faulty_warranty;
 mod liquidation_signertimeout;
 mod liquidation_undercollateral;
 mod mint_xt;
+mod partial_redeem;
 mod pre_undercollateral_redeem;
 mod preterm_redeem;
+mod price_oracle;
+mod signer_handover;
+mod signer_handover_request;
+mod topup_collateral;
 mod withdraw_collateral;
+mod withdraw_partial_collateral;
 mod withdraw_pledge;
 mod withdraw_pledge_collateral;
 
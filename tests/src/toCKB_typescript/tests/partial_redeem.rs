@@ -0,0 +1,119 @@
+use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
+use tockb_types::{
+    config::{
+        BTC_LOT_AMOUNT_RANGE, CKB_UNITS, SIGNER_FEE_RATE, SIGNER_FEE_RATE_DENOMINATOR,
+        XT_CELL_CAPACITY,
+    },
+    Error,
+};
+
+const INPUT_TOCKB_CELL_CAPACITY: u64 = 100_000 * CKB_UNITS;
+const LOT_AMOUNT: u128 = 25_000_000;
+const BTC_BURN_AMOUNT: u128 = 10_000_000;
+const SIGNER_FEE: u128 = BTC_BURN_AMOUNT * SIGNER_FEE_RATE as u128 / SIGNER_FEE_RATE_DENOMINATOR;
+
+#[test]
+fn test_correct_tx() {
+    let case = get_correct_btc_case_if_redeemer_is_user();
+    case_runner::run_test(case);
+
+    let case = get_correct_btc_case_if_redeemer_is_not_user();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_legacy_lot_size() {
+    let mut case = get_correct_btc_case_if_redeemer_is_user();
+    case.toCKB_cells.inputs[0].data.lot_size = 1;
+    case.toCKB_cells.inputs[0].data.amount = None;
+    case.toCKB_cells.outputs[0].data.lot_size = 1;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_whole_lot_burned() {
+    let mut case = get_correct_btc_case_if_redeemer_is_user();
+    case.sudt_cells.inputs[0].amount = LOT_AMOUNT;
+    case.expect_return_code = Error::XTBurnInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_redeem_amount() {
+    let mut case = get_correct_btc_case_if_redeemer_is_user();
+    case.toCKB_cells.outputs[0].data.redeem_amount = LOT_AMOUNT;
+    case.sudt_cells.inputs[0].amount = LOT_AMOUNT;
+    case.expect_return_code = Error::RedeemAmountInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_dust_redeem_amount() {
+    let mut case = get_correct_btc_case_if_redeemer_is_user();
+    let redeem_amount = BTC_LOT_AMOUNT_RANGE.0 - 1;
+    case.toCKB_cells.outputs[0].data.redeem_amount = redeem_amount;
+    case.sudt_cells.inputs[0].amount = redeem_amount;
+    case.expect_return_code = Error::RedeemAmountInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_dust_remainder() {
+    let mut case = get_correct_btc_case_if_redeemer_is_user();
+    let redeem_amount = LOT_AMOUNT - BTC_LOT_AMOUNT_RANGE.0 + 1;
+    case.toCKB_cells.outputs[0].data.redeem_amount = redeem_amount;
+    case.sudt_cells.inputs[0].amount = redeem_amount;
+    case.expect_return_code = Error::RedeemAmountInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_modified_amount() {
+    let mut case = get_correct_btc_case_if_redeemer_is_user();
+    case.toCKB_cells.outputs[0].data.amount = Some(LOT_AMOUNT - BTC_BURN_AMOUNT);
+    case.expect_return_code = Error::InvariantDataMutated as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_fee_refund() {
+    let mut case = get_correct_btc_case_if_redeemer_is_not_user();
+    case.sudt_cells.outputs.pop();
+    case.expect_return_code = Error::XTBurnInvalid as i8;
+    case_runner::run_test(case)
+}
+
+fn get_correct_btc_case_if_redeemer_is_user() -> TestCase {
+    let mut output = btc_warranty_cell(INPUT_TOCKB_CELL_CAPACITY);
+    output.data.status = 4;
+    output.data.redeem_amount = BTC_BURN_AMOUNT;
+    output.data.x_unlock_address = BTC_LOCK_ADDRESS.to_string();
+    let mut case = toCKB_cell_case(btc_warranty_cell(INPUT_TOCKB_CELL_CAPACITY), output);
+    case.sudt_cells.inputs.push(SudtCell {
+        capacity: 210 * CKB_UNITS,
+        amount: BTC_BURN_AMOUNT,
+        lockscript: Default::default(),
+        owner_script: Default::default(),
+        token_address: None,
+        index: 1,
+    });
+    case
+}
+
+fn get_correct_btc_case_if_redeemer_is_not_user() -> TestCase {
+    let mut case = get_correct_btc_case_if_redeemer_is_user();
+    case.sudt_cells.inputs[0].amount = BTC_BURN_AMOUNT + SIGNER_FEE;
+    case.sudt_cells.inputs[0].lockscript = ScriptView {
+        outpoint_key: ALWAYS_SUCCESS_OUTPOINT_KEY,
+        args: Bytes::from("redeemer_is_not_user"),
+    };
+    case.sudt_cells.outputs.push(SudtCell {
+        capacity: XT_CELL_CAPACITY,
+        amount: SIGNER_FEE,
+        lockscript: Default::default(),
+        owner_script: Default::default(),
+        token_address: None,
+        index: 1,
+    });
+    case
+}
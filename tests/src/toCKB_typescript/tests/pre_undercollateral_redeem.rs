@@ -1,10 +1,10 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
-    config::{CKB_UNITS, PRE_UNDERCOLLATERAL_RATE, XT_CELL_CAPACITY},
+    config::{CKB_UNITS, PLEDGE, PRE_UNDERCOLLATERAL_RATE, SIGNER_FEE_RATE, XT_CELL_CAPACITY},
     Error,
 };
 
-const BTC_PRICE: u128 = 100_000;
+pub(super) const BTC_PRICE: u128 = 100_000;
 const BTC_BURN_AMOUNT: u128 = 25_000_000;
 const TOCKB_INPUT_CELL_CAPACITY: u64 =
     (BTC_BURN_AMOUNT / BTC_PRICE * CKB_UNITS as u128 * PRE_UNDERCOLLATERAL_RATE as u128 / 100)
@@ -21,9 +21,7 @@ fn test_correct_btc_tx() {
 #[test]
 fn test_wrong_price_condition() {
     let mut case = get_correct_btc_case();
-    if let CellDepView::PriceOracle(price) = &mut case.cell_deps[0] {
-        *price = 2 * BTC_PRICE;
-    }
+    case.cell_deps = price_oracles(2 * BTC_PRICE);
     case.expect_return_code = Error::UndercollateralInvalid as i8;
     case_runner::run_test(case)
 }
@@ -47,15 +45,23 @@ fn test_wrong_xt_burn() {
     case_runner::run_test(case)
 }
 
-fn get_correct_btc_case() -> TestCase {
+pub(super) fn get_correct_btc_case() -> TestCase {
     TestCase {
-        cell_deps: vec![CellDepView::PriceOracle(BTC_PRICE)],
+        cell_deps: price_oracles(BTC_PRICE),
+        header_deps: vec![PRICE_ORACLE_TIMESTAMP],
+        toCKB_input_header: Some(PRICE_ORACLE_TIMESTAMP),
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: TOCKB_INPUT_CELL_CAPACITY,
                 data: ToCKBCellDataView {
                     status: 3,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -66,6 +72,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -79,6 +88,7 @@ fn get_correct_btc_case() -> TestCase {
                 amount: BTC_BURN_AMOUNT,
                 lockscript: Default::default(),
                 owner_script: Default::default(),
+                token_address: None,
                 index: 1,
             }],
             outputs: vec![],
@@ -92,6 +102,7 @@ fn get_correct_btc_case() -> TestCase {
             }],
         },
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
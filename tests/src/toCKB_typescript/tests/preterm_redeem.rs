@@ -1,12 +1,12 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
-    config::{CKB_UNITS, SIGNER_FEE_RATE, XT_CELL_CAPACITY},
+    config::{CKB_UNITS, PLEDGE, SIGNER_FEE_RATE, SIGNER_FEE_RATE_DENOMINATOR, XT_CELL_CAPACITY},
     Error,
 };
 
 const INPUT_TOCKB_CELL_CAPACITY: u64 = 100_000 * CKB_UNITS;
 const BTC_BURN_AMOUNT: u128 = 25_000_000;
-const SIGNER_FEE: u128 = BTC_BURN_AMOUNT * SIGNER_FEE_RATE.0 / SIGNER_FEE_RATE.1;
+const SIGNER_FEE: u128 = BTC_BURN_AMOUNT * SIGNER_FEE_RATE as u128 / SIGNER_FEE_RATE_DENOMINATOR;
 
 #[test]
 fn test_correct_tx() {
@@ -26,6 +26,14 @@ fn test_wrong_x_address() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_legacy_x_address() {
+    let mut case = get_correct_btc_case_if_redeemer_is_user();
+    case.toCKB_cells.outputs[0].data.x_unlock_address =
+        "mhds2Q22ECZhgx4tgDvvy4zphsY6vDzFTH".to_string();
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_lock_address_modified() {
     let mut case = get_correct_btc_case_if_redeemer_is_user();
@@ -54,12 +62,20 @@ fn test_wrong_signer_fee_refund() {
 fn get_correct_btc_case_if_redeemer_is_user() -> TestCase {
     TestCase {
         cell_deps: vec![],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: INPUT_TOCKB_CELL_CAPACITY,
                 data: ToCKBCellDataView {
                     status: 3,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -70,6 +86,9 @@ fn get_correct_btc_case_if_redeemer_is_user() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -79,7 +98,13 @@ fn get_correct_btc_case_if_redeemer_is_user() -> TestCase {
                 capacity: INPUT_TOCKB_CELL_CAPACITY,
                 data: ToCKBCellDataView {
                     status: 4,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -90,6 +115,9 @@ fn get_correct_btc_case_if_redeemer_is_user() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -102,12 +130,14 @@ fn get_correct_btc_case_if_redeemer_is_user() -> TestCase {
                 amount: BTC_BURN_AMOUNT,
                 lockscript: Default::default(),
                 owner_script: Default::default(),
+                token_address: None,
                 index: 1,
             }],
             outputs: vec![],
         },
         capacity_cells: Default::default(),
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
@@ -124,6 +154,7 @@ fn get_correct_btc_case_if_redeemer_is_not_user() -> TestCase {
         amount: SIGNER_FEE,
         lockscript: Default::default(),
         owner_script: Default::default(),
+        token_address: None,
         index: 1,
     });
     case
@@ -0,0 +1,168 @@
+use super::pre_undercollateral_redeem::{get_correct_btc_case, BTC_PRICE};
+use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
+use tockb_types::{config::PRICE_ORACLE_MAX_AGE, Error};
+
+// the pre undercollateral redeem case is undercollateral at BTC_PRICE and not at any higher price,
+// so it tells whether the median price is at most BTC_PRICE
+
+#[test]
+fn test_median_price() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![
+        price_oracle(1, 100 * BTC_PRICE),
+        price_oracle(2, BTC_PRICE),
+        price_oracle(3, BTC_PRICE / 2),
+    ];
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_median_price_above() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![
+        price_oracle(1, BTC_PRICE / 100),
+        price_oracle(2, 2 * BTC_PRICE),
+        price_oracle(3, 2 * BTC_PRICE),
+    ];
+    case.expect_return_code = Error::UndercollateralInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_median_price_of_even_prices() {
+    for (highest_price, expect_return_code) in &[
+        (BTC_PRICE, 0),
+        (2 * BTC_PRICE, Error::UndercollateralInvalid as i8),
+    ] {
+        let mut case = get_correct_btc_case();
+        case.cell_deps = vec![
+            price_oracle(1, BTC_PRICE / 2),
+            price_oracle(2, BTC_PRICE),
+            price_oracle(3, *highest_price),
+            price_oracle(4, 4 * BTC_PRICE),
+        ];
+        case.expect_return_code = *expect_return_code;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_stale_price() {
+    let mut case = get_correct_btc_case();
+    case.header_deps = vec![
+        PRICE_ORACLE_TIMESTAMP,
+        PRICE_ORACLE_TIMESTAMP + PRICE_ORACLE_MAX_AGE + 1,
+    ];
+    case.expect_return_code = Error::PriceOracleStale as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_price_at_max_age() {
+    let mut case = get_correct_btc_case();
+    case.header_deps = vec![
+        PRICE_ORACLE_TIMESTAMP,
+        PRICE_ORACLE_TIMESTAMP + PRICE_ORACLE_MAX_AGE,
+    ];
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_stale_price_without_header_dep() {
+    let mut case = get_correct_btc_case();
+    case.header_deps = vec![];
+    case.expect_return_code = Error::PriceOracleStale as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_stale_price_without_input_header() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_input_header = Some(PRICE_ORACLE_TIMESTAMP + PRICE_ORACLE_MAX_AGE + 1);
+    case.expect_return_code = Error::PriceOracleStale as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_duplicated_publisher() {
+    // an old round of the first publisher next to its new round
+    let mut case = get_correct_btc_case();
+    case.cell_deps.push(CellDepView::PriceOracle(PriceOracle {
+        publisher: 1,
+        price: BTC_PRICE,
+        timestamp: PRICE_ORACLE_TIMESTAMP - 1,
+        type_index: Some(0),
+    }));
+    case.expect_return_code = Error::PriceOracleInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_not_whitelisted_publisher() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps[0] = CellDepView::PriceOracle(PriceOracle {
+        publisher: 9,
+        price: BTC_PRICE,
+        timestamp: PRICE_ORACLE_TIMESTAMP,
+        type_index: Some(0),
+    });
+    case.expect_return_code = Error::PriceOraclePublisherInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_publisher_in_cell_of_another() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps[0] = CellDepView::PriceOracle(PriceOracle {
+        publisher: 4,
+        price: BTC_PRICE,
+        timestamp: PRICE_ORACLE_TIMESTAMP,
+        type_index: Some(0),
+    });
+    case.expect_return_code = Error::PriceOraclePublisherInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_below_threshold() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps.pop();
+    case.expect_return_code = Error::PriceOracleNotEnough as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_price_without_oracle_type_not_counted() {
+    let mut case = get_correct_btc_case();
+    let last = case.cell_deps.len() - 1;
+    case.cell_deps[last] = CellDepView::PriceOracle(PriceOracle {
+        publisher: 3,
+        price: BTC_PRICE,
+        timestamp: PRICE_ORACLE_TIMESTAMP,
+        type_index: None,
+    });
+    case.expect_return_code = Error::PriceOracleNotEnough as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_skip_unrelated_cell_dep() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps.push(CellDepView::PriceOracle(PriceOracle {
+        publisher: 9,
+        price: 100 * BTC_PRICE,
+        timestamp: PRICE_ORACLE_TIMESTAMP,
+        type_index: None,
+    }));
+    case_runner::run_test(case)
+}
+
+/// Fresh price of `publisher` in its own price cell
+fn price_oracle(publisher: u8, price: u128) -> CellDepView {
+    CellDepView::PriceOracle(PriceOracle {
+        publisher,
+        price,
+        timestamp: PRICE_ORACLE_TIMESTAMP,
+        type_index: Some(publisher as usize - 1),
+    })
+}
@@ -0,0 +1,239 @@
+use crate::toCKB_typescript::utils::{case_builder::*, case_runner, eth_proof};
+use tockb_types::{config::CKB_UNITS, Error};
+
+const COLLATERAL: u64 = 100_000 * CKB_UNITS;
+const OLD_LOCK_ADDRESS: &str = "bcrt1q4r9hqljdpfwxu6gp3x7qqedg77r6408dn4wmnf";
+const NEW_LOCK_ADDRESS: &str = "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d";
+const LOCK_TX_HASH: &str = "5227c5fbad9d9202ade7f02452cf880dac1ed270255ebfe6716e8b3e8956571d";
+const NEW_LOCK_TX_HASH: &str = "2b21846ae6f15cc29e41b2846c78d756abfedb0d6fea7222263cac0024713bc3";
+const NEW_ETH_LOCK_ADDRESS: &str = "0x2222222222222222222222222222222222222222";
+const ETH_LOT_AMOUNT: u128 = 250_000_000_000_000_000;
+
+#[test]
+fn test_correct_tx() {
+    let case = get_correct_btc_case();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_collateral_not_released() {
+    let mut case = get_correct_btc_case();
+    case.capacity_cells.outputs[0].capacity = COLLATERAL - 1;
+    case.expect_return_code = Error::CapacityInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_toCKB_capacity() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].capacity = 2 * COLLATERAL;
+    case.expect_return_code = Error::CapacityInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_moved_to_other_address() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.x_unlock_address = OLD_LOCK_ADDRESS.to_string();
+    case.toCKB_cells.outputs[0].data.x_lock_address = OLD_LOCK_ADDRESS.to_string();
+    case.toCKB_cells.outputs[0].data.x_unlock_address = OLD_LOCK_ADDRESS.to_string();
+    case.expect_return_code = Error::WrongFundingAddr as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_not_spend_locked_btc() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.x_extra = XExtraView::Btc(BtcExtraView {
+        lock_tx_hash: LOCK_TX_HASH.to_string(),
+        lock_vout_index: 0,
+    });
+    case.expect_return_code = Error::SignerHandoverInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_unchanged() {
+    // a handover can not be cancelled back to the current signer, it ends by a proof of the move
+    // or by liquidation
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.signer_lockscript = Default::default();
+    case.expect_return_code = Error::InvalidDataChange as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_not_changed_to_new_signer() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.signer_lockscript = ScriptView {
+        outpoint_key: ALWAYS_SUCCESS_OUTPOINT_KEY,
+        args: Bytes::from("other_signer"),
+    };
+    case.expect_return_code = Error::InvalidDataChange as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_x_extra() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.x_extra = XExtraView::Btc(BtcExtraView {
+        lock_tx_hash: LOCK_TX_HASH.to_string(),
+        lock_vout_index: 1,
+    });
+    case.expect_return_code = Error::InvalidDataChange as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_btc_proof() {
+    let mut case = get_correct_btc_case();
+    if let Witness::Btc(btc_witness) = &mut case.witnesses[0] {
+        btc_witness.spv_proof.index = 2;
+        case.expect_return_code = Error::BadMerkleProof as i8;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_wrong_without_lock_registry() {
+    let mut case = get_correct_btc_case();
+    case.lock_registry = None;
+    case.expect_return_code = Error::LockRegistryNotFound as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_relocked_to_registered_lock_tx() {
+    let mut case = get_correct_btc_case();
+    case.lock_registry.as_mut().unwrap().registered = vec![XExtraView::Btc(BtcExtraView {
+        lock_tx_hash: NEW_LOCK_TX_HASH.to_string(),
+        lock_vout_index: 0,
+    })];
+    case.expect_return_code = Error::XLockTxReused as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_eth_tx() {
+    let case = get_correct_eth_case(get_handover_tx(
+        &eth_proof::default_signer_key(),
+        ETH_LOT_AMOUNT,
+    ));
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_amount() {
+    let mut case = get_correct_eth_case(get_handover_tx(
+        &eth_proof::default_signer_key(),
+        ETH_LOT_AMOUNT - 1,
+    ));
+    case.expect_return_code = Error::FundingNotEnough as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_moved_to_other_address() {
+    let tx_data =
+        eth_proof::build_transfer_tx(&eth_proof::default_signer_key(), &[3u8; 20], ETH_LOT_AMOUNT);
+    let mut case = get_correct_eth_case(tx_data);
+    case.expect_return_code = Error::WrongFundingAddr as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_sender() {
+    // paying the new signer from another address leaves the locked eth at x_lock_address
+    let other_key = secp256k1::SecretKey::from_slice(&[2u8; 32]).expect("build secret key");
+    let mut case = get_correct_eth_case(get_handover_tx(&other_key, ETH_LOT_AMOUNT));
+    case.expect_return_code = Error::SignerHandoverInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_tx_before_lock() {
+    let tx_data = get_handover_tx(&eth_proof::default_signer_key(), ETH_LOT_AMOUNT);
+    let mut case = get_correct_eth_case(tx_data.clone());
+    set_eth_spv_proof(&mut case, tx_data, vec![], 1);
+    case.expect_return_code = Error::SignerHandoverInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_x_extra() {
+    let mut case = get_correct_eth_case(get_handover_tx(
+        &eth_proof::default_signer_key(),
+        ETH_LOT_AMOUNT,
+    ));
+    if let XExtraView::Eth(eth_extra) = &mut case.toCKB_cells.outputs[0].data.x_extra {
+        eth_extra.lock_block_number = 1;
+    }
+    case.expect_return_code = Error::InvalidDataChange as i8;
+    case_runner::run_test(case)
+}
+
+fn new_signer_lockscript() -> ScriptView {
+    ScriptView {
+        outpoint_key: ALWAYS_SUCCESS_OUTPOINT_KEY,
+        args: Bytes::from("new_signer"),
+    }
+}
+
+fn get_correct_btc_case() -> TestCase {
+    let input = btc_handover_cell(2 * COLLATERAL, OLD_LOCK_ADDRESS, new_signer_lockscript());
+    let mut output = btc_warranty_cell(COLLATERAL);
+    output.data.signer_lockscript = new_signer_lockscript();
+    output.data.x_unlock_address = NEW_LOCK_ADDRESS.to_string();
+    output.data.redeemer_lockscript = new_signer_lockscript();
+    output.data.x_extra = XExtraView::Btc(BtcExtraView {
+        lock_tx_hash: NEW_LOCK_TX_HASH.to_string(),
+        lock_vout_index: 0,
+    });
+    let mut case = toCKB_cell_case(input, output);
+    case.cell_deps = btc_spv_cell_deps();
+    case.capacity_cells.outputs.push(CapacityCell {
+        capacity: COLLATERAL,
+        lockscript: Default::default(),
+        index: 1,
+    });
+    case.witnesses = vec![btc_lock_tx_spending_witness()];
+    case.lock_registry = Some(LockRegistryCells {
+        registered: vec![],
+        inserted: XExtraView::Btc(BtcExtraView {
+            lock_tx_hash: NEW_LOCK_TX_HASH.to_string(),
+            lock_vout_index: 0,
+        }),
+        input_index: 1,
+        output_index: 2,
+    });
+    case
+}
+
+fn get_handover_tx(privkey: &secp256k1::SecretKey, value: u128) -> Vec<u8> {
+    let new_lock_address = hex::decode(&NEW_ETH_LOCK_ADDRESS[2..]).expect("decode address hex");
+    eth_proof::build_transfer_tx(privkey, &new_lock_address, value)
+}
+
+/// The signer moves the locked eth from x_lock_address to the new signer by `tx_data`
+fn get_correct_eth_case(tx_data: Vec<u8>) -> TestCase {
+    let mut case = get_correct_btc_case();
+    let input = &mut case.toCKB_cells.inputs[0];
+    input.type_args.xchain_kind = 2;
+    input.data.amount = Some(ETH_LOT_AMOUNT);
+    input.data.x_lock_address = eth_proof::get_address(&eth_proof::default_signer_key());
+    input.data.x_unlock_address = NEW_ETH_LOCK_ADDRESS.to_owned();
+    input.data.x_extra = XExtraView::Eth(EthExtraView {
+        lock_tx_hash: hex::encode([1u8; 32]),
+        lock_tx_index: 0,
+        lock_block_number: 1,
+    });
+    let new_lock = set_eth_spv_proof(&mut case, tx_data, vec![], 2);
+    let output = &mut case.toCKB_cells.outputs[0];
+    output.type_args.xchain_kind = 2;
+    output.data.amount = Some(ETH_LOT_AMOUNT);
+    output.data.x_lock_address = NEW_ETH_LOCK_ADDRESS.to_owned();
+    output.data.x_unlock_address = NEW_ETH_LOCK_ADDRESS.to_owned();
+    output.data.x_extra = XExtraView::Eth(new_lock.clone());
+    case.lock_registry.as_mut().unwrap().inserted = XExtraView::Eth(new_lock);
+    case
+}
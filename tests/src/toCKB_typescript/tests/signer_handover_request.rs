@@ -0,0 +1,104 @@
+use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
+use tockb_types::{config::CKB_UNITS, Error};
+
+const COLLATERAL: u64 = 100_000 * CKB_UNITS;
+const OLD_LOCK_ADDRESS: &str = "bcrt1q4r9hqljdpfwxu6gp3x7qqedg77r6408dn4wmnf";
+const NEW_LOCK_ADDRESS: &str = "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d";
+
+#[test]
+fn test_correct_tx() {
+    let case = get_correct_btc_case();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_batch_tx() {
+    let mut case = get_correct_btc_case();
+    batch_toCKB_cells(&mut case, get_correct_btc_case());
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_collateral() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].capacity = 2 * COLLATERAL - 1;
+    case.expect_return_code = Error::CollateralInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_without_signer() {
+    let mut case = get_correct_btc_case();
+    case.capacity_cells.inputs[0].lockscript = ScriptView {
+        outpoint_key: ALWAYS_SUCCESS_OUTPOINT_KEY,
+        args: Bytes::from("not_signer"),
+    };
+    case.expect_return_code = Error::InputSignerInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_new_signer_is_signer() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.redeemer_lockscript = Default::default();
+    case.expect_return_code = Error::SignerHandoverInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_new_lock_address() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.x_unlock_address = "bcrt1qinvalid".to_string();
+    case.expect_return_code = Error::XChainAddressInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_modified_x_lock_address() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.x_lock_address = NEW_LOCK_ADDRESS.to_string();
+    case.expect_return_code = Error::InvariantDataMutated as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_signer_group() {
+    let mut case = get_correct_btc_case();
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.data.signer_group = SignerGroupView {
+            threshold: 1,
+            members: vec![SignerMemberView {
+                lockscript: Default::default(),
+                share: 1,
+                x_pubkey: Default::default(),
+            }],
+        };
+    }
+    case.expect_return_code = Error::SignerHandoverInvalid as i8;
+    case_runner::run_test(case)
+}
+
+fn new_signer_lockscript() -> ScriptView {
+    ScriptView {
+        outpoint_key: ALWAYS_SUCCESS_OUTPOINT_KEY,
+        args: Bytes::from("new_signer"),
+    }
+}
+
+fn get_correct_btc_case() -> TestCase {
+    let mut input = btc_warranty_cell(COLLATERAL);
+    input.data.x_lock_address = OLD_LOCK_ADDRESS.to_string();
+    let output = btc_handover_cell(2 * COLLATERAL, OLD_LOCK_ADDRESS, new_signer_lockscript());
+    let mut case = toCKB_cell_case(input, output);
+    case.capacity_cells.inputs.push(CapacityCell {
+        capacity: COLLATERAL,
+        lockscript: Default::default(),
+        index: 1,
+    });
+    case
+}
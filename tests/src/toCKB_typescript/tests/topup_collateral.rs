@@ -0,0 +1,89 @@
+use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
+use tockb_types::{
+    config::{CKB_UNITS, XT_CELL_CAPACITY},
+    Error,
+};
+
+const TOCKB_CELL_CAPACITY: u64 = 3_750_000u64 * CKB_UNITS + XT_CELL_CAPACITY;
+const TOPUP_CAPACITY: u64 = 1_000_000u64 * CKB_UNITS;
+
+#[test]
+fn test_correct_tx() {
+    let case = get_correct_btc_case();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_batch_tx() {
+    let mut case = get_correct_btc_case();
+    batch_toCKB_cells(&mut case, get_correct_btc_case());
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_capacity_not_increased() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].capacity = TOCKB_CELL_CAPACITY;
+    case.expect_return_code = Error::CollateralInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_mint_xt() {
+    let mut case = get_correct_btc_case();
+    case.sudt_cells.outputs.push(SudtCell {
+        capacity: 200 * CKB_UNITS,
+        amount: 100,
+        lockscript: Default::default(),
+        owner_script: Default::default(),
+        token_address: None,
+        index: 1,
+    });
+    case.expect_return_code = Error::TxInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_lock_registry_update() {
+    let mut case = get_correct_btc_case();
+    case.lock_registry = Some(LockRegistryCells {
+        registered: vec![],
+        inserted: Default::default(),
+        input_index: 2,
+        output_index: 1,
+    });
+    case.expect_return_code = Error::LockRegistryInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_modified_signer_lockscript() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.signer_lockscript = ScriptView {
+        outpoint_key: ALWAYS_SUCCESS_OUTPOINT_KEY,
+        args: Bytes::from("signer"),
+    };
+    case.expect_return_code = Error::InvariantDataMutated as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_modified_x_lock_address() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.x_lock_address = "".to_string();
+    case.expect_return_code = Error::InvariantDataMutated as i8;
+    case_runner::run_test(case)
+}
+
+fn get_correct_btc_case() -> TestCase {
+    let mut case = toCKB_cell_case(
+        btc_warranty_cell(TOCKB_CELL_CAPACITY),
+        btc_warranty_cell(TOCKB_CELL_CAPACITY + TOPUP_CAPACITY),
+    );
+    case.capacity_cells.inputs.push(CapacityCell {
+        capacity: TOPUP_CAPACITY,
+        lockscript: Default::default(),
+        index: 1,
+    });
+    case
+}
@@ -1,7 +1,14 @@
-use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
-use tockb_types::{config::CKB_UNITS, Error};
+use crate::toCKB_typescript::utils::{case_builder::*, case_runner, eth_proof};
+use tockb_types::{
+    config::{CKB_UNITS, PLEDGE, SIGNER_FEE_RATE},
+    Error,
+};
 
 const COLLATERAL: u64 = 100 * CKB_UNITS;
+const ETH_UNLOCK_ADDRESS: &str = "0x2222222222222222222222222222222222222222";
+const ETH_LOT_AMOUNT: u128 = 250_000_000_000_000_000;
+const ERC20_TOKEN_ADDRESS: &str = "a0b86991c6218b36c1d1";
+const ERC20_LOT_AMOUNT: u128 = 1_000_000_000_000_000_000_000;
 
 #[test]
 fn test_correct_tx() {
@@ -39,6 +46,14 @@ fn test_wrong_btc_difficulty() {
     }
 }
 
+#[test]
+fn test_wrong_partial_redeem() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.redeem_amount = 10_000_000;
+    case.expect_return_code = Error::TxInvalid as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_withdraw_amount() {
     let mut case = get_correct_btc_case();
@@ -47,18 +62,164 @@ fn test_wrong_withdraw_amount() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_wrong_batch_tx() {
+    let mut case = get_correct_btc_case();
+    // a topup of another deposit can be batched, but withdrawing pays out for the whole tx
+    let topup_cell = |capacity| {
+        let mut cell = get_correct_btc_case().toCKB_cells.inputs.pop().unwrap();
+        cell.data.status = 3;
+        cell.type_args.cell_id = ToCKBTypeArgsView::cell_id_of(1);
+        cell.capacity = capacity;
+        cell.index = 1;
+        cell
+    };
+    case.toCKB_cells.inputs.push(topup_cell(COLLATERAL));
+    case.toCKB_cells
+        .outputs
+        .push(topup_cell(COLLATERAL + CKB_UNITS));
+    case.expect_return_code = Error::TxInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_eth_tx() {
+    let case = get_correct_eth_case(get_signer_redeem_tx(
+        &eth_proof::default_signer_key(),
+        ETH_LOT_AMOUNT,
+    ));
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_amount() {
+    let mut case = get_correct_eth_case(get_signer_redeem_tx(
+        &eth_proof::default_signer_key(),
+        ETH_LOT_AMOUNT - 1,
+    ));
+    case.expect_return_code = Error::FundingNotEnough as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_unlock_address() {
+    let tx_data =
+        eth_proof::build_transfer_tx(&eth_proof::default_signer_key(), &[3u8; 20], ETH_LOT_AMOUNT);
+    let mut case = get_correct_eth_case(tx_data);
+    case.expect_return_code = Error::WrongFundingAddr as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_sender() {
+    // someone else paying x_unlock_address does not spend the lock of the deposit
+    let other_key = secp256k1::SecretKey::from_slice(&[2u8; 32]).expect("build secret key");
+    let mut case = get_correct_eth_case(get_signer_redeem_tx(&other_key, ETH_LOT_AMOUNT));
+    case.expect_return_code = Error::FaultyEthWitnessInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_tx_before_lock() {
+    let tx_data = get_signer_redeem_tx(&eth_proof::default_signer_key(), ETH_LOT_AMOUNT);
+    let mut case = get_correct_eth_case(tx_data.clone());
+    set_eth_spv_proof(&mut case, tx_data, vec![], 1);
+    case.expect_return_code = Error::FaultyEthWitnessInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_erc20_tx() {
+    let case = get_correct_erc20_case(get_unlock_address(), ERC20_LOT_AMOUNT);
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_erc20_amount() {
+    let mut case = get_correct_erc20_case(get_unlock_address(), ERC20_LOT_AMOUNT - 1);
+    case.expect_return_code = Error::FundingNotEnough as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_erc20_unlock_address() {
+    let mut case = get_correct_erc20_case(vec![3u8; 20], ERC20_LOT_AMOUNT);
+    case.expect_return_code = Error::WrongFundingAddr as i8;
+    case_runner::run_test(case)
+}
+
+fn get_unlock_address() -> Vec<u8> {
+    hex::decode(&ETH_UNLOCK_ADDRESS[2..]).expect("decode address hex")
+}
+
+fn get_signer_redeem_tx(privkey: &secp256k1::SecretKey, value: u128) -> Vec<u8> {
+    eth_proof::build_transfer_tx(privkey, &get_unlock_address(), value)
+}
+
+fn get_correct_eth_case(tx_data: Vec<u8>) -> TestCase {
+    let mut case = get_correct_btc_case();
+    for cell in case.toCKB_cells.inputs.iter_mut() {
+        cell.type_args.xchain_kind = 2;
+        cell.data.amount = Some(ETH_LOT_AMOUNT);
+        cell.data.x_lock_address = eth_proof::get_address(&eth_proof::default_signer_key());
+        cell.data.x_unlock_address = ETH_UNLOCK_ADDRESS.to_owned();
+        cell.data.x_extra = XExtraView::Eth(EthExtraView {
+            lock_tx_hash: hex::encode([1u8; 32]),
+            lock_tx_index: 0,
+            lock_block_number: 1,
+        });
+    }
+    set_eth_spv_proof(&mut case, tx_data, vec![], 2);
+    case
+}
+
+/// The signer moves `value` of the token from x_lock_address to `to`
+fn get_correct_erc20_case(to: Vec<u8>, value: u128) -> TestCase {
+    let signer_key = eth_proof::default_signer_key();
+    let token = ERC20_TOKEN_ADDRESS.as_bytes();
+    let tx_data = eth_proof::build_token_transfer_tx(&signer_key, token, &to, value);
+    let from = hex::decode(&eth_proof::get_address(&signer_key)[2..]).expect("decode address hex");
+    let log = eth_proof::build_transfer_log(token, &from, &to, value);
+    let mut case = get_correct_eth_case(tx_data.clone());
+    for cell in case.toCKB_cells.inputs.iter_mut() {
+        cell.type_args.xchain_kind = 4;
+        cell.data.amount = Some(ERC20_LOT_AMOUNT);
+        cell.data.x_extra = XExtraView::Erc20(Erc20ExtraView {
+            token_address: ERC20_TOKEN_ADDRESS.to_owned(),
+            lock: EthExtraView {
+                lock_tx_hash: hex::encode([1u8; 32]),
+                lock_tx_index: 0,
+                lock_block_number: 1,
+            },
+        });
+    }
+    set_eth_spv_proof(&mut case, tx_data, vec![log], 2);
+    case
+}
+
 fn get_correct_btc_case() -> TestCase {
     TestCase {
-        cell_deps: vec![CellDepView::DifficultyOracle(DifficultyOracle {
-            previous: 17557993035167,
-            current: 17557993035167,
-        })],
+        cell_deps: vec![
+            CellDepView::DifficultyOracle(DifficultyOracle {
+                previous: 17557993035167,
+                current: 17557993035167,
+            }),
+            CellDepView::single_header_protocol_params(),
+        ],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: COLLATERAL,
                 data: ToCKBCellDataView {
                     status: 4,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -69,6 +230,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: 0,
@@ -100,6 +264,7 @@ fn get_correct_btc_case() -> TestCase {
                 funding_input_index: 0,
             },
         })],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
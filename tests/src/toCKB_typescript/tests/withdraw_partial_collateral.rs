@@ -0,0 +1,209 @@
+use crate::toCKB_typescript::utils::{case_builder::*, case_runner, eth_proof};
+use tockb_types::{
+    config::{CKB_UNITS, XT_CELL_CAPACITY},
+    Error,
+};
+
+const LOT_AMOUNT: u128 = 26_000_000;
+const REDEEM_AMOUNT: u128 = 25_000_000;
+// the collateral backing the remaining 1_000_000 satoshi
+const COLLATERAL: u64 = 130_000 * CKB_UNITS;
+const REMAIN_COLLATERAL: u64 = 5_000 * CKB_UNITS;
+const X_LOCK_ADDRESS: &str = "bcrt1qa7txt08c975rapc2x59x25dqnm5pne9r5zeyfd";
+// pays REDEEM_AMOUNT to BTC_LOCK_ADDRESS at output 0 and the remainder back to X_LOCK_ADDRESS at
+// output 1
+const PARTIAL_WITHDRAW_VOUT: &str = "0x0240787d0100000000160014173ec3a12e289b102f8edcc1d4ecd3b5b893e2dc40420f0000000000160014ef9665bcf82fa83e870a350a6551a09ee819e4a3";
+// the same share of the lot as the btc case, so the capacities are the same
+const ETH_LOT_AMOUNT: u128 = 2_600_000_000_000_000_000;
+const ETH_REDEEM_AMOUNT: u128 = 2_500_000_000_000_000_000;
+const ETH_UNLOCK_ADDRESS: &str = "0x2222222222222222222222222222222222222222";
+
+#[test]
+fn test_correct_tx() {
+    let case = get_correct_btc_case();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_not_partial_redeem() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.redeem_amount = 0;
+    case.expect_return_code = Error::TxInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_remain_not_paid_back() {
+    let mut case = get_correct_btc_case();
+    let x_lock_address = "bcrt1qw508d6qejxtdg4y5r3zarvary0c5xw7kygt080".to_string();
+    case.toCKB_cells.inputs[0].data.x_lock_address = x_lock_address.clone();
+    case.toCKB_cells.outputs[0].data.x_lock_address = x_lock_address;
+    case.expect_return_code = Error::PartialWithdrawInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_remain_amount() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].data.amount = Some(LOT_AMOUNT);
+    case.expect_return_code = Error::CapacityInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_x_extra() {
+    let mut case = get_correct_btc_case();
+    if let XExtraView::Btc(btc_extra) = &mut case.toCKB_cells.outputs[0].data.x_extra {
+        btc_extra.lock_vout_index = 0;
+    }
+    case.expect_return_code = Error::InvalidDataChange as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_released_collateral() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.outputs[0].capacity -= CKB_UNITS;
+    case.capacity_cells.outputs[0].capacity += CKB_UNITS;
+    case.expect_return_code = Error::CapacityInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_without_lock_registry() {
+    let mut case = get_correct_btc_case();
+    case.lock_registry = None;
+    case.expect_return_code = Error::LockRegistryNotFound as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_relocked_to_registered_lock_tx() {
+    let mut case = get_correct_btc_case();
+    let registry = case.lock_registry.as_mut().unwrap();
+    if let XExtraView::Btc(new_lock) = &registry.inserted {
+        registry.registered = vec![XExtraView::Btc(BtcExtraView {
+            lock_tx_hash: new_lock.lock_tx_hash.clone(),
+            lock_vout_index: 1,
+        })];
+        case.expect_return_code = Error::XLockTxReused as i8;
+        case_runner::run_test(case)
+    }
+}
+
+#[test]
+fn test_correct_eth_tx() {
+    let case = get_correct_eth_case(get_signer_redeem_tx(
+        &eth_proof::default_signer_key(),
+        ETH_REDEEM_AMOUNT,
+    ));
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_amount() {
+    let mut case = get_correct_eth_case(get_signer_redeem_tx(
+        &eth_proof::default_signer_key(),
+        ETH_REDEEM_AMOUNT - 1,
+    ));
+    case.expect_return_code = Error::FundingNotEnough as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_unlock_address() {
+    let tx_data = eth_proof::build_transfer_tx(
+        &eth_proof::default_signer_key(),
+        &[3u8; 20],
+        ETH_REDEEM_AMOUNT,
+    );
+    let mut case = get_correct_eth_case(tx_data);
+    case.expect_return_code = Error::WrongFundingAddr as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_sender() {
+    // paying x_unlock_address from another address does not withdraw from the deposit
+    let other_key = secp256k1::SecretKey::from_slice(&[2u8; 32]).expect("build secret key");
+    let mut case = get_correct_eth_case(get_signer_redeem_tx(&other_key, ETH_REDEEM_AMOUNT));
+    case.expect_return_code = Error::PartialWithdrawInvalid as i8;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_eth_tx_before_lock() {
+    let tx_data = get_signer_redeem_tx(&eth_proof::default_signer_key(), ETH_REDEEM_AMOUNT);
+    let mut case = get_correct_eth_case(tx_data.clone());
+    set_eth_spv_proof(&mut case, tx_data, vec![], 1);
+    case.expect_return_code = Error::PartialWithdrawInvalid as i8;
+    case_runner::run_test(case)
+}
+
+fn get_signer_redeem_tx(privkey: &secp256k1::SecretKey, value: u128) -> Vec<u8> {
+    let unlock_address = hex::decode(&ETH_UNLOCK_ADDRESS[2..]).expect("decode address hex");
+    eth_proof::build_transfer_tx(privkey, &unlock_address, value)
+}
+
+/// The signer pays the redeemed part from x_lock_address to x_unlock_address by `tx_data`, the
+/// remainder stays at x_lock_address
+fn get_correct_eth_case(tx_data: Vec<u8>) -> TestCase {
+    let mut case = get_correct_btc_case();
+    let x_lock_address = eth_proof::get_address(&eth_proof::default_signer_key());
+    let input = &mut case.toCKB_cells.inputs[0];
+    input.type_args.xchain_kind = 2;
+    input.type_args.header_relay = None;
+    input.data.amount = Some(ETH_LOT_AMOUNT);
+    input.data.redeem_amount = ETH_REDEEM_AMOUNT;
+    input.data.x_lock_address = x_lock_address.clone();
+    input.data.x_unlock_address = ETH_UNLOCK_ADDRESS.to_owned();
+    input.data.x_extra = XExtraView::Eth(EthExtraView {
+        lock_tx_hash: hex::encode([1u8; 32]),
+        lock_tx_index: 0,
+        lock_block_number: 1,
+    });
+    let new_lock = set_eth_spv_proof(&mut case, tx_data, vec![], 2);
+    let output = &mut case.toCKB_cells.outputs[0];
+    output.type_args.xchain_kind = 2;
+    output.type_args.header_relay = None;
+    output.data.amount = Some(ETH_LOT_AMOUNT - ETH_REDEEM_AMOUNT);
+    output.data.x_lock_address = x_lock_address;
+    output.data.x_unlock_address = ETH_UNLOCK_ADDRESS.to_owned();
+    output.data.x_extra = XExtraView::Eth(new_lock.clone());
+    case.lock_registry.as_mut().unwrap().inserted = XExtraView::Eth(new_lock);
+    case
+}
+
+fn get_correct_btc_case() -> TestCase {
+    let mut input = btc_warranty_cell(COLLATERAL + XT_CELL_CAPACITY);
+    input.data.status = 4;
+    input.data.amount = Some(LOT_AMOUNT);
+    input.data.redeem_amount = REDEEM_AMOUNT;
+    input.data.x_lock_address = X_LOCK_ADDRESS.to_string();
+    input.data.x_unlock_address = BTC_LOCK_ADDRESS.to_string();
+    let mut output = btc_warranty_cell(REMAIN_COLLATERAL + XT_CELL_CAPACITY);
+    output.data.amount = Some(LOT_AMOUNT - REDEEM_AMOUNT);
+    output.data.x_lock_address = X_LOCK_ADDRESS.to_string();
+    output.data.x_unlock_address = BTC_LOCK_ADDRESS.to_string();
+    let mut case = toCKB_cell_case(input, output);
+    let new_lock_tx_hash = set_btc_relay_spending_proof(&mut case, PARTIAL_WITHDRAW_VOUT);
+    let new_lock = || {
+        XExtraView::Btc(BtcExtraView {
+            lock_tx_hash: new_lock_tx_hash.clone(),
+            lock_vout_index: 1,
+        })
+    };
+    case.toCKB_cells.outputs[0].data.x_extra = new_lock();
+    case.capacity_cells.outputs.push(CapacityCell {
+        capacity: COLLATERAL - REMAIN_COLLATERAL,
+        lockscript: Default::default(),
+        index: 1,
+    });
+    case.lock_registry = Some(LockRegistryCells {
+        registered: vec![],
+        inserted: new_lock(),
+        input_index: 1,
+        output_index: 2,
+    });
+    case
+}
@@ -1,6 +1,9 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
-    config::{PLEDGE, SINCE_WITHDRAW_PLEDGE},
+    config::{
+        CKB_UNITS, LOCK_TYPE_FLAG, PLEDGE, SIGNER_FEE_RATE, SINCE_TYPE_BLOCK_NUMBER,
+        SINCE_WITHDRAW_PLEDGE,
+    },
     Error,
 };
 
@@ -26,6 +29,39 @@ fn test_wrong_withdraw_capacity() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_pledge_updated_by_governance() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![CellDepView::ProtocolParams(ProtocolParamsView {
+        pledge: 20000 * CKB_UNITS,
+        ..Default::default()
+    })];
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_correct_block_number_timeout() {
+    const SINCE_BY_BLOCK_NUMBER: u64 = LOCK_TYPE_FLAG | SINCE_TYPE_BLOCK_NUMBER | 10800;
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![CellDepView::ProtocolParams(ProtocolParamsView {
+        since_withdraw_pledge: SINCE_BY_BLOCK_NUMBER,
+        ..Default::default()
+    })];
+    case.toCKB_cells.inputs[0].since = SINCE_BY_BLOCK_NUMBER;
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_block_number_timeout_by_timestamp() {
+    let mut case = get_correct_btc_case();
+    case.cell_deps = vec![CellDepView::ProtocolParams(ProtocolParamsView {
+        since_withdraw_pledge: LOCK_TYPE_FLAG | SINCE_TYPE_BLOCK_NUMBER | 10800,
+        ..Default::default()
+    })];
+    case.expect_return_code = Error::InputSinceInvalid as i8;
+    case_runner::run_test(case)
+}
+
 #[test]
 fn test_wrong_withdrawer() {
     let mut case = get_correct_btc_case();
@@ -37,15 +73,46 @@ fn test_wrong_withdrawer() {
     case_runner::run_test(case)
 }
 
+#[test]
+fn test_correct_expired_with_chosen_signer() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.signer_lockscript = chosen_signer_lockscript();
+    case_runner::run_test(case)
+}
+
+#[test]
+fn test_wrong_pledge_to_chosen_signer() {
+    let mut case = get_correct_btc_case();
+    case.toCKB_cells.inputs[0].data.signer_lockscript = chosen_signer_lockscript();
+    case.capacity_cells.outputs[0].lockscript = chosen_signer_lockscript();
+    case.expect_return_code = Error::CapacityInvalid as i8;
+    case_runner::run_test(case)
+}
+
+fn chosen_signer_lockscript() -> ScriptView {
+    ScriptView {
+        outpoint_key: ALWAYS_SUCCESS_OUTPOINT_KEY,
+        args: Bytes::from("chosen_signer"),
+    }
+}
+
 fn get_correct_btc_case() -> TestCase {
     TestCase {
         cell_deps: vec![],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: PLEDGE,
                 data: ToCKBCellDataView {
                     status: 1,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -56,6 +123,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: SINCE_WITHDRAW_PLEDGE,
@@ -76,6 +146,7 @@ fn get_correct_btc_case() -> TestCase {
             }],
         },
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
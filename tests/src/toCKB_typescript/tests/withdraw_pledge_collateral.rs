@@ -1,6 +1,6 @@
 use crate::toCKB_typescript::utils::{case_builder::*, case_runner};
 use tockb_types::{
-    config::{CKB_UNITS, SINCE_WITHDRAW_PLEDGE_COLLATERAL},
+    config::{CKB_UNITS, PLEDGE, SIGNER_FEE_RATE, SINCE_WITHDRAW_PLEDGE_COLLATERAL},
     Error,
 };
 
@@ -42,12 +42,20 @@ fn test_wrong_withdrawer() {
 fn get_correct_btc_case() -> TestCase {
     TestCase {
         cell_deps: vec![],
+        header_deps: vec![],
+        toCKB_input_header: None,
         toCKB_cells: ToCKBCells {
             inputs: vec![ToCKBCell {
                 capacity: COLLATERAL,
                 data: ToCKBCellDataView {
                     status: 2,
-                    lot_size: 1,
+                    lot_size: 0,
+                    amount: Some(25_000_000),
+                    redeem_amount: 0,
+                    signer_group: Default::default(),
+                    signer_fee_rate: SIGNER_FEE_RATE,
+                    pledge: PLEDGE,
+                    params_snapshot: Default::default(),
                     user_lockscript: Default::default(),
                     x_lock_address: Default::default(),
                     signer_lockscript: Default::default(),
@@ -58,6 +66,9 @@ fn get_correct_btc_case() -> TestCase {
                 },
                 type_args: ToCKBTypeArgsView {
                     xchain_kind: 1,
+                    xchain_network: 3,
+                    header_relay: None,
+                    legacy: false,
                     cell_id: ToCKBTypeArgsView::default_cell_id(),
                 },
                 since: SINCE_WITHDRAW_PLEDGE_COLLATERAL,
@@ -78,6 +89,7 @@ fn get_correct_btc_case() -> TestCase {
             }],
         },
         witnesses: vec![],
+        lock_registry: None,
         expect_return_code: 0,
     }
 }
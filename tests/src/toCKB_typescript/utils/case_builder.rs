@@ -1,21 +1,38 @@
+use crate::toCKB_typescript::utils::eth_proof;
 use crate::toCKB_typescript::utils::types::generated::{
-    basic, btc_difficulty, mint_xt_witness,
-    tockb_cell_data::{BtcExtra, EthExtra, ToCKBCellData, ToCKBTypeArgs, XExtra, XExtraUnion},
+    basic, btc_difficulty, eth_confirmed_headers, mint_xt_witness, price_oracle,
+    tockb_cell_data::{
+        BtcExtra, Erc20Extra, EthExtra, LegacyToCKBCellData, LegacyToCKBTypeArgs, LtcExtra,
+        SignerGroup, SignerMember, SignerMemberVec, ToCKBCellData, ToCKBTypeArgs, XExtra,
+        XExtraUnion,
+    },
 };
 use anyhow::Result;
 use ckb_testtool::context::Context;
 pub use ckb_tool::ckb_types::bytes::Bytes;
-use ckb_tool::ckb_types::{packed::*, prelude::*};
+use ckb_tool::ckb_types::{core::ScriptHashType, packed::*, prelude::*};
+use secp256k1::{Message, Secp256k1, SecretKey};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 use std::convert::{TryFrom, TryInto};
 use std::vec::Vec;
+use tockb_types::btc_relay::{hash256, BtcRelayEntry, BtcRelayView};
+use tockb_types::config::{
+    BTC_SPV_CONFIRMATIONS, CKB_UNITS, ETH_CONFIRMED_HEADERS_TYPE_HASH, LOCK_REGISTRY_TYPE_HASH,
+    PLEDGE, PRICE_ORACLE_THRESHOLD, PRICE_ORACLE_TYPE_HASHES, PROTOCOL_PARAMS_LOCK_HASH,
+    PROTOCOL_PARAMS_TYPE_HASH, SIGNER_FEE_RATE, TYPE_ID_CODE_HASH,
+};
+use tockb_types::lock_registry::{build_lock_registry, get_registry_key};
+pub use tockb_types::protocol_params::{ParamsSnapshotView, ProtocolParamsView};
 
 pub const USER_LOCKSCRIPT_OUTPOINT_KEY: &str = "user_lockscript_outpoint_key";
 pub const TOCKB_TYPESCRIPT_OUTPOINT_KEY: &str = "toCKB_typescript_outpoint_key";
 pub const TOCKB_LOCKSCRIPT_OUTPOINT_KEY: &str = "toCKB_lockscript_outpoint_key";
 pub const SUDT_TYPESCRIPT_OUTPOINT_KEY: &str = "sudt_typescript_key";
 pub const FIRST_INPUT_OUTPOINT_KEY: &str = "toCKB_cell_id_outpoint_key";
+pub const SECOND_INPUT_OUTPOINT_KEY: &str = "toCKB_second_cell_id_outpoint_key";
+// outpoints of the inputs which deposit requests can take as cell id, by index
+pub const INPUT_OUTPOINT_KEYS: [&str; 2] = [FIRST_INPUT_OUTPOINT_KEY, SECOND_INPUT_OUTPOINT_KEY];
 pub const ALWAYS_SUCCESS_OUTPOINT_KEY: &str = "always_success_outpoint_key";
 
 pub type OutpointsContext = HashMap<&'static str, OutPoint>;
@@ -45,19 +62,42 @@ pub trait CellBuilder {
 
 pub struct TestCase {
     pub cell_deps: Vec<CellDepView>,
+    // timestamps in seconds of header deps
+    pub header_deps: Vec<u64>,
+    // timestamp in seconds of the block creating the toCKB input, the header is not put in header
+    // deps unless it is also in header_deps
+    pub toCKB_input_header: Option<u64>,
     pub toCKB_cells: ToCKBCells,
     pub sudt_cells: SudtCells,
     pub capacity_cells: CapacityCells,
     pub witnesses: Vec<Witness>,
+    pub lock_registry: Option<LockRegistryCells>,
     pub expect_return_code: i8,
 }
 
 pub enum CellDepView {
     DifficultyOracle(DifficultyOracle),
-    PriceOracle(u128),
+    PriceOracle(PriceOracle),
+    ConfirmedHeadersOracle(Vec<String>),
+    // confirmed headers in a cell without the trusted type, the typescript rejects them
+    UntrustedConfirmedHeaders(Vec<String>),
+    HeaderRelay(HeaderRelay),
+    ProtocolParams(ProtocolParamsView),
+    // protocol params in a cell with the params type but without the governance lock, the
+    // typescript rejects them
+    UntrustedProtocolParams(ProtocolParamsView),
 }
 
 impl CellDepView {
+    /// Protocol params accepting the BTC spv proofs of the test cases, which only have the header
+    /// containing the tx
+    pub fn single_header_protocol_params() -> CellDepView {
+        CellDepView::ProtocolParams(ProtocolParamsView {
+            btc_spv_confirmations: 1,
+            ..Default::default()
+        })
+    }
+
     pub fn build_cell_dep(&self, context: &mut Context) -> CellDep {
         match self {
             CellDepView::DifficultyOracle(difficulty) => {
@@ -70,19 +110,208 @@ impl CellDepView {
                     .out_point(difficulty_outpoint)
                     .build()
             }
-            CellDepView::PriceOracle(price) => {
-                let price_outpoint = context.deploy_cell(price.to_le_bytes().to_vec().into());
+            CellDepView::PriceOracle(price_oracle) => {
+                let price_cell = CellOutput::new_builder()
+                    .type_(price_oracle.type_script().pack())
+                    .build();
+                let price_outpoint =
+                    context.create_cell(price_cell, price_oracle.as_molecule_bytes());
                 CellDep::new_builder().out_point(price_outpoint).build()
             }
+            CellDepView::ConfirmedHeadersOracle(block_hashes)
+            | CellDepView::UntrustedConfirmedHeaders(block_hashes) => {
+                let block_hashes = block_hashes
+                    .iter()
+                    .map(|hash| hex::decode(clear_0x(hash)).expect("decode block hash hex"))
+                    .collect::<Vec<_>>()
+                    .concat();
+                let confirmed_headers = eth_confirmed_headers::ETHConfirmedHeaders::new_builder()
+                    .block_hashes(block_hashes.into())
+                    .build();
+                let type_ = match self {
+                    CellDepView::ConfirmedHeadersOracle(_) => {
+                        Some(eth_confirmed_headers_type_script())
+                    }
+                    _ => None,
+                };
+                let confirmed_headers_cell = CellOutput::new_builder().type_(type_.pack()).build();
+                let confirmed_headers_outpoint =
+                    context.create_cell(confirmed_headers_cell, confirmed_headers.as_bytes());
+                CellDep::new_builder()
+                    .out_point(confirmed_headers_outpoint)
+                    .build()
+            }
+            CellDepView::HeaderRelay(relay) => {
+                let relay_data = BtcRelayView {
+                    height: relay.height,
+                    epoch_start_time: 0,
+                    epoch_bits: 0,
+                    headers: relay
+                        .hashes
+                        .iter()
+                        .map(|hash| BtcRelayEntry {
+                            hash: *hash,
+                            chain_work: Default::default(),
+                            time: 0,
+                            bits: 0,
+                        })
+                        .collect(),
+                };
+                let relay_cell = CellOutput::new_builder()
+                    .type_(Some(relay.type_script()).pack())
+                    .build();
+                let relay_outpoint = context.create_cell(relay_cell, relay_data.as_molecule_data());
+                CellDep::new_builder().out_point(relay_outpoint).build()
+            }
+            CellDepView::ProtocolParams(params) | CellDepView::UntrustedProtocolParams(params) => {
+                let lock = match self {
+                    CellDepView::ProtocolParams(_) => protocol_params_lock_script(),
+                    _ => Default::default(),
+                };
+                let params_cell = CellOutput::new_builder()
+                    .lock(lock)
+                    .type_(Some(protocol_params_type_script()).pack())
+                    .build();
+                let params_outpoint = context.create_cell(params_cell, params.as_molecule_data());
+                CellDep::new_builder().out_point(params_outpoint).build()
+            }
         }
     }
 }
 
+/// Type id script of the ETH confirmed headers cell with the dev args `[4; 32]`
+pub fn eth_confirmed_headers_type_script() -> Script {
+    let script = Script::new_builder()
+        .code_hash(TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(Bytes::from(vec![4u8; 32]).pack())
+        .build();
+    assert_eq!(
+        script.calc_script_hash().as_slice(),
+        ETH_CONFIRMED_HEADERS_TYPE_HASH.as_ref()
+    );
+    script
+}
+
 pub struct DifficultyOracle {
     pub previous: u64,
     pub current: u64,
 }
 
+/// Price signed by the dev key `[publisher; 32]`
+pub struct PriceOracle {
+    pub publisher: u8,
+    pub price: u128,
+    pub timestamp: u64,
+    // index of the price cell type in PRICE_ORACLE_TYPE_HASHES, cells without a price cell type
+    // are skipped by the typescript
+    pub type_index: Option<usize>,
+}
+
+impl PriceOracle {
+    /// Type id script of the price cell at `type_index` with the dev args `[0x10 + type_index; 32]`
+    pub fn type_script(&self) -> Option<Script> {
+        let type_index = self.type_index?;
+        let script = Script::new_builder()
+            .code_hash(TYPE_ID_CODE_HASH.pack())
+            .hash_type(ScriptHashType::Type.into())
+            .args(Bytes::from(vec![0x10 + type_index as u8; 32]).pack())
+            .build();
+        assert_eq!(
+            script.calc_script_hash().as_slice(),
+            PRICE_ORACLE_TYPE_HASHES[type_index].as_ref()
+        );
+        Some(script)
+    }
+
+    pub fn as_molecule_bytes(&self) -> Bytes {
+        let privkey = SecretKey::from_slice(&[self.publisher; 32]).expect("build secret key");
+        let publisher = hex::decode(clear_0x(&eth_proof::get_address(&privkey))).unwrap();
+        let mut message = self.price.to_le_bytes().to_vec();
+        message.extend_from_slice(&self.timestamp.to_le_bytes());
+        let message = Message::from_slice(&eth_proof::keccak256(&message)).expect("build message");
+        let signature = Secp256k1::signing_only().sign_recoverable(&message, &privkey);
+        let (recovery_id, signature) = signature.serialize_compact();
+        let mut signature = signature.to_vec();
+        signature.push(recovery_id.to_i32() as u8);
+
+        let mut data = self.price.to_le_bytes().to_vec();
+        data.extend_from_slice(&self.timestamp.to_le_bytes());
+        data.extend_from_slice(&publisher);
+        data.extend_from_slice(&signature);
+        price_oracle::SignedPrice::from_slice(&data)
+            .expect("build signed price")
+            .as_bytes()
+    }
+}
+
+pub const PRICE_ORACLE_TIMESTAMP: u64 = 1_600_000_000;
+
+/// Fresh prices of the first PRICE_ORACLE_THRESHOLD publishers
+pub fn price_oracles(price: u128) -> Vec<CellDepView> {
+    (1..=PRICE_ORACLE_THRESHOLD as u8)
+        .map(|publisher| {
+            CellDepView::PriceOracle(PriceOracle {
+                publisher,
+                price,
+                timestamp: PRICE_ORACLE_TIMESTAMP,
+                type_index: Some(publisher as usize - 1),
+            })
+        })
+        .collect()
+}
+
+/// Header relay cell kept as cell dep, `hashes` are the kept headers and the last one is the tip
+pub struct HeaderRelay {
+    pub type_args: Bytes,
+    pub height: u64,
+    pub hashes: Vec<[u8; 32]>,
+}
+
+impl HeaderRelay {
+    pub fn type_script(&self) -> Script {
+        Script::new_builder().args(self.type_args.pack()).build()
+    }
+
+    pub fn type_hash(&self) -> [u8; 32] {
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(self.type_script().calc_script_hash().as_slice());
+        hash
+    }
+}
+
+/// Type id script of the protocol params cell with the dev args `[1; 32]`
+pub fn protocol_params_type_script() -> Script {
+    let script = Script::new_builder()
+        .code_hash(TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(Bytes::from(vec![1u8; 32]).pack())
+        .build();
+    assert_eq!(
+        script.calc_script_hash().as_slice(),
+        PROTOCOL_PARAMS_TYPE_HASH.as_ref()
+    );
+    script
+}
+
+/// Secp multisig lock of the governance owning the protocol params cell with the dev args `[2; 20]`
+pub fn protocol_params_lock_script() -> Script {
+    let multisig_type_hash: [u8; 32] = [
+        92, 80, 105, 235, 8, 87, 239, 198, 94, 27, 202, 12, 7, 223, 52, 195, 22, 99, 179, 98, 47,
+        211, 135, 108, 135, 99, 32, 252, 150, 52, 226, 168,
+    ];
+    let script = Script::new_builder()
+        .code_hash(multisig_type_hash.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(Bytes::from(vec![2u8; 20]).pack())
+        .build();
+    assert_eq!(
+        script.calc_script_hash().as_slice(),
+        PROTOCOL_PARAMS_LOCK_HASH.as_ref()
+    );
+    script
+}
+
 pub struct ToCKBCells {
     pub inputs: Vec<ToCKBCell>,
     pub outputs: Vec<ToCKBCell>,
@@ -148,9 +377,240 @@ impl CellBuilder for ToCKBCell {
     }
 }
 
+pub const BTC_LOCK_ADDRESS: &str = "bcrt1qzulv8gfw9zd3qtuwmnqafmxnkkuf8cku05t03d";
+pub const BTC_LOCK_TX_HASH: &str =
+    "5227c5fbad9d9202ade7f02452cf880dac1ed270255ebfe6716e8b3e8956571d";
+
+/// Regtest BTC typescript args of the toCKB cell with the default cell id
+pub fn btc_type_args() -> ToCKBTypeArgsView {
+    ToCKBTypeArgsView {
+        xchain_kind: 1,
+        xchain_network: 3,
+        header_relay: None,
+        legacy: false,
+        cell_id: ToCKBTypeArgsView::default_cell_id(),
+    }
+}
+
+/// Data of a BTC deposit of 25_000_000 satoshi in Warranty, locked to BTC_LOCK_ADDRESS at output 1
+/// of BTC_LOCK_TX_HASH
+pub fn btc_warranty_data() -> ToCKBCellDataView {
+    ToCKBCellDataView {
+        status: 3,
+        lot_size: 0,
+        amount: Some(25_000_000),
+        redeem_amount: 0,
+        signer_group: Default::default(),
+        signer_fee_rate: SIGNER_FEE_RATE,
+        pledge: PLEDGE,
+        params_snapshot: Default::default(),
+        user_lockscript: Default::default(),
+        x_lock_address: BTC_LOCK_ADDRESS.to_string(),
+        signer_lockscript: Default::default(),
+        x_unlock_address: Default::default(),
+        redeemer_lockscript: Default::default(),
+        liquidation_trigger_lockscript: Default::default(),
+        x_extra: XExtraView::Btc(BtcExtraView {
+            lock_tx_hash: BTC_LOCK_TX_HASH.to_string(),
+            lock_vout_index: 1,
+        }),
+    }
+}
+
+/// The toCKB cell at index 0 with `btc_warranty_data`, cases override the fields of their
+/// transition
+pub fn btc_warranty_cell(capacity: u64) -> ToCKBCell {
+    ToCKBCell {
+        capacity,
+        data: btc_warranty_data(),
+        type_args: btc_type_args(),
+        since: 0,
+        index: 0,
+    }
+}
+
+/// The toCKB cell of `btc_warranty_data` in SignerHandover, moving from `x_lock_address` to
+/// BTC_LOCK_ADDRESS under `new_signer_lockscript`
+pub fn btc_handover_cell(
+    capacity: u64,
+    x_lock_address: &str,
+    new_signer_lockscript: ScriptView,
+) -> ToCKBCell {
+    let mut cell = btc_warranty_cell(capacity);
+    cell.data.status = 9;
+    cell.data.x_lock_address = x_lock_address.to_string();
+    cell.data.x_unlock_address = BTC_LOCK_ADDRESS.to_string();
+    cell.data.redeemer_lockscript = new_signer_lockscript;
+    cell
+}
+
+/// Cell deps to check `btc_lock_tx_spending_witness` against the difficulty oracle
+pub fn btc_spv_cell_deps() -> Vec<CellDepView> {
+    vec![
+        CellDepView::DifficultyOracle(DifficultyOracle {
+            previous: 17557993035167,
+            current: 17557993035167,
+        }),
+        CellDepView::single_header_protocol_params(),
+    ]
+}
+
+/// Proof of the tx 2b21846a.. spending output 1 of BTC_LOCK_TX_HASH, it pays BTC_LOCK_ADDRESS at
+/// output 0 and bcrt1qa7txt08c975rapc2x59x25dqnm5pne9r5zeyfd at output 1
+pub fn btc_lock_tx_spending_witness() -> Witness {
+    Witness::Btc(BtcWitness {
+        cell_dep_index_list: vec![0],
+        spv_proof: BTCSPVProofJson {
+            version: 2,
+            vin: "0x015227c5fbad9d9202ade7f02452cf880dac1ed270255ebfe6716e8b3e8956571d0100000017160014085fc2ea0c102fc4db8dbbb10dd6f93684c178c9feffffff".to_owned(),
+            vout: "0x028c79171300000000160014173ec3a12e289b102f8edcc1d4ecd3b5b893e2dc97b2030000000000160014ef9665bcf82fa83e870a350a6551a09ee819e4a3".to_owned(),
+            locktime: 645339,
+            tx_id: "0x2b21846ae6f15cc29e41b2846c78d756abfedb0d6fea7222263cac0024713bc3".to_owned(),
+            index: 3,
+            headers: "0x00000020acf05cadf6d066d01f5aca661690f4e1779a8144b90b070000000000000000006bbb5a7851af48d883e8ac5d6f61c6ad9a4132a9a12531c1b6f085760b3b2e427ba0455fea0710177d792e86".to_owned(),
+            intermediate_nodes: "0x8546dfccb488115f9c3210255523c0e186fb9b64d16ac68b3d8903bf037dc3ab26069e90c930cc55105d5f8b4ddd798bc33f057641e748fd2e70de0b8747cae802af46fb1e1fccf354b4b46d87f5a85c564fd5284cbe2a5711c16c446fbb6e9e0b3c7beec06a156a8005883b8cf224f665d361a2269b6b21491c1ccbb8160c311b609b5ca21b0a9f708e6124b36871b71c5536d8d556054be435cf0444da70d0814e678eb0e081805d777f9cf84911f9e04b6a80b6cf60dec31527ec73aaa8ba77ec6bff2e04fbb80c8c81b1cc38b415bc21dd732f51a4a903ee265b0eef2c589f751e66e46bb02aa36ed8418ae93317316b84d12f1b1702dd9641ead0ad7f8777526ad7a4ff599946d219a7a932ec8cd2e42649b3d5fa123d2e4532de6d46bddb27a8c02de8fb8fe2c4d88a14132de8cdd7d471bc6a8c8c217aeec600fd295e8925b663332f45bdb6877dd6e0ecd28bfae530ba3ed8bd3959644a82bc418f9c887746e15ae55d82369c3761187ea449c7f7bdff1acaa0b467e1335b3919089d".to_owned(),
+            funding_output_index: 0,
+            funding_input_index: 0,
+        },
+    })
+}
+
+/// Prove a tx spending output 1 of BTC_LOCK_TX_HASH with the serialized `vout` by a header relay
+/// keeping its block with BTC_SPV_CONFIRMATIONS, which replaces the cell deps and witnesses of
+/// `case`, return the tx id. The proof of work is left to the relay, so the tx needs not be mined.
+pub fn set_btc_relay_spending_proof(case: &mut TestCase, vout: &str) -> String {
+    let vin = "0x015227c5fbad9d9202ade7f02452cf880dac1ed270255ebfe6716e8b3e8956571d0100000017160014085fc2ea0c102fc4db8dbbb10dd6f93684c178c9feffffff";
+    let tx = [
+        &2u32.to_le_bytes()[..],
+        &hex::decode(clear_0x(vin)).expect("decode vin hex")[..],
+        &hex::decode(clear_0x(vout)).expect("decode vout hex")[..],
+        &0u32.to_le_bytes()[..],
+    ]
+    .concat();
+    let tx_id = hash256(&tx);
+    // the block has only the tx, whose id is the merkle root
+    let header = [
+        &2u32.to_le_bytes()[..],
+        &[0u8; 32][..],
+        &tx_id[..],
+        &[0u8; 4][..],
+        // regtest bits
+        &[0xff, 0xff, 0x7f, 0x20][..],
+        &[0u8; 4][..],
+    ]
+    .concat();
+    let mut hashes = vec![[0u8; 32]; BTC_SPV_CONFIRMATIONS as usize];
+    hashes[0] = hash256(&header);
+    for (i, hash) in hashes.iter_mut().enumerate().skip(1) {
+        hash[0] = i as u8;
+    }
+    let relay = HeaderRelay {
+        type_args: Bytes::from("btc header relay"),
+        height: 645340 + BTC_SPV_CONFIRMATIONS as u64,
+        hashes,
+    };
+    for cell in case
+        .toCKB_cells
+        .inputs
+        .iter_mut()
+        .chain(case.toCKB_cells.outputs.iter_mut())
+    {
+        cell.type_args.header_relay = Some(relay.type_hash());
+    }
+    case.cell_deps = vec![CellDepView::HeaderRelay(relay)];
+    case.witnesses = vec![Witness::Btc(BtcWitness {
+        cell_dep_index_list: vec![0],
+        spv_proof: BTCSPVProofJson {
+            version: 2,
+            vin: vin.to_owned(),
+            vout: vout.to_owned(),
+            locktime: 0,
+            tx_id: format!("0x{}", hex::encode(tx_id)),
+            index: 0,
+            headers: format!("0x{}", hex::encode(header)),
+            intermediate_nodes: "0x".to_owned(),
+            funding_output_index: 0,
+            funding_input_index: 0,
+        },
+    })];
+    hex::encode(tx_id)
+}
+
+/// Prove `tx_data`, whose receipt has the encoded `logs`, in a confirmed block at `block_number`,
+/// return the lock of the tx as it is recorded in x_extra
+pub fn set_eth_spv_proof(
+    case: &mut TestCase,
+    tx_data: Vec<u8>,
+    logs: Vec<Vec<u8>>,
+    block_number: u64,
+) -> EthExtraView {
+    let (block_hash, proof) =
+        eth_proof::build_block_proof_with_logs(tx_data, true, logs, block_number);
+    let lock = EthExtraView {
+        lock_tx_hash: eth_proof::get_tx_hash(&proof),
+        lock_tx_index: proof.tx_index as u32,
+        lock_block_number: block_number,
+    };
+    case.cell_deps = vec![CellDepView::ConfirmedHeadersOracle(vec![block_hash])];
+    case.witnesses = vec![Witness::Eth(EthWitness {
+        cell_dep_index_list: vec![0],
+        spv_proof: proof,
+    })];
+    lock
+}
+
+/// Batch the toCKB cells of another deposit in `other` into `case`, they are given the cell id
+/// `cell_id_of(1)` and placed after the cells of `case` together with the witness of the input
+pub fn batch_toCKB_cells(case: &mut TestCase, mut other: TestCase) {
+    let inputs_len = case.toCKB_cells.inputs.len()
+        + case.sudt_cells.inputs.len()
+        + case.capacity_cells.inputs.len()
+        + case.lock_registry.iter().count();
+    let outputs_len = case.toCKB_cells.outputs.len()
+        + case.sudt_cells.outputs.len()
+        + case.capacity_cells.outputs.len()
+        + case.lock_registry.iter().count();
+    if let Some(mut input) = other.toCKB_cells.inputs.pop() {
+        input.type_args.cell_id = ToCKBTypeArgsView::cell_id_of(1);
+        input.index = inputs_len;
+        case.toCKB_cells.inputs.push(input);
+        if let Some(witness) = other.witnesses.pop() {
+            assert_eq!(case.witnesses.len(), inputs_len);
+            case.witnesses.push(witness);
+        }
+    }
+    if let Some(mut output) = other.toCKB_cells.outputs.pop() {
+        output.type_args.cell_id = ToCKBTypeArgsView::cell_id_of(1);
+        output.index = outputs_len;
+        case.toCKB_cells.outputs.push(output);
+    }
+}
+
+/// Case of a tx only transforming the toCKB cell `input` to `output`, cases add the other cells
+/// of their transition
+pub fn toCKB_cell_case(input: ToCKBCell, output: ToCKBCell) -> TestCase {
+    TestCase {
+        cell_deps: vec![],
+        header_deps: vec![],
+        toCKB_input_header: None,
+        toCKB_cells: ToCKBCells {
+            inputs: vec![input],
+            outputs: vec![output],
+        },
+        sudt_cells: Default::default(),
+        capacity_cells: Default::default(),
+        witnesses: vec![],
+        lock_registry: None,
+        expect_return_code: 0,
+    }
+}
+
 pub struct ToCKBCellDataView {
     pub status: u8,
     pub lot_size: u8,
+    // None means the cell is encoded in the legacy layout without amount field
+    pub amount: Option<u128>,
+    pub redeem_amount: u128,
     pub user_lockscript: ScriptView,
     pub x_lock_address: String,
     pub signer_lockscript: ScriptView,
@@ -158,11 +618,53 @@ pub struct ToCKBCellDataView {
     pub redeemer_lockscript: ScriptView,
     pub liquidation_trigger_lockscript: ScriptView,
     pub x_extra: XExtraView,
+    pub signer_group: SignerGroupView,
+    pub signer_fee_rate: u32,
+    pub pledge: u64,
+    pub params_snapshot: ParamsSnapshotView,
 }
 
 impl ToCKBCellDataView {
     pub fn as_molecule_bytes(&self, context: &mut Context, outpoints: &OutpointsContext) -> Bytes {
+        let amount = match self.amount {
+            Some(amount) => amount,
+            None => return self.as_legacy_molecule_bytes(context, outpoints),
+        };
         let toCKB_data = ToCKBCellData::new_builder()
+            .status(Byte::new(self.status))
+            .lot_size(Byte::new(self.lot_size))
+            .user_lockscript(self.user_lockscript.build_basic_script(context, outpoints))
+            .x_lock_address(str_to_molecule_bytes(self.x_lock_address.as_str()))
+            .signer_lockscript(
+                self.signer_lockscript
+                    .build_basic_script(context, outpoints),
+            )
+            .x_unlock_address(str_to_molecule_bytes(self.x_unlock_address.as_str()))
+            .redeemer_lockscript(
+                self.redeemer_lockscript
+                    .build_basic_script(context, outpoints),
+            )
+            .liquidation_trigger_lockscript(
+                self.liquidation_trigger_lockscript
+                    .build_basic_script(context, outpoints),
+            )
+            .x_extra(self.x_extra.as_xextra())
+            .amount(amount.into())
+            .redeem_amount(self.redeem_amount.into())
+            .signer_group(self.signer_group.as_signer_group(context, outpoints))
+            .signer_fee_rate(self.signer_fee_rate.into())
+            .pledge(self.pledge.into())
+            .params_snapshot(self.params_snapshot.as_molecule())
+            .build();
+        toCKB_data.as_bytes()
+    }
+
+    fn as_legacy_molecule_bytes(
+        &self,
+        context: &mut Context,
+        outpoints: &OutpointsContext,
+    ) -> Bytes {
+        let toCKB_data = LegacyToCKBCellData::new_builder()
             .status(Byte::new(self.status))
             .lot_size(Byte::new(self.lot_size))
             .user_lockscript(self.user_lockscript.build_basic_script(context, outpoints))
@@ -219,10 +721,49 @@ impl ScriptView {
     }
 }
 
+// no members means a single signer
+#[derive(Default)]
+pub struct SignerGroupView {
+    pub threshold: u8,
+    pub members: Vec<SignerMemberView>,
+}
+
+pub struct SignerMemberView {
+    pub lockscript: ScriptView,
+    pub share: u32,
+    pub x_pubkey: Bytes,
+}
+
+impl SignerGroupView {
+    pub fn as_signer_group(
+        &self,
+        context: &mut Context,
+        outpoints: &OutpointsContext,
+    ) -> SignerGroup {
+        let members = self
+            .members
+            .iter()
+            .map(|member| {
+                SignerMember::new_builder()
+                    .lockscript(member.lockscript.build_basic_script(context, outpoints))
+                    .share(member.share.into())
+                    .x_pubkey(member.x_pubkey.to_vec().into())
+                    .build()
+            })
+            .collect();
+        SignerGroup::new_builder()
+            .threshold(Byte::new(self.threshold))
+            .members(SignerMemberVec::new_builder().set(members).build())
+            .build()
+    }
+}
+
 #[derive(Debug)]
 pub enum XExtraView {
     Btc(BtcExtraView),
     Eth(EthExtraView),
+    Ltc(BtcExtraView),
+    Erc20(Erc20ExtraView),
 }
 
 impl Default for XExtraView {
@@ -266,62 +807,173 @@ impl XExtraView {
                 XExtra::new_builder().set(x_extra).build()
             }
             XExtraView::Eth(eth_extra) => {
+                let lock_tx_hash = if eth_extra.lock_tx_hash.is_empty() {
+                    basic::Byte32::new_builder().build()
+                } else {
+                    hex::decode(eth_extra.lock_tx_hash.as_str())
+                        .expect("decode lock_tx_hash hex")
+                        .try_into()
+                        .expect("convert lock_tx_hash")
+                };
                 let eth_extra = EthExtra::new_builder()
-                    .dummy(basic::Bytes::new_unchecked(eth_extra.dummy.clone()))
+                    .lock_tx_hash(lock_tx_hash)
+                    .lock_tx_index(eth_extra.lock_tx_index.into())
+                    .lock_block_number(eth_extra.lock_block_number.into())
                     .build();
                 let x_extra = XExtraUnion::EthExtra(eth_extra);
                 XExtra::new_builder().set(x_extra).build()
             }
+            XExtraView::Ltc(ltc_extra) => {
+                let lock_tx_hash = if ltc_extra.lock_tx_hash.is_empty() {
+                    basic::Byte32::new_builder().build()
+                } else {
+                    hex::decode(ltc_extra.lock_tx_hash.as_str())
+                        .expect("decode lock_tx_hash hex")
+                        .try_into()
+                        .expect("convert lock_tx_hash")
+                };
+                let ltc_extra = LtcExtra::new_builder()
+                    .lock_tx_hash(lock_tx_hash)
+                    .lock_vout_index(ltc_extra.lock_vout_index.into())
+                    .build();
+                let x_extra = XExtraUnion::LtcExtra(ltc_extra);
+                XExtra::new_builder().set(x_extra).build()
+            }
+            XExtraView::Erc20(erc20_extra) => {
+                let lock = &erc20_extra.lock;
+                let lock_tx_hash = if lock.lock_tx_hash.is_empty() {
+                    basic::Byte32::new_builder().build()
+                } else {
+                    hex::decode(lock.lock_tx_hash.as_str())
+                        .expect("decode lock_tx_hash hex")
+                        .try_into()
+                        .expect("convert lock_tx_hash")
+                };
+                let erc20_extra = Erc20Extra::new_builder()
+                    .token_address(erc20_extra.token_address.as_bytes().to_vec().into())
+                    .lock_tx_hash(lock_tx_hash)
+                    .lock_tx_index(lock.lock_tx_index.into())
+                    .lock_block_number(lock.lock_block_number.into())
+                    .build();
+                let x_extra = XExtraUnion::Erc20Extra(erc20_extra);
+                XExtra::new_builder().set(x_extra).build()
+            }
         }
     }
 }
 
+impl XExtraView {
+    /// Key of the lock tx in the lock registry
+    pub fn registry_key(&self) -> [u8; 32] {
+        let decode_hash = |hash: &str| -> Bytes {
+            if hash.is_empty() {
+                vec![0u8; 32].into()
+            } else {
+                hex::decode(hash).expect("decode lock_tx_hash hex").into()
+            }
+        };
+        let x_extra = match self {
+            XExtraView::Btc(btc_extra) => tockb_types::XExtraView::Btc(tockb_types::BtcExtraView {
+                lock_tx_hash: decode_hash(&btc_extra.lock_tx_hash),
+                lock_vout_index: btc_extra.lock_vout_index,
+            }),
+            XExtraView::Eth(eth_extra) => tockb_types::XExtraView::Eth(tockb_types::EthExtraView {
+                lock_tx_hash: decode_hash(&eth_extra.lock_tx_hash),
+                lock_tx_index: eth_extra.lock_tx_index,
+                lock_block_number: eth_extra.lock_block_number,
+            }),
+            XExtraView::Ltc(ltc_extra) => tockb_types::XExtraView::Ltc(tockb_types::BtcExtraView {
+                lock_tx_hash: decode_hash(&ltc_extra.lock_tx_hash),
+                lock_vout_index: ltc_extra.lock_vout_index,
+            }),
+            XExtraView::Erc20(erc20_extra) => {
+                tockb_types::XExtraView::Erc20(tockb_types::Erc20ExtraView {
+                    token_address: erc20_extra.token_address.as_bytes().to_vec().into(),
+                    lock: tockb_types::EthExtraView {
+                        lock_tx_hash: decode_hash(&erc20_extra.lock.lock_tx_hash),
+                        lock_tx_index: erc20_extra.lock.lock_tx_index,
+                        lock_block_number: erc20_extra.lock.lock_block_number,
+                    },
+                })
+            }
+        };
+        get_registry_key(&x_extra)
+    }
+}
+
 #[derive(Debug, Default)]
 pub struct BtcExtraView {
     pub lock_tx_hash: String,
     pub lock_vout_index: u32,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Default, Clone)]
 pub struct EthExtraView {
-    pub dummy: Bytes,
+    pub lock_tx_hash: String,
+    pub lock_tx_index: u32,
+    pub lock_block_number: u64,
 }
 
-impl Default for EthExtraView {
-    fn default() -> Self {
-        Self {
-            dummy: basic::Bytes::new_builder().build().as_bytes(),
-        }
-    }
+#[derive(Debug, Default)]
+pub struct Erc20ExtraView {
+    // raw bytes of the address like `x_lock_address` of eth
+    pub token_address: String,
+    pub lock: EthExtraView,
 }
 
 pub struct ToCKBTypeArgsView {
     pub xchain_kind: u8,
-    pub cell_id: Option<Bytes>,
+    pub xchain_network: u8,
+    pub header_relay: Option<[u8; 32]>,
+    // encode with the legacy layout, which has neither xchain_network nor header_relay
+    pub legacy: bool,
+    pub cell_id: CellIdView,
+}
+
+pub enum CellIdView {
+    // the outpoint of the input at the index, which a deposit request consumes
+    Input(usize),
+    OutPoint(Bytes),
 }
 
 impl ToCKBTypeArgsView {
-    pub fn default_cell_id() -> Option<Bytes> {
-        Some(basic::OutPoint::new_builder().build().as_bytes())
+    pub fn default_cell_id() -> CellIdView {
+        CellIdView::OutPoint(basic::OutPoint::new_builder().build().as_bytes())
+    }
+
+    /// cell id of another deposit in the same tx
+    pub fn cell_id_of(index: u32) -> CellIdView {
+        CellIdView::OutPoint(
+            basic::OutPoint::new_builder()
+                .index(index.into())
+                .build()
+                .as_bytes(),
+        )
     }
 }
 
 impl ToCKBTypeArgsView {
     pub fn as_molecule_bytes(&self, outpoints: &OutpointsContext) -> Bytes {
-        if let Some(cell_id) = self.cell_id.as_ref() {
-            let toCKB_type_args = ToCKBTypeArgs::new_builder()
+        let cell_id = match &self.cell_id {
+            CellIdView::OutPoint(cell_id) => basic::OutPoint::new_unchecked(cell_id.clone()),
+            CellIdView::Input(index) => outpoints[INPUT_OUTPOINT_KEYS[*index]].clone().into(),
+        };
+        if self.legacy {
+            return LegacyToCKBTypeArgs::new_builder()
                 .xchain_kind(Byte::new(self.xchain_kind))
-                .cell_id(basic::OutPoint::new_unchecked(cell_id.clone()))
-                .build();
-            toCKB_type_args.as_bytes()
-        } else {
-            let cell_id = &outpoints[FIRST_INPUT_OUTPOINT_KEY];
-            let toCKB_type_args = ToCKBTypeArgs::new_builder()
-                .xchain_kind(Byte::new(self.xchain_kind))
-                .cell_id(cell_id.clone().into())
-                .build();
-            toCKB_type_args.as_bytes()
+                .cell_id(cell_id)
+                .build()
+                .as_bytes();
         }
+        ToCKBTypeArgs::new_builder()
+            .xchain_kind(Byte::new(self.xchain_kind))
+            .xchain_network(Byte::new(self.xchain_network))
+            .header_relay(
+                basic::Byte32::try_from(self.header_relay.unwrap_or_default().to_vec()).unwrap(),
+            )
+            .cell_id(cell_id)
+            .build()
+            .as_bytes()
     }
 }
 
@@ -337,6 +989,8 @@ pub struct SudtCell {
     pub amount: u128,
     pub lockscript: ScriptView,
     pub owner_script: ScriptView,
+    // the sudt of an ERC-20 token is owned by the toCKB lock and the token address
+    pub token_address: Option<String>,
     pub index: usize,
 }
 
@@ -349,7 +1003,11 @@ impl SudtCell {
             )
             .expect("build owner script");
         let args: [u8; 32] = owner_script.calc_script_hash().unpack();
-        let args: Bytes = args.to_vec().into();
+        let mut args = args.to_vec();
+        if let Some(token_address) = self.token_address.as_ref() {
+            args.extend_from_slice(token_address.as_bytes());
+        }
+        let args: Bytes = args.into();
         context
             .build_script(&outpoints[SUDT_TYPESCRIPT_OUTPOINT_KEY], args)
             .expect("build sudt typescript succ")
@@ -407,15 +1065,80 @@ impl CellBuilder for CapacityCell {
     }
 }
 
+/// The lock tx registry spent and recreated by mint. Its witness is the merkle proof of the
+/// `inserted` lock tx, and the output inserts it with the type hash of the toCKB output.
+pub struct LockRegistryCells {
+    // lock txs registered before the tx
+    pub registered: Vec<XExtraView>,
+    pub inserted: XExtraView,
+    pub input_index: usize,
+    pub output_index: usize,
+}
+
+impl LockRegistryCells {
+    /// Build the input and output registry cells and the witness of the input
+    pub fn build(
+        &self,
+        context: &mut Context,
+        outpoints: &OutpointsContext,
+        inserted_value: [u8; 32],
+    ) -> (CellInput, (Bytes, CellOutput), Bytes) {
+        let registered = self
+            .registered
+            .iter()
+            .map(|x_extra| (x_extra.registry_key(), [1u8; 32]))
+            .collect::<Vec<_>>();
+        let key = self.inserted.registry_key();
+        let (input_registry, proof) =
+            build_lock_registry(&registered, key).expect("build lock registry");
+        let output_registry = input_registry
+            .insert(key, inserted_value, &proof)
+            .unwrap_or_else(|_| input_registry.clone());
+        let registry_cell = CellOutput::new_builder()
+            .capacity((100 * CKB_UNITS).pack())
+            .lock(ScriptView::default().build_script(context, outpoints))
+            .type_(Some(lock_registry_type_script()).pack())
+            .build();
+        let input_out_point =
+            context.create_cell(registry_cell.clone(), input_registry.as_molecule_data());
+        let input = CellInput::new_builder()
+            .previous_output(input_out_point)
+            .build();
+        let witness = WitnessArgs::new_builder()
+            .input_type(Some(Bytes::from(proof)).pack())
+            .build();
+        (
+            input,
+            (output_registry.as_molecule_data(), registry_cell),
+            witness.as_bytes(),
+        )
+    }
+}
+
+pub fn lock_registry_type_script() -> Script {
+    let script = Script::new_builder()
+        .code_hash(TYPE_ID_CODE_HASH.pack())
+        .hash_type(ScriptHashType::Type.into())
+        .args(Bytes::from(vec![2u8; 32]).pack())
+        .build();
+    assert_eq!(
+        script.calc_script_hash().as_slice(),
+        LOCK_REGISTRY_TYPE_HASH.as_ref()
+    );
+    script
+}
+
 #[derive(Clone)]
 pub enum Witness {
     Btc(BtcWitness),
+    Eth(EthWitness),
 }
 
 impl Witness {
     pub fn as_bytes(&self) -> Bytes {
         match self {
             Witness::Btc(btc_witness) => btc_witness.as_bytes(),
+            Witness::Eth(eth_witness) => eth_witness.as_bytes(),
         }
     }
 }
@@ -478,6 +1201,62 @@ impl TryFrom<BTCSPVProofJson> for mint_xt_witness::BTCSPVProof {
     }
 }
 
+#[derive(Clone)]
+pub struct EthWitness {
+    pub cell_dep_index_list: Vec<u8>,
+    pub spv_proof: ETHSPVProofJson,
+}
+
+impl EthWitness {
+    pub fn as_bytes(&self) -> Bytes {
+        let spv_proof: mint_xt_witness::ETHSPVProof = self
+            .spv_proof
+            .clone()
+            .try_into()
+            .expect("try into mint_xt_witness::ETHSPVProof succ");
+        let spv_proof = spv_proof.as_slice().to_vec();
+        let witness_data = mint_xt_witness::MintXTWitness::new_builder()
+            .spv_proof(spv_proof.into())
+            .cell_dep_index_list(self.cell_dep_index_list.clone().into())
+            .build();
+        let witness = WitnessArgs::new_builder()
+            .input_type(Some(witness_data.as_bytes()).pack())
+            .build();
+        witness.as_bytes()
+    }
+}
+
+#[derive(Serialize, Deserialize, Default, Clone)]
+pub struct ETHSPVProofJson {
+    pub header_data: String,
+    pub tx_index: u64,
+    pub tx_data: String,
+    pub tx_proof: Vec<String>,
+    pub receipt_data: String,
+    pub receipt_proof: Vec<String>,
+}
+
+impl TryFrom<ETHSPVProofJson> for mint_xt_witness::ETHSPVProof {
+    type Error = anyhow::Error;
+
+    fn try_from(proof: ETHSPVProofJson) -> Result<Self> {
+        let decode_nodes = |nodes: &[String]| -> Result<Vec<Vec<u8>>> {
+            Ok(nodes
+                .iter()
+                .map(|node| hex::decode(clear_0x(node)))
+                .collect::<std::result::Result<Vec<_>, _>>()?)
+        };
+        Ok(mint_xt_witness::ETHSPVProof::new_builder()
+            .header_data(hex::decode(clear_0x(&proof.header_data))?.into())
+            .tx_index(proof.tx_index.into())
+            .tx_data(hex::decode(clear_0x(&proof.tx_data))?.into())
+            .tx_proof(decode_nodes(&proof.tx_proof)?.into())
+            .receipt_data(hex::decode(clear_0x(&proof.receipt_data))?.into())
+            .receipt_proof(decode_nodes(&proof.receipt_proof)?.into())
+            .build())
+    }
+}
+
 pub fn clear_0x(s: &str) -> &str {
     if &s[..2] == "0x" || &s[..2] == "0X" {
         &s[2..]
@@ -491,7 +1270,11 @@ pub fn json_to_btc_proof(proof: &str) -> Result<mint_xt_witness::BTCSPVProof> {
     proof.try_into()
 }
 
+// eth addresses written with `0x` prefix are hex decoded
 fn str_to_molecule_bytes(s: &str) -> basic::Bytes {
+    if s.starts_with("0x") {
+        return hex::decode(&s[2..]).expect("decode address hex").into();
+    }
     basic::Bytes::new_builder()
         .set(
             s.as_bytes()
@@ -1,11 +1,12 @@
 use super::case_builder::{
-    CellBuilder, OutpointsContext, TestCase, ALWAYS_SUCCESS_OUTPOINT_KEY, FIRST_INPUT_OUTPOINT_KEY,
-    SUDT_TYPESCRIPT_OUTPOINT_KEY, TOCKB_LOCKSCRIPT_OUTPOINT_KEY, TOCKB_TYPESCRIPT_OUTPOINT_KEY,
+    CellBuilder, CellDepView, OutpointsContext, TestCase, ALWAYS_SUCCESS_OUTPOINT_KEY,
+    INPUT_OUTPOINT_KEYS, SUDT_TYPESCRIPT_OUTPOINT_KEY, TOCKB_LOCKSCRIPT_OUTPOINT_KEY,
+    TOCKB_TYPESCRIPT_OUTPOINT_KEY,
 };
 use crate::*;
 use ckb_testtool::{builtin::ALWAYS_SUCCESS, context::Context};
 use ckb_tool::ckb_types::{
-    core::TransactionBuilder,
+    core::{HeaderBuilder, TransactionBuilder},
     packed::{CellDep, CellInput, CellOutput},
     prelude::*,
 };
@@ -29,24 +30,48 @@ pub fn run_test(case: TestCase) {
         let cell_dep = CellDep::new_builder().out_point(v.clone()).build();
         cell_deps.push(cell_dep);
     }
+    // Protocol params cell dep with default params, appended after the others so that cell dep
+    // indexes in witnesses are not affected
+    if !case.cell_deps.iter().any(|cell_dep_view| {
+        matches!(
+            cell_dep_view,
+            CellDepView::ProtocolParams(_) | CellDepView::UntrustedProtocolParams(_)
+        )
+    }) {
+        cell_deps
+            .push(CellDepView::ProtocolParams(Default::default()).build_cell_dep(&mut context));
+    }
 
     // Cells
+    let registry_len = case.lock_registry.iter().count();
     let inputs_len = case.toCKB_cells.inputs.len()
         + case.sudt_cells.inputs.len()
-        + case.capacity_cells.inputs.len();
+        + case.capacity_cells.inputs.len()
+        + registry_len;
     let outputs_len = case.toCKB_cells.outputs.len()
         + case.sudt_cells.outputs.len()
-        + case.capacity_cells.outputs.len();
+        + case.capacity_cells.outputs.len()
+        + registry_len;
+    let toCKB_output_index = case.toCKB_cells.outputs.first().map(|cell| cell.index);
     let mut inputs = vec![CellInput::default(); inputs_len];
     let mut outputs = vec![CellOutput::default(); outputs_len];
     let mut outputs_data = vec![Bytes::default(); outputs_len];
 
+    let toCKB_input_index = case.toCKB_cells.inputs.first().map(|cell| cell.index);
     build_input_cell(
         case.toCKB_cells.inputs.into_iter(),
         &mut context,
         &mut outpoints_context,
         &mut inputs,
     );
+    if let (Some(index), Some(timestamp)) = (toCKB_input_index, case.toCKB_input_header) {
+        let header = HeaderBuilder::default()
+            .timestamp((timestamp * 1000).pack())
+            .build();
+        let block_hash = header.hash();
+        context.insert_header(header);
+        context.link_cell_with_block(inputs[index].previous_output(), block_hash, 0);
+    }
     build_input_cell(
         case.sudt_cells.inputs.into_iter(),
         &mut context,
@@ -82,6 +107,21 @@ pub fn run_test(case: TestCase) {
         &mut outputs_data,
     );
 
+    // Lock registry which inserts the lock tx with the type hash of the toCKB output
+    let mut registry_witness = None;
+    if let Some(registry) = case.lock_registry {
+        let mut inserted_value = [1u8; 32];
+        if let Some(type_script) = toCKB_output_index.and_then(|i| outputs[i].type_().to_opt()) {
+            inserted_value.copy_from_slice(type_script.calc_script_hash().as_slice());
+        }
+        let (input, (output_data, output), witness) =
+            registry.build(&mut context, &outpoints_context, inserted_value);
+        inputs[registry.input_index] = input;
+        outputs[registry.output_index] = output;
+        outputs_data[registry.output_index] = output_data;
+        registry_witness = Some((registry.input_index, witness));
+    }
+
     dbg!("inputs: {:?}", &inputs);
     dbg!("outputs: {:?}", &outputs);
 
@@ -90,10 +130,27 @@ pub fn run_test(case: TestCase) {
     for witness in case.witnesses {
         witnesses.push(witness.as_bytes().pack());
     }
+    if let Some((index, witness)) = registry_witness {
+        if witnesses.len() <= index {
+            witnesses.resize(index + 1, Default::default());
+        }
+        witnesses[index] = witness.pack();
+    }
 
     // Build tx
+    // Header deps
+    let mut header_deps = vec![];
+    for timestamp in case.header_deps.iter() {
+        let header = HeaderBuilder::default()
+            .timestamp((timestamp * 1000).pack())
+            .build();
+        header_deps.push(header.hash());
+        context.insert_header(header);
+    }
+
     let tx = TransactionBuilder::default()
         .cell_deps(cell_deps)
+        .header_deps(header_deps)
         .inputs(inputs)
         .outputs(outputs)
         .outputs_data(outputs_data.pack())
@@ -148,8 +205,8 @@ fn build_input_cell<I, B>(
         let index = input.get_index();
         let (input_outpoint, input_cell) = input.build_input_cell(context, outpoints_context);
         let _old_value = replace(&mut inputs[index], input_cell);
-        if 0 == index {
-            outpoints_context.insert(FIRST_INPUT_OUTPOINT_KEY, input_outpoint);
+        if let Some(key) = INPUT_OUTPOINT_KEYS.get(index) {
+            outpoints_context.insert(key, input_outpoint);
         }
     }
 }
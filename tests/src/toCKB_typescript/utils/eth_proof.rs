@@ -0,0 +1,218 @@
+use super::case_builder::ETHSPVProofJson;
+use secp256k1::{Message, PublicKey, Secp256k1, SecretKey};
+use tiny_keccak::{Hasher, Keccak};
+
+pub const LEGACY_TX_GAS: u64 = 21000;
+pub const CHAIN_ID: u64 = 1;
+// keccak256("Transfer(address,address,uint256)")
+pub const TRANSFER_EVENT_TOPIC: &str =
+    "ddf252ad1be2c89b69c2b068fc378daa952ba7f163c4a11628f55a4df523b3ef";
+// selector of `transfer(address,uint256)`
+pub const TRANSFER_SELECTOR: [u8; 4] = [0xa9, 0x05, 0x9c, 0xbb];
+
+pub fn keccak256(data: &[u8]) -> [u8; 32] {
+    let mut hasher = Keccak::v256();
+    let mut output = [0u8; 32];
+    hasher.update(data);
+    hasher.finalize(&mut output);
+    output
+}
+
+fn rlp_encode_length(len: usize, offset: u8) -> Vec<u8> {
+    if len < 56 {
+        vec![offset + len as u8]
+    } else {
+        let len_bytes = len.to_be_bytes();
+        let start = len_bytes.iter().position(|b| *b != 0).unwrap_or(0);
+        let mut ret = vec![offset + 55 + (len_bytes.len() - start) as u8];
+        ret.extend_from_slice(&len_bytes[start..]);
+        ret
+    }
+}
+
+pub fn rlp_encode_bytes(data: &[u8]) -> Vec<u8> {
+    if data.len() == 1 && data[0] < 0x80 {
+        return data.to_vec();
+    }
+    let mut ret = rlp_encode_length(data.len(), 0x80);
+    ret.extend_from_slice(data);
+    ret
+}
+
+pub fn rlp_encode_uint(value: u128) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    rlp_encode_bytes(&bytes[start..])
+}
+
+pub fn rlp_encode_list(items: &[Vec<u8>]) -> Vec<u8> {
+    let payload = items.concat();
+    let mut ret = rlp_encode_length(payload.len(), 0xc0);
+    ret.extend(payload);
+    ret
+}
+
+pub fn rlp_encode_be_bytes(bytes: &[u8]) -> Vec<u8> {
+    let start = bytes.iter().position(|b| *b != 0).unwrap_or(bytes.len());
+    rlp_encode_bytes(&bytes[start..])
+}
+
+pub fn default_signer_key() -> SecretKey {
+    SecretKey::from_slice(&[1u8; 32]).expect("build secret key")
+}
+
+/// Return the hex encoded address of the private key with `0x` prefix
+pub fn get_address(privkey: &SecretKey) -> String {
+    let pubkey = PublicKey::from_secret_key(&Secp256k1::signing_only(), privkey);
+    let pubkey_hash = keccak256(&pubkey.serialize_uncompressed()[1..]);
+    format!("0x{}", hex::encode(&pubkey_hash[12..]))
+}
+
+/// Build an EIP-155 legacy transaction transferring `value` wei to `to`, signed by `privkey`
+pub fn build_transfer_tx(privkey: &SecretKey, to: &[u8], value: u128) -> Vec<u8> {
+    build_call_tx(privkey, to, value, &[])
+}
+
+/// Build an EIP-155 legacy transaction calling `transfer(to, value)` of the token contract
+pub fn build_token_transfer_tx(
+    privkey: &SecretKey,
+    token: &[u8],
+    to: &[u8],
+    value: u128,
+) -> Vec<u8> {
+    let mut input = TRANSFER_SELECTOR.to_vec();
+    input.extend(left_pad(to));
+    input.extend(left_pad(&value.to_be_bytes()));
+    build_call_tx(privkey, token, 0, &input)
+}
+
+fn build_call_tx(privkey: &SecretKey, to: &[u8], value: u128, input: &[u8]) -> Vec<u8> {
+    let unsigned_fields = vec![
+        rlp_encode_uint(0),
+        rlp_encode_uint(1_000_000_000),
+        rlp_encode_uint(LEGACY_TX_GAS as u128),
+        rlp_encode_bytes(to),
+        rlp_encode_uint(value),
+        rlp_encode_bytes(input),
+    ];
+    let mut signing_fields = unsigned_fields.clone();
+    signing_fields.extend(vec![
+        rlp_encode_uint(CHAIN_ID as u128),
+        rlp_encode_uint(0),
+        rlp_encode_uint(0),
+    ]);
+    let message =
+        Message::from_slice(&keccak256(&rlp_encode_list(&signing_fields))).expect("build message");
+    let signature = Secp256k1::signing_only().sign_recoverable(&message, privkey);
+    let (recovery_id, signature) = signature.serialize_compact();
+    let mut fields = unsigned_fields;
+    fields.extend(vec![
+        rlp_encode_uint((CHAIN_ID * 2 + 35) as u128 + recovery_id.to_i32() as u128),
+        rlp_encode_be_bytes(&signature[..32]),
+        rlp_encode_be_bytes(&signature[32..]),
+    ]);
+    rlp_encode_list(&fields)
+}
+
+/// Left pad the big endian bytes to an abi word of 32 bytes
+fn left_pad(data: &[u8]) -> Vec<u8> {
+    let start = data.iter().position(|b| *b != 0).unwrap_or(data.len());
+    let mut word = vec![0u8; 32 - (data.len() - start)];
+    word.extend_from_slice(&data[start..]);
+    word
+}
+
+/// Build the `Transfer` event log emitted by the token contract
+pub fn build_transfer_log(token: &[u8], from: &[u8], to: &[u8], value: u128) -> Vec<u8> {
+    let topic = hex::decode(TRANSFER_EVENT_TOPIC).expect("decode topic hex");
+    rlp_encode_list(&[
+        rlp_encode_bytes(token),
+        rlp_encode_list(&[
+            rlp_encode_bytes(&topic),
+            rlp_encode_bytes(&left_pad(from)),
+            rlp_encode_bytes(&left_pad(to)),
+        ]),
+        rlp_encode_bytes(&left_pad(&value.to_be_bytes())),
+    ])
+}
+
+/// Build a block at `block_number` which contains only the transaction,
+/// return the block hash and the spv proof of the transaction.
+pub fn build_block_proof(
+    tx_data: Vec<u8>,
+    success: bool,
+    block_number: u64,
+) -> (String, ETHSPVProofJson) {
+    build_block_proof_with_logs(tx_data, success, vec![], block_number)
+}
+
+/// Same as `build_block_proof`, with the receipt of the transaction containing the encoded logs
+pub fn build_block_proof_with_logs(
+    tx_data: Vec<u8>,
+    success: bool,
+    logs: Vec<Vec<u8>>,
+    block_number: u64,
+) -> (String, ETHSPVProofJson) {
+    let receipt_data = rlp_encode_list(&[
+        rlp_encode_uint(success as u128),
+        rlp_encode_uint(LEGACY_TX_GAS as u128),
+        rlp_encode_bytes(&[0u8; 256]),
+        rlp_encode_list(&logs),
+    ]);
+    // the trie contains only one leaf, whose key is rlp(0) = 0x80
+    let tx_leaf = rlp_encode_list(&[rlp_encode_bytes(&[0x20, 0x80]), rlp_encode_bytes(&tx_data)]);
+    let receipt_leaf = rlp_encode_list(&[
+        rlp_encode_bytes(&[0x20, 0x80]),
+        rlp_encode_bytes(&receipt_data),
+    ]);
+    let mut header_fields = vec![rlp_encode_bytes(&[0u8; 32]); 4];
+    header_fields.push(rlp_encode_bytes(&keccak256(&tx_leaf)));
+    header_fields.push(rlp_encode_bytes(&keccak256(&receipt_leaf)));
+    header_fields.push(rlp_encode_bytes(&[0u8; 256]));
+    header_fields.push(rlp_encode_uint(1));
+    header_fields.push(rlp_encode_uint(block_number as u128));
+    header_fields.extend(vec![rlp_encode_uint(1); 3]);
+    header_fields.push(rlp_encode_bytes(&[]));
+    header_fields.push(rlp_encode_bytes(&[0u8; 32]));
+    header_fields.push(rlp_encode_bytes(&[0u8; 8]));
+    let header_data = rlp_encode_list(&header_fields);
+    let block_hash = hex::encode(keccak256(&header_data));
+    let proof = ETHSPVProofJson {
+        header_data: hex::encode(header_data),
+        tx_index: 0,
+        tx_data: hex::encode(tx_data),
+        tx_proof: vec![hex::encode(tx_leaf)],
+        receipt_data: hex::encode(receipt_data),
+        receipt_proof: vec![hex::encode(receipt_leaf)],
+    };
+    (block_hash, proof)
+}
+
+/// Build a block which contains only one transaction transferring `value` wei to `to`
+pub fn build_single_transfer_proof(
+    to: &[u8],
+    value: u128,
+    success: bool,
+) -> (String, ETHSPVProofJson) {
+    let tx_data = build_transfer_tx(&default_signer_key(), to, value);
+    build_block_proof(tx_data, success, 1)
+}
+
+/// Build a block which contains only one transaction transferring `value` of the token to `to`,
+/// whose receipt has the `Transfer` event
+pub fn build_single_token_transfer_proof(
+    token: &[u8],
+    to: &[u8],
+    value: u128,
+) -> (String, ETHSPVProofJson) {
+    let privkey = default_signer_key();
+    let from = hex::decode(&get_address(&privkey)[2..]).expect("decode address hex");
+    let tx_data = build_token_transfer_tx(&privkey, token, to, value);
+    let log = build_transfer_log(token, &from, to, value);
+    build_block_proof_with_logs(tx_data, true, vec![log], 1)
+}
+
+pub fn get_tx_hash(proof: &ETHSPVProofJson) -> String {
+    let tx_data = hex::decode(&proof.tx_data).expect("decode tx data hex");
+    hex::encode(keccak256(&tx_data))
+}
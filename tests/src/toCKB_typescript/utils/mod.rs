@@ -1,5 +1,6 @@
 pub mod case_builder;
 pub mod case_runner;
+pub mod eth_proof;
 
 pub use tockb_types as types;
 pub use tockb_types::config;
@@ -13,7 +13,7 @@ use molecule::prelude::Entity;
 use tockb_types::generated::mint_xt_witness::BTCSPVProof;
 use utils::{generate_mint_xt_proof, spv_prove};
 
-/// generate btc proof for toCKB
+/// generate btc or ltc proof for toCKB
 #[derive(Clap)]
 #[clap(version = "0.1", author = "jacobdenver007 <jacobdenver@163.com>")]
 struct Opts {
@@ -34,6 +34,12 @@ struct MintXt {
     funding_input_index: u32,
     #[clap(short = 'o', long)]
     funding_output_index: u32,
+    /// number of headers in the proof, i.e. the block of the tx and the blocks on top of it
+    #[clap(short, long, default_value = "1")]
+    confirmations: u64,
+    /// rpc config of the node, use a litecoind node for ltc proofs
+    #[clap(long, default_value = "tools/btc-proof-generator-by-rpc/src/cli.toml")]
+    config: String,
 }
 
 #[derive(Deserialize)]
@@ -44,7 +50,7 @@ struct BTCClient {
 }
 
 fn process_mint_xt(args: MintXt) -> Result<()> {
-    let cli_toml = fs::read_to_string("tools/btc-proof-generator-by-rpc/src/cli.toml").unwrap();
+    let cli_toml = fs::read_to_string(&args.config).unwrap();
     let cli: BTCClient = toml::from_str(&cli_toml).unwrap();
     let rpc = Client::new(cli.node, Auth::UserPass(cli.user, cli.password)).unwrap();
 
@@ -56,8 +62,21 @@ fn process_mint_xt(args: MintXt) -> Result<()> {
     let block_hash = tx.blockhash.expect("get block_hash from tx");
     let block = rpc.get_block(&block_hash).expect("rpc get_block");
 
+    // litecoind serves the same rpc and block format, so ltc blocks are read the same way
+    let height = rpc
+        .get_block_info(&block_hash)
+        .expect("rpc get_block_info")
+        .height as u64;
+    let confirm_headers = (1..args.confirmations)
+        .map(|i| {
+            let hash = rpc.get_block_hash(height + i).expect("rpc get_block_hash");
+            rpc.get_block(&hash).expect("rpc get_block").header
+        })
+        .collect::<Vec<_>>();
+
     let mint_xt_proof = generate_mint_xt_proof(
         &block,
+        &confirm_headers,
         args.tx_hash.as_str(),
         args.funding_output_index,
         args.funding_input_index,
@@ -1,5 +1,5 @@
 use anyhow::Result;
-use bitcoin::{consensus::encode::serialize_hex, Block};
+use bitcoin::{consensus::encode::serialize_hex, Block, BlockHeader};
 use bitcoin_spv::types::Hash256Digest;
 use bitcoin_spv::{btcspv::hash256_merkle_step, types::MerkleArray, validatespv};
 use molecule::prelude::{Builder, Entity};
@@ -41,8 +41,10 @@ impl TryFrom<MintXTProof> for BTCSPVProof {
     }
 }
 
+/// `confirm_headers` are the headers on top of the block, which are appended to the proof headers
 pub fn generate_mint_xt_proof(
     block: &Block,
+    confirm_headers: &[BlockHeader],
     tx_hash: &str,
     funding_output_index: u32,
     funding_input_index: u32,
@@ -65,6 +67,10 @@ pub fn generate_mint_xt_proof(
         .into_iter()
         .flat_map(|p| p.into_iter())
         .collect::<Vec<u8>>();
+    let headers = std::iter::once(&block.header)
+        .chain(confirm_headers)
+        .map(serialize_hex)
+        .collect::<String>();
 
     Ok(MintXTProof {
         version: tx.version,
@@ -73,7 +79,7 @@ pub fn generate_mint_xt_proof(
         locktime: tx.lock_time,
         tx_id: hex::encode(tx.txid().as_ref()),
         index: tx_index as u64,
-        headers: serialize_hex(&block.header),
+        headers,
         intermediate_nodes: hex::encode(flat_proof),
         funding_output_index,
         funding_input_index,
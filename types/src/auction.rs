@@ -0,0 +1,62 @@
+use crate::Error;
+
+/// The price curve of auctions, chosen by the protocol params.
+///
+/// The bidder pays the lot of XT and gets `AUCTION_INIT_PERCENT` of the collateral at first. The
+/// discount to the whole collateral shrinks with the auction time along the curve, and the bidder
+/// gets the whole collateral after `AUCTION_MAX_TIME`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum AuctionCurve {
+    /// The discount shrinks linearly to zero at the max time.
+    Linear,
+    /// The discount halves every `half_life` seconds, linear within a half life.
+    ExponentialDecay { half_life: u64 },
+    /// The discount shrinks linearly to zero at the max time, but only once every `step` seconds.
+    Stepwise { step: u64 },
+}
+
+impl AuctionCurve {
+    pub fn new(kind: u8, param: u64) -> Result<AuctionCurve, Error> {
+        match (kind, param) {
+            (0, _) => Ok(AuctionCurve::Linear),
+            (1, half_life) if half_life > 0 => Ok(AuctionCurve::ExponentialDecay { half_life }),
+            (2, step) if step > 0 => Ok(AuctionCurve::Stepwise { step }),
+            _ => Err(Error::ProtocolParamsInvalid),
+        }
+    }
+
+    /// Collateral paid to the bidder of an auction which started `auction_time` seconds ago
+    pub fn get_bidder_payout(
+        &self,
+        collateral: u64,
+        init_percent: u8,
+        max_time: u64,
+        auction_time: u64,
+    ) -> u64 {
+        if auction_time >= max_time {
+            return collateral;
+        }
+        let init_collateral = mul_div(collateral, init_percent as u64, 100);
+        let discount = collateral - init_collateral;
+        let remaining_discount = match *self {
+            AuctionCurve::Linear => discount - mul_div(discount, auction_time, max_time),
+            AuctionCurve::Stepwise { step } => {
+                discount - mul_div(discount, auction_time / step * step, max_time)
+            }
+            AuctionCurve::ExponentialDecay { half_life } => {
+                let halvings = auction_time / half_life;
+                if halvings >= 64 {
+                    0
+                } else {
+                    let start = discount >> halvings;
+                    start - mul_div(start - start / 2, auction_time % half_life, half_life)
+                }
+            }
+        };
+        collateral - remaining_discount
+    }
+}
+
+fn mul_div(value: u64, numerator: u64, denominator: u64) -> u64 {
+    (value as u128 * numerator as u128 / denominator as u128) as u64
+}
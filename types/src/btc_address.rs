@@ -0,0 +1,227 @@
+use crate::Error;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use sha2::{Digest, Sha256};
+
+const BASE58_ALPHABET: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BECH32_CHARSET: &[u8] = b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const BECH32_CONST: u32 = 1;
+const BECH32M_CONST: u32 = 0x2bc8_30a3;
+const BECH32_MAX_LEN: usize = 90;
+const BECH32_CHECKSUM_LEN: usize = 6;
+
+const OP_0: u8 = 0x00;
+const OP_1: u8 = 0x51;
+const OP_DUP: u8 = 0x76;
+const OP_HASH160: u8 = 0xa9;
+const OP_EQUAL: u8 = 0x87;
+const OP_EQUALVERIFY: u8 = 0x88;
+const OP_CHECKSIG: u8 = 0xac;
+const OP_CHECKMULTISIG: u8 = 0xae;
+
+/// Address encoding of a network of a BTC like chain
+pub trait AddressNetwork {
+    /// human-readable part of bech32 addresses
+    fn address_hrp(&self) -> &'static str;
+    /// base58check version byte of P2PKH addresses
+    fn p2pkh_version(&self) -> u8;
+    /// base58check version byte of P2SH addresses
+    fn p2sh_version(&self) -> u8;
+}
+
+/// Decode a BTC address, or an address of a chain sharing its formats like LTC, and return the
+/// script_pubkey it pays to.
+///
+/// Supported types are base58 P2PKH/P2SH, bech32 P2WPKH/P2WSH and bech32m P2TR,
+/// addresses of other networks are rejected.
+pub fn address_to_script<N: AddressNetwork>(addr: &[u8], network: N) -> Result<Vec<u8>, Error> {
+    let hrp = network.address_hrp().as_bytes();
+    if addr.len() > hrp.len()
+        && addr[..hrp.len()].eq_ignore_ascii_case(hrp)
+        && addr[hrp.len()] == b'1'
+    {
+        segwit_address_to_script(addr, hrp.len())
+    } else {
+        base58_address_to_script(addr, network)
+    }
+}
+
+/// Return true if the script_pubkey is one of the types `address_to_script` may produce.
+pub fn is_supported_script(script: &[u8]) -> bool {
+    match script {
+        [OP_DUP, OP_HASH160, 0x14, .., OP_EQUALVERIFY, OP_CHECKSIG] => script.len() == 25,
+        [OP_HASH160, 0x14, .., OP_EQUAL] => script.len() == 23,
+        [OP_0, 0x14, ..] => script.len() == 22,
+        [OP_0, 0x20, ..] | [OP_1, 0x20, ..] => script.len() == 34,
+        _ => false,
+    }
+}
+
+/// Return the P2WSH script_pubkey of the `threshold`-of-N multisig over compressed `pubkeys`,
+/// i.e. the witness script `OP_M <pubkey>... OP_N OP_CHECKMULTISIG` hashed with sha256.
+pub fn multisig_p2wsh_script(threshold: u8, pubkeys: &[&[u8]]) -> Result<Vec<u8>, Error> {
+    // OP_1 to OP_16 encode M and N
+    if threshold == 0 || threshold as usize > pubkeys.len() || pubkeys.len() > 16 {
+        return Err(Error::SignerGroupInvalid);
+    }
+    let mut witness_script = Vec::with_capacity(3 + 34 * pubkeys.len());
+    witness_script.push(OP_1 + threshold - 1);
+    for pubkey in pubkeys {
+        match pubkey {
+            [0x02, ..] | [0x03, ..] if pubkey.len() == 33 => {}
+            _ => return Err(Error::SignerGroupInvalid),
+        }
+        witness_script.push(pubkey.len() as u8);
+        witness_script.extend_from_slice(pubkey);
+    }
+    witness_script.push(OP_1 + pubkeys.len() as u8 - 1);
+    witness_script.push(OP_CHECKMULTISIG);
+
+    let mut script = Vec::with_capacity(34);
+    script.push(OP_0);
+    script.push(0x20);
+    script.extend_from_slice(&Sha256::digest(&witness_script));
+    Ok(script)
+}
+
+fn base58_address_to_script<N: AddressNetwork>(addr: &[u8], network: N) -> Result<Vec<u8>, Error> {
+    let decoded = base58_decode(addr)?;
+    // version(1) + hash160(20) + checksum(4)
+    if decoded.len() != 25 {
+        return Err(Error::XChainAddressInvalid);
+    }
+    let (payload, checksum) = decoded.split_at(21);
+    if &Sha256::digest(&Sha256::digest(payload))[..4] != checksum {
+        return Err(Error::XChainAddressInvalid);
+    }
+    let hash = &payload[1..];
+    let mut script = Vec::with_capacity(25);
+    match payload[0] {
+        version if version == network.p2pkh_version() => {
+            script.extend_from_slice(&[OP_DUP, OP_HASH160, 0x14]);
+            script.extend_from_slice(hash);
+            script.extend_from_slice(&[OP_EQUALVERIFY, OP_CHECKSIG]);
+        }
+        version if version == network.p2sh_version() => {
+            script.extend_from_slice(&[OP_HASH160, 0x14]);
+            script.extend_from_slice(hash);
+            script.push(OP_EQUAL);
+        }
+        _ => return Err(Error::XChainAddressInvalid),
+    }
+    Ok(script)
+}
+
+fn base58_decode(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut ret: Vec<u8> = Vec::with_capacity(data.len());
+    for c in data {
+        let mut carry = BASE58_ALPHABET
+            .iter()
+            .position(|a| a == c)
+            .ok_or(Error::XChainAddressInvalid)? as u32;
+        for byte in ret.iter_mut() {
+            carry += (*byte as u32) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+        while carry > 0 {
+            ret.push(carry as u8);
+            carry >>= 8;
+        }
+    }
+    // every leading '1' stands for a leading zero byte
+    for _ in data.iter().take_while(|c| **c == BASE58_ALPHABET[0]) {
+        ret.push(0);
+    }
+    ret.reverse();
+    Ok(ret)
+}
+
+fn segwit_address_to_script(addr: &[u8], hrp_len: usize) -> Result<Vec<u8>, Error> {
+    if addr.len() > BECH32_MAX_LEN {
+        return Err(Error::XChainAddressInvalid);
+    }
+    // mixed case is not allowed
+    if addr.iter().any(u8::is_ascii_lowercase) && addr.iter().any(u8::is_ascii_uppercase) {
+        return Err(Error::XChainAddressInvalid);
+    }
+    let addr: Vec<u8> = addr.iter().map(u8::to_ascii_lowercase).collect();
+    let data = addr[hrp_len + 1..]
+        .iter()
+        .map(|c| {
+            BECH32_CHARSET
+                .iter()
+                .position(|a| a == c)
+                .map(|p| p as u8)
+                .ok_or(Error::XChainAddressInvalid)
+        })
+        .collect::<Result<Vec<u8>, Error>>()?;
+    if data.len() < BECH32_CHECKSUM_LEN + 1 {
+        return Err(Error::XChainAddressInvalid);
+    }
+
+    let mut values = hrp_expand(&addr[..hrp_len]);
+    values.extend_from_slice(&data);
+    let checksum_const = bech32_polymod(&values);
+
+    let version = data[0];
+    let program = convert_bits(&data[1..data.len() - BECH32_CHECKSUM_LEN])?;
+    // BIP173 for version 0 and BIP350 for version 1
+    match (version, checksum_const, program.len()) {
+        (0, BECH32_CONST, 20) | (0, BECH32_CONST, 32) | (1, BECH32M_CONST, 32) => {}
+        _ => return Err(Error::XChainAddressInvalid),
+    }
+
+    let mut script = Vec::with_capacity(program.len() + 2);
+    script.push(if version == 0 { OP_0 } else { OP_1 });
+    script.push(program.len() as u8);
+    script.extend(program);
+    Ok(script)
+}
+
+fn hrp_expand(hrp: &[u8]) -> Vec<u8> {
+    let mut ret: Vec<u8> = hrp.iter().map(|c| c >> 5).collect();
+    ret.push(0);
+    ret.extend(hrp.iter().map(|c| c & 0x1f));
+    ret
+}
+
+fn bech32_polymod(values: &[u8]) -> u32 {
+    const GENERATOR: [u32; 5] = [
+        0x3b6a_57b2,
+        0x2650_8e6d,
+        0x1ea1_19fa,
+        0x3d42_33dd,
+        0x2a14_62b3,
+    ];
+    let mut chk: u32 = 1;
+    for v in values {
+        let top = chk >> 25;
+        chk = ((chk & 0x01ff_ffff) << 5) ^ (*v as u32);
+        for (i, g) in GENERATOR.iter().enumerate() {
+            if (top >> i) & 1 == 1 {
+                chk ^= g;
+            }
+        }
+    }
+    chk
+}
+
+/// Regroup 5-bit values into bytes, the padding must be zero and shorter than 5 bits
+fn convert_bits(data: &[u8]) -> Result<Vec<u8>, Error> {
+    let mut acc: u32 = 0;
+    let mut bits: u32 = 0;
+    let mut ret = Vec::with_capacity(data.len() * 5 / 8);
+    for v in data {
+        acc = ((acc << 5) | (*v as u32)) & 0xfff;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            ret.push((acc >> bits) as u8);
+        }
+    }
+    if bits >= 5 || acc & ((1 << bits) - 1) != 0 {
+        return Err(Error::XChainAddressInvalid);
+    }
+    Ok(ret)
+}
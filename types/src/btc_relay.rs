@@ -0,0 +1,298 @@
+use crate::{
+    config::BTC_RELAY_MAX_HEADERS,
+    generated::btc_header_relay::{BTCHeaderRelay, BTCHeaderRelayReader},
+    tockb_cell::BtcNetwork,
+    Error,
+};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::convert::TryInto;
+use molecule::{
+    bytes::Bytes,
+    prelude::{Builder, Entity, Reader},
+};
+use primitive_types::U256;
+use sha2::{Digest, Sha256};
+
+pub const BTC_HEADER_SIZE: usize = 80;
+// hash(32 byte) + chain_work(32 byte) + time(4 byte) + bits(4 byte)
+pub const BTC_RELAY_ENTRY_SIZE: usize = 72;
+
+const DIFFICULTY_ADJUSTMENT_INTERVAL: u64 = 2016;
+const POW_TARGET_TIMESPAN: u64 = 14 * 24 * 3600;
+const POW_TARGET_SPACING: u32 = 10 * 60;
+
+/// A header kept by the relay cell
+#[derive(Debug, Clone, PartialEq)]
+pub struct BtcRelayEntry {
+    pub hash: [u8; 32],
+    pub chain_work: U256,
+    pub time: u32,
+    pub bits: u32,
+}
+
+impl BtcRelayEntry {
+    fn from_slice(slice: &[u8]) -> Self {
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&slice[..32]);
+        BtcRelayEntry {
+            hash,
+            chain_work: U256::from_little_endian(&slice[32..64]),
+            time: u32::from_le_bytes(slice[64..68].try_into().unwrap()),
+            bits: u32::from_le_bytes(slice[68..72].try_into().unwrap()),
+        }
+    }
+
+    fn to_bytes(&self) -> [u8; BTC_RELAY_ENTRY_SIZE] {
+        let mut ret = [0u8; BTC_RELAY_ENTRY_SIZE];
+        ret[..32].copy_from_slice(&self.hash);
+        self.chain_work.to_little_endian(&mut ret[32..64]);
+        ret[64..68].copy_from_slice(&self.time.to_le_bytes());
+        ret[68..72].copy_from_slice(&self.bits.to_le_bytes());
+        ret
+    }
+}
+
+/// Data of the BTC header relay cell, `headers` are the latest headers of the best chain
+/// in ascending order, the last one is the tip at `height`.
+#[derive(Debug, Clone)]
+pub struct BtcRelayView {
+    pub height: u64,
+    pub epoch_start_time: u32,
+    pub epoch_bits: u32,
+    pub headers: Vec<BtcRelayEntry>,
+}
+
+impl BtcRelayView {
+    pub fn new(data: &[u8]) -> Result<BtcRelayView, Error> {
+        BTCHeaderRelayReader::verify(data, false).map_err(|_| Error::HeaderRelayDataInvalid)?;
+        let data_reader = BTCHeaderRelayReader::new_unchecked(data);
+        let raw_headers = data_reader.headers().raw_data();
+        let count = raw_headers.len() / BTC_RELAY_ENTRY_SIZE;
+        let height: u64 = data_reader.height().into();
+        if raw_headers.len() % BTC_RELAY_ENTRY_SIZE != 0
+            || count == 0
+            || count > BTC_RELAY_MAX_HEADERS
+            || (count as u64) > height + 1
+        {
+            return Err(Error::HeaderRelayDataInvalid);
+        }
+        Ok(BtcRelayView {
+            height,
+            epoch_start_time: data_reader.epoch_start_time().into(),
+            epoch_bits: data_reader.epoch_bits().into(),
+            headers: raw_headers
+                .chunks(BTC_RELAY_ENTRY_SIZE)
+                .map(BtcRelayEntry::from_slice)
+                .collect(),
+        })
+    }
+
+    pub fn as_molecule_data(&self) -> Bytes {
+        let mut raw_headers = Vec::with_capacity(self.headers.len() * BTC_RELAY_ENTRY_SIZE);
+        for entry in self.headers.iter() {
+            raw_headers.extend_from_slice(&entry.to_bytes());
+        }
+        BTCHeaderRelay::new_builder()
+            .height(self.height.into())
+            .epoch_start_time(self.epoch_start_time.into())
+            .epoch_bits(self.epoch_bits.into())
+            .headers(raw_headers.into())
+            .build()
+            .as_bytes()
+    }
+
+    pub fn tip(&self) -> &BtcRelayEntry {
+        self.headers
+            .last()
+            .expect("relay keeps at least one header")
+    }
+
+    /// Return the number of confirmations of the header if it is kept by the relay,
+    /// the tip has 1 confirmation.
+    pub fn get_confirmations(&self, hash: &[u8]) -> Option<u64> {
+        self.headers
+            .iter()
+            .rposition(|entry| &entry.hash[..] == hash)
+            .map(|index| (self.headers.len() - index) as u64)
+    }
+
+    /// Connect serialized 80-byte headers to the relay and return the new relay data.
+    ///
+    /// The first header may build on any kept header, in which case the headers after it are
+    /// replaced if the new branch has more chain work. A fork can not start before the first
+    /// header of the current difficulty epoch.
+    pub fn apply_headers(
+        &self,
+        network: BtcNetwork,
+        raw_headers: &[u8],
+    ) -> Result<BtcRelayView, Error> {
+        if raw_headers.is_empty() || raw_headers.len() % BTC_HEADER_SIZE != 0 {
+            return Err(Error::BtcHeaderInvalid);
+        }
+        let fork_index = self
+            .headers
+            .iter()
+            .rposition(|entry| entry.hash[..] == raw_headers[4..36])
+            .ok_or(Error::BtcHeaderForkInvalid)?;
+        let mut height = self.height - (self.headers.len() - 1 - fork_index) as u64;
+        if height < self.height - self.height % DIFFICULTY_ADJUSTMENT_INTERVAL {
+            return Err(Error::BtcHeaderForkInvalid);
+        }
+
+        let mut epoch_start_time = self.epoch_start_time;
+        let mut epoch_bits = self.epoch_bits;
+        let mut headers = self.headers[..=fork_index].to_vec();
+        for raw_header in raw_headers.chunks(BTC_HEADER_SIZE) {
+            let prev = headers.last().expect("relay keeps at least one header");
+            if prev.hash[..] != raw_header[4..36] {
+                return Err(Error::BtcHeaderInvalid);
+            }
+            height += 1;
+            let time = u32::from_le_bytes(raw_header[68..72].try_into().unwrap());
+            let bits = u32::from_le_bytes(raw_header[72..76].try_into().unwrap());
+
+            let is_epoch_start = height % DIFFICULTY_ADJUSTMENT_INTERVAL == 0;
+            let expect_bits = if is_epoch_start && !network.no_retargeting() {
+                calc_next_bits(network, prev, epoch_start_time)?
+            } else if network.allow_min_difficulty_blocks()
+                && time > prev.time.saturating_add(POW_TARGET_SPACING * 2)
+            {
+                network.pow_limit_bits()
+            } else {
+                epoch_bits
+            };
+            if bits != expect_bits {
+                return Err(Error::BtcHeaderInvalid);
+            }
+            if is_epoch_start {
+                epoch_start_time = time;
+                epoch_bits = bits;
+            }
+
+            let target = bits_to_target(bits)?;
+            let hash = hash256(raw_header);
+            if target > bits_to_target(network.pow_limit_bits())?
+                || U256::from_little_endian(&hash) > target
+            {
+                return Err(Error::BtcHeaderInvalid);
+            }
+            let chain_work = prev.chain_work + get_work(target);
+            headers.push(BtcRelayEntry {
+                hash,
+                chain_work,
+                time,
+                bits,
+            });
+        }
+
+        if headers.last().unwrap().chain_work <= self.tip().chain_work {
+            return Err(Error::BtcChainWorkNotEnough);
+        }
+        if headers.len() > BTC_RELAY_MAX_HEADERS {
+            headers.drain(..headers.len() - BTC_RELAY_MAX_HEADERS);
+        }
+        Ok(BtcRelayView {
+            height,
+            epoch_start_time,
+            epoch_bits,
+            headers,
+        })
+    }
+}
+
+/// Return the difficulty each header of a spv proof must be worth, given the difficulties of the
+/// proof headers and the previous and current epoch difficulties of the difficulty oracle.
+///
+/// The headers must all be at one of the two difficulties, or start at the previous one and
+/// retarget once to the current one. Headers carry no height, so a retarget is only accepted if
+/// neither side is longer than an epoch and the change is within the 4x bound of a retarget.
+pub fn get_spv_required_difficulty(
+    header_difficulties: &[U256],
+    previous: U256,
+    current: U256,
+) -> Result<U256, Error> {
+    let first = *header_difficulties.first().ok_or(Error::BtcHeaderInvalid)?;
+    if first != current && first != previous {
+        return Err(Error::NotAtCurrentOrPreviousDifficulty);
+    }
+    let retarget_index = match header_difficulties.iter().position(|diff| *diff != first) {
+        Some(index) => index,
+        None => return Ok(first),
+    };
+    let epoch_len = DIFFICULTY_ADJUSTMENT_INTERVAL as usize;
+    if first != previous
+        || header_difficulties[retarget_index..]
+            .iter()
+            .any(|diff| *diff != current)
+        || retarget_index > epoch_len
+        || header_difficulties.len() - retarget_index > epoch_len
+        || previous.saturating_mul(U256::from(4)) < current
+        || current.saturating_mul(U256::from(4)) < previous
+    {
+        return Err(Error::BtcRetargetInvalid);
+    }
+    Ok(previous.min(current))
+}
+
+pub fn hash256(data: &[u8]) -> [u8; 32] {
+    let mut ret = [0u8; 32];
+    ret.copy_from_slice(&Sha256::digest(&Sha256::digest(data)));
+    ret
+}
+
+/// Same as `CalculateNextWorkRequired` of bitcoin core, `last` is the last header of the epoch
+fn calc_next_bits(
+    network: BtcNetwork,
+    last: &BtcRelayEntry,
+    epoch_start_time: u32,
+) -> Result<u32, Error> {
+    let timespan = (last.time as i64 - epoch_start_time as i64).max(0) as u64;
+    let timespan = timespan
+        .max(POW_TARGET_TIMESPAN / 4)
+        .min(POW_TARGET_TIMESPAN * 4);
+    let pow_limit = bits_to_target(network.pow_limit_bits())?;
+    let target = match bits_to_target(last.bits)?.checked_mul(timespan.into()) {
+        Some(target) => (target / POW_TARGET_TIMESPAN).min(pow_limit),
+        None => pow_limit,
+    };
+    Ok(target_to_bits(target))
+}
+
+/// Decode the compact target, negative or overflowed targets are invalid
+pub fn bits_to_target(bits: u32) -> Result<U256, Error> {
+    let exponent = bits >> 24;
+    let mantissa = bits & 0x007f_ffff;
+    if mantissa != 0
+        && (bits & 0x0080_0000 != 0
+            || exponent > 34
+            || (mantissa > 0xff && exponent > 33)
+            || (mantissa > 0xffff && exponent > 32))
+    {
+        return Err(Error::BtcHeaderInvalid);
+    }
+    if exponent <= 3 {
+        Ok(U256::from(mantissa >> (8 * (3 - exponent))))
+    } else {
+        Ok(U256::from(mantissa) << (8 * (exponent - 3) as usize))
+    }
+}
+
+pub fn target_to_bits(target: U256) -> u32 {
+    let mut size = (target.bits() + 7) / 8;
+    let mut compact = if size <= 3 {
+        target.low_u32() << (8 * (3 - size))
+    } else {
+        (target >> (8 * (size - 3))).low_u32()
+    };
+    if compact & 0x0080_0000 != 0 {
+        compact >>= 8;
+        size += 1;
+    }
+    compact | (size as u32) << 24
+}
+
+/// Expected number of hashes to find a header meeting the target
+fn get_work(target: U256) -> U256 {
+    (!target / (target + 1)) + 1
+}
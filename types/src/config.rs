@@ -5,20 +5,54 @@ pub const XT_CELL_CAPACITY: u64 = 200 * CKB_UNITS;
 
 pub const COLLATERAL_PERCENT: u8 = 150;
 
-pub const TX_PROOF_DIFFICULTY_FACTOR: u8 = 1;
-// default value is 2/1000
-pub const SIGNER_FEE_RATE: (u128, u128) = (2, 1000);
+// min number of headers, i.e. the block containing the XChain tx and the blocks on top of it, in
+// the BTC spv proof checked against the difficulty oracle
+pub const BTC_SPV_CONFIRMATIONS: u8 = 6;
+// LTC blocks come 4 times as often as BTC ones, so LTC spv proofs need 4 times the confirmations
+pub const LTC_SPV_CONFIRMATIONS_FACTOR: u8 = 4;
+// signer fee rates are in units of 1/SIGNER_FEE_RATE_DENOMINATOR, i.e. 0.001%
+pub const SIGNER_FEE_RATE_DENOMINATOR: u128 = 100_000;
+// default value is 2/1000, cells created before the per-deposit fee rate use it
+pub const SIGNER_FEE_RATE: u32 = 200;
+// max fee rate a deposit can propose, 1%
+pub const MAX_SIGNER_FEE_RATE: u32 = 1000;
+// max number of signers in a signer group, the BTC standardness limit of multisig keys
+pub const MAX_SIGNER_GROUP_SIZE: usize = 15;
+// seconds only the signer chosen at deposit request can bond the deposit, other signers bond it
+// with a relative since of at least this long
+pub const BONDING_WINDOW: u64 = 2 * 3600;
+// (min, max) XT amount of a deposit in base units, 0.01 to 10 BTC, 0.1 to 100 ETH and 1 to
+// 1000 LTC
+pub const BTC_LOT_AMOUNT_RANGE: (u128, u128) = (1_000_000, 1_000_000_000);
+pub const ETH_LOT_AMOUNT_RANGE: (u128, u128) =
+    (100_000_000_000_000_000, 100_000_000_000_000_000_000);
+pub const LTC_LOT_AMOUNT_RANGE: (u128, u128) = (100_000_000, 100_000_000_000);
+// tokens have different decimals, the range of ERC-20 deposits in base units of the token only
+// rules out dust and amounts too large for any token
+pub const ERC20_LOT_AMOUNT_RANGE: (u128, u128) = (1_000, 1_000_000_000_000_000_000_000_000_000);
 pub const SUDT_CODE_HASH: [u8; 32] = [
     225, 227, 84, 214, 214, 67, 173, 66, 114, 77, 64, 150, 126, 51, 73, 132, 83, 78, 3, 103, 64,
     92, 90, 228, 42, 157, 125, 99, 215, 125, 244, 25,
 ];
+// type hash of the cell listing confirmed ETH headers, ETH proofs are only checked against it.
+// it is the type id script with the dev args 0x0404..04 and must be replaced before deployment
+pub const ETH_CONFIRMED_HEADERS_TYPE_HASH: [u8; 32] = [
+    30, 62, 83, 0, 84, 206, 26, 112, 133, 80, 164, 9, 131, 96, 138, 98, 22, 47, 106, 146, 7, 150,
+    254, 199, 9, 173, 143, 133, 248, 242, 81, 208,
+];
 
 // since
 pub const LOCK_TYPE_FLAG: u64 = 1 << 63;
+pub const SINCE_TYPE_BLOCK_NUMBER: u64 = 0x0;
+pub const SINCE_TYPE_EPOCH_NUMBER: u64 = 0x2000_0000_0000_0000;
 pub const SINCE_TYPE_TIMESTAMP: u64 = 0x4000_0000_0000_0000;
 pub const METRIC_TYPE_FLAG_MASK: u64 = 0x6000_0000_0000_0000;
 pub const VALUE_MASK: u64 = 0x00ff_ffff_ffff_ffff;
 pub const REMAIN_FLAGS_BITS: u64 = 0x1f00_0000_0000_0000;
+// the target block interval and epoch duration of CKB, relative block number and epoch since are
+// converted to seconds with them when pricing auctions
+pub const BLOCK_INTERVAL_SECONDS: u64 = 8;
+pub const EPOCH_DURATION_SECONDS: u64 = 4 * 3600;
 
 // 24 * 3600 means 1 day, the unit is second
 pub const SINCE_SIGNER_TIMEOUT: u64 = LOCK_TYPE_FLAG | SINCE_TYPE_TIMESTAMP | 24 * 3600;
@@ -40,5 +74,89 @@ The time unit is second.
 */
 pub const AUCTION_MAX_TIME: u64 = 3 * 24 * 3600;
 pub const AUCTION_INIT_PERCENT: u8 = 67;
+// the price curve of auctions and its parameter, see auction::AuctionCurve
+pub const AUCTION_CURVE: u8 = 0;
+pub const AUCTION_CURVE_PARAM: u64 = 0;
+// percent of the collateral the depositor gets when the signer moves the locked XAsset during the
+// warranty, the rest is auctioned
+pub const FAULTY_COMPENSATION_PERCENT: u8 = 10;
+
+// max number of latest headers kept by the BTC header relay cell, about one week
+pub const BTC_RELAY_MAX_HEADERS: usize = 1008;
 
-pub const BTC_ADDRESS_PREFIX: &str = "bcrt";
+// min number of whitelisted publishers whose prices are aggregated into the median price
+pub const PRICE_ORACLE_THRESHOLD: usize = 3;
+// prices older than PRICE_ORACLE_MAX_AGE seconds before the latest header dep are stale, the header
+// of the block creating the toCKB input must be in header deps
+pub const PRICE_ORACLE_MAX_AGE: u64 = 3600;
+// eth style addresses of whitelisted price publishers,
+// they are the dev keys 0x0101..01 to 0x0505..05 and must be replaced before deployment
+pub const PRICE_ORACLE_PUBLISHERS: [[u8; 20]; 5] = [
+    [
+        26, 100, 47, 14, 60, 58, 245, 69, 231, 172, 189, 56, 176, 114, 81, 179, 153, 9, 20, 241,
+    ],
+    [
+        80, 80, 164, 244, 179, 249, 51, 140, 52, 114, 220, 192, 26, 135, 199, 106, 20, 75, 60, 156,
+    ],
+    [
+        51, 37, 167, 132, 37, 241, 122, 126, 72, 126, 181, 102, 107, 43, 253, 147, 171, 176, 108,
+        112,
+    ],
+    [
+        196, 139, 129, 43, 180, 52, 1, 57, 44, 3, 115, 129, 172, 169, 52, 244, 6, 156, 5, 23,
+    ],
+    [
+        208, 154, 209, 64, 128, 212, 178, 87, 168, 25, 164, 245, 121, 184, 72, 91, 232, 143, 8, 108,
+    ],
+];
+
+// type hashes of the price cells of PRICE_ORACLE_PUBLISHERS in the same order, cell deps of other
+// types are not prices. each publisher updates its type id cell every round, so the old round is
+// spent. they are the type id scripts with the dev args 0x1010..10 to 0x1414..14 and must be
+// replaced before deployment
+pub const PRICE_ORACLE_TYPE_HASHES: [[u8; 32]; 5] = [
+    [
+        104, 161, 248, 54, 112, 149, 38, 184, 240, 86, 30, 230, 231, 118, 127, 188, 253, 44, 5,
+        121, 115, 74, 68, 253, 152, 106, 70, 16, 244, 123, 166, 147,
+    ],
+    [
+        94, 108, 169, 239, 133, 113, 157, 91, 43, 212, 133, 162, 84, 255, 237, 255, 244, 76, 30,
+        225, 243, 134, 228, 42, 236, 246, 130, 62, 201, 252, 91, 247,
+    ],
+    [
+        39, 234, 14, 197, 249, 114, 243, 197, 103, 104, 47, 93, 143, 51, 81, 52, 111, 239, 2, 173,
+        11, 93, 128, 16, 202, 229, 199, 199, 56, 197, 34, 2,
+    ],
+    [
+        247, 178, 254, 0, 157, 189, 23, 192, 34, 207, 154, 153, 254, 106, 7, 15, 89, 234, 192, 85,
+        249, 166, 184, 238, 230, 164, 229, 52, 27, 106, 196, 238,
+    ],
+    [
+        67, 152, 29, 180, 75, 99, 191, 7, 194, 117, 161, 172, 157, 125, 195, 59, 53, 199, 196, 44,
+        42, 161, 136, 104, 159, 13, 90, 214, 128, 90, 137, 152,
+    ],
+];
+// code hash of the built-in type id script, with hash type `type`
+pub const TYPE_ID_CODE_HASH: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 84, 89, 80, 69, 95,
+    73, 68,
+];
+// type hash of the governance cell holding the protocol params, the constants above are its defaults.
+// it is the type id script with the dev args 0x0101..01 and must be replaced before deployment
+pub const PROTOCOL_PARAMS_TYPE_HASH: [u8; 32] = [
+    148, 111, 113, 124, 246, 50, 75, 205, 9, 235, 251, 223, 171, 171, 60, 212, 29, 73, 214, 178, 9,
+    31, 76, 82, 157, 88, 234, 58, 168, 53, 107, 91,
+];
+// lock hash of the governance which owns the protocol params cell, a params cell under any other
+// lock is rejected. it is the secp multisig lock with the dev args 0x0202..02 and must be replaced
+// before deployment
+pub const PROTOCOL_PARAMS_LOCK_HASH: [u8; 32] = [
+    149, 60, 113, 62, 81, 91, 213, 72, 191, 200, 37, 30, 68, 121, 218, 29, 241, 24, 82, 37, 216,
+    121, 242, 187, 121, 27, 168, 41, 63, 162, 76, 145,
+];
+// type hash of the registry of XChain lock txs which have minted XT, see lock_registry.rs.
+// it is the type id script with the dev args 0x0202..02 and must be replaced before deployment
+pub const LOCK_REGISTRY_TYPE_HASH: [u8; 32] = [
+    119, 234, 157, 2, 113, 201, 151, 21, 180, 24, 15, 10, 198, 181, 229, 91, 55, 205, 148, 168,
+    192, 78, 152, 20, 152, 255, 90, 223, 225, 163, 118, 95,
+];
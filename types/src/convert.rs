@@ -8,7 +8,8 @@ use ckb_std::ckb_types::packed;
 use ckb_types::packed;
 
 use crate::generated::basic::{
-    Byte32, Byte4, Bytes, OutPoint, Script, Uint32, Uint32Reader, Uint64,
+    Byte32, Byte4, Bytes, BytesVec, OutPoint, Script, Uint128, Uint128Reader, Uint32, Uint32Reader,
+    Uint64, Uint64Reader,
 };
 use core::convert::TryFrom;
 use molecule::{
@@ -24,6 +25,14 @@ impl From<Vec<u8>> for Bytes {
     }
 }
 
+impl From<Vec<Vec<u8>>> for BytesVec {
+    fn from(v: Vec<Vec<u8>>) -> Self {
+        BytesVec::new_builder()
+            .set(v.into_iter().map(Bytes::from).collect())
+            .build()
+    }
+}
+
 impl TryFrom<Vec<u8>> for Byte4 {
     type Error = VerificationError;
     fn try_from(v: Vec<u8>) -> Result<Self, VerificationError> {
@@ -102,6 +111,36 @@ impl From<u64> for Uint64 {
     }
 }
 
+impl From<Uint64Reader<'_>> for u64 {
+    fn from(v: Uint64Reader<'_>) -> Self {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(v.raw_data());
+        u64::from_le_bytes(buf)
+    }
+}
+
+impl From<u128> for Uint128 {
+    fn from(v: u128) -> Self {
+        let mut inner = [Byte::new(0); 16];
+        let v = v
+            .to_le_bytes()
+            .to_vec()
+            .into_iter()
+            .map(Byte::new)
+            .collect::<Vec<_>>();
+        inner.copy_from_slice(&v);
+        Self::new_builder().set(inner).build()
+    }
+}
+
+impl From<Uint128Reader<'_>> for u128 {
+    fn from(v: Uint128Reader<'_>) -> Self {
+        let mut buf = [0u8; 16];
+        buf.copy_from_slice(v.raw_data());
+        u128::from_le_bytes(buf)
+    }
+}
+
 impl From<packed::Script> for Script {
     fn from(v: packed::Script) -> Self {
         Self::new_unchecked(v.as_bytes())
@@ -40,6 +40,37 @@ pub enum Error {
     InvalidTriggerOrSignerCell,
     InvalidAuctionXTCell,
     XTAmountInvalid,
+    ConfirmedHeadersDataInvalid,
+    EthHeaderNotConfirmed,
+    EthTxFailed,
+    FaultyEthWitnessInvalid,
+    HeaderRelayDataInvalid,
+    HeaderRelayMismatch,
+    BtcHeaderInvalid,
+    BtcHeaderForkInvalid,
+    BtcChainWorkNotEnough,
+    BtcHeaderNotInRelay,
+    BtcHeaderNotConfirmed,
+    PriceOracleInvalid,
+    PriceOraclePublisherInvalid,
+    PriceOracleNotEnough,
+    PriceOracleStale,
+    SignerHandoverInvalid,
+    RedeemAmountInvalid,
+    PartialWithdrawInvalid,
+    SignerGroupInvalid,
+    ProtocolParamsNotFound,
+    ProtocolParamsInvalid,
+    SignerFeeRateInvalid,
+    LockRegistryNotFound,
+    LockRegistryInvalid,
+    XLockTxReused,
+    BtcTxLengthInvalid,
+    BtcRetargetInvalid,
+    LtcHeaderInvalid,
+    InvalidCompensationCell,
+    SignerOfferInvalid,
+    SignerNotChosen,
 }
 
 impl<T: IntEnum> From<IntEnumError<T>> for Error {
@@ -0,0 +1,487 @@
+// Generated by Molecule 0.6.0
+
+use super::basic::*;
+use molecule::prelude::*;
+#[derive(Clone)]
+pub struct BTCHeaderRelayArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BTCHeaderRelayArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BTCHeaderRelayArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BTCHeaderRelayArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "network", self.network())?;
+        write!(f, ", {}: {}", "cell_id", self.cell_id())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for BTCHeaderRelayArgs {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        BTCHeaderRelayArgs::new_unchecked(v.into())
+    }
+}
+impl BTCHeaderRelayArgs {
+    pub const TOTAL_SIZE: usize = 37;
+    pub const FIELD_SIZES: [usize; 2] = [1, 36];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn network(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn cell_id(&self) -> OutPoint {
+        OutPoint::new_unchecked(self.0.slice(1..37))
+    }
+    pub fn as_reader<'r>(&'r self) -> BTCHeaderRelayArgsReader<'r> {
+        BTCHeaderRelayArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BTCHeaderRelayArgs {
+    type Builder = BTCHeaderRelayArgsBuilder;
+    const NAME: &'static str = "BTCHeaderRelayArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BTCHeaderRelayArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BTCHeaderRelayArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BTCHeaderRelayArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .network(self.network())
+            .cell_id(self.cell_id())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BTCHeaderRelayArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BTCHeaderRelayArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BTCHeaderRelayArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BTCHeaderRelayArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "network", self.network())?;
+        write!(f, ", {}: {}", "cell_id", self.cell_id())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> BTCHeaderRelayArgsReader<'r> {
+    pub const TOTAL_SIZE: usize = 37;
+    pub const FIELD_SIZES: [usize; 2] = [1, 36];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn network(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn cell_id(&self) -> OutPointReader<'r> {
+        OutPointReader::new_unchecked(&self.as_slice()[1..37])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BTCHeaderRelayArgsReader<'r> {
+    type Entity = BTCHeaderRelayArgs;
+    const NAME: &'static str = "BTCHeaderRelayArgsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BTCHeaderRelayArgsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BTCHeaderRelayArgsBuilder {
+    pub(crate) network: Byte,
+    pub(crate) cell_id: OutPoint,
+}
+impl BTCHeaderRelayArgsBuilder {
+    pub const TOTAL_SIZE: usize = 37;
+    pub const FIELD_SIZES: [usize; 2] = [1, 36];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn network(mut self, v: Byte) -> Self {
+        self.network = v;
+        self
+    }
+    pub fn cell_id(mut self, v: OutPoint) -> Self {
+        self.cell_id = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for BTCHeaderRelayArgsBuilder {
+    type Entity = BTCHeaderRelayArgs;
+    const NAME: &'static str = "BTCHeaderRelayArgsBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.network.as_slice())?;
+        writer.write_all(self.cell_id.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BTCHeaderRelayArgs::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct BTCHeaderRelay(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for BTCHeaderRelay {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for BTCHeaderRelay {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for BTCHeaderRelay {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "epoch_start_time", self.epoch_start_time())?;
+        write!(f, ", {}: {}", "epoch_bits", self.epoch_bits())?;
+        write!(f, ", {}: {}", "headers", self.headers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for BTCHeaderRelay {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            40, 0, 0, 0, 20, 0, 0, 0, 28, 0, 0, 0, 32, 0, 0, 0, 36, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        BTCHeaderRelay::new_unchecked(v.into())
+    }
+}
+impl BTCHeaderRelay {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn height(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn epoch_start_time(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn epoch_bits(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn headers(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
+        } else {
+            Bytes::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> BTCHeaderRelayReader<'r> {
+        BTCHeaderRelayReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for BTCHeaderRelay {
+    type Builder = BTCHeaderRelayBuilder;
+    const NAME: &'static str = "BTCHeaderRelay";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        BTCHeaderRelay(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BTCHeaderRelayReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        BTCHeaderRelayReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .height(self.height())
+            .epoch_start_time(self.epoch_start_time())
+            .epoch_bits(self.epoch_bits())
+            .headers(self.headers())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct BTCHeaderRelayReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for BTCHeaderRelayReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for BTCHeaderRelayReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for BTCHeaderRelayReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "height", self.height())?;
+        write!(f, ", {}: {}", "epoch_start_time", self.epoch_start_time())?;
+        write!(f, ", {}: {}", "epoch_bits", self.epoch_bits())?;
+        write!(f, ", {}: {}", "headers", self.headers())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> BTCHeaderRelayReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn height(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn epoch_start_time(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn epoch_bits(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn headers(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for BTCHeaderRelayReader<'r> {
+    type Entity = BTCHeaderRelay;
+    const NAME: &'static str = "BTCHeaderRelayReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        BTCHeaderRelayReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..]
+            .chunks(molecule::NUMBER_SIZE)
+            .take(field_count)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        BytesReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct BTCHeaderRelayBuilder {
+    pub(crate) height: Uint64,
+    pub(crate) epoch_start_time: Uint32,
+    pub(crate) epoch_bits: Uint32,
+    pub(crate) headers: Bytes,
+}
+impl BTCHeaderRelayBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn height(mut self, v: Uint64) -> Self {
+        self.height = v;
+        self
+    }
+    pub fn epoch_start_time(mut self, v: Uint32) -> Self {
+        self.epoch_start_time = v;
+        self
+    }
+    pub fn epoch_bits(mut self, v: Uint32) -> Self {
+        self.epoch_bits = v;
+        self
+    }
+    pub fn headers(mut self, v: Bytes) -> Self {
+        self.headers = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for BTCHeaderRelayBuilder {
+    type Entity = BTCHeaderRelay;
+    const NAME: &'static str = "BTCHeaderRelayBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.height.as_slice().len()
+            + self.epoch_start_time.as_slice().len()
+            + self.epoch_bits.as_slice().len()
+            + self.headers.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.height.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.epoch_start_time.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.epoch_bits.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.headers.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.height.as_slice())?;
+        writer.write_all(self.epoch_start_time.as_slice())?;
+        writer.write_all(self.epoch_bits.as_slice())?;
+        writer.write_all(self.headers.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        BTCHeaderRelay::new_unchecked(inner.into())
+    }
+}
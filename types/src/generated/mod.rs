@@ -1,4 +1,10 @@
 pub mod basic;
 pub mod btc_difficulty;
+pub mod btc_header_relay;
+pub mod eth_confirmed_headers;
+pub mod lock_registry;
 pub mod mint_xt_witness;
+pub mod price_oracle;
+pub mod protocol_params;
+pub mod signer_offer;
 pub mod tockb_cell_data;
@@ -0,0 +1,719 @@
+// Generated by Molecule 0.6.0
+
+use super::basic::*;
+use molecule::prelude::*;
+#[derive(Clone)]
+pub struct ProtocolParams(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ProtocolParams {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ProtocolParams {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ProtocolParams {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "pledge", self.pledge())?;
+        write!(
+            f,
+            ", {}: {}",
+            "collateral_percent",
+            self.collateral_percent()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "liquidation_collateral_percent",
+            self.liquidation_collateral_percent()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "pre_undercollateral_rate",
+            self.pre_undercollateral_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "auction_init_percent",
+            self.auction_init_percent()
+        )?;
+        write!(f, ", {}: {}", "auction_max_time", self.auction_max_time())?;
+        write!(f, ", {}: {}", "auction_curve", self.auction_curve())?;
+        write!(
+            f,
+            ", {}: {}",
+            "auction_curve_param",
+            self.auction_curve_param()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "max_signer_fee_rate",
+            self.max_signer_fee_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "btc_spv_confirmations",
+            self.btc_spv_confirmations()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "faulty_compensation_percent",
+            self.faulty_compensation_percent()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_signer_timeout",
+            self.since_signer_timeout()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_at_term_redeem",
+            self.since_at_term_redeem()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_withdraw_pledge_collateral",
+            self.since_withdraw_pledge_collateral()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_withdraw_pledge",
+            self.since_withdraw_pledge()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ProtocolParams {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            131, 0, 0, 0, 64, 0, 0, 0, 72, 0, 0, 0, 73, 0, 0, 0, 74, 0, 0, 0, 75, 0, 0, 0, 76, 0,
+            0, 0, 84, 0, 0, 0, 85, 0, 0, 0, 93, 0, 0, 0, 97, 0, 0, 0, 98, 0, 0, 0, 99, 0, 0, 0,
+            107, 0, 0, 0, 115, 0, 0, 0, 123, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        ProtocolParams::new_unchecked(v.into())
+    }
+}
+impl ProtocolParams {
+    pub const FIELD_COUNT: usize = 15;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn pledge(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn collateral_percent(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn liquidation_collateral_percent(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn pre_undercollateral_rate(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn auction_init_percent(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn auction_max_time(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn auction_curve(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn auction_curve_param(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn max_signer_fee_rate(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn btc_spv_confirmations(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn faulty_compensation_percent(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn since_signer_timeout(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn since_at_term_redeem(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn since_withdraw_pledge_collateral(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn since_withdraw_pledge(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[64..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint64::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> ProtocolParamsReader<'r> {
+        ProtocolParamsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ProtocolParams {
+    type Builder = ProtocolParamsBuilder;
+    const NAME: &'static str = "ProtocolParams";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ProtocolParams(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProtocolParamsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ProtocolParamsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .pledge(self.pledge())
+            .collateral_percent(self.collateral_percent())
+            .liquidation_collateral_percent(self.liquidation_collateral_percent())
+            .pre_undercollateral_rate(self.pre_undercollateral_rate())
+            .auction_init_percent(self.auction_init_percent())
+            .auction_max_time(self.auction_max_time())
+            .auction_curve(self.auction_curve())
+            .auction_curve_param(self.auction_curve_param())
+            .max_signer_fee_rate(self.max_signer_fee_rate())
+            .btc_spv_confirmations(self.btc_spv_confirmations())
+            .faulty_compensation_percent(self.faulty_compensation_percent())
+            .since_signer_timeout(self.since_signer_timeout())
+            .since_at_term_redeem(self.since_at_term_redeem())
+            .since_withdraw_pledge_collateral(self.since_withdraw_pledge_collateral())
+            .since_withdraw_pledge(self.since_withdraw_pledge())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ProtocolParamsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ProtocolParamsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ProtocolParamsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ProtocolParamsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "pledge", self.pledge())?;
+        write!(
+            f,
+            ", {}: {}",
+            "collateral_percent",
+            self.collateral_percent()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "liquidation_collateral_percent",
+            self.liquidation_collateral_percent()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "pre_undercollateral_rate",
+            self.pre_undercollateral_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "auction_init_percent",
+            self.auction_init_percent()
+        )?;
+        write!(f, ", {}: {}", "auction_max_time", self.auction_max_time())?;
+        write!(f, ", {}: {}", "auction_curve", self.auction_curve())?;
+        write!(
+            f,
+            ", {}: {}",
+            "auction_curve_param",
+            self.auction_curve_param()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "max_signer_fee_rate",
+            self.max_signer_fee_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "btc_spv_confirmations",
+            self.btc_spv_confirmations()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "faulty_compensation_percent",
+            self.faulty_compensation_percent()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_signer_timeout",
+            self.since_signer_timeout()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_at_term_redeem",
+            self.since_at_term_redeem()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_withdraw_pledge_collateral",
+            self.since_withdraw_pledge_collateral()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_withdraw_pledge",
+            self.since_withdraw_pledge()
+        )?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> ProtocolParamsReader<'r> {
+    pub const FIELD_COUNT: usize = 15;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn pledge(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn collateral_percent(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn liquidation_collateral_percent(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn pre_undercollateral_rate(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn auction_init_percent(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn auction_max_time(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn auction_curve(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn auction_curve_param(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn max_signer_fee_rate(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn btc_spv_confirmations(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn faulty_compensation_percent(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn since_signer_timeout(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn since_at_term_redeem(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn since_withdraw_pledge_collateral(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn since_withdraw_pledge(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[64..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ProtocolParamsReader<'r> {
+    type Entity = ProtocolParams;
+    const NAME: &'static str = "ProtocolParamsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ProtocolParamsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..]
+            .chunks(molecule::NUMBER_SIZE)
+            .take(field_count)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Uint64Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ByteReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        ByteReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ByteReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        ByteReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        ByteReader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        ByteReader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[14]..offsets[15]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ProtocolParamsBuilder {
+    pub(crate) pledge: Uint64,
+    pub(crate) collateral_percent: Byte,
+    pub(crate) liquidation_collateral_percent: Byte,
+    pub(crate) pre_undercollateral_rate: Byte,
+    pub(crate) auction_init_percent: Byte,
+    pub(crate) auction_max_time: Uint64,
+    pub(crate) auction_curve: Byte,
+    pub(crate) auction_curve_param: Uint64,
+    pub(crate) max_signer_fee_rate: Uint32,
+    pub(crate) btc_spv_confirmations: Byte,
+    pub(crate) faulty_compensation_percent: Byte,
+    pub(crate) since_signer_timeout: Uint64,
+    pub(crate) since_at_term_redeem: Uint64,
+    pub(crate) since_withdraw_pledge_collateral: Uint64,
+    pub(crate) since_withdraw_pledge: Uint64,
+}
+impl ProtocolParamsBuilder {
+    pub const FIELD_COUNT: usize = 15;
+    pub fn pledge(mut self, v: Uint64) -> Self {
+        self.pledge = v;
+        self
+    }
+    pub fn collateral_percent(mut self, v: Byte) -> Self {
+        self.collateral_percent = v;
+        self
+    }
+    pub fn liquidation_collateral_percent(mut self, v: Byte) -> Self {
+        self.liquidation_collateral_percent = v;
+        self
+    }
+    pub fn pre_undercollateral_rate(mut self, v: Byte) -> Self {
+        self.pre_undercollateral_rate = v;
+        self
+    }
+    pub fn auction_init_percent(mut self, v: Byte) -> Self {
+        self.auction_init_percent = v;
+        self
+    }
+    pub fn auction_max_time(mut self, v: Uint64) -> Self {
+        self.auction_max_time = v;
+        self
+    }
+    pub fn auction_curve(mut self, v: Byte) -> Self {
+        self.auction_curve = v;
+        self
+    }
+    pub fn auction_curve_param(mut self, v: Uint64) -> Self {
+        self.auction_curve_param = v;
+        self
+    }
+    pub fn max_signer_fee_rate(mut self, v: Uint32) -> Self {
+        self.max_signer_fee_rate = v;
+        self
+    }
+    pub fn btc_spv_confirmations(mut self, v: Byte) -> Self {
+        self.btc_spv_confirmations = v;
+        self
+    }
+    pub fn faulty_compensation_percent(mut self, v: Byte) -> Self {
+        self.faulty_compensation_percent = v;
+        self
+    }
+    pub fn since_signer_timeout(mut self, v: Uint64) -> Self {
+        self.since_signer_timeout = v;
+        self
+    }
+    pub fn since_at_term_redeem(mut self, v: Uint64) -> Self {
+        self.since_at_term_redeem = v;
+        self
+    }
+    pub fn since_withdraw_pledge_collateral(mut self, v: Uint64) -> Self {
+        self.since_withdraw_pledge_collateral = v;
+        self
+    }
+    pub fn since_withdraw_pledge(mut self, v: Uint64) -> Self {
+        self.since_withdraw_pledge = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ProtocolParamsBuilder {
+    type Entity = ProtocolParams;
+    const NAME: &'static str = "ProtocolParamsBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.pledge.as_slice().len()
+            + self.collateral_percent.as_slice().len()
+            + self.liquidation_collateral_percent.as_slice().len()
+            + self.pre_undercollateral_rate.as_slice().len()
+            + self.auction_init_percent.as_slice().len()
+            + self.auction_max_time.as_slice().len()
+            + self.auction_curve.as_slice().len()
+            + self.auction_curve_param.as_slice().len()
+            + self.max_signer_fee_rate.as_slice().len()
+            + self.btc_spv_confirmations.as_slice().len()
+            + self.faulty_compensation_percent.as_slice().len()
+            + self.since_signer_timeout.as_slice().len()
+            + self.since_at_term_redeem.as_slice().len()
+            + self.since_withdraw_pledge_collateral.as_slice().len()
+            + self.since_withdraw_pledge.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.pledge.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.collateral_percent.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.liquidation_collateral_percent.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.pre_undercollateral_rate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.auction_init_percent.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.auction_max_time.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.auction_curve.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.auction_curve_param.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.max_signer_fee_rate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.btc_spv_confirmations.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.faulty_compensation_percent.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.since_signer_timeout.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.since_at_term_redeem.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.since_withdraw_pledge_collateral.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.since_withdraw_pledge.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.pledge.as_slice())?;
+        writer.write_all(self.collateral_percent.as_slice())?;
+        writer.write_all(self.liquidation_collateral_percent.as_slice())?;
+        writer.write_all(self.pre_undercollateral_rate.as_slice())?;
+        writer.write_all(self.auction_init_percent.as_slice())?;
+        writer.write_all(self.auction_max_time.as_slice())?;
+        writer.write_all(self.auction_curve.as_slice())?;
+        writer.write_all(self.auction_curve_param.as_slice())?;
+        writer.write_all(self.max_signer_fee_rate.as_slice())?;
+        writer.write_all(self.btc_spv_confirmations.as_slice())?;
+        writer.write_all(self.faulty_compensation_percent.as_slice())?;
+        writer.write_all(self.since_signer_timeout.as_slice())?;
+        writer.write_all(self.since_at_term_redeem.as_slice())?;
+        writer.write_all(self.since_withdraw_pledge_collateral.as_slice())?;
+        writer.write_all(self.since_withdraw_pledge.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ProtocolParams::new_unchecked(inner.into())
+    }
+}
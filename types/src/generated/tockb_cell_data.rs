@@ -40,6 +40,12 @@ impl ::core::fmt::Display for ToCKBCellData {
             self.liquidation_trigger_lockscript()
         )?;
         write!(f, ", {}: {}", "x_extra", self.x_extra())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "redeem_amount", self.redeem_amount())?;
+        write!(f, ", {}: {}", "signer_group", self.signer_group())?;
+        write!(f, ", {}: {}", "signer_fee_rate", self.signer_fee_rate())?;
+        write!(f, ", {}: {}", "pledge", self.pledge())?;
+        write!(f, ", {}: {}", "params_snapshot", self.params_snapshot())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -50,8 +56,9 @@ impl ::core::fmt::Display for ToCKBCellData {
 impl ::core::default::Default for ToCKBCellData {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            58, 1, 0, 0, 40, 0, 0, 0, 41, 0, 0, 0, 42, 0, 0, 0, 95, 0, 0, 0, 99, 0, 0, 0, 152, 0,
-            0, 0, 156, 0, 0, 0, 209, 0, 0, 0, 6, 1, 0, 0, 0, 0, 53, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0,
+            161, 1, 0, 0, 64, 0, 0, 0, 65, 0, 0, 0, 66, 0, 0, 0, 119, 0, 0, 0, 123, 0, 0, 0, 176,
+            0, 0, 0, 180, 0, 0, 0, 233, 0, 0, 0, 30, 1, 0, 0, 82, 1, 0, 0, 98, 1, 0, 0, 114, 1, 0,
+            0, 131, 1, 0, 0, 135, 1, 0, 0, 143, 1, 0, 0, 0, 0, 53, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0,
             0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0,
             0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
@@ -61,13 +68,15 @@ impl ::core::default::Default for ToCKBCellData {
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0,
             0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 4, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
         ToCKBCellData::new_unchecked(v.into())
     }
 }
 impl ToCKBCellData {
-    pub const FIELD_COUNT: usize = 9;
+    pub const FIELD_COUNT: usize = 15;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -135,11 +144,47 @@ impl ToCKBCellData {
     pub fn x_extra(&self) -> XExtra {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        XExtra::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn amount(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn redeem_amount(&self) -> Uint128 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        Uint128::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn signer_group(&self) -> SignerGroup {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        SignerGroup::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn signer_fee_rate(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn pledge(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        Uint64::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn params_snapshot(&self) -> ParamsSnapshot {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[40..]) as usize;
-            XExtra::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[64..]) as usize;
+            ParamsSnapshot::new_unchecked(self.0.slice(start..end))
         } else {
-            XExtra::new_unchecked(self.0.slice(start..))
+            ParamsSnapshot::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> ToCKBCellDataReader<'r> {
@@ -178,6 +223,12 @@ impl molecule::prelude::Entity for ToCKBCellData {
             .redeemer_lockscript(self.redeemer_lockscript())
             .liquidation_trigger_lockscript(self.liquidation_trigger_lockscript())
             .x_extra(self.x_extra())
+            .amount(self.amount())
+            .redeem_amount(self.redeem_amount())
+            .signer_group(self.signer_group())
+            .signer_fee_rate(self.signer_fee_rate())
+            .pledge(self.pledge())
+            .params_snapshot(self.params_snapshot())
     }
 }
 #[derive(Clone, Copy)]
@@ -218,6 +269,12 @@ impl<'r> ::core::fmt::Display for ToCKBCellDataReader<'r> {
             self.liquidation_trigger_lockscript()
         )?;
         write!(f, ", {}: {}", "x_extra", self.x_extra())?;
+        write!(f, ", {}: {}", "amount", self.amount())?;
+        write!(f, ", {}: {}", "redeem_amount", self.redeem_amount())?;
+        write!(f, ", {}: {}", "signer_group", self.signer_group())?;
+        write!(f, ", {}: {}", "signer_fee_rate", self.signer_fee_rate())?;
+        write!(f, ", {}: {}", "pledge", self.pledge())?;
+        write!(f, ", {}: {}", "params_snapshot", self.params_snapshot())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -226,7 +283,7 @@ impl<'r> ::core::fmt::Display for ToCKBCellDataReader<'r> {
     }
 }
 impl<'r> ToCKBCellDataReader<'r> {
-    pub const FIELD_COUNT: usize = 9;
+    pub const FIELD_COUNT: usize = 15;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -294,11 +351,47 @@ impl<'r> ToCKBCellDataReader<'r> {
     pub fn x_extra(&self) -> XExtraReader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[36..]) as usize;
+        let end = molecule::unpack_number(&slice[40..]) as usize;
+        XExtraReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn amount(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[40..]) as usize;
+        let end = molecule::unpack_number(&slice[44..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn redeem_amount(&self) -> Uint128Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[44..]) as usize;
+        let end = molecule::unpack_number(&slice[48..]) as usize;
+        Uint128Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn signer_group(&self) -> SignerGroupReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[48..]) as usize;
+        let end = molecule::unpack_number(&slice[52..]) as usize;
+        SignerGroupReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn signer_fee_rate(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[52..]) as usize;
+        let end = molecule::unpack_number(&slice[56..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn pledge(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[56..]) as usize;
+        let end = molecule::unpack_number(&slice[60..]) as usize;
+        Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn params_snapshot(&self) -> ParamsSnapshotReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[60..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[40..]) as usize;
-            XExtraReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[64..]) as usize;
+            ParamsSnapshotReader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            XExtraReader::new_unchecked(&self.as_slice()[start..])
+            ParamsSnapshotReader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -362,6 +455,12 @@ impl<'r> molecule::prelude::Reader<'r> for ToCKBCellDataReader<'r> {
         ScriptReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
         ScriptReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
         XExtraReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[9]..offsets[10]], compatible)?;
+        Uint128Reader::verify(&slice[offsets[10]..offsets[11]], compatible)?;
+        SignerGroupReader::verify(&slice[offsets[11]..offsets[12]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[12]..offsets[13]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[13]..offsets[14]], compatible)?;
+        ParamsSnapshotReader::verify(&slice[offsets[14]..offsets[15]], compatible)?;
         Ok(())
     }
 }
@@ -376,9 +475,15 @@ pub struct ToCKBCellDataBuilder {
     pub(crate) redeemer_lockscript: Script,
     pub(crate) liquidation_trigger_lockscript: Script,
     pub(crate) x_extra: XExtra,
+    pub(crate) amount: Uint128,
+    pub(crate) redeem_amount: Uint128,
+    pub(crate) signer_group: SignerGroup,
+    pub(crate) signer_fee_rate: Uint32,
+    pub(crate) pledge: Uint64,
+    pub(crate) params_snapshot: ParamsSnapshot,
 }
 impl ToCKBCellDataBuilder {
-    pub const FIELD_COUNT: usize = 9;
+    pub const FIELD_COUNT: usize = 15;
     pub fn status(mut self, v: Byte) -> Self {
         self.status = v;
         self
@@ -415,6 +520,30 @@ impl ToCKBCellDataBuilder {
         self.x_extra = v;
         self
     }
+    pub fn amount(mut self, v: Uint128) -> Self {
+        self.amount = v;
+        self
+    }
+    pub fn redeem_amount(mut self, v: Uint128) -> Self {
+        self.redeem_amount = v;
+        self
+    }
+    pub fn signer_group(mut self, v: SignerGroup) -> Self {
+        self.signer_group = v;
+        self
+    }
+    pub fn signer_fee_rate(mut self, v: Uint32) -> Self {
+        self.signer_fee_rate = v;
+        self
+    }
+    pub fn pledge(mut self, v: Uint64) -> Self {
+        self.pledge = v;
+        self
+    }
+    pub fn params_snapshot(mut self, v: ParamsSnapshot) -> Self {
+        self.params_snapshot = v;
+        self
+    }
 }
 impl molecule::prelude::Builder for ToCKBCellDataBuilder {
     type Entity = ToCKBCellData;
@@ -430,6 +559,12 @@ impl molecule::prelude::Builder for ToCKBCellDataBuilder {
             + self.redeemer_lockscript.as_slice().len()
             + self.liquidation_trigger_lockscript.as_slice().len()
             + self.x_extra.as_slice().len()
+            + self.amount.as_slice().len()
+            + self.redeem_amount.as_slice().len()
+            + self.signer_group.as_slice().len()
+            + self.signer_fee_rate.as_slice().len()
+            + self.pledge.as_slice().len()
+            + self.params_snapshot.as_slice().len()
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
@@ -452,6 +587,18 @@ impl molecule::prelude::Builder for ToCKBCellDataBuilder {
         total_size += self.liquidation_trigger_lockscript.as_slice().len();
         offsets.push(total_size);
         total_size += self.x_extra.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.amount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.redeem_amount.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.signer_group.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.signer_fee_rate.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.pledge.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.params_snapshot.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
@@ -465,6 +612,12 @@ impl molecule::prelude::Builder for ToCKBCellDataBuilder {
         writer.write_all(self.redeemer_lockscript.as_slice())?;
         writer.write_all(self.liquidation_trigger_lockscript.as_slice())?;
         writer.write_all(self.x_extra.as_slice())?;
+        writer.write_all(self.amount.as_slice())?;
+        writer.write_all(self.redeem_amount.as_slice())?;
+        writer.write_all(self.signer_group.as_slice())?;
+        writer.write_all(self.signer_fee_rate.as_slice())?;
+        writer.write_all(self.pledge.as_slice())?;
+        writer.write_all(self.params_snapshot.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -507,7 +660,7 @@ impl ::core::default::Default for XExtra {
     }
 }
 impl XExtra {
-    pub const ITEMS_COUNT: usize = 2;
+    pub const ITEMS_COUNT: usize = 4;
     pub fn item_id(&self) -> molecule::Number {
         molecule::unpack_number(self.as_slice())
     }
@@ -516,6 +669,8 @@ impl XExtra {
         match self.item_id() {
             0 => BtcExtra::new_unchecked(inner).into(),
             1 => EthExtra::new_unchecked(inner).into(),
+            2 => LtcExtra::new_unchecked(inner).into(),
+            3 => Erc20Extra::new_unchecked(inner).into(),
             _ => panic!("{}: invalid data", Self::NAME),
         }
     }
@@ -572,7 +727,7 @@ impl<'r> ::core::fmt::Display for XExtraReader<'r> {
     }
 }
 impl<'r> XExtraReader<'r> {
-    pub const ITEMS_COUNT: usize = 2;
+    pub const ITEMS_COUNT: usize = 4;
     pub fn item_id(&self) -> molecule::Number {
         molecule::unpack_number(self.as_slice())
     }
@@ -581,6 +736,8 @@ impl<'r> XExtraReader<'r> {
         match self.item_id() {
             0 => BtcExtraReader::new_unchecked(inner).into(),
             1 => EthExtraReader::new_unchecked(inner).into(),
+            2 => LtcExtraReader::new_unchecked(inner).into(),
+            3 => Erc20ExtraReader::new_unchecked(inner).into(),
             _ => panic!("{}: invalid data", Self::NAME),
         }
     }
@@ -608,6 +765,8 @@ impl<'r> molecule::prelude::Reader<'r> for XExtraReader<'r> {
         match item_id {
             0 => BtcExtraReader::verify(inner_slice, compatible),
             1 => EthExtraReader::verify(inner_slice, compatible),
+            2 => LtcExtraReader::verify(inner_slice, compatible),
+            3 => Erc20ExtraReader::verify(inner_slice, compatible),
             _ => ve!(Self, UnknownItem, Self::ITEMS_COUNT, item_id),
         }?;
         Ok(())
@@ -616,7 +775,7 @@ impl<'r> molecule::prelude::Reader<'r> for XExtraReader<'r> {
 #[derive(Debug, Default)]
 pub struct XExtraBuilder(pub(crate) XExtraUnion);
 impl XExtraBuilder {
-    pub const ITEMS_COUNT: usize = 2;
+    pub const ITEMS_COUNT: usize = 4;
     pub fn set<I>(mut self, v: I) -> Self
     where
         I: ::core::convert::Into<XExtraUnion>,
@@ -646,11 +805,15 @@ impl molecule::prelude::Builder for XExtraBuilder {
 pub enum XExtraUnion {
     BtcExtra(BtcExtra),
     EthExtra(EthExtra),
+    LtcExtra(LtcExtra),
+    Erc20Extra(Erc20Extra),
 }
 #[derive(Debug, Clone, Copy)]
 pub enum XExtraUnionReader<'r> {
     BtcExtra(BtcExtraReader<'r>),
     EthExtra(EthExtraReader<'r>),
+    LtcExtra(LtcExtraReader<'r>),
+    Erc20Extra(Erc20ExtraReader<'r>),
 }
 impl ::core::default::Default for XExtraUnion {
     fn default() -> Self {
@@ -666,6 +829,12 @@ impl ::core::fmt::Display for XExtraUnion {
             XExtraUnion::EthExtra(ref item) => {
                 write!(f, "{}::{}({})", Self::NAME, EthExtra::NAME, item)
             }
+            XExtraUnion::LtcExtra(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, LtcExtra::NAME, item)
+            }
+            XExtraUnion::Erc20Extra(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Erc20Extra::NAME, item)
+            }
         }
     }
 }
@@ -678,6 +847,12 @@ impl<'r> ::core::fmt::Display for XExtraUnionReader<'r> {
             XExtraUnionReader::EthExtra(ref item) => {
                 write!(f, "{}::{}({})", Self::NAME, EthExtra::NAME, item)
             }
+            XExtraUnionReader::LtcExtra(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, LtcExtra::NAME, item)
+            }
+            XExtraUnionReader::Erc20Extra(ref item) => {
+                write!(f, "{}::{}({})", Self::NAME, Erc20Extra::NAME, item)
+            }
         }
     }
 }
@@ -686,6 +861,8 @@ impl XExtraUnion {
         match self {
             XExtraUnion::BtcExtra(ref item) => write!(f, "{}", item),
             XExtraUnion::EthExtra(ref item) => write!(f, "{}", item),
+            XExtraUnion::LtcExtra(ref item) => write!(f, "{}", item),
+            XExtraUnion::Erc20Extra(ref item) => write!(f, "{}", item),
         }
     }
 }
@@ -694,6 +871,8 @@ impl<'r> XExtraUnionReader<'r> {
         match self {
             XExtraUnionReader::BtcExtra(ref item) => write!(f, "{}", item),
             XExtraUnionReader::EthExtra(ref item) => write!(f, "{}", item),
+            XExtraUnionReader::LtcExtra(ref item) => write!(f, "{}", item),
+            XExtraUnionReader::Erc20Extra(ref item) => write!(f, "{}", item),
         }
     }
 }
@@ -707,6 +886,16 @@ impl ::core::convert::From<EthExtra> for XExtraUnion {
         XExtraUnion::EthExtra(item)
     }
 }
+impl ::core::convert::From<LtcExtra> for XExtraUnion {
+    fn from(item: LtcExtra) -> Self {
+        XExtraUnion::LtcExtra(item)
+    }
+}
+impl ::core::convert::From<Erc20Extra> for XExtraUnion {
+    fn from(item: Erc20Extra) -> Self {
+        XExtraUnion::Erc20Extra(item)
+    }
+}
 impl<'r> ::core::convert::From<BtcExtraReader<'r>> for XExtraUnionReader<'r> {
     fn from(item: BtcExtraReader<'r>) -> Self {
         XExtraUnionReader::BtcExtra(item)
@@ -717,36 +906,56 @@ impl<'r> ::core::convert::From<EthExtraReader<'r>> for XExtraUnionReader<'r> {
         XExtraUnionReader::EthExtra(item)
     }
 }
+impl<'r> ::core::convert::From<LtcExtraReader<'r>> for XExtraUnionReader<'r> {
+    fn from(item: LtcExtraReader<'r>) -> Self {
+        XExtraUnionReader::LtcExtra(item)
+    }
+}
+impl<'r> ::core::convert::From<Erc20ExtraReader<'r>> for XExtraUnionReader<'r> {
+    fn from(item: Erc20ExtraReader<'r>) -> Self {
+        XExtraUnionReader::Erc20Extra(item)
+    }
+}
 impl XExtraUnion {
     pub const NAME: &'static str = "XExtraUnion";
     pub fn as_bytes(&self) -> molecule::bytes::Bytes {
         match self {
             XExtraUnion::BtcExtra(item) => item.as_bytes(),
             XExtraUnion::EthExtra(item) => item.as_bytes(),
+            XExtraUnion::LtcExtra(item) => item.as_bytes(),
+            XExtraUnion::Erc20Extra(item) => item.as_bytes(),
         }
     }
     pub fn as_slice(&self) -> &[u8] {
         match self {
             XExtraUnion::BtcExtra(item) => item.as_slice(),
             XExtraUnion::EthExtra(item) => item.as_slice(),
+            XExtraUnion::LtcExtra(item) => item.as_slice(),
+            XExtraUnion::Erc20Extra(item) => item.as_slice(),
         }
     }
     pub fn item_id(&self) -> molecule::Number {
         match self {
             XExtraUnion::BtcExtra(_) => 0,
             XExtraUnion::EthExtra(_) => 1,
+            XExtraUnion::LtcExtra(_) => 2,
+            XExtraUnion::Erc20Extra(_) => 3,
         }
     }
     pub fn item_name(&self) -> &str {
         match self {
             XExtraUnion::BtcExtra(_) => "BtcExtra",
             XExtraUnion::EthExtra(_) => "EthExtra",
+            XExtraUnion::LtcExtra(_) => "LtcExtra",
+            XExtraUnion::Erc20Extra(_) => "Erc20Extra",
         }
     }
     pub fn as_reader<'r>(&'r self) -> XExtraUnionReader<'r> {
         match self {
             XExtraUnion::BtcExtra(item) => item.as_reader().into(),
             XExtraUnion::EthExtra(item) => item.as_reader().into(),
+            XExtraUnion::LtcExtra(item) => item.as_reader().into(),
+            XExtraUnion::Erc20Extra(item) => item.as_reader().into(),
         }
     }
 }
@@ -756,18 +965,24 @@ impl<'r> XExtraUnionReader<'r> {
         match self {
             XExtraUnionReader::BtcExtra(item) => item.as_slice(),
             XExtraUnionReader::EthExtra(item) => item.as_slice(),
+            XExtraUnionReader::LtcExtra(item) => item.as_slice(),
+            XExtraUnionReader::Erc20Extra(item) => item.as_slice(),
         }
     }
     pub fn item_id(&self) -> molecule::Number {
         match self {
             XExtraUnionReader::BtcExtra(_) => 0,
             XExtraUnionReader::EthExtra(_) => 1,
+            XExtraUnionReader::LtcExtra(_) => 2,
+            XExtraUnionReader::Erc20Extra(_) => 3,
         }
     }
     pub fn item_name(&self) -> &str {
         match self {
             XExtraUnionReader::BtcExtra(_) => "BtcExtra",
             XExtraUnionReader::EthExtra(_) => "EthExtra",
+            XExtraUnionReader::LtcExtra(_) => "LtcExtra",
+            XExtraUnionReader::Erc20Extra(_) => "Erc20Extra",
         }
     }
 }
@@ -1057,7 +1272,9 @@ impl ::core::fmt::Debug for EthExtra {
 impl ::core::fmt::Display for EthExtra {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "dummy", self.dummy())?;
+        write!(f, "{}: {}", "lock_tx_hash", self.lock_tx_hash())?;
+        write!(f, ", {}: {}", "lock_tx_index", self.lock_tx_index())?;
+        write!(f, ", {}: {}", "lock_block_number", self.lock_block_number())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1067,12 +1284,16 @@ impl ::core::fmt::Display for EthExtra {
 }
 impl ::core::default::Default for EthExtra {
     fn default() -> Self {
-        let v: Vec<u8> = vec![12, 0, 0, 0, 8, 0, 0, 0, 0, 0, 0, 0];
+        let v: Vec<u8> = vec![
+            60, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 52, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0,
+        ];
         EthExtra::new_unchecked(v.into())
     }
 }
 impl EthExtra {
-    pub const FIELD_COUNT: usize = 1;
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1089,14 +1310,26 @@ impl EthExtra {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn dummy(&self) -> Bytes {
+    pub fn lock_tx_hash(&self) -> Byte32 {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn lock_tx_index(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn lock_block_number(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            Bytes::new_unchecked(self.0.slice(start..end))
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
         } else {
-            Bytes::new_unchecked(self.0.slice(start..))
+            Uint64::new_unchecked(self.0.slice(start..))
         }
     }
     pub fn as_reader<'r>(&'r self) -> EthExtraReader<'r> {
@@ -1125,7 +1358,10 @@ impl molecule::prelude::Entity for EthExtra {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
-        Self::new_builder().dummy(self.dummy())
+        Self::new_builder()
+            .lock_tx_hash(self.lock_tx_hash())
+            .lock_tx_index(self.lock_tx_index())
+            .lock_block_number(self.lock_block_number())
     }
 }
 #[derive(Clone, Copy)]
@@ -1147,7 +1383,9 @@ impl<'r> ::core::fmt::Debug for EthExtraReader<'r> {
 impl<'r> ::core::fmt::Display for EthExtraReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "dummy", self.dummy())?;
+        write!(f, "{}: {}", "lock_tx_hash", self.lock_tx_hash())?;
+        write!(f, ", {}: {}", "lock_tx_index", self.lock_tx_index())?;
+        write!(f, ", {}: {}", "lock_block_number", self.lock_block_number())?;
         let extra_count = self.count_extra_fields();
         if extra_count != 0 {
             write!(f, ", .. ({} fields)", extra_count)?;
@@ -1156,7 +1394,7 @@ impl<'r> ::core::fmt::Display for EthExtraReader<'r> {
     }
 }
 impl<'r> EthExtraReader<'r> {
-    pub const FIELD_COUNT: usize = 1;
+    pub const FIELD_COUNT: usize = 3;
     pub fn total_size(&self) -> usize {
         molecule::unpack_number(self.as_slice()) as usize
     }
@@ -1173,14 +1411,26 @@ impl<'r> EthExtraReader<'r> {
     pub fn has_extra_fields(&self) -> bool {
         Self::FIELD_COUNT != self.field_count()
     }
-    pub fn dummy(&self) -> BytesReader<'r> {
+    pub fn lock_tx_hash(&self) -> Byte32Reader<'r> {
         let slice = self.as_slice();
         let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn lock_tx_index(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn lock_block_number(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
         if self.has_extra_fields() {
-            let end = molecule::unpack_number(&slice[8..]) as usize;
-            BytesReader::new_unchecked(&self.as_slice()[start..end])
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
         } else {
-            BytesReader::new_unchecked(&self.as_slice()[start..])
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
         }
     }
 }
@@ -1235,18 +1485,30 @@ impl<'r> molecule::prelude::Reader<'r> for EthExtraReader<'r> {
         if offsets.windows(2).any(|i| i[0] > i[1]) {
             return ve!(Self, OffsetsNotMatch);
         }
-        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
         Ok(())
     }
 }
 #[derive(Debug, Default)]
 pub struct EthExtraBuilder {
-    pub(crate) dummy: Bytes,
+    pub(crate) lock_tx_hash: Byte32,
+    pub(crate) lock_tx_index: Uint32,
+    pub(crate) lock_block_number: Uint64,
 }
 impl EthExtraBuilder {
-    pub const FIELD_COUNT: usize = 1;
-    pub fn dummy(mut self, v: Bytes) -> Self {
-        self.dummy = v;
+    pub const FIELD_COUNT: usize = 3;
+    pub fn lock_tx_hash(mut self, v: Byte32) -> Self {
+        self.lock_tx_hash = v;
+        self
+    }
+    pub fn lock_tx_index(mut self, v: Uint32) -> Self {
+        self.lock_tx_index = v;
+        self
+    }
+    pub fn lock_block_number(mut self, v: Uint64) -> Self {
+        self.lock_block_number = v;
         self
     }
 }
@@ -1254,18 +1516,27 @@ impl molecule::prelude::Builder for EthExtraBuilder {
     type Entity = EthExtra;
     const NAME: &'static str = "EthExtraBuilder";
     fn expected_length(&self) -> usize {
-        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1) + self.dummy.as_slice().len()
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.lock_tx_hash.as_slice().len()
+            + self.lock_tx_index.as_slice().len()
+            + self.lock_block_number.as_slice().len()
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
         let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
         let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
         offsets.push(total_size);
-        total_size += self.dummy.as_slice().len();
+        total_size += self.lock_tx_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.lock_tx_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.lock_block_number.as_slice().len();
         writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
         for offset in offsets.into_iter() {
             writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
         }
-        writer.write_all(self.dummy.as_slice())?;
+        writer.write_all(self.lock_tx_hash.as_slice())?;
+        writer.write_all(self.lock_tx_index.as_slice())?;
+        writer.write_all(self.lock_block_number.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
@@ -1276,8 +1547,8 @@ impl molecule::prelude::Builder for EthExtraBuilder {
     }
 }
 #[derive(Clone)]
-pub struct ToCKBTypeArgs(molecule::bytes::Bytes);
-impl ::core::fmt::LowerHex for ToCKBTypeArgs {
+pub struct LtcExtra(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for LtcExtra {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1286,47 +1557,75 @@ impl ::core::fmt::LowerHex for ToCKBTypeArgs {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl ::core::fmt::Debug for ToCKBTypeArgs {
+impl ::core::fmt::Debug for LtcExtra {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl ::core::fmt::Display for ToCKBTypeArgs {
+impl ::core::fmt::Display for LtcExtra {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "xchain_kind", self.xchain_kind())?;
-        write!(f, ", {}: {}", "cell_id", self.cell_id())?;
+        write!(f, "{}: {}", "lock_tx_hash", self.lock_tx_hash())?;
+        write!(f, ", {}: {}", "lock_vout_index", self.lock_vout_index())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl ::core::default::Default for ToCKBTypeArgs {
+impl ::core::default::Default for LtcExtra {
     fn default() -> Self {
         let v: Vec<u8> = vec![
-            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-            0, 0, 0, 0, 0, 0, 0, 0,
+            48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
         ];
-        ToCKBTypeArgs::new_unchecked(v.into())
+        LtcExtra::new_unchecked(v.into())
     }
 }
-impl ToCKBTypeArgs {
-    pub const TOTAL_SIZE: usize = 37;
-    pub const FIELD_SIZES: [usize; 2] = [1, 36];
+impl LtcExtra {
     pub const FIELD_COUNT: usize = 2;
-    pub fn xchain_kind(&self) -> Byte {
-        Byte::new_unchecked(self.0.slice(0..1))
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn cell_id(&self) -> OutPoint {
-        OutPoint::new_unchecked(self.0.slice(1..37))
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
     }
-    pub fn as_reader<'r>(&'r self) -> ToCKBTypeArgsReader<'r> {
-        ToCKBTypeArgsReader::new_unchecked(self.as_slice())
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn lock_tx_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn lock_vout_index(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint32::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint32::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> LtcExtraReader<'r> {
+        LtcExtraReader::new_unchecked(self.as_slice())
     }
 }
-impl molecule::prelude::Entity for ToCKBTypeArgs {
-    type Builder = ToCKBTypeArgsBuilder;
-    const NAME: &'static str = "ToCKBTypeArgs";
+impl molecule::prelude::Entity for LtcExtra {
+    type Builder = LtcExtraBuilder;
+    const NAME: &'static str = "LtcExtra";
     fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
-        ToCKBTypeArgs(data)
+        LtcExtra(data)
     }
     fn as_bytes(&self) -> molecule::bytes::Bytes {
         self.0.clone()
@@ -1335,23 +1634,23 @@ impl molecule::prelude::Entity for ToCKBTypeArgs {
         &self.0[..]
     }
     fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ToCKBTypeArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+        LtcExtraReader::from_slice(slice).map(|reader| reader.to_entity())
     }
     fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
-        ToCKBTypeArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+        LtcExtraReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
     }
     fn new_builder() -> Self::Builder {
         ::core::default::Default::default()
     }
     fn as_builder(self) -> Self::Builder {
         Self::new_builder()
-            .xchain_kind(self.xchain_kind())
-            .cell_id(self.cell_id())
+            .lock_tx_hash(self.lock_tx_hash())
+            .lock_vout_index(self.lock_vout_index())
     }
 }
 #[derive(Clone, Copy)]
-pub struct ToCKBTypeArgsReader<'r>(&'r [u8]);
-impl<'r> ::core::fmt::LowerHex for ToCKBTypeArgsReader<'r> {
+pub struct LtcExtraReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for LtcExtraReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         use molecule::hex_string;
         if f.alternate() {
@@ -1360,84 +1659,2437 @@ impl<'r> ::core::fmt::LowerHex for ToCKBTypeArgsReader<'r> {
         write!(f, "{}", hex_string(self.as_slice()))
     }
 }
-impl<'r> ::core::fmt::Debug for ToCKBTypeArgsReader<'r> {
+impl<'r> ::core::fmt::Debug for LtcExtraReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{}({:#x})", Self::NAME, self)
     }
 }
-impl<'r> ::core::fmt::Display for ToCKBTypeArgsReader<'r> {
+impl<'r> ::core::fmt::Display for LtcExtraReader<'r> {
     fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
         write!(f, "{} {{ ", Self::NAME)?;
-        write!(f, "{}: {}", "xchain_kind", self.xchain_kind())?;
-        write!(f, ", {}: {}", "cell_id", self.cell_id())?;
+        write!(f, "{}: {}", "lock_tx_hash", self.lock_tx_hash())?;
+        write!(f, ", {}: {}", "lock_vout_index", self.lock_vout_index())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
         write!(f, " }}")
     }
 }
-impl<'r> ToCKBTypeArgsReader<'r> {
-    pub const TOTAL_SIZE: usize = 37;
-    pub const FIELD_SIZES: [usize; 2] = [1, 36];
+impl<'r> LtcExtraReader<'r> {
     pub const FIELD_COUNT: usize = 2;
-    pub fn xchain_kind(&self) -> ByteReader<'r> {
-        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
     }
-    pub fn cell_id(&self) -> OutPointReader<'r> {
-        OutPointReader::new_unchecked(&self.as_slice()[1..37])
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn lock_tx_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn lock_vout_index(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint32Reader::new_unchecked(&self.as_slice()[start..])
+        }
     }
 }
-impl<'r> molecule::prelude::Reader<'r> for ToCKBTypeArgsReader<'r> {
-    type Entity = ToCKBTypeArgs;
-    const NAME: &'static str = "ToCKBTypeArgsReader";
+impl<'r> molecule::prelude::Reader<'r> for LtcExtraReader<'r> {
+    type Entity = LtcExtra;
+    const NAME: &'static str = "LtcExtraReader";
     fn to_entity(&self) -> Self::Entity {
         Self::Entity::new_unchecked(self.as_slice().to_owned().into())
     }
     fn new_unchecked(slice: &'r [u8]) -> Self {
-        ToCKBTypeArgsReader(slice)
+        LtcExtraReader(slice)
     }
     fn as_slice(&self) -> &'r [u8] {
         self.0
     }
-    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
         use molecule::verification_error as ve;
         let slice_len = slice.len();
-        if slice_len != Self::TOTAL_SIZE {
-            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
         }
-        Ok(())
-    }
-}
-#[derive(Debug, Default)]
-pub struct ToCKBTypeArgsBuilder {
-    pub(crate) xchain_kind: Byte,
-    pub(crate) cell_id: OutPoint,
-}
-impl ToCKBTypeArgsBuilder {
-    pub const TOTAL_SIZE: usize = 37;
-    pub const FIELD_SIZES: [usize; 2] = [1, 36];
-    pub const FIELD_COUNT: usize = 2;
-    pub fn xchain_kind(mut self, v: Byte) -> Self {
-        self.xchain_kind = v;
-        self
-    }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..]
+            .chunks(molecule::NUMBER_SIZE)
+            .take(field_count)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        Byte32Reader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct LtcExtraBuilder {
+    pub(crate) lock_tx_hash: Byte32,
+    pub(crate) lock_vout_index: Uint32,
+}
+impl LtcExtraBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn lock_tx_hash(mut self, v: Byte32) -> Self {
+        self.lock_tx_hash = v;
+        self
+    }
+    pub fn lock_vout_index(mut self, v: Uint32) -> Self {
+        self.lock_vout_index = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for LtcExtraBuilder {
+    type Entity = LtcExtra;
+    const NAME: &'static str = "LtcExtraBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.lock_tx_hash.as_slice().len()
+            + self.lock_vout_index.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.lock_tx_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.lock_vout_index.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.lock_tx_hash.as_slice())?;
+        writer.write_all(self.lock_vout_index.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        LtcExtra::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct Erc20Extra(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for Erc20Extra {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for Erc20Extra {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for Erc20Extra {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "token_address", self.token_address())?;
+        write!(f, ", {}: {}", "lock_tx_hash", self.lock_tx_hash())?;
+        write!(f, ", {}: {}", "lock_tx_index", self.lock_tx_index())?;
+        write!(f, ", {}: {}", "lock_block_number", self.lock_block_number())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for Erc20Extra {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            68, 0, 0, 0, 20, 0, 0, 0, 24, 0, 0, 0, 56, 0, 0, 0, 60, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        Erc20Extra::new_unchecked(v.into())
+    }
+}
+impl Erc20Extra {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn token_address(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn lock_tx_hash(&self) -> Byte32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn lock_tx_index(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn lock_block_number(&self) -> Uint64 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Uint64::new_unchecked(self.0.slice(start..end))
+        } else {
+            Uint64::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> Erc20ExtraReader<'r> {
+        Erc20ExtraReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for Erc20Extra {
+    type Builder = Erc20ExtraBuilder;
+    const NAME: &'static str = "Erc20Extra";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        Erc20Extra(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        Erc20ExtraReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        Erc20ExtraReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .token_address(self.token_address())
+            .lock_tx_hash(self.lock_tx_hash())
+            .lock_tx_index(self.lock_tx_index())
+            .lock_block_number(self.lock_block_number())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct Erc20ExtraReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for Erc20ExtraReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for Erc20ExtraReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for Erc20ExtraReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "token_address", self.token_address())?;
+        write!(f, ", {}: {}", "lock_tx_hash", self.lock_tx_hash())?;
+        write!(f, ", {}: {}", "lock_tx_index", self.lock_tx_index())?;
+        write!(f, ", {}: {}", "lock_block_number", self.lock_block_number())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> Erc20ExtraReader<'r> {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn token_address(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn lock_tx_hash(&self) -> Byte32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn lock_tx_index(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn lock_block_number(&self) -> Uint64Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[20..]) as usize;
+            Uint64Reader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            Uint64Reader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for Erc20ExtraReader<'r> {
+    type Entity = Erc20Extra;
+    const NAME: &'static str = "Erc20ExtraReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        Erc20ExtraReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..]
+            .chunks(molecule::NUMBER_SIZE)
+            .take(field_count)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        BytesReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Byte32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Uint64Reader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct Erc20ExtraBuilder {
+    pub(crate) token_address: Bytes,
+    pub(crate) lock_tx_hash: Byte32,
+    pub(crate) lock_tx_index: Uint32,
+    pub(crate) lock_block_number: Uint64,
+}
+impl Erc20ExtraBuilder {
+    pub const FIELD_COUNT: usize = 4;
+    pub fn token_address(mut self, v: Bytes) -> Self {
+        self.token_address = v;
+        self
+    }
+    pub fn lock_tx_hash(mut self, v: Byte32) -> Self {
+        self.lock_tx_hash = v;
+        self
+    }
+    pub fn lock_tx_index(mut self, v: Uint32) -> Self {
+        self.lock_tx_index = v;
+        self
+    }
+    pub fn lock_block_number(mut self, v: Uint64) -> Self {
+        self.lock_block_number = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for Erc20ExtraBuilder {
+    type Entity = Erc20Extra;
+    const NAME: &'static str = "Erc20ExtraBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.token_address.as_slice().len()
+            + self.lock_tx_hash.as_slice().len()
+            + self.lock_tx_index.as_slice().len()
+            + self.lock_block_number.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.token_address.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.lock_tx_hash.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.lock_tx_index.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.lock_block_number.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.token_address.as_slice())?;
+        writer.write_all(self.lock_tx_hash.as_slice())?;
+        writer.write_all(self.lock_tx_index.as_slice())?;
+        writer.write_all(self.lock_block_number.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        Erc20Extra::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SignerMember(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SignerMember {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SignerMember {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SignerMember {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lockscript", self.lockscript())?;
+        write!(f, ", {}: {}", "share", self.share())?;
+        write!(f, ", {}: {}", "x_pubkey", self.x_pubkey())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SignerMember {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            77, 0, 0, 0, 16, 0, 0, 0, 69, 0, 0, 0, 73, 0, 0, 0, 53, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0,
+            0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        SignerMember::new_unchecked(v.into())
+    }
+}
+impl SignerMember {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn lockscript(&self) -> Script {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Script::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn share(&self) -> Uint32 {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn x_pubkey(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            Bytes::new_unchecked(self.0.slice(start..end))
+        } else {
+            Bytes::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SignerMemberReader<'r> {
+        SignerMemberReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SignerMember {
+    type Builder = SignerMemberBuilder;
+    const NAME: &'static str = "SignerMember";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SignerMember(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SignerMemberReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SignerMemberReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .lockscript(self.lockscript())
+            .share(self.share())
+            .x_pubkey(self.x_pubkey())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SignerMemberReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SignerMemberReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SignerMemberReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SignerMemberReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "lockscript", self.lockscript())?;
+        write!(f, ", {}: {}", "share", self.share())?;
+        write!(f, ", {}: {}", "x_pubkey", self.x_pubkey())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SignerMemberReader<'r> {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn lockscript(&self) -> ScriptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ScriptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn share(&self) -> Uint32Reader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Uint32Reader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn x_pubkey(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[16..]) as usize;
+            BytesReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            BytesReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SignerMemberReader<'r> {
+    type Entity = SignerMember;
+    const NAME: &'static str = "SignerMemberReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SignerMemberReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..]
+            .chunks(molecule::NUMBER_SIZE)
+            .take(field_count)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ScriptReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        Uint32Reader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        BytesReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SignerMemberBuilder {
+    pub(crate) lockscript: Script,
+    pub(crate) share: Uint32,
+    pub(crate) x_pubkey: Bytes,
+}
+impl SignerMemberBuilder {
+    pub const FIELD_COUNT: usize = 3;
+    pub fn lockscript(mut self, v: Script) -> Self {
+        self.lockscript = v;
+        self
+    }
+    pub fn share(mut self, v: Uint32) -> Self {
+        self.share = v;
+        self
+    }
+    pub fn x_pubkey(mut self, v: Bytes) -> Self {
+        self.x_pubkey = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SignerMemberBuilder {
+    type Entity = SignerMember;
+    const NAME: &'static str = "SignerMemberBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.lockscript.as_slice().len()
+            + self.share.as_slice().len()
+            + self.x_pubkey.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.lockscript.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.share.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.x_pubkey.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.lockscript.as_slice())?;
+        writer.write_all(self.share.as_slice())?;
+        writer.write_all(self.x_pubkey.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SignerMember::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct SignerMemberVec(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SignerMemberVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SignerMemberVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SignerMemberVec {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl ::core::default::Default for SignerMemberVec {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![4, 0, 0, 0];
+        SignerMemberVec::new_unchecked(v.into())
+    }
+}
+impl SignerMemberVec {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<SignerMember> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> SignerMember {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            SignerMember::new_unchecked(self.0.slice(start..))
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            SignerMember::new_unchecked(self.0.slice(start..end))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SignerMemberVecReader<'r> {
+        SignerMemberVecReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SignerMemberVec {
+    type Builder = SignerMemberVecBuilder;
+    const NAME: &'static str = "SignerMemberVec";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SignerMemberVec(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SignerMemberVecReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SignerMemberVecReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder().extend(self.into_iter())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SignerMemberVecReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SignerMemberVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SignerMemberVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SignerMemberVecReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} [", Self::NAME)?;
+        for i in 0..self.len() {
+            if i == 0 {
+                write!(f, "{}", self.get_unchecked(i))?;
+            } else {
+                write!(f, ", {}", self.get_unchecked(i))?;
+            }
+        }
+        write!(f, "]")
+    }
+}
+impl<'r> SignerMemberVecReader<'r> {
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn item_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn len(&self) -> usize {
+        self.item_count()
+    }
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    pub fn get(&self, idx: usize) -> Option<SignerMemberReader<'r>> {
+        if idx >= self.len() {
+            None
+        } else {
+            Some(self.get_unchecked(idx))
+        }
+    }
+    pub fn get_unchecked(&self, idx: usize) -> SignerMemberReader<'r> {
+        let slice = self.as_slice();
+        let start_idx = molecule::NUMBER_SIZE * (1 + idx);
+        let start = molecule::unpack_number(&slice[start_idx..]) as usize;
+        if idx == self.len() - 1 {
+            SignerMemberReader::new_unchecked(&self.as_slice()[start..])
+        } else {
+            let end_idx = start_idx + molecule::NUMBER_SIZE;
+            let end = molecule::unpack_number(&slice[end_idx..]) as usize;
+            SignerMemberReader::new_unchecked(&self.as_slice()[start..end])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SignerMemberVecReader<'r> {
+    type Entity = SignerMemberVec;
+    const NAME: &'static str = "SignerMemberVecReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SignerMemberVecReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(
+                Self,
+                TotalSizeNotMatch,
+                molecule::NUMBER_SIZE * 2,
+                slice_len
+            );
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let item_count = offset_first / 4 - 1;
+        let header_size = molecule::NUMBER_SIZE * (item_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..]
+            .chunks(molecule::NUMBER_SIZE)
+            .take(item_count)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        for pair in offsets.windows(2) {
+            let start = pair[0];
+            let end = pair[1];
+            SignerMemberReader::verify(&slice[start..end], compatible)?;
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SignerMemberVecBuilder(pub(crate) Vec<SignerMember>);
+impl SignerMemberVecBuilder {
+    pub fn set(mut self, v: Vec<SignerMember>) -> Self {
+        self.0 = v;
+        self
+    }
+    pub fn push(mut self, v: SignerMember) -> Self {
+        self.0.push(v);
+        self
+    }
+    pub fn extend<T: ::core::iter::IntoIterator<Item = SignerMember>>(mut self, iter: T) -> Self {
+        for elem in iter {
+            self.0.push(elem);
+        }
+        self
+    }
+}
+impl molecule::prelude::Builder for SignerMemberVecBuilder {
+    type Entity = SignerMemberVec;
+    const NAME: &'static str = "SignerMemberVecBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (self.0.len() + 1)
+            + self
+                .0
+                .iter()
+                .map(|inner| inner.as_slice().len())
+                .sum::<usize>()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let item_count = self.0.len();
+        if item_count == 0 {
+            writer.write_all(&molecule::pack_number(
+                molecule::NUMBER_SIZE as molecule::Number,
+            ))?;
+        } else {
+            let (total_size, offsets) = self.0.iter().fold(
+                (
+                    molecule::NUMBER_SIZE * (item_count + 1),
+                    Vec::with_capacity(item_count),
+                ),
+                |(start, mut offsets), inner| {
+                    offsets.push(start);
+                    (start + inner.as_slice().len(), offsets)
+                },
+            );
+            writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+            for offset in offsets.into_iter() {
+                writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+            }
+            for inner in self.0.iter() {
+                writer.write_all(inner.as_slice())?;
+            }
+        }
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SignerMemberVec::new_unchecked(inner.into())
+    }
+}
+pub struct SignerMemberVecIterator(SignerMemberVec, usize, usize);
+impl ::core::iter::Iterator for SignerMemberVecIterator {
+    type Item = SignerMember;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl ::core::iter::ExactSizeIterator for SignerMemberVecIterator {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+impl ::core::iter::IntoIterator for SignerMemberVec {
+    type Item = SignerMember;
+    type IntoIter = SignerMemberVecIterator;
+    fn into_iter(self) -> Self::IntoIter {
+        let len = self.len();
+        SignerMemberVecIterator(self, 0, len)
+    }
+}
+impl<'r> SignerMemberVecReader<'r> {
+    pub fn iter<'t>(&'t self) -> SignerMemberVecReaderIterator<'t, 'r> {
+        SignerMemberVecReaderIterator(&self, 0, self.len())
+    }
+}
+pub struct SignerMemberVecReaderIterator<'t, 'r>(&'t SignerMemberVecReader<'r>, usize, usize);
+impl<'t: 'r, 'r> ::core::iter::Iterator for SignerMemberVecReaderIterator<'t, 'r> {
+    type Item = SignerMemberReader<'t>;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.1 >= self.2 {
+            None
+        } else {
+            let ret = self.0.get_unchecked(self.1);
+            self.1 += 1;
+            Some(ret)
+        }
+    }
+}
+impl<'t: 'r, 'r> ::core::iter::ExactSizeIterator for SignerMemberVecReaderIterator<'t, 'r> {
+    fn len(&self) -> usize {
+        self.2 - self.1
+    }
+}
+#[derive(Clone)]
+pub struct SignerGroup(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for SignerGroup {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for SignerGroup {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for SignerGroup {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "threshold", self.threshold())?;
+        write!(f, ", {}: {}", "members", self.members())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for SignerGroup {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![17, 0, 0, 0, 12, 0, 0, 0, 13, 0, 0, 0, 0, 4, 0, 0, 0];
+        SignerGroup::new_unchecked(v.into())
+    }
+}
+impl SignerGroup {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn threshold(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn members(&self) -> SignerMemberVec {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            SignerMemberVec::new_unchecked(self.0.slice(start..end))
+        } else {
+            SignerMemberVec::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> SignerGroupReader<'r> {
+        SignerGroupReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for SignerGroup {
+    type Builder = SignerGroupBuilder;
+    const NAME: &'static str = "SignerGroup";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        SignerGroup(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SignerGroupReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        SignerGroupReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .threshold(self.threshold())
+            .members(self.members())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct SignerGroupReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for SignerGroupReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for SignerGroupReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for SignerGroupReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "threshold", self.threshold())?;
+        write!(f, ", {}: {}", "members", self.members())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> SignerGroupReader<'r> {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn threshold(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn members(&self) -> SignerMemberVecReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[12..]) as usize;
+            SignerMemberVecReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            SignerMemberVecReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for SignerGroupReader<'r> {
+    type Entity = SignerGroup;
+    const NAME: &'static str = "SignerGroupReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        SignerGroupReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..]
+            .chunks(molecule::NUMBER_SIZE)
+            .take(field_count)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        SignerMemberVecReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct SignerGroupBuilder {
+    pub(crate) threshold: Byte,
+    pub(crate) members: SignerMemberVec,
+}
+impl SignerGroupBuilder {
+    pub const FIELD_COUNT: usize = 2;
+    pub fn threshold(mut self, v: Byte) -> Self {
+        self.threshold = v;
+        self
+    }
+    pub fn members(mut self, v: SignerMemberVec) -> Self {
+        self.members = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for SignerGroupBuilder {
+    type Entity = SignerGroup;
+    const NAME: &'static str = "SignerGroupBuilder";
+    fn expected_length(&self) -> usize {
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.threshold.as_slice().len()
+            + self.members.as_slice().len()
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.threshold.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.members.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.threshold.as_slice())?;
+        writer.write_all(self.members.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        SignerGroup::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ParamsSnapshot(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ParamsSnapshot {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ParamsSnapshot {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ParamsSnapshot {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "liquidation_collateral_percent",
+            self.liquidation_collateral_percent()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "pre_undercollateral_rate",
+            self.pre_undercollateral_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_signer_timeout",
+            self.since_signer_timeout()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_at_term_redeem",
+            self.since_at_term_redeem()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ParamsSnapshot {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0];
+        ParamsSnapshot::new_unchecked(v.into())
+    }
+}
+impl ParamsSnapshot {
+    pub const TOTAL_SIZE: usize = 18;
+    pub const FIELD_SIZES: [usize; 4] = [1, 1, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn liquidation_collateral_percent(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn pre_undercollateral_rate(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1..2))
+    }
+    pub fn since_signer_timeout(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(2..10))
+    }
+    pub fn since_at_term_redeem(&self) -> Uint64 {
+        Uint64::new_unchecked(self.0.slice(10..18))
+    }
+    pub fn as_reader<'r>(&'r self) -> ParamsSnapshotReader<'r> {
+        ParamsSnapshotReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ParamsSnapshot {
+    type Builder = ParamsSnapshotBuilder;
+    const NAME: &'static str = "ParamsSnapshot";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ParamsSnapshot(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ParamsSnapshotReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ParamsSnapshotReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .liquidation_collateral_percent(self.liquidation_collateral_percent())
+            .pre_undercollateral_rate(self.pre_undercollateral_rate())
+            .since_signer_timeout(self.since_signer_timeout())
+            .since_at_term_redeem(self.since_at_term_redeem())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ParamsSnapshotReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ParamsSnapshotReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ParamsSnapshotReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ParamsSnapshotReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(
+            f,
+            "{}: {}",
+            "liquidation_collateral_percent",
+            self.liquidation_collateral_percent()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "pre_undercollateral_rate",
+            self.pre_undercollateral_rate()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_signer_timeout",
+            self.since_signer_timeout()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "since_at_term_redeem",
+            self.since_at_term_redeem()
+        )?;
+        write!(f, " }}")
+    }
+}
+impl<'r> ParamsSnapshotReader<'r> {
+    pub const TOTAL_SIZE: usize = 18;
+    pub const FIELD_SIZES: [usize; 4] = [1, 1, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn liquidation_collateral_percent(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn pre_undercollateral_rate(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    }
+    pub fn since_signer_timeout(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[2..10])
+    }
+    pub fn since_at_term_redeem(&self) -> Uint64Reader<'r> {
+        Uint64Reader::new_unchecked(&self.as_slice()[10..18])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ParamsSnapshotReader<'r> {
+    type Entity = ParamsSnapshot;
+    const NAME: &'static str = "ParamsSnapshotReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ParamsSnapshotReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ParamsSnapshotBuilder {
+    pub(crate) liquidation_collateral_percent: Byte,
+    pub(crate) pre_undercollateral_rate: Byte,
+    pub(crate) since_signer_timeout: Uint64,
+    pub(crate) since_at_term_redeem: Uint64,
+}
+impl ParamsSnapshotBuilder {
+    pub const TOTAL_SIZE: usize = 18;
+    pub const FIELD_SIZES: [usize; 4] = [1, 1, 8, 8];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn liquidation_collateral_percent(mut self, v: Byte) -> Self {
+        self.liquidation_collateral_percent = v;
+        self
+    }
+    pub fn pre_undercollateral_rate(mut self, v: Byte) -> Self {
+        self.pre_undercollateral_rate = v;
+        self
+    }
+    pub fn since_signer_timeout(mut self, v: Uint64) -> Self {
+        self.since_signer_timeout = v;
+        self
+    }
+    pub fn since_at_term_redeem(mut self, v: Uint64) -> Self {
+        self.since_at_term_redeem = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ParamsSnapshotBuilder {
+    type Entity = ParamsSnapshot;
+    const NAME: &'static str = "ParamsSnapshotBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.liquidation_collateral_percent.as_slice())?;
+        writer.write_all(self.pre_undercollateral_rate.as_slice())?;
+        writer.write_all(self.since_signer_timeout.as_slice())?;
+        writer.write_all(self.since_at_term_redeem.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ParamsSnapshot::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct ToCKBTypeArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for ToCKBTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for ToCKBTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for ToCKBTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "xchain_kind", self.xchain_kind())?;
+        write!(f, ", {}: {}", "xchain_network", self.xchain_network())?;
+        write!(f, ", {}: {}", "header_relay", self.header_relay())?;
+        write!(f, ", {}: {}", "cell_id", self.cell_id())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for ToCKBTypeArgs {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        ToCKBTypeArgs::new_unchecked(v.into())
+    }
+}
+impl ToCKBTypeArgs {
+    pub const TOTAL_SIZE: usize = 70;
+    pub const FIELD_SIZES: [usize; 4] = [1, 1, 32, 36];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn xchain_kind(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn xchain_network(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(1..2))
+    }
+    pub fn header_relay(&self) -> Byte32 {
+        Byte32::new_unchecked(self.0.slice(2..34))
+    }
+    pub fn cell_id(&self) -> OutPoint {
+        OutPoint::new_unchecked(self.0.slice(34..70))
+    }
+    pub fn as_reader<'r>(&'r self) -> ToCKBTypeArgsReader<'r> {
+        ToCKBTypeArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for ToCKBTypeArgs {
+    type Builder = ToCKBTypeArgsBuilder;
+    const NAME: &'static str = "ToCKBTypeArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        ToCKBTypeArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ToCKBTypeArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        ToCKBTypeArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .xchain_kind(self.xchain_kind())
+            .xchain_network(self.xchain_network())
+            .header_relay(self.header_relay())
+            .cell_id(self.cell_id())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct ToCKBTypeArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for ToCKBTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for ToCKBTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for ToCKBTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "xchain_kind", self.xchain_kind())?;
+        write!(f, ", {}: {}", "xchain_network", self.xchain_network())?;
+        write!(f, ", {}: {}", "header_relay", self.header_relay())?;
+        write!(f, ", {}: {}", "cell_id", self.cell_id())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> ToCKBTypeArgsReader<'r> {
+    pub const TOTAL_SIZE: usize = 70;
+    pub const FIELD_SIZES: [usize; 4] = [1, 1, 32, 36];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn xchain_kind(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn xchain_network(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[1..2])
+    }
+    pub fn header_relay(&self) -> Byte32Reader<'r> {
+        Byte32Reader::new_unchecked(&self.as_slice()[2..34])
+    }
+    pub fn cell_id(&self) -> OutPointReader<'r> {
+        OutPointReader::new_unchecked(&self.as_slice()[34..70])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for ToCKBTypeArgsReader<'r> {
+    type Entity = ToCKBTypeArgs;
+    const NAME: &'static str = "ToCKBTypeArgsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        ToCKBTypeArgsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct ToCKBTypeArgsBuilder {
+    pub(crate) xchain_kind: Byte,
+    pub(crate) xchain_network: Byte,
+    pub(crate) header_relay: Byte32,
+    pub(crate) cell_id: OutPoint,
+}
+impl ToCKBTypeArgsBuilder {
+    pub const TOTAL_SIZE: usize = 70;
+    pub const FIELD_SIZES: [usize; 4] = [1, 1, 32, 36];
+    pub const FIELD_COUNT: usize = 4;
+    pub fn xchain_kind(mut self, v: Byte) -> Self {
+        self.xchain_kind = v;
+        self
+    }
+    pub fn xchain_network(mut self, v: Byte) -> Self {
+        self.xchain_network = v;
+        self
+    }
+    pub fn header_relay(mut self, v: Byte32) -> Self {
+        self.header_relay = v;
+        self
+    }
+    pub fn cell_id(mut self, v: OutPoint) -> Self {
+        self.cell_id = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for ToCKBTypeArgsBuilder {
+    type Entity = ToCKBTypeArgs;
+    const NAME: &'static str = "ToCKBTypeArgsBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.xchain_kind.as_slice())?;
+        writer.write_all(self.xchain_network.as_slice())?;
+        writer.write_all(self.header_relay.as_slice())?;
+        writer.write_all(self.cell_id.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        ToCKBTypeArgs::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct LegacyToCKBTypeArgs(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for LegacyToCKBTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for LegacyToCKBTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for LegacyToCKBTypeArgs {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "xchain_kind", self.xchain_kind())?;
+        write!(f, ", {}: {}", "cell_id", self.cell_id())?;
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for LegacyToCKBTypeArgs {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0,
+        ];
+        LegacyToCKBTypeArgs::new_unchecked(v.into())
+    }
+}
+impl LegacyToCKBTypeArgs {
+    pub const TOTAL_SIZE: usize = 37;
+    pub const FIELD_SIZES: [usize; 2] = [1, 36];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn xchain_kind(&self) -> Byte {
+        Byte::new_unchecked(self.0.slice(0..1))
+    }
+    pub fn cell_id(&self) -> OutPoint {
+        OutPoint::new_unchecked(self.0.slice(1..37))
+    }
+    pub fn as_reader<'r>(&'r self) -> LegacyToCKBTypeArgsReader<'r> {
+        LegacyToCKBTypeArgsReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for LegacyToCKBTypeArgs {
+    type Builder = LegacyToCKBTypeArgsBuilder;
+    const NAME: &'static str = "LegacyToCKBTypeArgs";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        LegacyToCKBTypeArgs(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LegacyToCKBTypeArgsReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LegacyToCKBTypeArgsReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .xchain_kind(self.xchain_kind())
+            .cell_id(self.cell_id())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct LegacyToCKBTypeArgsReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for LegacyToCKBTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for LegacyToCKBTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for LegacyToCKBTypeArgsReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "xchain_kind", self.xchain_kind())?;
+        write!(f, ", {}: {}", "cell_id", self.cell_id())?;
+        write!(f, " }}")
+    }
+}
+impl<'r> LegacyToCKBTypeArgsReader<'r> {
+    pub const TOTAL_SIZE: usize = 37;
+    pub const FIELD_SIZES: [usize; 2] = [1, 36];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn xchain_kind(&self) -> ByteReader<'r> {
+        ByteReader::new_unchecked(&self.as_slice()[0..1])
+    }
+    pub fn cell_id(&self) -> OutPointReader<'r> {
+        OutPointReader::new_unchecked(&self.as_slice()[1..37])
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for LegacyToCKBTypeArgsReader<'r> {
+    type Entity = LegacyToCKBTypeArgs;
+    const NAME: &'static str = "LegacyToCKBTypeArgsReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        LegacyToCKBTypeArgsReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], _compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len != Self::TOTAL_SIZE {
+            return ve!(Self, TotalSizeNotMatch, Self::TOTAL_SIZE, slice_len);
+        }
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct LegacyToCKBTypeArgsBuilder {
+    pub(crate) xchain_kind: Byte,
+    pub(crate) cell_id: OutPoint,
+}
+impl LegacyToCKBTypeArgsBuilder {
+    pub const TOTAL_SIZE: usize = 37;
+    pub const FIELD_SIZES: [usize; 2] = [1, 36];
+    pub const FIELD_COUNT: usize = 2;
+    pub fn xchain_kind(mut self, v: Byte) -> Self {
+        self.xchain_kind = v;
+        self
+    }
     pub fn cell_id(mut self, v: OutPoint) -> Self {
         self.cell_id = v;
         self
     }
 }
-impl molecule::prelude::Builder for ToCKBTypeArgsBuilder {
-    type Entity = ToCKBTypeArgs;
-    const NAME: &'static str = "ToCKBTypeArgsBuilder";
+impl molecule::prelude::Builder for LegacyToCKBTypeArgsBuilder {
+    type Entity = LegacyToCKBTypeArgs;
+    const NAME: &'static str = "LegacyToCKBTypeArgsBuilder";
+    fn expected_length(&self) -> usize {
+        Self::TOTAL_SIZE
+    }
+    fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
+        writer.write_all(self.xchain_kind.as_slice())?;
+        writer.write_all(self.cell_id.as_slice())?;
+        Ok(())
+    }
+    fn build(&self) -> Self::Entity {
+        let mut inner = Vec::with_capacity(self.expected_length());
+        self.write(&mut inner)
+            .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
+        LegacyToCKBTypeArgs::new_unchecked(inner.into())
+    }
+}
+#[derive(Clone)]
+pub struct LegacyToCKBCellData(molecule::bytes::Bytes);
+impl ::core::fmt::LowerHex for LegacyToCKBCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl ::core::fmt::Debug for LegacyToCKBCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl ::core::fmt::Display for LegacyToCKBCellData {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "status", self.status())?;
+        write!(f, ", {}: {}", "lot_size", self.lot_size())?;
+        write!(f, ", {}: {}", "user_lockscript", self.user_lockscript())?;
+        write!(f, ", {}: {}", "x_lock_address", self.x_lock_address())?;
+        write!(f, ", {}: {}", "signer_lockscript", self.signer_lockscript())?;
+        write!(f, ", {}: {}", "x_unlock_address", self.x_unlock_address())?;
+        write!(
+            f,
+            ", {}: {}",
+            "redeemer_lockscript",
+            self.redeemer_lockscript()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "liquidation_trigger_lockscript",
+            self.liquidation_trigger_lockscript()
+        )?;
+        write!(f, ", {}: {}", "x_extra", self.x_extra())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl ::core::default::Default for LegacyToCKBCellData {
+    fn default() -> Self {
+        let v: Vec<u8> = vec![
+            58, 1, 0, 0, 40, 0, 0, 0, 41, 0, 0, 0, 42, 0, 0, 0, 95, 0, 0, 0, 99, 0, 0, 0, 152, 0,
+            0, 0, 156, 0, 0, 0, 209, 0, 0, 0, 6, 1, 0, 0, 0, 0, 53, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0,
+            0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0,
+            0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0,
+            0, 49, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 53, 0, 0, 0, 16, 0, 0, 0, 48, 0, 0, 0, 49, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 48, 0, 0, 0, 12, 0, 0, 0, 44, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0,
+        ];
+        LegacyToCKBCellData::new_unchecked(v.into())
+    }
+}
+impl LegacyToCKBCellData {
+    pub const FIELD_COUNT: usize = 9;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn status(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn lot_size(&self) -> Byte {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        Byte::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn user_lockscript(&self) -> Script {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        Script::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn x_lock_address(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn signer_lockscript(&self) -> Script {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        Script::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn x_unlock_address(&self) -> Bytes {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        Bytes::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn redeemer_lockscript(&self) -> Script {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        Script::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn liquidation_trigger_lockscript(&self) -> Script {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        Script::new_unchecked(self.0.slice(start..end))
+    }
+    pub fn x_extra(&self) -> XExtra {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[40..]) as usize;
+            XExtra::new_unchecked(self.0.slice(start..end))
+        } else {
+            XExtra::new_unchecked(self.0.slice(start..))
+        }
+    }
+    pub fn as_reader<'r>(&'r self) -> LegacyToCKBCellDataReader<'r> {
+        LegacyToCKBCellDataReader::new_unchecked(self.as_slice())
+    }
+}
+impl molecule::prelude::Entity for LegacyToCKBCellData {
+    type Builder = LegacyToCKBCellDataBuilder;
+    const NAME: &'static str = "LegacyToCKBCellData";
+    fn new_unchecked(data: molecule::bytes::Bytes) -> Self {
+        LegacyToCKBCellData(data)
+    }
+    fn as_bytes(&self) -> molecule::bytes::Bytes {
+        self.0.clone()
+    }
+    fn as_slice(&self) -> &[u8] {
+        &self.0[..]
+    }
+    fn from_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LegacyToCKBCellDataReader::from_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn from_compatible_slice(slice: &[u8]) -> molecule::error::VerificationResult<Self> {
+        LegacyToCKBCellDataReader::from_compatible_slice(slice).map(|reader| reader.to_entity())
+    }
+    fn new_builder() -> Self::Builder {
+        ::core::default::Default::default()
+    }
+    fn as_builder(self) -> Self::Builder {
+        Self::new_builder()
+            .status(self.status())
+            .lot_size(self.lot_size())
+            .user_lockscript(self.user_lockscript())
+            .x_lock_address(self.x_lock_address())
+            .signer_lockscript(self.signer_lockscript())
+            .x_unlock_address(self.x_unlock_address())
+            .redeemer_lockscript(self.redeemer_lockscript())
+            .liquidation_trigger_lockscript(self.liquidation_trigger_lockscript())
+            .x_extra(self.x_extra())
+    }
+}
+#[derive(Clone, Copy)]
+pub struct LegacyToCKBCellDataReader<'r>(&'r [u8]);
+impl<'r> ::core::fmt::LowerHex for LegacyToCKBCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        use molecule::hex_string;
+        if f.alternate() {
+            write!(f, "0x")?;
+        }
+        write!(f, "{}", hex_string(self.as_slice()))
+    }
+}
+impl<'r> ::core::fmt::Debug for LegacyToCKBCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{}({:#x})", Self::NAME, self)
+    }
+}
+impl<'r> ::core::fmt::Display for LegacyToCKBCellDataReader<'r> {
+    fn fmt(&self, f: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        write!(f, "{} {{ ", Self::NAME)?;
+        write!(f, "{}: {}", "status", self.status())?;
+        write!(f, ", {}: {}", "lot_size", self.lot_size())?;
+        write!(f, ", {}: {}", "user_lockscript", self.user_lockscript())?;
+        write!(f, ", {}: {}", "x_lock_address", self.x_lock_address())?;
+        write!(f, ", {}: {}", "signer_lockscript", self.signer_lockscript())?;
+        write!(f, ", {}: {}", "x_unlock_address", self.x_unlock_address())?;
+        write!(
+            f,
+            ", {}: {}",
+            "redeemer_lockscript",
+            self.redeemer_lockscript()
+        )?;
+        write!(
+            f,
+            ", {}: {}",
+            "liquidation_trigger_lockscript",
+            self.liquidation_trigger_lockscript()
+        )?;
+        write!(f, ", {}: {}", "x_extra", self.x_extra())?;
+        let extra_count = self.count_extra_fields();
+        if extra_count != 0 {
+            write!(f, ", .. ({} fields)", extra_count)?;
+        }
+        write!(f, " }}")
+    }
+}
+impl<'r> LegacyToCKBCellDataReader<'r> {
+    pub const FIELD_COUNT: usize = 9;
+    pub fn total_size(&self) -> usize {
+        molecule::unpack_number(self.as_slice()) as usize
+    }
+    pub fn field_count(&self) -> usize {
+        if self.total_size() == molecule::NUMBER_SIZE {
+            0
+        } else {
+            (molecule::unpack_number(&self.as_slice()[molecule::NUMBER_SIZE..]) as usize / 4) - 1
+        }
+    }
+    pub fn count_extra_fields(&self) -> usize {
+        self.field_count() - Self::FIELD_COUNT
+    }
+    pub fn has_extra_fields(&self) -> bool {
+        Self::FIELD_COUNT != self.field_count()
+    }
+    pub fn status(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[4..]) as usize;
+        let end = molecule::unpack_number(&slice[8..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn lot_size(&self) -> ByteReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[8..]) as usize;
+        let end = molecule::unpack_number(&slice[12..]) as usize;
+        ByteReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn user_lockscript(&self) -> ScriptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[12..]) as usize;
+        let end = molecule::unpack_number(&slice[16..]) as usize;
+        ScriptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn x_lock_address(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[16..]) as usize;
+        let end = molecule::unpack_number(&slice[20..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn signer_lockscript(&self) -> ScriptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[20..]) as usize;
+        let end = molecule::unpack_number(&slice[24..]) as usize;
+        ScriptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn x_unlock_address(&self) -> BytesReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[24..]) as usize;
+        let end = molecule::unpack_number(&slice[28..]) as usize;
+        BytesReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn redeemer_lockscript(&self) -> ScriptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[28..]) as usize;
+        let end = molecule::unpack_number(&slice[32..]) as usize;
+        ScriptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn liquidation_trigger_lockscript(&self) -> ScriptReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[32..]) as usize;
+        let end = molecule::unpack_number(&slice[36..]) as usize;
+        ScriptReader::new_unchecked(&self.as_slice()[start..end])
+    }
+    pub fn x_extra(&self) -> XExtraReader<'r> {
+        let slice = self.as_slice();
+        let start = molecule::unpack_number(&slice[36..]) as usize;
+        if self.has_extra_fields() {
+            let end = molecule::unpack_number(&slice[40..]) as usize;
+            XExtraReader::new_unchecked(&self.as_slice()[start..end])
+        } else {
+            XExtraReader::new_unchecked(&self.as_slice()[start..])
+        }
+    }
+}
+impl<'r> molecule::prelude::Reader<'r> for LegacyToCKBCellDataReader<'r> {
+    type Entity = LegacyToCKBCellData;
+    const NAME: &'static str = "LegacyToCKBCellDataReader";
+    fn to_entity(&self) -> Self::Entity {
+        Self::Entity::new_unchecked(self.as_slice().to_owned().into())
+    }
+    fn new_unchecked(slice: &'r [u8]) -> Self {
+        LegacyToCKBCellDataReader(slice)
+    }
+    fn as_slice(&self) -> &'r [u8] {
+        self.0
+    }
+    fn verify(slice: &[u8], compatible: bool) -> molecule::error::VerificationResult<()> {
+        use molecule::verification_error as ve;
+        let slice_len = slice.len();
+        if slice_len < molecule::NUMBER_SIZE {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE, slice_len);
+        }
+        let total_size = molecule::unpack_number(slice) as usize;
+        if slice_len != total_size {
+            return ve!(Self, TotalSizeNotMatch, total_size, slice_len);
+        }
+        if slice_len == molecule::NUMBER_SIZE && Self::FIELD_COUNT == 0 {
+            return Ok(());
+        }
+        if slice_len < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, HeaderIsBroken, molecule::NUMBER_SIZE * 2, slice_len);
+        }
+        let offset_first = molecule::unpack_number(&slice[molecule::NUMBER_SIZE..]) as usize;
+        if offset_first % 4 != 0 || offset_first < molecule::NUMBER_SIZE * 2 {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        let field_count = offset_first / 4 - 1;
+        if field_count < Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        } else if !compatible && field_count > Self::FIELD_COUNT {
+            return ve!(Self, FieldCountNotMatch, Self::FIELD_COUNT, field_count);
+        };
+        let header_size = molecule::NUMBER_SIZE * (field_count + 1);
+        if slice_len < header_size {
+            return ve!(Self, HeaderIsBroken, header_size, slice_len);
+        }
+        let mut offsets: Vec<usize> = slice[molecule::NUMBER_SIZE..]
+            .chunks(molecule::NUMBER_SIZE)
+            .take(field_count)
+            .map(|x| molecule::unpack_number(x) as usize)
+            .collect();
+        offsets.push(total_size);
+        if offsets.windows(2).any(|i| i[0] > i[1]) {
+            return ve!(Self, OffsetsNotMatch);
+        }
+        ByteReader::verify(&slice[offsets[0]..offsets[1]], compatible)?;
+        ByteReader::verify(&slice[offsets[1]..offsets[2]], compatible)?;
+        ScriptReader::verify(&slice[offsets[2]..offsets[3]], compatible)?;
+        BytesReader::verify(&slice[offsets[3]..offsets[4]], compatible)?;
+        ScriptReader::verify(&slice[offsets[4]..offsets[5]], compatible)?;
+        BytesReader::verify(&slice[offsets[5]..offsets[6]], compatible)?;
+        ScriptReader::verify(&slice[offsets[6]..offsets[7]], compatible)?;
+        ScriptReader::verify(&slice[offsets[7]..offsets[8]], compatible)?;
+        XExtraReader::verify(&slice[offsets[8]..offsets[9]], compatible)?;
+        Ok(())
+    }
+}
+#[derive(Debug, Default)]
+pub struct LegacyToCKBCellDataBuilder {
+    pub(crate) status: Byte,
+    pub(crate) lot_size: Byte,
+    pub(crate) user_lockscript: Script,
+    pub(crate) x_lock_address: Bytes,
+    pub(crate) signer_lockscript: Script,
+    pub(crate) x_unlock_address: Bytes,
+    pub(crate) redeemer_lockscript: Script,
+    pub(crate) liquidation_trigger_lockscript: Script,
+    pub(crate) x_extra: XExtra,
+}
+impl LegacyToCKBCellDataBuilder {
+    pub const FIELD_COUNT: usize = 9;
+    pub fn status(mut self, v: Byte) -> Self {
+        self.status = v;
+        self
+    }
+    pub fn lot_size(mut self, v: Byte) -> Self {
+        self.lot_size = v;
+        self
+    }
+    pub fn user_lockscript(mut self, v: Script) -> Self {
+        self.user_lockscript = v;
+        self
+    }
+    pub fn x_lock_address(mut self, v: Bytes) -> Self {
+        self.x_lock_address = v;
+        self
+    }
+    pub fn signer_lockscript(mut self, v: Script) -> Self {
+        self.signer_lockscript = v;
+        self
+    }
+    pub fn x_unlock_address(mut self, v: Bytes) -> Self {
+        self.x_unlock_address = v;
+        self
+    }
+    pub fn redeemer_lockscript(mut self, v: Script) -> Self {
+        self.redeemer_lockscript = v;
+        self
+    }
+    pub fn liquidation_trigger_lockscript(mut self, v: Script) -> Self {
+        self.liquidation_trigger_lockscript = v;
+        self
+    }
+    pub fn x_extra(mut self, v: XExtra) -> Self {
+        self.x_extra = v;
+        self
+    }
+}
+impl molecule::prelude::Builder for LegacyToCKBCellDataBuilder {
+    type Entity = LegacyToCKBCellData;
+    const NAME: &'static str = "LegacyToCKBCellDataBuilder";
     fn expected_length(&self) -> usize {
-        Self::TOTAL_SIZE
+        molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1)
+            + self.status.as_slice().len()
+            + self.lot_size.as_slice().len()
+            + self.user_lockscript.as_slice().len()
+            + self.x_lock_address.as_slice().len()
+            + self.signer_lockscript.as_slice().len()
+            + self.x_unlock_address.as_slice().len()
+            + self.redeemer_lockscript.as_slice().len()
+            + self.liquidation_trigger_lockscript.as_slice().len()
+            + self.x_extra.as_slice().len()
     }
     fn write<W: ::molecule::io::Write>(&self, writer: &mut W) -> ::molecule::io::Result<()> {
-        writer.write_all(self.xchain_kind.as_slice())?;
-        writer.write_all(self.cell_id.as_slice())?;
+        let mut total_size = molecule::NUMBER_SIZE * (Self::FIELD_COUNT + 1);
+        let mut offsets = Vec::with_capacity(Self::FIELD_COUNT);
+        offsets.push(total_size);
+        total_size += self.status.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.lot_size.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.user_lockscript.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.x_lock_address.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.signer_lockscript.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.x_unlock_address.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.redeemer_lockscript.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.liquidation_trigger_lockscript.as_slice().len();
+        offsets.push(total_size);
+        total_size += self.x_extra.as_slice().len();
+        writer.write_all(&molecule::pack_number(total_size as molecule::Number))?;
+        for offset in offsets.into_iter() {
+            writer.write_all(&molecule::pack_number(offset as molecule::Number))?;
+        }
+        writer.write_all(self.status.as_slice())?;
+        writer.write_all(self.lot_size.as_slice())?;
+        writer.write_all(self.user_lockscript.as_slice())?;
+        writer.write_all(self.x_lock_address.as_slice())?;
+        writer.write_all(self.signer_lockscript.as_slice())?;
+        writer.write_all(self.x_unlock_address.as_slice())?;
+        writer.write_all(self.redeemer_lockscript.as_slice())?;
+        writer.write_all(self.liquidation_trigger_lockscript.as_slice())?;
+        writer.write_all(self.x_extra.as_slice())?;
         Ok(())
     }
     fn build(&self) -> Self::Entity {
         let mut inner = Vec::with_capacity(self.expected_length());
         self.write(&mut inner)
             .unwrap_or_else(|_| panic!("{} build should be ok", Self::NAME));
-        ToCKBTypeArgs::new_unchecked(inner.into())
+        LegacyToCKBCellData::new_unchecked(inner.into())
     }
 }
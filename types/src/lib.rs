@@ -1,9 +1,17 @@
 #![cfg_attr(not(feature = "std"), no_std)]
 
+pub mod auction;
+pub mod btc_address;
+pub mod btc_relay;
 pub mod config;
 pub mod convert;
 pub mod error;
 pub mod generated;
+pub mod lock_registry;
+pub mod ltc_pow;
+pub mod protocol_params;
+pub mod signer_offer;
+pub mod since;
 pub mod tockb_cell;
 
 pub use error::Error;
@@ -0,0 +1,143 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+
+use crate::{
+    generated::{
+        basic::Byte32,
+        lock_registry::{LockRegistry, LockRegistryReader},
+    },
+    tockb_cell::{XChainKind, XExtraView},
+    Error,
+};
+use core::convert::TryInto;
+use molecule::{
+    bytes::Bytes,
+    prelude::{Builder, Entity, Reader},
+};
+use sha2::{Digest, Sha256};
+use sparse_merkle_tree::{traits::Hasher, CompiledMerkleProof, H256};
+
+/// The registry of XChain lock txs which have minted XT, kept in the type id cell whose type hash
+/// is LOCK_REGISTRY_TYPE_HASH.
+///
+/// It is a sparse merkle tree from the key of a lock tx to the type hash of the deposit which
+/// minted with it. Mint proves the key is absent and inserts it, so one lock tx can not mint twice.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct LockRegistryView {
+    pub root: [u8; 32],
+}
+
+impl LockRegistryView {
+    pub fn new(data: &[u8]) -> Result<LockRegistryView, Error> {
+        LockRegistryReader::verify(data, false).map_err(|_| Error::LockRegistryInvalid)?;
+        let data_reader = LockRegistryReader::new_unchecked(data);
+        let root = data_reader
+            .root()
+            .raw_data()
+            .try_into()
+            .expect("should not happen");
+        Ok(LockRegistryView { root })
+    }
+
+    pub fn as_molecule_data(&self) -> Bytes {
+        LockRegistry::new_builder()
+            .root(Byte32::from_slice(&self.root).expect("should not happen"))
+            .build()
+            .as_bytes()
+    }
+
+    /// Return the registry with `key` set to `value`, if `proof` shows the key is absent now.
+    pub fn insert(
+        &self,
+        key: [u8; 32],
+        value: [u8; 32],
+        proof: &[u8],
+    ) -> Result<LockRegistryView, Error> {
+        let proof = CompiledMerkleProof(proof.to_vec());
+        let compute_root = |value: [u8; 32]| -> Result<[u8; 32], Error> {
+            let root = proof
+                .compute_root::<LockRegistryHasher>(vec![(key.into(), value.into())])
+                .map_err(|_| Error::LockRegistryInvalid)?;
+            Ok(root.into())
+        };
+        if compute_root([0u8; 32])? != self.root {
+            return Err(Error::XLockTxReused);
+        }
+        Ok(LockRegistryView {
+            root: compute_root(value)?,
+        })
+    }
+}
+
+/// Build the registry holding `registered` leaves, and the merkle proof of `key` in it which mint
+/// puts in the registry witness.
+#[cfg(feature = "std")]
+pub fn build_lock_registry(
+    registered: &[([u8; 32], [u8; 32])],
+    key: [u8; 32],
+) -> Result<(LockRegistryView, Vec<u8>), Error> {
+    use sparse_merkle_tree::{default_store::DefaultStore, SparseMerkleTree};
+
+    let mut tree: SparseMerkleTree<LockRegistryHasher, H256, DefaultStore<H256>> =
+        Default::default();
+    for (registered_key, value) in registered {
+        tree.update((*registered_key).into(), (*value).into())
+            .map_err(|_| Error::LockRegistryInvalid)?;
+    }
+    let value = tree
+        .get(&key.into())
+        .map_err(|_| Error::LockRegistryInvalid)?;
+    let proof = tree
+        .merkle_proof(vec![key.into()])
+        .and_then(|proof| proof.compile(vec![(key.into(), value)]))
+        .map_err(|_| Error::LockRegistryInvalid)?;
+    let registry = LockRegistryView {
+        root: (*tree.root()).into(),
+    };
+    Ok((registry, proof.0))
+}
+
+/// Key of the lock tx of a deposit in the registry. A BTC or LTC lock is identified by the
+/// outpoint it creates, an ETH or ERC-20 lock by its tx hash.
+pub fn get_registry_key(x_extra: &XExtraView) -> [u8; 32] {
+    let mut hasher = Sha256::default();
+    match x_extra {
+        XExtraView::Btc(extra) => {
+            hasher.input(&[XChainKind::Btc as u8]);
+            hasher.input(extra.lock_tx_hash.as_ref());
+            hasher.input(&extra.lock_vout_index.to_le_bytes());
+        }
+        XExtraView::Eth(extra) => {
+            hasher.input(&[XChainKind::Eth as u8]);
+            hasher.input(extra.lock_tx_hash.as_ref());
+        }
+        XExtraView::Ltc(extra) => {
+            hasher.input(&[XChainKind::Ltc as u8]);
+            hasher.input(extra.lock_tx_hash.as_ref());
+            hasher.input(&extra.lock_vout_index.to_le_bytes());
+        }
+        XExtraView::Erc20(extra) => {
+            hasher.input(&[XChainKind::Erc20 as u8]);
+            hasher.input(extra.lock.lock_tx_hash.as_ref());
+        }
+    }
+    let mut key = [0u8; 32];
+    key.copy_from_slice(&hasher.result());
+    key
+}
+
+/// The sparse merkle tree of the registry hashes with sha256.
+#[derive(Default)]
+pub struct LockRegistryHasher(Sha256);
+
+impl Hasher for LockRegistryHasher {
+    fn write_h256(&mut self, h: &H256) {
+        self.0.input(h.as_slice());
+    }
+
+    fn finish(self) -> H256 {
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(&self.0.result());
+        hash.into()
+    }
+}
@@ -0,0 +1,137 @@
+use crate::{
+    btc_relay::{bits_to_target, hash256, BTC_HEADER_SIZE},
+    Error,
+};
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+use core::convert::TryInto;
+use primitive_types::U256;
+use sha2::{Digest, Sha256};
+
+// scrypt parameters of litecoin, N = 1024, r = 1, p = 1
+const SCRYPT_N: usize = 1024;
+// 128 * r bytes as u32 words
+const SCRYPT_BLOCK_WORDS: usize = 32;
+
+/// Verify the LTC headers build on each other and every header meets its target with the scrypt
+/// hash, return the sum of their difficulties.
+///
+/// The difficulty is computed like bitcoin-spv does for BTC headers, so it can be compared with
+/// the difficulty oracle of a LTC deployment.
+pub fn validate_header_chain(raw_headers: &[u8]) -> Result<U256, Error> {
+    if raw_headers.is_empty() || raw_headers.len() % BTC_HEADER_SIZE != 0 {
+        return Err(Error::LtcHeaderInvalid);
+    }
+    let diff1_target = U256::from(0xffff) << 208;
+    let mut scratch = vec![0u32; SCRYPT_N * SCRYPT_BLOCK_WORDS];
+    let mut total_difficulty = U256::zero();
+    let mut prev_hash: Option<[u8; 32]> = None;
+    for raw_header in raw_headers.chunks(BTC_HEADER_SIZE) {
+        if let Some(prev_hash) = prev_hash {
+            if prev_hash[..] != raw_header[4..36] {
+                return Err(Error::LtcHeaderInvalid);
+            }
+        }
+        let bits = u32::from_le_bytes(raw_header[72..76].try_into().unwrap());
+        let target = bits_to_target(bits).map_err(|_| Error::LtcHeaderInvalid)?;
+        let pow_hash = scrypt_hash(raw_header, &mut scratch);
+        if target.is_zero() || U256::from_little_endian(&pow_hash) > target {
+            return Err(Error::LtcHeaderInvalid);
+        }
+        total_difficulty += diff1_target / target;
+        prev_hash = Some(hash256(raw_header));
+    }
+    Ok(total_difficulty)
+}
+
+/// The proof of work hash of a LTC header, i.e. scrypt with the header as both password and salt.
+/// `scratch` is the memory of ROMix, it must hold `SCRYPT_N * SCRYPT_BLOCK_WORDS` words.
+pub fn scrypt_hash(header: &[u8], scratch: &mut [u32]) -> [u8; 32] {
+    let mut block = [0u8; 4 * SCRYPT_BLOCK_WORDS];
+    for (i, chunk) in block.chunks_mut(32).enumerate() {
+        chunk.copy_from_slice(&hmac_sha256(
+            header,
+            &[header, &(i as u32 + 1).to_be_bytes()],
+        ));
+    }
+    let mut x = [0u32; SCRYPT_BLOCK_WORDS];
+    for (word, bytes) in x.iter_mut().zip(block.chunks(4)) {
+        *word = u32::from_le_bytes(bytes.try_into().unwrap());
+    }
+    romix(&mut x, scratch);
+    for (bytes, word) in block.chunks_mut(4).zip(x.iter()) {
+        bytes.copy_from_slice(&word.to_le_bytes());
+    }
+    hmac_sha256(header, &[&block, &1u32.to_be_bytes()])
+}
+
+fn romix(x: &mut [u32; SCRYPT_BLOCK_WORDS], scratch: &mut [u32]) {
+    for v in scratch.chunks_mut(SCRYPT_BLOCK_WORDS) {
+        v.copy_from_slice(&x[..]);
+        block_mix(x);
+    }
+    for _ in 0..SCRYPT_N {
+        let j = x[SCRYPT_BLOCK_WORDS - 16] as usize % SCRYPT_N;
+        let v = &scratch[j * SCRYPT_BLOCK_WORDS..(j + 1) * SCRYPT_BLOCK_WORDS];
+        for (word, v_word) in x.iter_mut().zip(v) {
+            *word ^= v_word;
+        }
+        block_mix(x);
+    }
+}
+
+// BlockMix with r = 1, the output order of the two halves stays the same
+fn block_mix(b: &mut [u32; SCRYPT_BLOCK_WORDS]) {
+    let mut x = [0u32; 16];
+    x.copy_from_slice(&b[16..]);
+    for half in b.chunks_mut(16) {
+        for (word, b_word) in x.iter_mut().zip(half.iter()) {
+            *word ^= b_word;
+        }
+        salsa20_8(&mut x);
+        half.copy_from_slice(&x);
+    }
+}
+
+fn salsa20_8(block: &mut [u32; 16]) {
+    const COLUMNS: [[usize; 4]; 4] = [[0, 4, 8, 12], [5, 9, 13, 1], [10, 14, 2, 6], [15, 3, 7, 11]];
+    const ROWS: [[usize; 4]; 4] = [[0, 1, 2, 3], [5, 6, 7, 4], [10, 11, 8, 9], [15, 12, 13, 14]];
+    let mut x = *block;
+    for _ in 0..4 {
+        for &[a, b, c, d] in COLUMNS.iter().chain(ROWS.iter()) {
+            x[b] ^= x[a].wrapping_add(x[d]).rotate_left(7);
+            x[c] ^= x[b].wrapping_add(x[a]).rotate_left(9);
+            x[d] ^= x[c].wrapping_add(x[b]).rotate_left(13);
+            x[a] ^= x[d].wrapping_add(x[c]).rotate_left(18);
+        }
+    }
+    for (word, x_word) in block.iter_mut().zip(x.iter()) {
+        *word = word.wrapping_add(*x_word);
+    }
+}
+
+fn hmac_sha256(key: &[u8], message: &[&[u8]]) -> [u8; 32] {
+    let mut key_block = [0u8; 64];
+    if key.len() > key_block.len() {
+        key_block[..32].copy_from_slice(&Sha256::digest(key));
+    } else {
+        key_block[..key.len()].copy_from_slice(key);
+    }
+    let mut inner = Sha256::default();
+    let mut outer = Sha256::default();
+    let mut ipad = [0x36u8; 64];
+    let mut opad = [0x5cu8; 64];
+    for ((i, o), k) in ipad.iter_mut().zip(opad.iter_mut()).zip(key_block.iter()) {
+        *i ^= k;
+        *o ^= k;
+    }
+    inner.input(&ipad[..]);
+    for part in message {
+        inner.input(part);
+    }
+    outer.input(&opad[..]);
+    outer.input(&inner.result());
+    let mut ret = [0u8; 32];
+    ret.copy_from_slice(&outer.result());
+    ret
+}
@@ -0,0 +1,204 @@
+use crate::{
+    auction::AuctionCurve,
+    config::*,
+    generated::{
+        protocol_params::{ProtocolParams, ProtocolParamsReader},
+        tockb_cell_data::{ParamsSnapshot, ParamsSnapshotReader},
+    },
+    since::RelativeSince,
+    Error,
+};
+use molecule::{
+    bytes::Bytes,
+    prelude::{Builder, Entity, Reader},
+};
+
+/// Protocol parameters kept in the governance cell, the toCKB typescript reads them from the
+/// cell dep whose type hash is PROTOCOL_PARAMS_TYPE_HASH.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ProtocolParamsView {
+    pub pledge: u64,
+    pub collateral_percent: u8,
+    pub liquidation_collateral_percent: u8,
+    pub pre_undercollateral_rate: u8,
+    pub auction_init_percent: u8,
+    pub auction_max_time: u64,
+    pub auction_curve: u8,
+    pub auction_curve_param: u64,
+    pub max_signer_fee_rate: u32,
+    pub btc_spv_confirmations: u8,
+    pub faulty_compensation_percent: u8,
+    pub since_signer_timeout: u64,
+    pub since_at_term_redeem: u64,
+    pub since_withdraw_pledge_collateral: u64,
+    pub since_withdraw_pledge: u64,
+}
+
+/// The values the governance cell is created with
+impl Default for ProtocolParamsView {
+    fn default() -> Self {
+        ProtocolParamsView {
+            pledge: PLEDGE,
+            collateral_percent: COLLATERAL_PERCENT,
+            liquidation_collateral_percent: LIQUIDATION_COLLATERAL_PERCENT,
+            pre_undercollateral_rate: PRE_UNDERCOLLATERAL_RATE,
+            auction_init_percent: AUCTION_INIT_PERCENT,
+            auction_max_time: AUCTION_MAX_TIME,
+            auction_curve: AUCTION_CURVE,
+            auction_curve_param: AUCTION_CURVE_PARAM,
+            max_signer_fee_rate: MAX_SIGNER_FEE_RATE,
+            btc_spv_confirmations: BTC_SPV_CONFIRMATIONS,
+            faulty_compensation_percent: FAULTY_COMPENSATION_PERCENT,
+            since_signer_timeout: SINCE_SIGNER_TIMEOUT,
+            since_at_term_redeem: SINCE_AT_TERM_REDEEM,
+            since_withdraw_pledge_collateral: SINCE_WITHDRAW_PLEDGE_COLLATERAL,
+            since_withdraw_pledge: SINCE_WITHDRAW_PLEDGE,
+        }
+    }
+}
+
+impl ProtocolParamsView {
+    pub fn new(data: &[u8]) -> Result<ProtocolParamsView, Error> {
+        ProtocolParamsReader::verify(data, false).map_err(|_| Error::ProtocolParamsInvalid)?;
+        let data_reader = ProtocolParamsReader::new_unchecked(data);
+        let params = ProtocolParamsView {
+            pledge: data_reader.pledge().into(),
+            collateral_percent: data_reader.collateral_percent().as_slice()[0],
+            liquidation_collateral_percent: data_reader.liquidation_collateral_percent().as_slice()
+                [0],
+            pre_undercollateral_rate: data_reader.pre_undercollateral_rate().as_slice()[0],
+            auction_init_percent: data_reader.auction_init_percent().as_slice()[0],
+            auction_max_time: data_reader.auction_max_time().into(),
+            auction_curve: data_reader.auction_curve().as_slice()[0],
+            auction_curve_param: data_reader.auction_curve_param().into(),
+            max_signer_fee_rate: data_reader.max_signer_fee_rate().into(),
+            btc_spv_confirmations: data_reader.btc_spv_confirmations().as_slice()[0],
+            faulty_compensation_percent: data_reader.faulty_compensation_percent().as_slice()[0],
+            since_signer_timeout: data_reader.since_signer_timeout().into(),
+            since_at_term_redeem: data_reader.since_at_term_redeem().into(),
+            since_withdraw_pledge_collateral: data_reader.since_withdraw_pledge_collateral().into(),
+            since_withdraw_pledge: data_reader.since_withdraw_pledge().into(),
+        };
+        if !params.is_valid() {
+            return Err(Error::ProtocolParamsInvalid);
+        }
+        Ok(params)
+    }
+
+    pub fn as_molecule_data(&self) -> Bytes {
+        ProtocolParams::new_builder()
+            .pledge(self.pledge.into())
+            .collateral_percent(self.collateral_percent.into())
+            .liquidation_collateral_percent(self.liquidation_collateral_percent.into())
+            .pre_undercollateral_rate(self.pre_undercollateral_rate.into())
+            .auction_init_percent(self.auction_init_percent.into())
+            .auction_max_time(self.auction_max_time.into())
+            .auction_curve(self.auction_curve.into())
+            .auction_curve_param(self.auction_curve_param.into())
+            .max_signer_fee_rate(self.max_signer_fee_rate.into())
+            .btc_spv_confirmations(self.btc_spv_confirmations.into())
+            .faulty_compensation_percent(self.faulty_compensation_percent.into())
+            .since_signer_timeout(self.since_signer_timeout.into())
+            .since_at_term_redeem(self.since_at_term_redeem.into())
+            .since_withdraw_pledge_collateral(self.since_withdraw_pledge_collateral.into())
+            .since_withdraw_pledge(self.since_withdraw_pledge.into())
+            .build()
+            .as_bytes()
+    }
+
+    /// The params a deposit keeps from bonding on
+    pub fn snapshot(&self) -> ParamsSnapshotView {
+        ParamsSnapshotView {
+            liquidation_collateral_percent: self.liquidation_collateral_percent,
+            pre_undercollateral_rate: self.pre_undercollateral_rate,
+            since_signer_timeout: self.since_signer_timeout,
+            since_at_term_redeem: self.since_at_term_redeem,
+        }
+    }
+
+    /// A deposit must be liquidated before it is undercollateralized, and a signer can only
+    /// start redeeming when it is about to be liquidated. The timeouts are relative since in
+    /// timestamp, block number or epoch. The auction curve must be a known one with a valid
+    /// parameter. The compensation of a faulty warranty must leave the auctioned collateral worth
+    /// the lot at the liquidation rate.
+    fn is_valid(&self) -> bool {
+        let is_relative = |since: u64| RelativeSince::new(since).is_ok();
+        self.liquidation_collateral_percent >= 100
+            && self.liquidation_collateral_percent <= self.pre_undercollateral_rate
+            && self.pre_undercollateral_rate <= self.collateral_percent
+            && self.auction_init_percent <= 100
+            && self.auction_max_time > 0
+            && AuctionCurve::new(self.auction_curve, self.auction_curve_param).is_ok()
+            && (self.max_signer_fee_rate as u128) < SIGNER_FEE_RATE_DENOMINATOR
+            && self.btc_spv_confirmations > 0
+            && self.faulty_compensation_percent <= 100
+            && (100 - self.faulty_compensation_percent as u32)
+                * self.liquidation_collateral_percent as u32
+                >= 100 * 100
+            && is_relative(self.since_signer_timeout)
+            && is_relative(self.since_at_term_redeem)
+            && is_relative(self.since_withdraw_pledge_collateral)
+            && is_relative(self.since_withdraw_pledge)
+    }
+    pub fn get_auction_curve(&self) -> Result<AuctionCurve, Error> {
+        AuctionCurve::new(self.auction_curve, self.auction_curve_param)
+    }
+
+    /// Collateral paid to the depositor out of the collateral of a faulty warranty deposit
+    pub fn get_faulty_compensation(&self, collateral: u64) -> u64 {
+        (collateral as u128 * self.faulty_compensation_percent as u128 / 100) as u64
+    }
+
+    /// Collateral paid to the bidder of an auction which started `auction_time` seconds ago
+    pub fn get_auction_bidder_payout(
+        &self,
+        collateral: u64,
+        auction_time: u64,
+    ) -> Result<u64, Error> {
+        Ok(self.get_auction_curve()?.get_bidder_payout(
+            collateral,
+            self.auction_init_percent,
+            self.auction_max_time,
+            auction_time,
+        ))
+    }
+}
+
+/// Protocol params recorded in the toCKB cell at bonding. The liquidation thresholds and the
+/// timeouts of a bonded deposit are read from here, so a params update only affects deposits
+/// bonded after it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParamsSnapshotView {
+    pub liquidation_collateral_percent: u8,
+    pub pre_undercollateral_rate: u8,
+    pub since_signer_timeout: u64,
+    pub since_at_term_redeem: u64,
+}
+
+/// The snapshot of the default params, cells bonded before the snapshot was introduced ran under
+/// them
+impl Default for ParamsSnapshotView {
+    fn default() -> Self {
+        ProtocolParamsView::default().snapshot()
+    }
+}
+
+impl ParamsSnapshotView {
+    pub fn new(reader: ParamsSnapshotReader) -> ParamsSnapshotView {
+        ParamsSnapshotView {
+            liquidation_collateral_percent: reader.liquidation_collateral_percent().as_slice()[0],
+            pre_undercollateral_rate: reader.pre_undercollateral_rate().as_slice()[0],
+            since_signer_timeout: reader.since_signer_timeout().into(),
+            since_at_term_redeem: reader.since_at_term_redeem().into(),
+        }
+    }
+
+    pub fn as_molecule(&self) -> ParamsSnapshot {
+        ParamsSnapshot::new_builder()
+            .liquidation_collateral_percent(self.liquidation_collateral_percent.into())
+            .pre_undercollateral_rate(self.pre_undercollateral_rate.into())
+            .since_signer_timeout(self.since_signer_timeout.into())
+            .since_at_term_redeem(self.since_at_term_redeem.into())
+            .build()
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use crate::{
+    config::SIGNER_FEE_RATE_DENOMINATOR,
+    generated::{
+        basic,
+        signer_offer::{SignerOffer, SignerOfferReader},
+    },
+    tockb_cell::XChainKind,
+    Error,
+};
+use int_enum::IntEnum;
+use molecule::{
+    bytes::Bytes,
+    error::VerificationError,
+    prelude::{Builder, Entity, Reader},
+};
+
+/// An offer a signer publishes in a cell of the signer registry typescript. The cell is locked by
+/// `signer_lockscript`, so only the signer can update or withdraw the offer, and its capacity
+/// backs the offered collateral.
+///
+/// A user picks an offer by requesting a deposit with the `signer_lockscript` of the offer, then
+/// only that signer can bond the deposit within BONDING_WINDOW.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SignerOfferView {
+    pub chain_kinds: Vec<XChainKind>,
+    pub min_lot_amount: u128,
+    pub max_lot_amount: u128,
+    pub signer_fee_rate: u32,
+    pub x_lock_address: Bytes,
+    pub collateral: u64,
+    pub signer_lockscript: Bytes,
+}
+
+impl SignerOfferView {
+    pub fn new(data: &[u8]) -> Result<SignerOfferView, Error> {
+        SignerOfferReader::verify(data, false).map_err(|_| Error::SignerOfferInvalid)?;
+        let data_reader = SignerOfferReader::new_unchecked(data);
+        let chain_kinds = data_reader
+            .chain_kinds()
+            .raw_data()
+            .iter()
+            .map(|kind| XChainKind::from_int(*kind).map_err(|_| Error::SignerOfferInvalid))
+            .collect::<Result<Vec<_>, _>>()?;
+        let offer = SignerOfferView {
+            chain_kinds,
+            min_lot_amount: data_reader.min_lot_amount().into(),
+            max_lot_amount: data_reader.max_lot_amount().into(),
+            signer_fee_rate: data_reader.signer_fee_rate().into(),
+            x_lock_address: data_reader.x_lock_address().to_entity().raw_data(),
+            collateral: data_reader.collateral().into(),
+            signer_lockscript: data_reader.signer_lockscript().to_entity().as_bytes(),
+        };
+        if !offer.is_valid() {
+            return Err(Error::SignerOfferInvalid);
+        }
+        Ok(offer)
+    }
+
+    pub fn as_molecule_data(&self) -> Result<Bytes, VerificationError> {
+        let chain_kinds = self
+            .chain_kinds
+            .iter()
+            .map(|kind| kind.int_value())
+            .collect::<Vec<u8>>();
+        Ok(SignerOffer::new_builder()
+            .chain_kinds(chain_kinds.into())
+            .min_lot_amount(self.min_lot_amount.into())
+            .max_lot_amount(self.max_lot_amount.into())
+            .signer_fee_rate(self.signer_fee_rate.into())
+            .x_lock_address(self.x_lock_address.to_vec().into())
+            .collateral(self.collateral.into())
+            .signer_lockscript(basic::Script::from_slice(&self.signer_lockscript)?)
+            .build()
+            .as_bytes())
+    }
+
+    /// An offer lists every chain kind once, and has a lot amount range and a fee rate below the
+    /// whole lot
+    fn is_valid(&self) -> bool {
+        let has_duplicated_kind = self
+            .chain_kinds
+            .iter()
+            .enumerate()
+            .any(|(i, kind)| self.chain_kinds[..i].contains(kind));
+        !self.chain_kinds.is_empty()
+            && !has_duplicated_kind
+            && self.min_lot_amount <= self.max_lot_amount
+            && (self.signer_fee_rate as u128) < SIGNER_FEE_RATE_DENOMINATOR
+    }
+
+    /// Whether the signer takes a deposit of `kind` and `lot_amount` paying `signer_fee_rate`
+    pub fn accepts(&self, kind: XChainKind, lot_amount: u128, signer_fee_rate: u32) -> bool {
+        self.chain_kinds.contains(&kind)
+            && lot_amount >= self.min_lot_amount
+            && lot_amount <= self.max_lot_amount
+            && signer_fee_rate >= self.signer_fee_rate
+    }
+}
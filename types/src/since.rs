@@ -0,0 +1,73 @@
+use crate::config::{
+    BLOCK_INTERVAL_SECONDS, EPOCH_DURATION_SECONDS, LOCK_TYPE_FLAG, METRIC_TYPE_FLAG_MASK,
+    REMAIN_FLAGS_BITS, SINCE_TYPE_BLOCK_NUMBER, SINCE_TYPE_EPOCH_NUMBER, SINCE_TYPE_TIMESTAMP,
+    VALUE_MASK,
+};
+use crate::Error;
+
+/// A relative since in one of the metrics of CKB. The timeouts of the protocol params and the
+/// since of auctions can be in any of them.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RelativeSince {
+    BlockNumber(u64),
+    /// `number` epochs and a fraction of `index / length` of an epoch
+    Epoch {
+        number: u64,
+        index: u64,
+        length: u64,
+    },
+    /// seconds of the median time of the past blocks
+    Timestamp(u64),
+}
+
+impl RelativeSince {
+    pub fn new(since: u64) -> Result<RelativeSince, Error> {
+        if since & REMAIN_FLAGS_BITS != 0 || since & LOCK_TYPE_FLAG == 0 {
+            return Err(Error::InputSinceInvalid);
+        }
+        let value = since & VALUE_MASK;
+        match since & METRIC_TYPE_FLAG_MASK {
+            SINCE_TYPE_BLOCK_NUMBER => Ok(RelativeSince::BlockNumber(value)),
+            SINCE_TYPE_EPOCH_NUMBER => {
+                let number = value & 0xff_ffff;
+                let index = (value >> 24) & 0xffff;
+                let length = (value >> 40) & 0xffff;
+                // CKB takes a zero length as a whole number of epochs
+                if (length == 0 && index != 0) || (length != 0 && index >= length) {
+                    return Err(Error::InputSinceInvalid);
+                }
+                Ok(RelativeSince::Epoch {
+                    number,
+                    index,
+                    length,
+                })
+            }
+            SINCE_TYPE_TIMESTAMP => Ok(RelativeSince::Timestamp(value)),
+            _ => Err(Error::InputSinceInvalid),
+        }
+    }
+
+    /// Elapsed seconds of the since, block numbers and epochs are counted at the target block
+    /// interval and epoch duration of CKB. A since too far to count in seconds is invalid.
+    pub fn as_seconds(&self) -> Result<u64, Error> {
+        let seconds = match *self {
+            RelativeSince::BlockNumber(number) => number.checked_mul(BLOCK_INTERVAL_SECONDS),
+            RelativeSince::Epoch {
+                number,
+                index,
+                length,
+            } => {
+                let fraction = if length == 0 {
+                    0
+                } else {
+                    index * EPOCH_DURATION_SECONDS / length
+                };
+                number
+                    .checked_mul(EPOCH_DURATION_SECONDS)
+                    .and_then(|seconds| seconds.checked_add(fraction))
+            }
+            RelativeSince::Timestamp(seconds) => Some(seconds),
+        };
+        seconds.ok_or(Error::InputSinceInvalid)
+    }
+}
@@ -1,14 +1,23 @@
 #[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(not(feature = "std"))]
 use ckb_std::debug;
 
+use crate::btc_address::{self, AddressNetwork};
+use crate::config::{
+    BTC_LOT_AMOUNT_RANGE, ERC20_LOT_AMOUNT_RANGE, ETH_LOT_AMOUNT_RANGE, LTC_LOT_AMOUNT_RANGE,
+    PLEDGE, SIGNER_FEE_RATE, SIGNER_FEE_RATE_DENOMINATOR,
+};
 use crate::error::Error;
 use crate::generated::{
     basic,
     tockb_cell_data::{
-        BtcExtra, EthExtra, ToCKBCellData, ToCKBCellDataReader, ToCKBTypeArgsReader, XExtra,
-        XExtraUnion, XExtraUnionReader,
+        BtcExtra, Erc20Extra, EthExtra, LegacyToCKBCellDataReader, LegacyToCKBTypeArgsReader,
+        LtcExtra, SignerGroup, SignerMember, SignerMemberVec, ToCKBCellData, ToCKBCellDataReader,
+        ToCKBTypeArgsReader, XExtra, XExtraUnion, XExtraUnionReader,
     },
 };
+use crate::protocol_params::ParamsSnapshotView;
 use core::convert::TryInto;
 use core::result::Result;
 use int_enum::IntEnum;
@@ -20,18 +29,117 @@ use molecule::{
 
 pub const BTC_UNIT: u128 = 100_000_000;
 pub const ETH_UNIT: u128 = 1_000_000_000_000_000_000;
+pub const LTC_UNIT: u128 = 100_000_000;
+
+// lot size of cells whose XT amount is given by the amount field instead of a lot size enum
+pub const ARBITRARY_LOT_SIZE: u8 = 0;
 
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, IntEnum)]
+#[derive(Debug, Clone, Copy, IntEnum, PartialEq)]
 pub enum XChainKind {
     Btc = 1,
     Eth = 2,
+    Ltc = 3,
+    Erc20 = 4,
+}
+
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, IntEnum, PartialEq)]
+pub enum BtcNetwork {
+    Mainnet = 0,
+    Testnet = 1,
+    Signet = 2,
+    Regtest = 3,
+}
+
+impl AddressNetwork for BtcNetwork {
+    fn address_hrp(&self) -> &'static str {
+        use BtcNetwork::*;
+        match self {
+            Mainnet => "bc",
+            Testnet | Signet => "tb",
+            Regtest => "bcrt",
+        }
+    }
+
+    fn p2pkh_version(&self) -> u8 {
+        match self {
+            BtcNetwork::Mainnet => 0x00,
+            _ => 0x6f,
+        }
+    }
+
+    fn p2sh_version(&self) -> u8 {
+        match self {
+            BtcNetwork::Mainnet => 0x05,
+            _ => 0xc4,
+        }
+    }
+}
+
+impl BtcNetwork {
+    /// compact form of the easiest target allowed by the network
+    pub fn pow_limit_bits(&self) -> u32 {
+        use BtcNetwork::*;
+        match self {
+            Mainnet | Testnet => 0x1d00_ffff,
+            Signet => 0x1e03_77ae,
+            Regtest => 0x207f_ffff,
+        }
+    }
+
+    /// whether a block may use the pow limit when it is 20 minutes later than its parent
+    pub fn allow_min_difficulty_blocks(&self) -> bool {
+        match self {
+            BtcNetwork::Testnet | BtcNetwork::Regtest => true,
+            _ => false,
+        }
+    }
+
+    pub fn no_retargeting(&self) -> bool {
+        *self == BtcNetwork::Regtest
+    }
+}
+
+/// LTC networks, numbered like `BtcNetwork`
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, IntEnum, PartialEq)]
+pub enum LtcNetwork {
+    Mainnet = 0,
+    Testnet = 1,
+    Regtest = 3,
+}
+
+impl AddressNetwork for LtcNetwork {
+    fn address_hrp(&self) -> &'static str {
+        use LtcNetwork::*;
+        match self {
+            Mainnet => "ltc",
+            Testnet => "tltc",
+            Regtest => "rltc",
+        }
+    }
+
+    fn p2pkh_version(&self) -> u8 {
+        match self {
+            LtcNetwork::Mainnet => 0x30,
+            _ => 0x6f,
+        }
+    }
+
+    fn p2sh_version(&self) -> u8 {
+        match self {
+            LtcNetwork::Mainnet => 0x32,
+            _ => 0x3a,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
 pub struct ToCKBCellDataView {
     pub status: ToCKBStatus,
     lot_size: u8,
+    amount: u128,
     pub user_lockscript: Bytes,
     pub x_lock_address: Bytes,
     pub signer_lockscript: Bytes,
@@ -39,12 +147,61 @@ pub struct ToCKBCellDataView {
     pub redeemer_lockscript: Bytes,
     pub liquidation_trigger_lockscript: Bytes,
     pub x_extra: XExtraView,
+    redeem_amount: u128,
+    pub signer_group: SignerGroupView,
+    pub signer_fee_rate: u32,
+    pub pledge: u64,
+    // recorded at bonding, not used before
+    pub params_snapshot: ParamsSnapshotView,
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub enum XExtraView {
     Btc(BtcExtraView),
     Eth(EthExtraView),
+    // a LTC lock is an outpoint like a BTC one
+    Ltc(BtcExtraView),
+    Erc20(Erc20ExtraView),
+}
+
+impl XExtraView {
+    /// The lock outpoint of a UTXO chain deposit
+    pub fn get_utxo_extra(&self) -> Option<&BtcExtraView> {
+        match self {
+            XExtraView::Btc(extra) | XExtraView::Ltc(extra) => Some(extra),
+            XExtraView::Eth(_) | XExtraView::Erc20(_) => None,
+        }
+    }
+
+    /// The lock tx of an ETH or ERC-20 deposit
+    pub fn get_eth_extra(&self) -> Option<&EthExtraView> {
+        match self {
+            XExtraView::Eth(extra) => Some(extra),
+            XExtraView::Erc20(extra) => Some(&extra.lock),
+            XExtraView::Btc(_) | XExtraView::Ltc(_) => None,
+        }
+    }
+
+    /// The token contract of an ERC-20 deposit, `None` for native assets
+    pub fn get_token_address(&self) -> Option<&[u8]> {
+        match self {
+            XExtraView::Erc20(extra) => Some(extra.token_address.as_ref()),
+            _ => None,
+        }
+    }
+
+    /// The extra of an ETH or ERC-20 deposit whose asset is relocked by the ETH tx `lock`, the
+    /// token of an ERC-20 deposit stays the same.
+    pub fn relock_eth(&self, lock: EthExtraView) -> Result<XExtraView, Error> {
+        match self {
+            XExtraView::Eth(_) => Ok(XExtraView::Eth(lock)),
+            XExtraView::Erc20(extra) => Ok(XExtraView::Erc20(Erc20ExtraView {
+                token_address: extra.token_address.clone(),
+                lock,
+            })),
+            XExtraView::Btc(_) | XExtraView::Ltc(_) => Err(Error::XChainMismatch),
+        }
+    }
 }
 
 #[derive(Debug, Eq, PartialEq, Clone)]
@@ -55,12 +212,38 @@ pub struct BtcExtraView {
 
 #[derive(Debug, Eq, PartialEq, Clone)]
 pub struct EthExtraView {
-    pub dummy: Bytes,
+    pub lock_tx_hash: Bytes,
+    pub lock_tx_index: u32,
+    pub lock_block_number: u64,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct Erc20ExtraView {
+    pub token_address: Bytes,
+    pub lock: EthExtraView,
+}
+
+/// M-of-N signers of a deposit, no members means a single signer
+#[derive(Debug, Eq, PartialEq, Clone, Default)]
+pub struct SignerGroupView {
+    pub threshold: u8,
+    pub members: Vec<SignerMemberView>,
+}
+
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct SignerMemberView {
+    pub lockscript: Bytes,
+    pub share: u32,
+    pub x_pubkey: Bytes,
 }
 
 impl ToCKBCellDataView {
     pub fn new(data: &[u8], x_kind: XChainKind) -> Result<ToCKBCellDataView, Error> {
-        ToCKBCellDataReader::verify(data, false).map_err(|_| Error::Encoding)?;
+        let data = if ToCKBCellDataReader::verify(data, false).is_ok() {
+            ToCKBCellData::new_unchecked(data.to_vec().into())
+        } else {
+            upgrade_legacy_data(data, x_kind)?
+        };
         cfg_if::cfg_if! {
             if #[cfg(feature = "std")] {
                 dbg!("molecule verify toCKB data format success");
@@ -68,9 +251,26 @@ impl ToCKBCellDataView {
                 debug!("molecule verify toCKB data format success");
             }
         }
-        let data_reader = ToCKBCellDataReader::new_unchecked(data);
+        let data_reader = data.as_reader();
         let status = ToCKBStatus::from_int(data_reader.status().to_entity().into())?;
         let lot_size = data_reader.lot_size().as_slice()[0];
+        let amount: u128 = data_reader.amount().into();
+        if lot_size != ARBITRARY_LOT_SIZE && get_legacy_lot_amount(x_kind, lot_size)? != amount {
+            return Err(Error::LotSizeInvalid);
+        }
+        let redeem_amount: u128 = data_reader.redeem_amount().into();
+        // a partial redemption only exists while redeeming, and neither the redeemed part nor
+        // the remainder may be below the minimum lot amount
+        if redeem_amount != 0 {
+            use ToCKBStatus::*;
+            let min_amount = get_lot_amount_range(x_kind).0;
+            match status {
+                Redeeming | SignerTimeout | FaultyWhenRedeeming
+                    if redeem_amount >= min_amount
+                        && amount.saturating_sub(redeem_amount) >= min_amount => {}
+                _ => return Err(Error::RedeemAmountInvalid),
+            }
+        }
         let user_lockscript = data_reader.user_lockscript().to_entity().as_bytes();
         let x_lock_address = data_reader.x_lock_address().to_entity().raw_data();
         let signer_lockscript = data_reader.signer_lockscript().to_entity().as_bytes();
@@ -95,15 +295,64 @@ impl ToCKBCellDataView {
                     lock_vout_index,
                 })
             }
+            (Ltc, LtcExtra(ltc_extra)) => {
+                let lock_tx_hash = ltc_extra.lock_tx_hash().to_entity().raw_data();
+                let lock_vout_index = ltc_extra.lock_vout_index().into();
+                XExtraView::Ltc(BtcExtraView {
+                    lock_tx_hash,
+                    lock_vout_index,
+                })
+            }
             (Eth, EthExtra(eth_extra)) => {
-                let dummy = eth_extra.dummy().to_entity().raw_data();
-                XExtraView::Eth(EthExtraView { dummy })
+                let lock_tx_hash = eth_extra.lock_tx_hash().to_entity().raw_data();
+                let lock_tx_index = eth_extra.lock_tx_index().into();
+                let lock_block_number = eth_extra.lock_block_number().into();
+                XExtraView::Eth(EthExtraView {
+                    lock_tx_hash,
+                    lock_tx_index,
+                    lock_block_number,
+                })
+            }
+            (Erc20, Erc20Extra(erc20_extra)) => {
+                let token_address = erc20_extra.token_address().to_entity().raw_data();
+                if token_address.len() != 20 {
+                    return Err(Error::XChainAddressInvalid);
+                }
+                XExtraView::Erc20(Erc20ExtraView {
+                    token_address,
+                    lock: EthExtraView {
+                        lock_tx_hash: erc20_extra.lock_tx_hash().to_entity().raw_data(),
+                        lock_tx_index: erc20_extra.lock_tx_index().into(),
+                        lock_block_number: erc20_extra.lock_block_number().into(),
+                    },
+                })
             }
             _ => return Err(Error::XChainMismatch),
         };
+        let signer_group_reader = data_reader.signer_group();
+        let signer_group = SignerGroupView {
+            threshold: signer_group_reader.threshold().as_slice()[0],
+            members: signer_group_reader
+                .members()
+                .iter()
+                .map(|member| SignerMemberView {
+                    lockscript: member.lockscript().to_entity().as_bytes(),
+                    share: member.share().into(),
+                    x_pubkey: member.x_pubkey().to_entity().raw_data(),
+                })
+                .collect(),
+        };
+        // payouts are split by shares, so every member must have one
+        if signer_group.members.iter().any(|member| member.share == 0) {
+            return Err(Error::SignerGroupInvalid);
+        }
+        let signer_fee_rate = data_reader.signer_fee_rate().into();
+        let pledge = data_reader.pledge().into();
+        let params_snapshot = ParamsSnapshotView::new(data_reader.params_snapshot());
         Ok(ToCKBCellDataView {
             status,
             lot_size,
+            amount,
             user_lockscript,
             x_lock_address,
             signer_lockscript,
@@ -111,6 +360,11 @@ impl ToCKBCellDataView {
             redeemer_lockscript,
             liquidation_trigger_lockscript,
             x_extra,
+            redeem_amount,
+            signer_group,
+            signer_fee_rate,
+            pledge,
+            params_snapshot,
         })
     }
 
@@ -125,12 +379,44 @@ impl ToCKBCellDataView {
             }
             XExtraView::Eth(eth_extra) => {
                 let eth_extra_mol = EthExtra::new_builder()
-                    .dummy(eth_extra.dummy.to_vec().into())
+                    .lock_tx_hash(eth_extra.lock_tx_hash.to_vec().try_into()?)
+                    .lock_tx_index(eth_extra.lock_tx_index.into())
+                    .lock_block_number(eth_extra.lock_block_number.into())
                     .build();
                 XExtraUnion::EthExtra(eth_extra_mol)
             }
+            XExtraView::Ltc(ltc_extra) => {
+                let ltc_extra_mol = LtcExtra::new_builder()
+                    .lock_tx_hash(ltc_extra.lock_tx_hash.to_vec().try_into()?)
+                    .lock_vout_index(ltc_extra.lock_vout_index.into())
+                    .build();
+                XExtraUnion::LtcExtra(ltc_extra_mol)
+            }
+            XExtraView::Erc20(erc20_extra) => {
+                let erc20_extra_mol = Erc20Extra::new_builder()
+                    .token_address(erc20_extra.token_address.to_vec().into())
+                    .lock_tx_hash(erc20_extra.lock.lock_tx_hash.to_vec().try_into()?)
+                    .lock_tx_index(erc20_extra.lock.lock_tx_index.into())
+                    .lock_block_number(erc20_extra.lock.lock_block_number.into())
+                    .build();
+                XExtraUnion::Erc20Extra(erc20_extra_mol)
+            }
         };
         let x_extra = XExtra::new_builder().set(x_extra_union).build();
+        let mut members = Vec::with_capacity(self.signer_group.members.len());
+        for member in self.signer_group.members.iter() {
+            members.push(
+                SignerMember::new_builder()
+                    .lockscript(basic::Script::from_slice(&member.lockscript)?)
+                    .share(member.share.into())
+                    .x_pubkey(member.x_pubkey.to_vec().into())
+                    .build(),
+            );
+        }
+        let signer_group = SignerGroup::new_builder()
+            .threshold(self.signer_group.threshold.into())
+            .members(SignerMemberVec::new_builder().set(members).build())
+            .build();
         let mol_obj = ToCKBCellData::new_builder()
             .status(self.status.int_value().into())
             .lot_size(self.lot_size.into())
@@ -143,6 +429,12 @@ impl ToCKBCellDataView {
                 &self.liquidation_trigger_lockscript,
             )?)
             .x_extra(x_extra)
+            .amount(self.amount.into())
+            .redeem_amount(self.redeem_amount.into())
+            .signer_group(signer_group)
+            .signer_fee_rate(self.signer_fee_rate.into())
+            .pledge(self.pledge.into())
+            .params_snapshot(self.params_snapshot.as_molecule())
             .build();
 
         Ok(mol_obj.as_bytes())
@@ -152,37 +444,156 @@ impl ToCKBCellDataView {
         self.lot_size
     }
 
-    // Caller should make sure xchain kind is Btc
-    pub fn get_btc_lot_size(&self) -> Result<BtcLotSize, Error> {
-        BtcLotSize::from_int(self.lot_size).map_err(|_e| Error::LotSizeInvalid)
-    }
-
-    // Caller should make sure xchain kind is Eth
-    pub fn get_eth_lot_size(&self) -> Result<EthLotSize, Error> {
-        EthLotSize::from_int(self.lot_size).map_err(|_e| Error::LotSizeInvalid)
-    }
-
     pub fn get_xchain_kind(&self) -> XChainKind {
         match self.x_extra {
             XExtraView::Btc(_) => XChainKind::Btc,
             XExtraView::Eth(_) => XChainKind::Eth,
+            XExtraView::Ltc(_) => XChainKind::Ltc,
+            XExtraView::Erc20(_) => XChainKind::Erc20,
         }
     }
 
-    pub fn get_lot_xt_amount(&self) -> Result<u128, Error> {
-        Ok(match self.get_xchain_kind() {
-            XChainKind::Btc => {
-                let btc_lot_size = self.get_btc_lot_size()?;
-                btc_lot_size.get_sudt_amount()
-            }
-            XChainKind::Eth => {
-                let eth_lot_size = self.get_eth_lot_size()?;
-                eth_lot_size.get_sudt_amount()
-            }
-        })
+    /// XT amount of the deposit in base units, i.e. satoshi for Btc, wei for Eth,
+    /// litoshi for Ltc and the smallest unit of the token for Erc20
+    pub fn get_lot_xt_amount(&self) -> u128 {
+        self.amount
+    }
+
+    /// Args of the sudt of the XT issued under the toCKB lock hash, the XT of each ERC-20 token
+    /// is a different sudt with the token address after the lock hash.
+    pub fn get_xt_args(&self, toCKB_lock_hash: &[u8]) -> Vec<u8> {
+        let mut args = toCKB_lock_hash.to_vec();
+        if let Some(token_address) = self.x_extra.get_token_address() {
+            args.extend_from_slice(token_address);
+        }
+        args
+    }
+
+    pub fn is_partial_redeem(&self) -> bool {
+        self.redeem_amount != 0
+    }
+
+    /// XT amount the redeemer burned and expects on XChain, the whole lot unless partially redeeming
+    pub fn get_redeem_xt_amount(&self) -> u128 {
+        if self.is_partial_redeem() {
+            self.redeem_amount
+        } else {
+            self.amount
+        }
+    }
+
+    /// Redeem `redeem_amount` out of the lot, redeeming the whole lot is not a partial redemption
+    pub fn set_redeem_xt_amount(&mut self, redeem_amount: u128) {
+        self.redeem_amount = if redeem_amount == self.amount {
+            0
+        } else {
+            redeem_amount
+        };
+    }
+
+    /// XT the signer gets for `xt_amount` minted or redeemed, at the fee rate of the deposit
+    pub fn get_signer_fee(&self, xt_amount: u128) -> u128 {
+        xt_amount * self.signer_fee_rate as u128 / SIGNER_FEE_RATE_DENOMINATOR
+    }
+
+    /// The signer the user picked from the signer registry at deposit request, a deposit request
+    /// without one leaves signer_lockscript as the default script
+    pub fn get_chosen_signer(&self) -> Option<&[u8]> {
+        if self.signer_lockscript.as_ref() == basic::Script::default().as_slice() {
+            None
+        } else {
+            Some(self.signer_lockscript.as_ref())
+        }
+    }
+
+    pub fn is_signer_group(&self) -> bool {
+        !self.signer_group.members.is_empty()
+    }
+
+    /// Split `value` paid to the signer side among the group by shares, the last member takes
+    /// the rounding remainder. A single signer gets the whole value.
+    pub fn get_signer_payouts(&self, value: u64) -> Vec<(&[u8], u64)> {
+        let members = &self.signer_group.members;
+        if members.is_empty() {
+            let mut payouts = Vec::with_capacity(1);
+            payouts.push((self.signer_lockscript.as_ref(), value));
+            return payouts;
+        }
+        let total_share: u128 = members.iter().map(|member| member.share as u128).sum();
+        let mut remain = value;
+        members
+            .iter()
+            .enumerate()
+            .map(|(i, member)| {
+                let payout = if i + 1 == members.len() {
+                    remain
+                } else {
+                    (value as u128 * member.share as u128 / total_share) as u64
+                };
+                remain -= payout;
+                (member.lockscript.as_ref(), payout)
+            })
+            .collect()
+    }
+
+    /// Drop the redeemed part from the lot after it is paid on XChain, the rest stays in the cell
+    pub fn settle_partial_redeem(&mut self) {
+        self.amount -= self.redeem_amount;
+        self.redeem_amount = 0;
+        self.lot_size = ARBITRARY_LOT_SIZE;
+    }
+}
+
+/// Range of the XT amount of a deposit, the parts of a partial redemption are bounded by it too
+pub fn get_lot_amount_range(x_kind: XChainKind) -> (u128, u128) {
+    match x_kind {
+        XChainKind::Btc => BTC_LOT_AMOUNT_RANGE,
+        XChainKind::Eth => ETH_LOT_AMOUNT_RANGE,
+        XChainKind::Ltc => LTC_LOT_AMOUNT_RANGE,
+        XChainKind::Erc20 => ERC20_LOT_AMOUNT_RANGE,
     }
 }
 
+fn get_legacy_lot_amount(x_kind: XChainKind, lot_size: u8) -> Result<u128, Error> {
+    Ok(match x_kind {
+        XChainKind::Btc => BtcLotSize::from_int(lot_size)
+            .map_err(|_e| Error::LotSizeInvalid)?
+            .get_sudt_amount(),
+        XChainKind::Eth => EthLotSize::from_int(lot_size)
+            .map_err(|_e| Error::LotSizeInvalid)?
+            .get_sudt_amount(),
+        XChainKind::Ltc => LtcLotSize::from_int(lot_size)
+            .map_err(|_e| Error::LotSizeInvalid)?
+            .get_sudt_amount(),
+        // ERC-20 deposits came after the amount field, they never have a lot size enum
+        XChainKind::Erc20 => return Err(Error::LotSizeInvalid),
+    })
+}
+
+/// Convert data of a cell created before the amount field into the current layout,
+/// the amount is derived from its lot size enum and the params are the compiled defaults it
+/// was created under.
+fn upgrade_legacy_data(data: &[u8], x_kind: XChainKind) -> Result<ToCKBCellData, Error> {
+    LegacyToCKBCellDataReader::verify(data, false).map_err(|_| Error::Encoding)?;
+    let legacy = LegacyToCKBCellDataReader::new_unchecked(data).to_entity();
+    let amount = get_legacy_lot_amount(x_kind, legacy.lot_size().as_slice()[0])?;
+    Ok(ToCKBCellData::new_builder()
+        .status(legacy.status())
+        .lot_size(legacy.lot_size())
+        .user_lockscript(legacy.user_lockscript())
+        .x_lock_address(legacy.x_lock_address())
+        .signer_lockscript(legacy.signer_lockscript())
+        .x_unlock_address(legacy.x_unlock_address())
+        .redeemer_lockscript(legacy.redeemer_lockscript())
+        .liquidation_trigger_lockscript(legacy.liquidation_trigger_lockscript())
+        .x_extra(legacy.x_extra())
+        .amount(amount.into())
+        .signer_fee_rate(SIGNER_FEE_RATE.into())
+        .pledge(PLEDGE.into())
+        .params_snapshot(ParamsSnapshotView::default().as_molecule())
+        .build())
+}
+
 #[repr(u8)]
 #[derive(Clone, Copy, IntEnum, PartialEq, Debug)]
 pub enum ToCKBStatus {
@@ -194,6 +605,7 @@ pub enum ToCKBStatus {
     Undercollateral = 6,
     FaultyWhenWarranty = 7,
     FaultyWhenRedeeming = 8,
+    Handover = 9,
 }
 
 #[repr(u8)]
@@ -240,21 +652,97 @@ impl EthLotSize {
     }
 }
 
+#[repr(u8)]
+#[derive(Clone, Copy, IntEnum, PartialEq)]
+pub enum LtcLotSize {
+    Single = 1,
+    Five = 2,
+    Ten = 3,
+    Fifty = 4,
+}
+
+impl LtcLotSize {
+    pub fn get_sudt_amount(&self) -> u128 {
+        use LtcLotSize::*;
+        match self {
+            Single => LTC_UNIT,
+            Five => LTC_UNIT * 5,
+            Ten => LTC_UNIT * 10,
+            Fifty => LTC_UNIT * 50,
+        }
+    }
+}
+
 pub struct ToCKBTypeArgsView {
     pub xchain_kind: XChainKind,
+    pub xchain_network: u8,
+    pub header_relay: basic::Byte32,
     pub cell_id: basic::OutPoint,
 }
 
 impl ToCKBTypeArgsView {
     pub fn from_slice(slice: &[u8]) -> Result<ToCKBTypeArgsView, Error> {
+        if slice.len() == LegacyToCKBTypeArgsReader::TOTAL_SIZE {
+            return Self::from_legacy_slice(slice);
+        }
         ToCKBTypeArgsReader::verify(slice, false).map_err(|_| Error::Encoding)?;
         let args_reader = ToCKBTypeArgsReader::new_unchecked(slice);
         let xchain_kind = args_reader.xchain_kind().as_slice()[0];
         let xchain_kind = XChainKind::from_int(xchain_kind)?;
+        let xchain_network = args_reader.xchain_network().as_slice()[0];
+        let header_relay = args_reader.header_relay().to_entity();
+        let cell_id = args_reader.cell_id().to_entity();
+        Ok(ToCKBTypeArgsView {
+            xchain_kind,
+            xchain_network,
+            header_relay,
+            cell_id,
+        })
+    }
+
+    /// Cells created before the network and header relay fields were only deployed on BTC
+    /// regtest, and check proofs against the difficulty oracle.
+    fn from_legacy_slice(slice: &[u8]) -> Result<ToCKBTypeArgsView, Error> {
+        LegacyToCKBTypeArgsReader::verify(slice, false).map_err(|_| Error::Encoding)?;
+        let args_reader = LegacyToCKBTypeArgsReader::new_unchecked(slice);
+        let xchain_kind = args_reader.xchain_kind().as_slice()[0];
+        let xchain_kind = XChainKind::from_int(xchain_kind)?;
         let cell_id = args_reader.cell_id().to_entity();
         Ok(ToCKBTypeArgsView {
             xchain_kind,
+            xchain_network: BtcNetwork::Regtest.int_value(),
+            header_relay: Default::default(),
             cell_id,
         })
     }
+
+    /// Return the type hash of the header relay cell which XChain proofs are checked against,
+    /// `None` means proofs are checked against the difficulty oracle.
+    pub fn get_header_relay(&self) -> Option<[u8; 32]> {
+        let mut hash = [0u8; 32];
+        hash.copy_from_slice(self.header_relay.as_slice());
+        if hash == [0u8; 32] {
+            None
+        } else {
+            Some(hash)
+        }
+    }
+
+    pub fn get_btc_network(&self) -> Result<BtcNetwork, Error> {
+        Ok(BtcNetwork::from_int(self.xchain_network)?)
+    }
+
+    pub fn get_ltc_network(&self) -> Result<LtcNetwork, Error> {
+        Ok(LtcNetwork::from_int(self.xchain_network)?)
+    }
+
+    /// Decode an address of the UTXO chain and network of the cell into the script_pubkey it
+    /// pays to.
+    pub fn address_to_script(&self, addr: &[u8]) -> Result<Vec<u8>, Error> {
+        match self.xchain_kind {
+            XChainKind::Btc => btc_address::address_to_script(addr, self.get_btc_network()?),
+            XChainKind::Ltc => btc_address::address_to_script(addr, self.get_ltc_network()?),
+            XChainKind::Eth | XChainKind::Erc20 => Err(Error::XChainMismatch),
+        }
+    }
 }